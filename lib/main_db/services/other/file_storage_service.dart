@@ -63,6 +63,9 @@ class FileStorageService {
       tempDir: tempDir.path,
       metadata: const [],
       chunkSize: const crypt_types.FrbChunkSizePreset.desktop(),
+      cipherSuite: crypt_types.FrbCipherSuite.xChaCha20Poly1305,
+      shredSource: false,
+      hideLengths: false,
     );
 
     String? resultPath;
@@ -47,6 +47,85 @@ Stream<FrbEncryptEvent> encryptFile({required FrbEncryptOptions opts}) =>
 Stream<FrbDecryptEvent> decryptFile({required FrbDecryptOptions opts}) =>
     RustLib.instance.api.crateApiCryptApiDecryptFile(opts: opts);
 
+/// Stream the plaintext of an encrypted file as it is decrypted, e.g. for
+/// media playback, without writing it to disk.
+///
+/// Blocks arrive in file order as `FrbDecryptChunk::Data`. At most
+/// `opts.window` blocks are sent ahead: call `decrypt_stream_ack` with
+/// `opts.stream_id` once a block has been consumed. While no block is
+/// acknowledged, an empty one is sent every few seconds to check that the
+/// stream is still listened to; acknowledge it like any other. Data reaches
+/// Dart before the whole file is authenticated; if the stream ends with
+/// `FrbDecryptChunk::Error` instead of `Done`, discard what was received.
+///
+/// # Dart usage
+/// ```dart
+/// await for (final chunk in api.decryptStream(opts: opts)) {
+///   switch (chunk) {
+///     case FrbDecryptChunk_Data(:final field0):
+///       await player.feed(field0);
+///       decryptStreamAck(streamId: opts.streamId);
+///     case FrbDecryptChunk_Done(:final field0):
+///       player.finish(field0);
+///     case FrbDecryptChunk_Error(:final field0):
+///       player.abort(field0);
+///   }
+/// }
+/// ```
+Stream<FrbDecryptChunk> decryptStream({
+  required FrbStreamDecryptOptions opts,
+}) => RustLib.instance.api.crateApiCryptApiDecryptStream(opts: opts);
+
+/// Acknowledge one block received from `decrypt_stream`, allowing the next
+/// one to be sent.
+void decryptStreamAck({required String streamId}) =>
+    RustLib.instance.api.crateApiCryptApiDecryptStreamAck(streamId: streamId);
+
+/// Stop a running `decrypt_stream`; it ends with `FrbDecryptChunk::Error`.
+void decryptStreamCancel({required String streamId}) =>
+    RustLib.instance.api.crateApiCryptApiDecryptStreamCancel(
+      streamId: streamId,
+    );
+
+/// Start encrypting data pushed from Dart into `opts.output_path`, e.g. a
+/// recording or an upload received in pieces.
+///
+/// Feed the plaintext with `encrypt_stream_push` and complete the file with
+/// `encrypt_stream_finish`. The output is written to `<output_path>.part`
+/// and only renamed into place when the stream finishes successfully.
+///
+/// # Dart usage
+/// ```dart
+/// await encryptStream(opts: opts);
+/// await for (final block in source) {
+///   await encryptStreamPush(streamId: opts.streamId, data: block);
+/// }
+/// final result = await encryptStreamFinish(streamId: opts.streamId);
+/// ```
+Future<void> encryptStream({required FrbStreamEncryptOptions opts}) =>
+    RustLib.instance.api.crateApiCryptApiEncryptStream(opts: opts);
+
+/// Queue the next block of plaintext. Completes once the encryptor has
+/// room for it, so awaiting each push keeps memory bounded.
+Future<void> encryptStreamPush({
+  required String streamId,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiCryptApiEncryptStreamPush(
+  streamId: streamId,
+  data: data,
+);
+
+/// End the input of an encrypt stream and wait for the file to be complete.
+Future<FrbStreamEncryptResult> encryptStreamFinish({
+  required String streamId,
+}) => RustLib.instance.api.crateApiCryptApiEncryptStreamFinish(
+  streamId: streamId,
+);
+
+/// Abandon an encrypt stream; its partial output is removed.
+void encryptStreamAbort({required String streamId}) =>
+    RustLib.instance.api.crateApiCryptApiEncryptStreamAbort(streamId: streamId);
+
 /// Encrypt multiple files sequentially.
 ///
 /// Events (per-file progress, per-file results, final summary) are emitted
@@ -76,6 +155,32 @@ Stream<FrbBatchDecryptEvent> decryptBatch({
   required FrbBatchDecryptOptions opts,
 }) => RustLib.instance.api.crateApiCryptApiDecryptBatch(opts: opts);
 
+/// Decrypt a file straight into memory, e.g. to show a photo or PDF
+/// without leaving plaintext in `attachments_decrypted/`.
+///
+/// Gzip is undone in memory. Fails if the decrypted content would exceed
+/// `max_size` bytes; use `read_encrypted_header` for the file name.
+Future<Uint8List> decryptToMemory({
+  required String inputPath,
+  required String password,
+  required BigInt maxSize,
+}) => RustLib.instance.api.crateApiCryptApiDecryptToMemory(
+  inputPath: inputPath,
+  password: password,
+  maxSize: maxSize,
+);
+
+/// Re-encrypt `.enc` files in place under a new password, e.g. for a
+/// vault-wide password change or to raise the Argon2 cost.
+///
+/// Files are streamed chunk by chunk into a temporary file and atomically
+/// swapped in; no plaintext reaches the disk and UUIDs and metadata are
+/// kept. Events are emitted like `decrypt_batch`; a failed file is left
+/// untouched and processing continues.
+Stream<FrbBatchReencryptEvent> reencryptBatch({
+  required FrbBatchReencryptOptions opts,
+}) => RustLib.instance.api.crateApiCryptApiReencryptBatch(opts: opts);
+
 /// Read only the header of an encrypted file without decrypting the data.
 ///
 /// Returns the decoded metadata (filename, extension, UUID, tags, etc.)
@@ -90,3 +195,143 @@ Future<FrbDecryptedMetadata> readEncryptedHeader({
   inputPath: inputPath,
   password: password,
 );
+
+/// Edit the tags and other key/value metadata of an encrypted file without
+/// re-encrypting its payload.
+///
+/// Usually only the header is rewritten; a file whose metadata outgrows the
+/// space reserved for it is copied once. Signed files are refused, as their
+/// signature covers the metadata.
+Future<FrbMetadataUpdateResult> updateMetadata({
+  required String inputPath,
+  required String password,
+  required FrbMetadataPatch patch,
+}) => RustLib.instance.api.crateApiCryptApiUpdateMetadata(
+  inputPath: inputPath,
+  password: password,
+  patch: patch,
+);
+
+/// Estimate the disk space `encrypt_file` needs with `opts` and compare it
+/// with the free space of the output and temp directories.
+///
+/// Sizes assume compression gains nothing, so they are upper bounds. A
+/// directory is walked to add up its size. `encrypt_file` fails fast with
+/// a "not enough space" error when `shortfall` would be set.
+Future<FrbSpacePlan> planEncrypt({required FrbEncryptOptions opts}) =>
+    RustLib.instance.api.crateApiCryptApiPlanEncrypt(opts: opts);
+
+/// Estimate the disk space `decrypt_file` needs with `opts`.
+///
+/// Unlocks the header with the password to learn the sizes. Compressed
+/// content grows when unpacked by an amount only known afterwards; such
+/// plans are marked `estimated`.
+Future<FrbSpacePlan> planDecrypt({required FrbDecryptOptions opts}) =>
+    RustLib.instance.api.crateApiCryptApiPlanDecrypt(opts: opts);
+
+/// Read the public header of an encrypted file without the password.
+///
+/// Reports the format version, Argon2id cost, chunk size, parity layer,
+/// signature scheme and an estimate of the payload size. Useful to show a
+/// KDF cost warning before asking for the password, or to spot truncated
+/// files (`whole_chunks == false`).
+Future<FrbInspectResult> inspectEncryptedFile({required String inputPath}) =>
+    RustLib.instance.api.crateApiCryptApiInspectEncryptedFile(
+      inputPath: inputPath,
+    );
+
+/// Generate a new Ed25519 identity for signing encrypted files.
+///
+/// Keep `secret_key` in secure storage and share `public_key` with
+/// recipients so they can check `FrbDecryptResult.signature`.
+FrbSigningIdentity generateSigningIdentity() =>
+    RustLib.instance.api.crateApiCryptApiGenerateSigningIdentity();
+
+/// Derive the 32-byte public key of a stored Ed25519 secret key.
+Uint8List signingPublicKey({required List<int> secretKey}) =>
+    RustLib.instance.api.crateApiCryptApiSigningPublicKey(secretKey: secretKey);
+
+/// Authenticate every chunk of an encrypted file and check its signature
+/// without writing any plaintext to disk.
+///
+/// Damaged chunks of files with a parity layer are rebuilt in memory and
+/// counted in `repaired_chunks`. A forged signature fails the call.
+Future<FrbVerifyResult> verifyFile({
+  required String inputPath,
+  required String password,
+}) => RustLib.instance.api.crateApiCryptApiVerifyFile(
+  inputPath: inputPath,
+  password: password,
+);
+
+/// Start the local media server, or return the running one.
+///
+/// It listens on 127.0.0.1 only, on a random port, and rejects requests
+/// without the per-session token. Register files with `media_server_add`
+/// and hand the returned URL to a video or audio player.
+Future<FrbMediaServerInfo> mediaServerStart() =>
+    RustLib.instance.api.crateApiCryptApiMediaServerStart();
+
+/// Stop the media server. Every source is forgotten and open connections
+/// are closed.
+void mediaServerStop() =>
+    RustLib.instance.api.crateApiCryptApiMediaServerStop();
+
+/// Unlock an encrypted file and serve its plaintext with HTTP `Range`
+/// support, decrypting only the chunks each request covers.
+///
+/// The server must be running. Gzip-compressed files are refused since
+/// their offsets cannot be mapped to chunks. The file's keys stay in memory
+/// until `media_server_remove` or `media_server_stop`.
+///
+/// # Dart usage
+/// ```dart
+/// await mediaServerStart();
+/// final source = await mediaServerAdd(inputPath: path, password: pw);
+/// await player.open(Media(source.url));
+/// // ...
+/// mediaServerRemove(sourceId: source.sourceId);
+/// ```
+Future<FrbMediaSource> mediaServerAdd({
+  required String inputPath,
+  required String password,
+}) => RustLib.instance.api.crateApiCryptApiMediaServerAdd(
+  inputPath: inputPath,
+  password: password,
+);
+
+/// Stop serving a source; its URL answers 404 from now on.
+void mediaServerRemove({required String sourceId}) =>
+    RustLib.instance.api.crateApiCryptApiMediaServerRemove(sourceId: sourceId);
+
+/// Track and bound the plaintext in the decrypted-attachments directory.
+///
+/// From now on every `decrypt_file` / `decrypt_batch` output inside
+/// `opts.root_dir` is recorded. Entries are shredded once the cache exceeds
+/// `max_bytes` (least recently used first) or has not been accessed for
+/// `ttl_secs`; expired entries are also swept every minute. Calling it
+/// again replaces the settings. Returns what was shredded right away.
+Future<FrbShredReport> decryptedCacheConfigure({
+  required FrbDecryptedCacheOptions opts,
+}) => RustLib.instance.api.crateApiCryptApiDecryptedCacheConfigure(opts: opts);
+
+/// Mark a decrypted file as just opened, so the TTL and LRU order start
+/// over for it.
+void decryptedCacheTouch({required String path}) =>
+    RustLib.instance.api.crateApiCryptApiDecryptedCacheTouch(path: path);
+
+/// Shred the entries past their TTL or over the byte budget now.
+Future<FrbShredReport> decryptedCachePurgeExpired() =>
+    RustLib.instance.api.crateApiCryptApiDecryptedCachePurgeExpired();
+
+/// Shred everything in the cache directory, tracked or not. Call it on
+/// auto-lock and when the store is closed.
+///
+/// Check `all_shredded`: a file still held open by a viewer may survive and
+/// is retried by the next purge.
+Future<FrbShredReport> decryptedCachePurgeAll() =>
+    RustLib.instance.api.crateApiCryptApiDecryptedCachePurgeAll();
+
+/// Size of the tracked cache. Fails if it is not configured.
+FrbDecryptedCacheStats decryptedCacheStats() =>
+    RustLib.instance.api.crateApiCryptApiDecryptedCacheStats();
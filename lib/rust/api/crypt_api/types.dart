@@ -9,7 +9,32 @@ import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'types.freezed.dart';
 
 // These functions are ignored because they are not marked as `pub`: `bytes`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+
+/// Argon2id parameters stored in the public header.
+class FrbArgon2Params {
+  final int tCost;
+  final int mCostKib;
+  final int parallelism;
+
+  const FrbArgon2Params({
+    required this.tCost,
+    required this.mCostKib,
+    required this.parallelism,
+  });
+
+  @override
+  int get hashCode => tCost.hashCode ^ mCostKib.hashCode ^ parallelism.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbArgon2Params &&
+          runtimeType == other.runtimeType &&
+          tCost == other.tCost &&
+          mCostKib == other.mCostKib &&
+          parallelism == other.parallelism;
+}
 
 @freezed
 sealed class FrbBatchDecryptEvent with _$FrbBatchDecryptEvent {
@@ -158,6 +183,18 @@ class FrbBatchEncryptOptions {
   /// Chunk-size preset.
   final FrbChunkSizePreset chunkSize;
 
+  /// AEAD for the new files.
+  final FrbCipherSuite cipherSuite;
+
+  /// Optional Ed25519 secret key (32 bytes) used to sign every file.
+  final Uint8List? signingKey;
+
+  /// Pad every output so its size does not give away the input size.
+  final bool hideLengths;
+
+  /// Split every output into volumes of at most this many bytes.
+  final BigInt? volumeSize;
+
   const FrbBatchEncryptOptions({
     required this.inputPaths,
     required this.outputDir,
@@ -166,6 +203,10 @@ class FrbBatchEncryptOptions {
     this.tempDir,
     required this.metadata,
     required this.chunkSize,
+    required this.cipherSuite,
+    this.signingKey,
+    required this.hideLengths,
+    this.volumeSize,
   });
 
   @override
@@ -176,7 +217,11 @@ class FrbBatchEncryptOptions {
       gzipCompressed.hashCode ^
       tempDir.hashCode ^
       metadata.hashCode ^
-      chunkSize.hashCode;
+      chunkSize.hashCode ^
+      cipherSuite.hashCode ^
+      signingKey.hashCode ^
+      hideLengths.hashCode ^
+      volumeSize.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -189,7 +234,11 @@ class FrbBatchEncryptOptions {
           gzipCompressed == other.gzipCompressed &&
           tempDir == other.tempDir &&
           metadata == other.metadata &&
-          chunkSize == other.chunkSize;
+          chunkSize == other.chunkSize &&
+          cipherSuite == other.cipherSuite &&
+          signingKey == other.signingKey &&
+          hideLengths == other.hideLengths &&
+          volumeSize == other.volumeSize;
 }
 
 /// Summary returned as the final `AllDone` event of `encrypt_batch`.
@@ -230,6 +279,112 @@ class FrbBatchError {
           error == other.error;
 }
 
+@freezed
+sealed class FrbBatchReencryptEvent with _$FrbBatchReencryptEvent {
+  const FrbBatchReencryptEvent._();
+
+  /// Progress for the currently processed file.
+  const factory FrbBatchReencryptEvent.fileProgress({
+    required int fileIndex,
+    required int totalFiles,
+    required String currentFile,
+    required FrbProgressEvent progress,
+  }) = FrbBatchReencryptEvent_FileProgress;
+
+  /// One file was re-encrypted and replaced.
+  const factory FrbBatchReencryptEvent.fileDone({
+    required int fileIndex,
+    required FrbReencryptResult result,
+  }) = FrbBatchReencryptEvent_FileDone;
+
+  /// One file failed and was left untouched; processing continues for
+  /// the rest.
+  const factory FrbBatchReencryptEvent.fileError({
+    required int fileIndex,
+    required String inputPath,
+    required String error,
+  }) = FrbBatchReencryptEvent_FileError;
+
+  /// Emitted once when all files are processed.
+  const factory FrbBatchReencryptEvent.allDone(FrbBatchReencryptResult field0) =
+      FrbBatchReencryptEvent_AllDone;
+}
+
+/// Options for re-encrypting `.enc` files in place, e.g. for a vault-wide
+/// password change.
+class FrbBatchReencryptOptions {
+  /// Paths to encrypted `.enc` files; each is replaced atomically.
+  final List<String> inputPaths;
+
+  /// Current password.
+  final String oldPassword;
+
+  /// New password.
+  final String newPassword;
+
+  /// Argon2id cost for the new password. Library defaults if `None`.
+  final FrbArgon2Params? argon2Params;
+
+  /// New chunk size. Each file keeps its own if `None`.
+  final FrbChunkSizePreset? chunkSize;
+
+  /// Optional Ed25519 secret key (32 bytes) used to re-sign every file.
+  /// Without it existing signatures are dropped.
+  final Uint8List? signingKey;
+
+  const FrbBatchReencryptOptions({
+    required this.inputPaths,
+    required this.oldPassword,
+    required this.newPassword,
+    this.argon2Params,
+    this.chunkSize,
+    this.signingKey,
+  });
+
+  @override
+  int get hashCode =>
+      inputPaths.hashCode ^
+      oldPassword.hashCode ^
+      newPassword.hashCode ^
+      argon2Params.hashCode ^
+      chunkSize.hashCode ^
+      signingKey.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbBatchReencryptOptions &&
+          runtimeType == other.runtimeType &&
+          inputPaths == other.inputPaths &&
+          oldPassword == other.oldPassword &&
+          newPassword == other.newPassword &&
+          argon2Params == other.argon2Params &&
+          chunkSize == other.chunkSize &&
+          signingKey == other.signingKey;
+}
+
+/// Summary returned as the final `AllDone` event of `reencrypt_batch`.
+class FrbBatchReencryptResult {
+  final List<FrbReencryptResult> succeeded;
+  final List<FrbBatchError> failed;
+
+  const FrbBatchReencryptResult({
+    required this.succeeded,
+    required this.failed,
+  });
+
+  @override
+  int get hashCode => succeeded.hashCode ^ failed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbBatchReencryptResult &&
+          runtimeType == other.runtimeType &&
+          succeeded == other.succeeded &&
+          failed == other.failed;
+}
+
 @freezed
 sealed class FrbChunkSizePreset with _$FrbChunkSizePreset {
   const FrbChunkSizePreset._();
@@ -245,6 +400,32 @@ sealed class FrbChunkSizePreset with _$FrbChunkSizePreset {
       FrbChunkSizePreset_Custom;
 }
 
+/// AEAD recorded in the public header.
+enum FrbCipherSuite {
+  /// XChaCha20-Poly1305 (default).
+  xChaCha20Poly1305,
+  /// AES-256-GCM-SIV, faster on CPUs with AES instructions.
+  aes256GcmSiv,
+}
+
+@freezed
+sealed class FrbDecryptChunk with _$FrbDecryptChunk {
+  const FrbDecryptChunk._();
+
+  /// Next block of plaintext, in file order, or an empty probe. Call
+  /// `decrypt_stream_ack` once it has been consumed.
+  const factory FrbDecryptChunk.data(Uint8List field0) = FrbDecryptChunk_Data;
+
+  /// Emitted exactly once after the last block, when the whole file
+  /// (and its signature) has been authenticated.
+  const factory FrbDecryptChunk.done(FrbStreamDecryptResult field0) =
+      FrbDecryptChunk_Done;
+
+  /// Operation failed; discard the blocks received so far. Always the
+  /// last event in the stream.
+  const factory FrbDecryptChunk.error(String field0) = FrbDecryptChunk_Error;
+}
+
 @freezed
 sealed class FrbDecryptEvent with _$FrbDecryptEvent {
   const FrbDecryptEvent._();
@@ -325,10 +506,18 @@ class FrbDecryptResult {
   /// Decoded metadata from the encrypted header.
   final FrbDecryptedMetadata metadata;
 
-  const FrbDecryptResult({required this.outputPath, required this.metadata});
+  /// Signature check outcome.
+  final FrbSignatureStatus signature;
+
+  const FrbDecryptResult({
+    required this.outputPath,
+    required this.metadata,
+    required this.signature,
+  });
 
   @override
-  int get hashCode => outputPath.hashCode ^ metadata.hashCode;
+  int get hashCode =>
+      outputPath.hashCode ^ metadata.hashCode ^ signature.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -336,7 +525,70 @@ class FrbDecryptResult {
       other is FrbDecryptResult &&
           runtimeType == other.runtimeType &&
           outputPath == other.outputPath &&
-          metadata == other.metadata;
+          metadata == other.metadata &&
+          signature == other.signature;
+}
+
+/// Settings of the decrypted-attachments cache.
+class FrbDecryptedCacheOptions {
+  /// Directory decrypted attachments are written to. Only outputs inside
+  /// it are tracked; files already in it are adopted.
+  final String rootDir;
+
+  /// Byte budget; least recently used entries are shredded beyond it.
+  final BigInt? maxBytes;
+
+  /// Entries not accessed for this many seconds are shredded.
+  final BigInt? ttlSecs;
+
+  const FrbDecryptedCacheOptions({
+    required this.rootDir,
+    this.maxBytes,
+    this.ttlSecs,
+  });
+
+  @override
+  int get hashCode => rootDir.hashCode ^ maxBytes.hashCode ^ ttlSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbDecryptedCacheOptions &&
+          runtimeType == other.runtimeType &&
+          rootDir == other.rootDir &&
+          maxBytes == other.maxBytes &&
+          ttlSecs == other.ttlSecs;
+}
+
+/// Current state of the decrypted-attachments cache.
+class FrbDecryptedCacheStats {
+  /// Canonical cache directory.
+  final String rootDir;
+
+  /// Tracked files and directories.
+  final BigInt entryCount;
+
+  /// Bytes they occupy.
+  final BigInt totalBytes;
+
+  const FrbDecryptedCacheStats({
+    required this.rootDir,
+    required this.entryCount,
+    required this.totalBytes,
+  });
+
+  @override
+  int get hashCode =>
+      rootDir.hashCode ^ entryCount.hashCode ^ totalBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbDecryptedCacheStats &&
+          runtimeType == other.runtimeType &&
+          rootDir == other.rootDir &&
+          entryCount == other.entryCount &&
+          totalBytes == other.totalBytes;
 }
 
 /// Metadata decoded from an encrypted header.
@@ -348,6 +600,9 @@ class FrbDecryptedMetadata {
   final String uuid;
   final List<FrbKeyValue> metadata;
 
+  /// Ed25519 public key of the signer (signed files only).
+  final Uint8List? signerPublicKey;
+
   const FrbDecryptedMetadata({
     required this.originalFilename,
     required this.originalExtension,
@@ -355,6 +610,7 @@ class FrbDecryptedMetadata {
     required this.originalSize,
     required this.uuid,
     required this.metadata,
+    this.signerPublicKey,
   });
 
   @override
@@ -364,7 +620,8 @@ class FrbDecryptedMetadata {
       gzipCompressed.hashCode ^
       originalSize.hashCode ^
       uuid.hashCode ^
-      metadata.hashCode;
+      metadata.hashCode ^
+      signerPublicKey.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -376,7 +633,8 @@ class FrbDecryptedMetadata {
           gzipCompressed == other.gzipCompressed &&
           originalSize == other.originalSize &&
           uuid == other.uuid &&
-          metadata == other.metadata;
+          metadata == other.metadata &&
+          signerPublicKey == other.signerPublicKey;
 }
 
 @freezed
@@ -426,6 +684,24 @@ class FrbEncryptOptions {
   /// Chunk-size preset (desktop is the default).
   final FrbChunkSizePreset chunkSize;
 
+  /// AEAD for the new file (XChaCha20-Poly1305 is the default).
+  final FrbCipherSuite cipherSuite;
+
+  /// Optional Ed25519 secret key (32 bytes) used to sign the file.
+  final Uint8List? signingKey;
+
+  /// Overwrite and delete the input file or directory once the
+  /// encrypted file is in place. Never runs if encryption fails.
+  final bool shredSource;
+
+  /// Pad the payload and metadata so the output size does not give
+  /// away the exact input size.
+  final bool hideLengths;
+
+  /// Split the output into `.001`, `.002`, … volumes of at most this
+  /// many bytes.
+  final BigInt? volumeSize;
+
   const FrbEncryptOptions({
     required this.inputPath,
     required this.outputDir,
@@ -436,6 +712,11 @@ class FrbEncryptOptions {
     this.tempDir,
     required this.metadata,
     required this.chunkSize,
+    required this.cipherSuite,
+    this.signingKey,
+    required this.shredSource,
+    required this.hideLengths,
+    this.volumeSize,
   });
 
   /// Minimal constructor: only the required fields.
@@ -459,7 +740,12 @@ class FrbEncryptOptions {
       outputExtension.hashCode ^
       tempDir.hashCode ^
       metadata.hashCode ^
-      chunkSize.hashCode;
+      chunkSize.hashCode ^
+      cipherSuite.hashCode ^
+      signingKey.hashCode ^
+      shredSource.hashCode ^
+      hideLengths.hashCode ^
+      volumeSize.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -474,29 +760,47 @@ class FrbEncryptOptions {
           outputExtension == other.outputExtension &&
           tempDir == other.tempDir &&
           metadata == other.metadata &&
-          chunkSize == other.chunkSize;
+          chunkSize == other.chunkSize &&
+          cipherSuite == other.cipherSuite &&
+          signingKey == other.signingKey &&
+          shredSource == other.shredSource &&
+          hideLengths == other.hideLengths &&
+          volumeSize == other.volumeSize;
 }
 
 /// Returned after a successful encryption.
 class FrbEncryptResult {
-  /// Absolute path to the encrypted output file.
+  /// Absolute path to the encrypted output file, or to the first
+  /// volume.
   final String outputPath;
 
+  /// Every volume written, in order; empty unless split.
+  final List<String> volumes;
+
   /// UUID embedded in the encrypted header.
   final String uuid;
 
   /// Original file size (bytes) before any compression.
   final BigInt originalSize;
 
+  /// Outcome of shredding the input, if `shred_source` was set.
+  final FrbShredReport? shred;
+
   const FrbEncryptResult({
     required this.outputPath,
+    required this.volumes,
     required this.uuid,
     required this.originalSize,
+    this.shred,
   });
 
   @override
   int get hashCode =>
-      outputPath.hashCode ^ uuid.hashCode ^ originalSize.hashCode;
+      outputPath.hashCode ^
+      volumes.hashCode ^
+      uuid.hashCode ^
+      originalSize.hashCode ^
+      shred.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -504,8 +808,120 @@ class FrbEncryptResult {
       other is FrbEncryptResult &&
           runtimeType == other.runtimeType &&
           outputPath == other.outputPath &&
+          volumes == other.volumes &&
           uuid == other.uuid &&
-          originalSize == other.originalSize;
+          originalSize == other.originalSize &&
+          shred == other.shred;
+}
+
+/// Reed–Solomon parity layout stored in the public header.
+class FrbFecParams {
+  /// Data chunks per parity group.
+  final int dataShards;
+
+  /// Parity blocks written after each group.
+  final int parityShards;
+
+  const FrbFecParams({required this.dataShards, required this.parityShards});
+
+  @override
+  int get hashCode => dataShards.hashCode ^ parityShards.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbFecParams &&
+          runtimeType == other.runtimeType &&
+          dataShards == other.dataShards &&
+          parityShards == other.parityShards;
+}
+
+/// Returned by `inspect_encrypted_file`. Read without the password.
+class FrbInspectResult {
+  /// Format version of the file.
+  final int version;
+
+  /// Argon2id parameters the password must be run through.
+  final FrbArgon2Params argon2Params;
+
+  /// Plaintext bytes per chunk.
+  final int chunkSize;
+
+  /// Reed–Solomon parity parameters, if present.
+  final FrbFecParams? fec;
+
+  /// Signature scheme, if the file is signed.
+  final FrbSignatureAlgorithm? signature;
+
+  /// AEAD protecting the metadata and the chunks.
+  final FrbCipherSuite cipher;
+
+  /// Whether the header commits to the key (checked on unlock).
+  final bool keyCommitment;
+
+  /// Total length of the file on disk.
+  final BigInt fileLen;
+
+  /// Length of the encrypted payload (data chunks and parity).
+  final BigInt ciphertextLen;
+
+  /// Number of encrypted data chunks (0 if the length is invalid).
+  final BigInt chunkCount;
+
+  /// Plaintext bytes carried by the payload, after compression.
+  final BigInt estimatedPlaintextSize;
+
+  /// `false` if the payload length does not match whole chunks,
+  /// which indicates truncation or trailing garbage.
+  final bool wholeChunks;
+
+  const FrbInspectResult({
+    required this.version,
+    required this.argon2Params,
+    required this.chunkSize,
+    this.fec,
+    this.signature,
+    required this.cipher,
+    required this.keyCommitment,
+    required this.fileLen,
+    required this.ciphertextLen,
+    required this.chunkCount,
+    required this.estimatedPlaintextSize,
+    required this.wholeChunks,
+  });
+
+  @override
+  int get hashCode =>
+      version.hashCode ^
+      argon2Params.hashCode ^
+      chunkSize.hashCode ^
+      fec.hashCode ^
+      signature.hashCode ^
+      cipher.hashCode ^
+      keyCommitment.hashCode ^
+      fileLen.hashCode ^
+      ciphertextLen.hashCode ^
+      chunkCount.hashCode ^
+      estimatedPlaintextSize.hashCode ^
+      wholeChunks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbInspectResult &&
+          runtimeType == other.runtimeType &&
+          version == other.version &&
+          argon2Params == other.argon2Params &&
+          chunkSize == other.chunkSize &&
+          fec == other.fec &&
+          signature == other.signature &&
+          cipher == other.cipher &&
+          keyCommitment == other.keyCommitment &&
+          fileLen == other.fileLen &&
+          ciphertextLen == other.ciphertextLen &&
+          chunkCount == other.chunkCount &&
+          estimatedPlaintextSize == other.estimatedPlaintextSize &&
+          wholeChunks == other.wholeChunks;
 }
 
 /// Key-value metadata entry stored in the encrypted header.
@@ -527,6 +943,122 @@ class FrbKeyValue {
           value == other.value;
 }
 
+/// Address of the local media server, returned by `media_server_start`.
+class FrbMediaServerInfo {
+  /// Port on 127.0.0.1, chosen by the OS.
+  final int port;
+
+  /// Random per-session token; every URL carries it as its first path
+  /// segment.
+  final String token;
+
+  const FrbMediaServerInfo({required this.port, required this.token});
+
+  @override
+  int get hashCode => port.hashCode ^ token.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbMediaServerInfo &&
+          runtimeType == other.runtimeType &&
+          port == other.port &&
+          token == other.token;
+}
+
+/// A file registered with `media_server_add`.
+class FrbMediaSource {
+  /// Id to pass to `media_server_remove`.
+  final String sourceId;
+
+  /// `http://127.0.0.1:<port>/<token>/<source_id>`, to hand to a player.
+  final String url;
+
+  /// Plaintext size in bytes.
+  final BigInt size;
+
+  /// `Content-Type` served, derived from the original extension.
+  final String mimeType;
+
+  /// Decoded metadata from the encrypted header.
+  final FrbDecryptedMetadata metadata;
+
+  const FrbMediaSource({
+    required this.sourceId,
+    required this.url,
+    required this.size,
+    required this.mimeType,
+    required this.metadata,
+  });
+
+  @override
+  int get hashCode =>
+      sourceId.hashCode ^
+      url.hashCode ^
+      size.hashCode ^
+      mimeType.hashCode ^
+      metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbMediaSource &&
+          runtimeType == other.runtimeType &&
+          sourceId == other.sourceId &&
+          url == other.url &&
+          size == other.size &&
+          mimeType == other.mimeType &&
+          metadata == other.metadata;
+}
+
+/// Changes passed to `update_metadata`.
+class FrbMetadataPatch {
+  /// Keys to remove.
+  final List<String> remove;
+
+  /// Entries to add or overwrite, applied after `remove`.
+  final List<FrbKeyValue> set_;
+
+  const FrbMetadataPatch({required this.remove, required this.set_});
+
+  @override
+  int get hashCode => remove.hashCode ^ set_.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbMetadataPatch &&
+          runtimeType == other.runtimeType &&
+          remove == other.remove &&
+          set_ == other.set_;
+}
+
+/// Returned by `update_metadata`.
+class FrbMetadataUpdateResult {
+  /// The metadata as now stored.
+  final FrbDecryptedMetadata metadata;
+
+  /// `true` if only the header was rewritten; `false` if the file had to
+  /// be copied because the metadata outgrew its reserved space.
+  final bool inPlace;
+
+  const FrbMetadataUpdateResult({
+    required this.metadata,
+    required this.inPlace,
+  });
+
+  @override
+  int get hashCode => metadata.hashCode ^ inPlace.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbMetadataUpdateResult &&
+          runtimeType == other.runtimeType &&
+          metadata == other.metadata &&
+          inPlace == other.inPlace;
+}
+
 /// Progress event emitted during encryption/decryption.
 class FrbProgressEvent {
   /// Current pipeline stage.
@@ -541,11 +1073,39 @@ class FrbProgressEvent {
   /// Completion percentage 0.0-100.0. 0 when total is unknown.
   final double percentage;
 
+  /// Files added to / extracted from a 7z archive so far.
+  final BigInt filesProcessed;
+
+  /// Files in the archive (0 outside the directory stages).
+  final BigInt totalFiles;
+
+  /// Throughput of the current stage.
+  final double bytesPerSecond;
+
+  /// Estimated seconds until the operation is done, if known.
+  final double? etaSeconds;
+
+  /// Completion of the whole operation across all stages.
+  final double overallPercentage;
+
+  /// Position of the current stage (0-based).
+  final int stageIndex;
+
+  /// Number of stages in the operation.
+  final int stageCount;
+
   const FrbProgressEvent({
     required this.stage,
     required this.bytesProcessed,
     required this.totalBytes,
     required this.percentage,
+    required this.filesProcessed,
+    required this.totalFiles,
+    required this.bytesPerSecond,
+    this.etaSeconds,
+    required this.overallPercentage,
+    required this.stageIndex,
+    required this.stageCount,
   });
 
   @override
@@ -553,7 +1113,14 @@ class FrbProgressEvent {
       stage.hashCode ^
       bytesProcessed.hashCode ^
       totalBytes.hashCode ^
-      percentage.hashCode;
+      percentage.hashCode ^
+      filesProcessed.hashCode ^
+      totalFiles.hashCode ^
+      bytesPerSecond.hashCode ^
+      etaSeconds.hashCode ^
+      overallPercentage.hashCode ^
+      stageIndex.hashCode ^
+      stageCount.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -563,7 +1130,14 @@ class FrbProgressEvent {
           stage == other.stage &&
           bytesProcessed == other.bytesProcessed &&
           totalBytes == other.totalBytes &&
-          percentage == other.percentage;
+          percentage == other.percentage &&
+          filesProcessed == other.filesProcessed &&
+          totalFiles == other.totalFiles &&
+          bytesPerSecond == other.bytesPerSecond &&
+          etaSeconds == other.etaSeconds &&
+          overallPercentage == other.overallPercentage &&
+          stageIndex == other.stageIndex &&
+          stageCount == other.stageCount;
 }
 
 /// Stage of the encryption/decryption pipeline.
@@ -576,3 +1150,424 @@ enum FrbProgressStage {
   decompressingDirectory,
   done,
 }
+
+/// Returned for each file re-encrypted by `reencrypt_batch`.
+class FrbReencryptResult {
+  /// Path of the re-encrypted file (the input, replaced).
+  final String outputPath;
+
+  /// Number of data chunks in the new file.
+  final BigInt chunkCount;
+
+  /// Signature check outcome of the original file.
+  final FrbSignatureStatus sourceSignature;
+
+  const FrbReencryptResult({
+    required this.outputPath,
+    required this.chunkCount,
+    required this.sourceSignature,
+  });
+
+  @override
+  int get hashCode =>
+      outputPath.hashCode ^ chunkCount.hashCode ^ sourceSignature.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbReencryptResult &&
+          runtimeType == other.runtimeType &&
+          outputPath == other.outputPath &&
+          chunkCount == other.chunkCount &&
+          sourceSignature == other.sourceSignature;
+}
+
+/// One path removed while shredding.
+class FrbShredEntry {
+  /// Path before it was renamed.
+  final String path;
+
+  /// Why the path could not be shredded, if it failed.
+  final String? error;
+
+  const FrbShredEntry({required this.path, this.error});
+
+  @override
+  int get hashCode => path.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbShredEntry &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          error == other.error;
+}
+
+/// Outcome of securely deleting the source of an encryption.
+class FrbShredReport {
+  /// Every file, link and directory processed, depth-first.
+  final List<FrbShredEntry> entries;
+
+  /// `true` if every entry was overwritten and removed.
+  final bool allShredded;
+
+  /// Why overwriting in place is best effort on SSDs and
+  /// journaling or copy-on-write file systems. Show it to the user.
+  final String caveat;
+
+  const FrbShredReport({
+    required this.entries,
+    required this.allShredded,
+    required this.caveat,
+  });
+
+  @override
+  int get hashCode => entries.hashCode ^ allShredded.hashCode ^ caveat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbShredReport &&
+          runtimeType == other.runtimeType &&
+          entries == other.entries &&
+          allShredded == other.allShredded &&
+          caveat == other.caveat;
+}
+
+/// Signature scheme recorded in the public header.
+enum FrbSignatureAlgorithm { ed25519 }
+
+@freezed
+sealed class FrbSignatureStatus with _$FrbSignatureStatus {
+  const FrbSignatureStatus._();
+
+  /// The file carries no signature.
+  const factory FrbSignatureStatus.unsigned() = FrbSignatureStatus_Unsigned;
+
+  /// The file was signed by `public_key` and is unmodified.
+  const factory FrbSignatureStatus.valid({required Uint8List publicKey}) =
+      FrbSignatureStatus_Valid;
+}
+
+/// Ed25519 identity used to sign encrypted files.
+class FrbSigningIdentity {
+  /// 32-byte secret key. Store it in secure storage.
+  final Uint8List secretKey;
+
+  /// 32-byte public key shared with recipients.
+  final Uint8List publicKey;
+
+  const FrbSigningIdentity({required this.secretKey, required this.publicKey});
+
+  @override
+  int get hashCode => secretKey.hashCode ^ publicKey.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbSigningIdentity &&
+          runtimeType == other.runtimeType &&
+          secretKey == other.secretKey &&
+          publicKey == other.publicKey;
+}
+
+/// Returned by `plan_encrypt` and `plan_decrypt`: the disk space an
+/// operation needs, to warn before starting it.
+class FrbSpacePlan {
+  /// Bytes written to the output directory.
+  final BigInt outputBytes;
+
+  /// Free bytes on the output file system, if known.
+  final BigInt? outputFree;
+
+  /// Most bytes held in the temp directory at once.
+  final BigInt tempBytes;
+
+  /// Free bytes on the temp file system, if known.
+  final BigInt? tempFree;
+
+  /// Whether output and temp files draw on the same free space.
+  final bool sharedVolume;
+
+  /// `true` when decompressed sizes are only known to be at least
+  /// the counts above (decrypting compressed data).
+  final bool estimated;
+
+  /// Where the space falls short, if it is known to.
+  final FrbSpaceShortfall? shortfall;
+
+  const FrbSpacePlan({
+    required this.outputBytes,
+    this.outputFree,
+    required this.tempBytes,
+    this.tempFree,
+    required this.sharedVolume,
+    required this.estimated,
+    this.shortfall,
+  });
+
+  @override
+  int get hashCode =>
+      outputBytes.hashCode ^
+      outputFree.hashCode ^
+      tempBytes.hashCode ^
+      tempFree.hashCode ^
+      sharedVolume.hashCode ^
+      estimated.hashCode ^
+      shortfall.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbSpacePlan &&
+          runtimeType == other.runtimeType &&
+          outputBytes == other.outputBytes &&
+          outputFree == other.outputFree &&
+          tempBytes == other.tempBytes &&
+          tempFree == other.tempFree &&
+          sharedVolume == other.sharedVolume &&
+          estimated == other.estimated &&
+          shortfall == other.shortfall;
+}
+
+/// A directory without room for what an operation writes there.
+class FrbSpaceShortfall {
+  final String path;
+  final BigInt needed;
+  final BigInt available;
+
+  const FrbSpaceShortfall({
+    required this.path,
+    required this.needed,
+    required this.available,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ needed.hashCode ^ available.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbSpaceShortfall &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          needed == other.needed &&
+          available == other.available;
+}
+
+/// Options for streaming the plaintext of an encrypted file to Dart.
+class FrbStreamDecryptOptions {
+  /// Caller-chosen id used by `decrypt_stream_ack` / `_cancel`.
+  final String streamId;
+
+  /// Path to the encrypted `.enc` file.
+  final String inputPath;
+
+  /// User password.
+  final String password;
+
+  /// Blocks that may be in flight before Dart acknowledges them.
+  /// At least 1.
+  final int window;
+
+  const FrbStreamDecryptOptions({
+    required this.streamId,
+    required this.inputPath,
+    required this.password,
+    required this.window,
+  });
+
+  @override
+  int get hashCode =>
+      streamId.hashCode ^
+      inputPath.hashCode ^
+      password.hashCode ^
+      window.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbStreamDecryptOptions &&
+          runtimeType == other.runtimeType &&
+          streamId == other.streamId &&
+          inputPath == other.inputPath &&
+          password == other.password &&
+          window == other.window;
+}
+
+/// Final event of `decrypt_stream`.
+class FrbStreamDecryptResult {
+  /// Plaintext bytes emitted, after decompression.
+  final BigInt bytesWritten;
+
+  /// Decoded metadata from the encrypted header.
+  final FrbDecryptedMetadata metadata;
+
+  /// Signature check outcome.
+  final FrbSignatureStatus signature;
+
+  const FrbStreamDecryptResult({
+    required this.bytesWritten,
+    required this.metadata,
+    required this.signature,
+  });
+
+  @override
+  int get hashCode =>
+      bytesWritten.hashCode ^ metadata.hashCode ^ signature.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbStreamDecryptResult &&
+          runtimeType == other.runtimeType &&
+          bytesWritten == other.bytesWritten &&
+          metadata == other.metadata &&
+          signature == other.signature;
+}
+
+/// Options for encrypting data pushed from Dart with
+/// `encrypt_stream_push`.
+class FrbStreamEncryptOptions {
+  /// Caller-chosen id used by `encrypt_stream_push` / `_finish`.
+  final String streamId;
+
+  /// Path of the encrypted file to create.
+  final String outputPath;
+
+  /// User password used for key derivation.
+  final String password;
+
+  /// File name recorded in the metadata, without extension.
+  final String originalFilename;
+
+  /// Extension recorded in the metadata, without the dot.
+  final String originalExtension;
+
+  /// Whether to apply Gzip compression before encryption.
+  final bool gzipCompressed;
+
+  /// Additional metadata to embed in the encrypted header.
+  final List<FrbKeyValue> metadata;
+
+  /// Chunk-size preset (desktop is the default).
+  final FrbChunkSizePreset chunkSize;
+
+  /// AEAD for the new file (XChaCha20-Poly1305 is the default).
+  final FrbCipherSuite cipherSuite;
+
+  /// Optional Ed25519 secret key (32 bytes) used to sign the file.
+  final Uint8List? signingKey;
+
+  const FrbStreamEncryptOptions({
+    required this.streamId,
+    required this.outputPath,
+    required this.password,
+    required this.originalFilename,
+    required this.originalExtension,
+    required this.gzipCompressed,
+    required this.metadata,
+    required this.chunkSize,
+    required this.cipherSuite,
+    this.signingKey,
+  });
+
+  @override
+  int get hashCode =>
+      streamId.hashCode ^
+      outputPath.hashCode ^
+      password.hashCode ^
+      originalFilename.hashCode ^
+      originalExtension.hashCode ^
+      gzipCompressed.hashCode ^
+      metadata.hashCode ^
+      chunkSize.hashCode ^
+      cipherSuite.hashCode ^
+      signingKey.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbStreamEncryptOptions &&
+          runtimeType == other.runtimeType &&
+          streamId == other.streamId &&
+          outputPath == other.outputPath &&
+          password == other.password &&
+          originalFilename == other.originalFilename &&
+          originalExtension == other.originalExtension &&
+          gzipCompressed == other.gzipCompressed &&
+          metadata == other.metadata &&
+          chunkSize == other.chunkSize &&
+          cipherSuite == other.cipherSuite &&
+          signingKey == other.signingKey;
+}
+
+/// Returned by `encrypt_stream_finish`.
+class FrbStreamEncryptResult {
+  /// Path of the encrypted file.
+  final String outputPath;
+
+  /// UUID embedded in the header.
+  final String uuid;
+
+  /// Plaintext bytes pushed from Dart.
+  final BigInt bytesRead;
+
+  const FrbStreamEncryptResult({
+    required this.outputPath,
+    required this.uuid,
+    required this.bytesRead,
+  });
+
+  @override
+  int get hashCode => outputPath.hashCode ^ uuid.hashCode ^ bytesRead.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbStreamEncryptResult &&
+          runtimeType == other.runtimeType &&
+          outputPath == other.outputPath &&
+          uuid == other.uuid &&
+          bytesRead == other.bytesRead;
+}
+
+/// Returned by `verify_file`.
+class FrbVerifyResult {
+  /// Decoded metadata from the encrypted header.
+  final FrbDecryptedMetadata metadata;
+
+  /// Number of authenticated data chunks.
+  final BigInt chunkCount;
+
+  /// Data chunks rebuilt from parity while verifying.
+  final BigInt repairedChunks;
+
+  /// Signature check outcome.
+  final FrbSignatureStatus signature;
+
+  const FrbVerifyResult({
+    required this.metadata,
+    required this.chunkCount,
+    required this.repairedChunks,
+    required this.signature,
+  });
+
+  @override
+  int get hashCode =>
+      metadata.hashCode ^
+      chunkCount.hashCode ^
+      repairedChunks.hashCode ^
+      signature.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrbVerifyResult &&
+          runtimeType == other.runtimeType &&
+          metadata == other.metadata &&
+          chunkCount == other.chunkCount &&
+          repairedChunks == other.repairedChunks &&
+          signature == other.signature;
+}
@@ -73,7 +73,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.12.0';
 
   @override
-  int get rustContentHash => -626618812;
+  int get rustContentHash => -1518262841;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -95,6 +95,32 @@ abstract class RustLibApi extends BaseApi {
     required FrbDecryptOptions opts,
   });
 
+  Stream<FrbDecryptChunk> crateApiCryptApiDecryptStream({
+    required FrbStreamDecryptOptions opts,
+  });
+
+  void crateApiCryptApiDecryptStreamAck({required String streamId});
+
+  void crateApiCryptApiDecryptStreamCancel({required String streamId});
+
+  Future<Uint8List> crateApiCryptApiDecryptToMemory({
+    required String inputPath,
+    required String password,
+    required BigInt maxSize,
+  });
+
+  Future<FrbShredReport> crateApiCryptApiDecryptedCacheConfigure({
+    required FrbDecryptedCacheOptions opts,
+  });
+
+  Future<FrbShredReport> crateApiCryptApiDecryptedCachePurgeAll();
+
+  Future<FrbShredReport> crateApiCryptApiDecryptedCachePurgeExpired();
+
+  FrbDecryptedCacheStats crateApiCryptApiDecryptedCacheStats();
+
+  void crateApiCryptApiDecryptedCacheTouch({required String path});
+
   Future<void> crateApiIconPackCatalogApiDeletePack({
     required String rootPath,
     required String packKey,
@@ -108,6 +134,21 @@ abstract class RustLibApi extends BaseApi {
     required FrbEncryptOptions opts,
   });
 
+  Future<void> crateApiCryptApiEncryptStream({
+    required FrbStreamEncryptOptions opts,
+  });
+
+  void crateApiCryptApiEncryptStreamAbort({required String streamId});
+
+  Future<FrbStreamEncryptResult> crateApiCryptApiEncryptStreamFinish({
+    required String streamId,
+  });
+
+  Future<void> crateApiCryptApiEncryptStreamPush({
+    required String streamId,
+    required List<int> data,
+  });
+
   Future<FrbKeepassDatabaseExport> crateApiKeepassApiExportKeepassDatabase({
     required FrbKeepassExportOptions opts,
   });
@@ -130,6 +171,8 @@ abstract class RustLibApi extends BaseApi {
     required String password,
   });
 
+  FrbSigningIdentity crateApiCryptApiGenerateSigningIdentity();
+
   String crateApiSimpleGreet({required String name});
 
   Stream<FrbIconPackImportEvent> crateApiIconPackCatalogApiImportDirectory({
@@ -146,6 +189,10 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiSimpleInitApp();
 
+  Future<FrbInspectResult> crateApiCryptApiInspectEncryptedFile({
+    required String inputPath,
+  });
+
   Future<void> crateApiLoggingInstallRustLogBridge({required int level});
 
   Future<List<FrbIconPackEntry>> crateApiIconPackCatalogApiListIcons({
@@ -160,12 +207,31 @@ abstract class RustLibApi extends BaseApi {
     required String rootPath,
   });
 
+  Future<FrbMediaSource> crateApiCryptApiMediaServerAdd({
+    required String inputPath,
+    required String password,
+  });
+
+  void crateApiCryptApiMediaServerRemove({required String sourceId});
+
+  Future<FrbMediaServerInfo> crateApiCryptApiMediaServerStart();
+
+  void crateApiCryptApiMediaServerStop();
+
   String crateApiIconPackCatalogApiNormalizeIconPathWithoutExtension({
     required String value,
   });
 
   String crateApiIconPackCatalogApiNormalizePackKey({required String value});
 
+  Future<FrbSpacePlan> crateApiCryptApiPlanDecrypt({
+    required FrbDecryptOptions opts,
+  });
+
+  Future<FrbSpacePlan> crateApiCryptApiPlanEncrypt({
+    required FrbEncryptOptions opts,
+  });
+
   Future<FrbDecryptedMetadata> crateApiCryptApiReadEncryptedHeader({
     required String inputPath,
     required String password,
@@ -176,6 +242,10 @@ abstract class RustLibApi extends BaseApi {
     required String iconKey,
   });
 
+  Stream<FrbBatchReencryptEvent> crateApiCryptApiReencryptBatch({
+    required FrbBatchReencryptOptions opts,
+  });
+
   Future<void> crateApiLoggingRustLog({
     required int level,
     required String tag,
@@ -206,6 +276,19 @@ abstract class RustLibApi extends BaseApi {
     required String tag,
     required String msg,
   });
+
+  Uint8List crateApiCryptApiSigningPublicKey({required List<int> secretKey});
+
+  Future<FrbMetadataUpdateResult> crateApiCryptApiUpdateMetadata({
+    required String inputPath,
+    required String password,
+    required FrbMetadataPatch patch,
+  });
+
+  Future<FrbVerifyResult> crateApiCryptApiVerifyFile({
+    required String inputPath,
+    required String password,
+  });
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -324,6 +407,271 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["opts", "sink"],
       );
 
+  @override
+  Stream<FrbDecryptChunk> crateApiCryptApiDecryptStream({
+    required FrbStreamDecryptOptions opts,
+  }) {
+    final sink = RustStreamSink<FrbDecryptChunk>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_box_autoadd_frb_stream_decrypt_options(opts, serializer);
+            sse_encode_StreamSink_frb_decrypt_chunk_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 4,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiCryptApiDecryptStreamConstMeta,
+          argValues: [opts, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCryptApiDecryptStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypt_stream",
+        argNames: ["opts", "sink"],
+      );
+
+  @override
+  void crateApiCryptApiDecryptStreamAck({required String streamId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(streamId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCryptApiDecryptStreamAckConstMeta,
+        argValues: [streamId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiDecryptStreamAckConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypt_stream_ack",
+        argNames: ["streamId"],
+      );
+
+  @override
+  void crateApiCryptApiDecryptStreamCancel({required String streamId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(streamId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCryptApiDecryptStreamCancelConstMeta,
+        argValues: [streamId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiDecryptStreamCancelConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypt_stream_cancel",
+        argNames: ["streamId"],
+      );
+
+  @override
+  Future<Uint8List> crateApiCryptApiDecryptToMemory({
+    required String inputPath,
+    required String password,
+    required BigInt maxSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(inputPath, serializer);
+          sse_encode_String(password, serializer);
+          sse_encode_u_64(maxSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiDecryptToMemoryConstMeta,
+        argValues: [inputPath, password, maxSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiDecryptToMemoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypt_to_memory",
+        argNames: ["inputPath", "password", "maxSize"],
+      );
+
+  @override
+  Future<FrbShredReport> crateApiCryptApiDecryptedCacheConfigure({
+    required FrbDecryptedCacheOptions opts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_frb_decrypted_cache_options(opts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_shred_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiDecryptedCacheConfigureConstMeta,
+        argValues: [opts],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiDecryptedCacheConfigureConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypted_cache_configure",
+        argNames: ["opts"],
+      );
+
+  @override
+  Future<FrbShredReport> crateApiCryptApiDecryptedCachePurgeAll() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_shred_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiDecryptedCachePurgeAllConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiDecryptedCachePurgeAllConstMeta =>
+      const TaskConstMeta(debugName: "decrypted_cache_purge_all", argNames: []);
+
+  @override
+  Future<FrbShredReport> crateApiCryptApiDecryptedCachePurgeExpired() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_shred_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCryptApiDecryptedCachePurgeExpiredConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiDecryptedCachePurgeExpiredConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypted_cache_purge_expired",
+        argNames: [],
+      );
+
+  @override
+  FrbDecryptedCacheStats crateApiCryptApiDecryptedCacheStats() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_decrypted_cache_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiDecryptedCacheStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiDecryptedCacheStatsConstMeta =>
+      const TaskConstMeta(debugName: "decrypted_cache_stats", argNames: []);
+
+  @override
+  void crateApiCryptApiDecryptedCacheTouch({required String path}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCryptApiDecryptedCacheTouchConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiDecryptedCacheTouchConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypted_cache_touch",
+        argNames: ["path"],
+      );
+
   @override
   Future<void> crateApiIconPackCatalogApiDeletePack({
     required String rootPath,
@@ -338,7 +686,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 13,
             port: port_,
           );
         },
@@ -374,7 +722,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 5,
+              funcId: 14,
               port: port_,
             );
           },
@@ -391,48 +739,172 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiCryptApiEncryptBatchConstMeta =>
+  TaskConstMeta get kCrateApiCryptApiEncryptBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "encrypt_batch",
+        argNames: ["opts", "sink"],
+      );
+
+  @override
+  Stream<FrbEncryptEvent> crateApiCryptApiEncryptFile({
+    required FrbEncryptOptions opts,
+  }) {
+    final sink = RustStreamSink<FrbEncryptEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_box_autoadd_frb_encrypt_options(opts, serializer);
+            sse_encode_StreamSink_frb_encrypt_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 15,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiCryptApiEncryptFileConstMeta,
+          argValues: [opts, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCryptApiEncryptFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "encrypt_file",
+        argNames: ["opts", "sink"],
+      );
+
+  @override
+  Future<void> crateApiCryptApiEncryptStream({
+    required FrbStreamEncryptOptions opts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_frb_stream_encrypt_options(opts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiEncryptStreamConstMeta,
+        argValues: [opts],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiEncryptStreamConstMeta =>
+      const TaskConstMeta(debugName: "encrypt_stream", argNames: ["opts"]);
+
+  @override
+  void crateApiCryptApiEncryptStreamAbort({required String streamId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(streamId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCryptApiEncryptStreamAbortConstMeta,
+        argValues: [streamId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiEncryptStreamAbortConstMeta =>
+      const TaskConstMeta(
+        debugName: "encrypt_stream_abort",
+        argNames: ["streamId"],
+      );
+
+  @override
+  Future<FrbStreamEncryptResult> crateApiCryptApiEncryptStreamFinish({
+    required String streamId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(streamId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_stream_encrypt_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiEncryptStreamFinishConstMeta,
+        argValues: [streamId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiEncryptStreamFinishConstMeta =>
       const TaskConstMeta(
-        debugName: "encrypt_batch",
-        argNames: ["opts", "sink"],
+        debugName: "encrypt_stream_finish",
+        argNames: ["streamId"],
       );
 
   @override
-  Stream<FrbEncryptEvent> crateApiCryptApiEncryptFile({
-    required FrbEncryptOptions opts,
+  Future<void> crateApiCryptApiEncryptStreamPush({
+    required String streamId,
+    required List<int> data,
   }) {
-    final sink = RustStreamSink<FrbEncryptEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_box_autoadd_frb_encrypt_options(opts, serializer);
-            sse_encode_StreamSink_frb_encrypt_event_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 6,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiCryptApiEncryptFileConstMeta,
-          argValues: [opts, sink],
-          apiImpl: this,
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(streamId, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
+        constMeta: kCrateApiCryptApiEncryptStreamPushConstMeta,
+        argValues: [streamId, data],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiCryptApiEncryptFileConstMeta =>
+  TaskConstMeta get kCrateApiCryptApiEncryptStreamPushConstMeta =>
       const TaskConstMeta(
-        debugName: "encrypt_file",
-        argNames: ["opts", "sink"],
+        debugName: "encrypt_stream_push",
+        argNames: ["streamId", "data"],
       );
 
   @override
@@ -447,7 +919,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 20,
             port: port_,
           );
         },
@@ -484,7 +956,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 21,
             port: port_,
           );
         },
@@ -521,7 +993,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 22,
             port: port_,
           );
         },
@@ -557,7 +1029,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 23,
             port: port_,
           );
         },
@@ -580,6 +1052,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["inputPath", "password"],
       );
 
+  @override
+  FrbSigningIdentity crateApiCryptApiGenerateSigningIdentity() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_signing_identity,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCryptApiGenerateSigningIdentityConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiGenerateSigningIdentityConstMeta =>
+      const TaskConstMeta(debugName: "generate_signing_identity", argNames: []);
+
   @override
   String crateApiSimpleGreet({required String name}) {
     return handler.executeSync(
@@ -587,7 +1081,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -625,7 +1119,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 12,
+              funcId: 26,
               port: port_,
             );
           },
@@ -670,7 +1164,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 13,
+              funcId: 27,
               port: port_,
             );
           },
@@ -702,7 +1196,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 28,
             port: port_,
           );
         },
@@ -720,6 +1214,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
       const TaskConstMeta(debugName: "init_app", argNames: []);
 
+  @override
+  Future<FrbInspectResult> crateApiCryptApiInspectEncryptedFile({
+    required String inputPath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(inputPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_inspect_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiInspectEncryptedFileConstMeta,
+        argValues: [inputPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiInspectEncryptedFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "inspect_encrypted_file",
+        argNames: ["inputPath"],
+      );
+
   @override
   Future<void> crateApiLoggingInstallRustLogBridge({required int level}) {
     return handler.executeNormal(
@@ -730,7 +1257,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 30,
             port: port_,
           );
         },
@@ -771,7 +1298,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 31,
             port: port_,
           );
         },
@@ -804,7 +1331,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 32,
             port: port_,
           );
         },
@@ -822,6 +1349,116 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiIconPackCatalogApiListPacksConstMeta =>
       const TaskConstMeta(debugName: "list_packs", argNames: ["rootPath"]);
 
+  @override
+  Future<FrbMediaSource> crateApiCryptApiMediaServerAdd({
+    required String inputPath,
+    required String password,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(inputPath, serializer);
+          sse_encode_String(password, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_media_source,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiMediaServerAddConstMeta,
+        argValues: [inputPath, password],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiMediaServerAddConstMeta =>
+      const TaskConstMeta(
+        debugName: "media_server_add",
+        argNames: ["inputPath", "password"],
+      );
+
+  @override
+  void crateApiCryptApiMediaServerRemove({required String sourceId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sourceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCryptApiMediaServerRemoveConstMeta,
+        argValues: [sourceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiMediaServerRemoveConstMeta =>
+      const TaskConstMeta(
+        debugName: "media_server_remove",
+        argNames: ["sourceId"],
+      );
+
+  @override
+  Future<FrbMediaServerInfo> crateApiCryptApiMediaServerStart() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_media_server_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiMediaServerStartConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiMediaServerStartConstMeta =>
+      const TaskConstMeta(debugName: "media_server_start", argNames: []);
+
+  @override
+  void crateApiCryptApiMediaServerStop() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCryptApiMediaServerStopConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiMediaServerStopConstMeta =>
+      const TaskConstMeta(debugName: "media_server_stop", argNames: []);
+
   @override
   String crateApiIconPackCatalogApiNormalizeIconPathWithoutExtension({
     required String value,
@@ -831,7 +1468,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(value, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -859,7 +1496,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(value, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -875,6 +1512,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiIconPackCatalogApiNormalizePackKeyConstMeta =>
       const TaskConstMeta(debugName: "normalize_pack_key", argNames: ["value"]);
 
+  @override
+  Future<FrbSpacePlan> crateApiCryptApiPlanDecrypt({
+    required FrbDecryptOptions opts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_frb_decrypt_options(opts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_space_plan,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiPlanDecryptConstMeta,
+        argValues: [opts],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiPlanDecryptConstMeta =>
+      const TaskConstMeta(debugName: "plan_decrypt", argNames: ["opts"]);
+
+  @override
+  Future<FrbSpacePlan> crateApiCryptApiPlanEncrypt({
+    required FrbEncryptOptions opts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_frb_encrypt_options(opts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_space_plan,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiPlanEncryptConstMeta,
+        argValues: [opts],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiPlanEncryptConstMeta =>
+      const TaskConstMeta(debugName: "plan_encrypt", argNames: ["opts"]);
+
   @override
   Future<FrbDecryptedMetadata> crateApiCryptApiReadEncryptedHeader({
     required String inputPath,
@@ -889,7 +1586,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 41,
             port: port_,
           );
         },
@@ -924,7 +1621,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 42,
             port: port_,
           );
         },
@@ -932,17 +1629,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiIconPackCatalogApiReadSvgByKeyConstMeta,
-        argValues: [rootPath, iconKey],
-        apiImpl: this,
+        constMeta: kCrateApiIconPackCatalogApiReadSvgByKeyConstMeta,
+        argValues: [rootPath, iconKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIconPackCatalogApiReadSvgByKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_svg_by_key",
+        argNames: ["rootPath", "iconKey"],
+      );
+
+  @override
+  Stream<FrbBatchReencryptEvent> crateApiCryptApiReencryptBatch({
+    required FrbBatchReencryptOptions opts,
+  }) {
+    final sink = RustStreamSink<FrbBatchReencryptEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_box_autoadd_frb_batch_reencrypt_options(
+              opts,
+              serializer,
+            );
+            sse_encode_StreamSink_frb_batch_reencrypt_event_Sse(
+              sink,
+              serializer,
+            );
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 43,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiCryptApiReencryptBatchConstMeta,
+          argValues: [opts, sink],
+          apiImpl: this,
+        ),
       ),
     );
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiIconPackCatalogApiReadSvgByKeyConstMeta =>
+  TaskConstMeta get kCrateApiCryptApiReencryptBatchConstMeta =>
       const TaskConstMeta(
-        debugName: "read_svg_by_key",
-        argNames: ["rootPath", "iconKey"],
+        debugName: "reencrypt_batch",
+        argNames: ["opts", "sink"],
       );
 
   @override
@@ -961,7 +1702,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 44,
             port: port_,
           );
         },
@@ -995,7 +1736,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 45,
             port: port_,
           );
         },
@@ -1030,7 +1771,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 46,
             port: port_,
           );
         },
@@ -1065,7 +1806,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 47,
             port: port_,
           );
         },
@@ -1100,7 +1841,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 48,
             port: port_,
           );
         },
@@ -1132,7 +1873,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 49,
             port: port_,
           );
         },
@@ -1153,6 +1894,104 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["tag", "msg"],
       );
 
+  @override
+  Uint8List crateApiCryptApiSigningPublicKey({required List<int> secretKey}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(secretKey, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiSigningPublicKeyConstMeta,
+        argValues: [secretKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiSigningPublicKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "signing_public_key",
+        argNames: ["secretKey"],
+      );
+
+  @override
+  Future<FrbMetadataUpdateResult> crateApiCryptApiUpdateMetadata({
+    required String inputPath,
+    required String password,
+    required FrbMetadataPatch patch,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(inputPath, serializer);
+          sse_encode_String(password, serializer);
+          sse_encode_box_autoadd_frb_metadata_patch(patch, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_metadata_update_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiUpdateMetadataConstMeta,
+        argValues: [inputPath, password, patch],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiUpdateMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_metadata",
+        argNames: ["inputPath", "password", "patch"],
+      );
+
+  @override
+  Future<FrbVerifyResult> crateApiCryptApiVerifyFile({
+    required String inputPath,
+    required String password,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(inputPath, serializer);
+          sse_encode_String(password, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_verify_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptApiVerifyFileConstMeta,
+        argValues: [inputPath, password],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptApiVerifyFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_file",
+        argNames: ["inputPath", "password"],
+      );
+
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1173,6 +2012,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<FrbBatchReencryptEvent>
+  dco_decode_StreamSink_frb_batch_reencrypt_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FrbDecryptChunk> dco_decode_StreamSink_frb_decrypt_chunk_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<FrbDecryptEvent> dco_decode_StreamSink_frb_decrypt_event_Sse(
     dynamic raw,
@@ -1220,6 +2074,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as bool;
   }
 
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_f_64(raw);
+  }
+
+  @protected
+  FrbArgon2Params dco_decode_box_autoadd_frb_argon_2_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_argon_2_params(raw);
+  }
+
   @protected
   FrbBatchDecryptOptions dco_decode_box_autoadd_frb_batch_decrypt_options(
     dynamic raw,
@@ -1252,6 +2118,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_frb_batch_encrypt_result(raw);
   }
 
+  @protected
+  FrbBatchReencryptOptions dco_decode_box_autoadd_frb_batch_reencrypt_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_batch_reencrypt_options(raw);
+  }
+
+  @protected
+  FrbBatchReencryptResult dco_decode_box_autoadd_frb_batch_reencrypt_result(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_batch_reencrypt_result(raw);
+  }
+
+  @protected
+  FrbChunkSizePreset dco_decode_box_autoadd_frb_chunk_size_preset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_chunk_size_preset(raw);
+  }
+
   @protected
   FrbDecryptOptions dco_decode_box_autoadd_frb_decrypt_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1264,6 +2152,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_frb_decrypt_result(raw);
   }
 
+  @protected
+  FrbDecryptedCacheOptions dco_decode_box_autoadd_frb_decrypted_cache_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_decrypted_cache_options(raw);
+  }
+
   @protected
   FrbEncryptOptions dco_decode_box_autoadd_frb_encrypt_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1276,6 +2172,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_frb_encrypt_result(raw);
   }
 
+  @protected
+  FrbFecParams dco_decode_box_autoadd_frb_fec_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_fec_params(raw);
+  }
+
   @protected
   FrbIconPackError dco_decode_box_autoadd_frb_icon_pack_error(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1322,12 +2224,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_frb_keepass_otp(raw);
   }
 
+  @protected
+  FrbMetadataPatch dco_decode_box_autoadd_frb_metadata_patch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_metadata_patch(raw);
+  }
+
   @protected
   FrbProgressEvent dco_decode_box_autoadd_frb_progress_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_frb_progress_event(raw);
   }
 
+  @protected
+  FrbReencryptResult dco_decode_box_autoadd_frb_reencrypt_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_reencrypt_result(raw);
+  }
+
+  @protected
+  FrbShredReport dco_decode_box_autoadd_frb_shred_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_shred_report(raw);
+  }
+
+  @protected
+  FrbSignatureAlgorithm dco_decode_box_autoadd_frb_signature_algorithm(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_signature_algorithm(raw);
+  }
+
+  @protected
+  FrbSpaceShortfall dco_decode_box_autoadd_frb_space_shortfall(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_space_shortfall(raw);
+  }
+
+  @protected
+  FrbStreamDecryptOptions dco_decode_box_autoadd_frb_stream_decrypt_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_stream_decrypt_options(raw);
+  }
+
+  @protected
+  FrbStreamDecryptResult dco_decode_box_autoadd_frb_stream_decrypt_result(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_stream_decrypt_result(raw);
+  }
+
+  @protected
+  FrbStreamEncryptOptions dco_decode_box_autoadd_frb_stream_encrypt_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frb_stream_encrypt_options(raw);
+  }
+
   @protected
   int dco_decode_box_autoadd_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1352,6 +2310,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as double;
   }
 
+  @protected
+  FrbArgon2Params dco_decode_frb_argon_2_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FrbArgon2Params(
+      tCost: dco_decode_u_32(arr[0]),
+      mCostKib: dco_decode_u_32(arr[1]),
+      parallelism: dco_decode_u_32(arr[2]),
+    );
+  }
+
   @protected
   FrbBatchDecryptEvent dco_decode_frb_batch_decrypt_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1415,26 +2386,102 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     switch (raw[0]) {
       case 0:
-        return FrbBatchEncryptEvent_FileProgress(
+        return FrbBatchEncryptEvent_FileProgress(
+          fileIndex: dco_decode_u_32(raw[1]),
+          totalFiles: dco_decode_u_32(raw[2]),
+          currentFile: dco_decode_String(raw[3]),
+          progress: dco_decode_box_autoadd_frb_progress_event(raw[4]),
+        );
+      case 1:
+        return FrbBatchEncryptEvent_FileDone(
+          fileIndex: dco_decode_u_32(raw[1]),
+          result: dco_decode_box_autoadd_frb_encrypt_result(raw[2]),
+        );
+      case 2:
+        return FrbBatchEncryptEvent_FileError(
+          fileIndex: dco_decode_u_32(raw[1]),
+          inputPath: dco_decode_String(raw[2]),
+          error: dco_decode_String(raw[3]),
+        );
+      case 3:
+        return FrbBatchEncryptEvent_AllDone(
+          dco_decode_box_autoadd_frb_batch_encrypt_result(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  FrbBatchEncryptOptions dco_decode_frb_batch_encrypt_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return FrbBatchEncryptOptions(
+      inputPaths: dco_decode_list_String(arr[0]),
+      outputDir: dco_decode_String(arr[1]),
+      password: dco_decode_String(arr[2]),
+      gzipCompressed: dco_decode_bool(arr[3]),
+      tempDir: dco_decode_opt_String(arr[4]),
+      metadata: dco_decode_list_frb_key_value(arr[5]),
+      chunkSize: dco_decode_frb_chunk_size_preset(arr[6]),
+      cipherSuite: dco_decode_frb_cipher_suite(arr[7]),
+      signingKey: dco_decode_opt_list_prim_u_8_strict(arr[8]),
+      hideLengths: dco_decode_bool(arr[9]),
+      volumeSize: dco_decode_opt_box_autoadd_u_64(arr[10]),
+    );
+  }
+
+  @protected
+  FrbBatchEncryptResult dco_decode_frb_batch_encrypt_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FrbBatchEncryptResult(
+      succeeded: dco_decode_list_frb_encrypt_result(arr[0]),
+      failed: dco_decode_list_frb_batch_error(arr[1]),
+    );
+  }
+
+  @protected
+  FrbBatchError dco_decode_frb_batch_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FrbBatchError(
+      inputPath: dco_decode_String(arr[0]),
+      error: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  FrbBatchReencryptEvent dco_decode_frb_batch_reencrypt_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return FrbBatchReencryptEvent_FileProgress(
           fileIndex: dco_decode_u_32(raw[1]),
           totalFiles: dco_decode_u_32(raw[2]),
           currentFile: dco_decode_String(raw[3]),
           progress: dco_decode_box_autoadd_frb_progress_event(raw[4]),
         );
       case 1:
-        return FrbBatchEncryptEvent_FileDone(
+        return FrbBatchReencryptEvent_FileDone(
           fileIndex: dco_decode_u_32(raw[1]),
-          result: dco_decode_box_autoadd_frb_encrypt_result(raw[2]),
+          result: dco_decode_box_autoadd_frb_reencrypt_result(raw[2]),
         );
       case 2:
-        return FrbBatchEncryptEvent_FileError(
+        return FrbBatchReencryptEvent_FileError(
           fileIndex: dco_decode_u_32(raw[1]),
           inputPath: dco_decode_String(raw[2]),
           error: dco_decode_String(raw[3]),
         );
       case 3:
-        return FrbBatchEncryptEvent_AllDone(
-          dco_decode_box_autoadd_frb_batch_encrypt_result(raw[1]),
+        return FrbBatchReencryptEvent_AllDone(
+          dco_decode_box_autoadd_frb_batch_reencrypt_result(raw[1]),
         );
       default:
         throw Exception("unreachable");
@@ -1442,46 +2489,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  FrbBatchEncryptOptions dco_decode_frb_batch_encrypt_options(dynamic raw) {
+  FrbBatchReencryptOptions dco_decode_frb_batch_reencrypt_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return FrbBatchEncryptOptions(
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return FrbBatchReencryptOptions(
       inputPaths: dco_decode_list_String(arr[0]),
-      outputDir: dco_decode_String(arr[1]),
-      password: dco_decode_String(arr[2]),
-      gzipCompressed: dco_decode_bool(arr[3]),
-      tempDir: dco_decode_opt_String(arr[4]),
-      metadata: dco_decode_list_frb_key_value(arr[5]),
-      chunkSize: dco_decode_frb_chunk_size_preset(arr[6]),
+      oldPassword: dco_decode_String(arr[1]),
+      newPassword: dco_decode_String(arr[2]),
+      argon2Params: dco_decode_opt_box_autoadd_frb_argon_2_params(arr[3]),
+      chunkSize: dco_decode_opt_box_autoadd_frb_chunk_size_preset(arr[4]),
+      signingKey: dco_decode_opt_list_prim_u_8_strict(arr[5]),
     );
   }
 
   @protected
-  FrbBatchEncryptResult dco_decode_frb_batch_encrypt_result(dynamic raw) {
+  FrbBatchReencryptResult dco_decode_frb_batch_reencrypt_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 2)
       throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return FrbBatchEncryptResult(
-      succeeded: dco_decode_list_frb_encrypt_result(arr[0]),
+    return FrbBatchReencryptResult(
+      succeeded: dco_decode_list_frb_reencrypt_result(arr[0]),
       failed: dco_decode_list_frb_batch_error(arr[1]),
     );
   }
 
-  @protected
-  FrbBatchError dco_decode_frb_batch_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return FrbBatchError(
-      inputPath: dco_decode_String(arr[0]),
-      error: dco_decode_String(arr[1]),
-    );
-  }
-
   @protected
   FrbChunkSizePreset dco_decode_frb_chunk_size_preset(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1497,6 +2531,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  FrbCipherSuite dco_decode_frb_cipher_suite(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FrbCipherSuite.values[raw as int];
+  }
+
+  @protected
+  FrbDecryptChunk dco_decode_frb_decrypt_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return FrbDecryptChunk_Data(dco_decode_list_prim_u_8_strict(raw[1]));
+      case 1:
+        return FrbDecryptChunk_Done(
+          dco_decode_box_autoadd_frb_stream_decrypt_result(raw[1]),
+        );
+      case 2:
+        return FrbDecryptChunk_Error(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   FrbDecryptEvent dco_decode_frb_decrypt_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1535,11 +2592,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   FrbDecryptResult dco_decode_frb_decrypt_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return FrbDecryptResult(
       outputPath: dco_decode_String(arr[0]),
       metadata: dco_decode_frb_decrypted_metadata(arr[1]),
+      signature: dco_decode_frb_signature_status(arr[2]),
+    );
+  }
+
+  @protected
+  FrbDecryptedCacheOptions dco_decode_frb_decrypted_cache_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FrbDecryptedCacheOptions(
+      rootDir: dco_decode_String(arr[0]),
+      maxBytes: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      ttlSecs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  FrbDecryptedCacheStats dco_decode_frb_decrypted_cache_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FrbDecryptedCacheStats(
+      rootDir: dco_decode_String(arr[0]),
+      entryCount: dco_decode_u_64(arr[1]),
+      totalBytes: dco_decode_u_64(arr[2]),
     );
   }
 
@@ -1547,8 +2631,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   FrbDecryptedMetadata dco_decode_frb_decrypted_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
     return FrbDecryptedMetadata(
       originalFilename: dco_decode_String(arr[0]),
       originalExtension: dco_decode_String(arr[1]),
@@ -1556,6 +2640,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       originalSize: dco_decode_u_64(arr[3]),
       uuid: dco_decode_String(arr[4]),
       metadata: dco_decode_list_frb_key_value(arr[5]),
+      signerPublicKey: dco_decode_opt_list_prim_u_8_strict(arr[6]),
     );
   }
 
@@ -1582,8 +2667,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   FrbEncryptOptions dco_decode_frb_encrypt_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
     return FrbEncryptOptions(
       inputPath: dco_decode_String(arr[0]),
       outputDir: dco_decode_String(arr[1]),
@@ -1594,6 +2679,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       tempDir: dco_decode_opt_String(arr[6]),
       metadata: dco_decode_list_frb_key_value(arr[7]),
       chunkSize: dco_decode_frb_chunk_size_preset(arr[8]),
+      cipherSuite: dco_decode_frb_cipher_suite(arr[9]),
+      signingKey: dco_decode_opt_list_prim_u_8_strict(arr[10]),
+      shredSource: dco_decode_bool(arr[11]),
+      hideLengths: dco_decode_bool(arr[12]),
+      volumeSize: dco_decode_opt_box_autoadd_u_64(arr[13]),
     );
   }
 
@@ -1601,12 +2691,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   FrbEncryptResult dco_decode_frb_encrypt_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return FrbEncryptResult(
       outputPath: dco_decode_String(arr[0]),
-      uuid: dco_decode_String(arr[1]),
-      originalSize: dco_decode_u_64(arr[2]),
+      volumes: dco_decode_list_String(arr[1]),
+      uuid: dco_decode_String(arr[2]),
+      originalSize: dco_decode_u_64(arr[3]),
+      shred: dco_decode_opt_box_autoadd_frb_shred_report(arr[4]),
+    );
+  }
+
+  @protected
+  FrbFecParams dco_decode_frb_fec_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FrbFecParams(
+      dataShards: dco_decode_u_8(arr[0]),
+      parityShards: dco_decode_u_8(arr[1]),
     );
   }
 
@@ -1691,6 +2795,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  FrbInspectResult dco_decode_frb_inspect_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return FrbInspectResult(
+      version: dco_decode_u_16(arr[0]),
+      argon2Params: dco_decode_frb_argon_2_params(arr[1]),
+      chunkSize: dco_decode_u_32(arr[2]),
+      fec: dco_decode_opt_box_autoadd_frb_fec_params(arr[3]),
+      signature: dco_decode_opt_box_autoadd_frb_signature_algorithm(arr[4]),
+      cipher: dco_decode_frb_cipher_suite(arr[5]),
+      keyCommitment: dco_decode_bool(arr[6]),
+      fileLen: dco_decode_u_64(arr[7]),
+      ciphertextLen: dco_decode_u_64(arr[8]),
+      chunkCount: dco_decode_u_64(arr[9]),
+      estimatedPlaintextSize: dco_decode_u_64(arr[10]),
+      wholeChunks: dco_decode_bool(arr[11]),
+    );
+  }
+
   @protected
   FrbKeepassAttachment dco_decode_frb_keepass_attachment(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2010,17 +3136,75 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  FrbMediaServerInfo dco_decode_frb_media_server_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FrbMediaServerInfo(
+      port: dco_decode_u_16(arr[0]),
+      token: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  FrbMediaSource dco_decode_frb_media_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return FrbMediaSource(
+      sourceId: dco_decode_String(arr[0]),
+      url: dco_decode_String(arr[1]),
+      size: dco_decode_u_64(arr[2]),
+      mimeType: dco_decode_String(arr[3]),
+      metadata: dco_decode_frb_decrypted_metadata(arr[4]),
+    );
+  }
+
+  @protected
+  FrbMetadataPatch dco_decode_frb_metadata_patch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FrbMetadataPatch(
+      remove: dco_decode_list_String(arr[0]),
+      set_: dco_decode_list_frb_key_value(arr[1]),
+    );
+  }
+
+  @protected
+  FrbMetadataUpdateResult dco_decode_frb_metadata_update_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FrbMetadataUpdateResult(
+      metadata: dco_decode_frb_decrypted_metadata(arr[0]),
+      inPlace: dco_decode_bool(arr[1]),
+    );
+  }
+
   @protected
   FrbProgressEvent dco_decode_frb_progress_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
     return FrbProgressEvent(
       stage: dco_decode_frb_progress_stage(arr[0]),
       bytesProcessed: dco_decode_u_64(arr[1]),
       totalBytes: dco_decode_u_64(arr[2]),
       percentage: dco_decode_f_64(arr[3]),
+      filesProcessed: dco_decode_u_64(arr[4]),
+      totalFiles: dco_decode_u_64(arr[5]),
+      bytesPerSecond: dco_decode_f_64(arr[6]),
+      etaSeconds: dco_decode_opt_box_autoadd_f_64(arr[7]),
+      overallPercentage: dco_decode_f_64(arr[8]),
+      stageIndex: dco_decode_u_32(arr[9]),
+      stageCount: dco_decode_u_32(arr[10]),
     );
   }
 
@@ -2030,6 +3214,181 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return FrbProgressStage.values[raw as int];
   }
 
+  @protected
+  FrbReencryptResult dco_decode_frb_reencrypt_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FrbReencryptResult(
+      outputPath: dco_decode_String(arr[0]),
+      chunkCount: dco_decode_u_64(arr[1]),
+      sourceSignature: dco_decode_frb_signature_status(arr[2]),
+    );
+  }
+
+  @protected
+  FrbShredEntry dco_decode_frb_shred_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FrbShredEntry(
+      path: dco_decode_String(arr[0]),
+      error: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  FrbShredReport dco_decode_frb_shred_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FrbShredReport(
+      entries: dco_decode_list_frb_shred_entry(arr[0]),
+      allShredded: dco_decode_bool(arr[1]),
+      caveat: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  FrbSignatureAlgorithm dco_decode_frb_signature_algorithm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FrbSignatureAlgorithm.values[raw as int];
+  }
+
+  @protected
+  FrbSignatureStatus dco_decode_frb_signature_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return const FrbSignatureStatus_Unsigned();
+      case 1:
+        return FrbSignatureStatus_Valid(
+          publicKey: dco_decode_list_prim_u_8_strict(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  FrbSigningIdentity dco_decode_frb_signing_identity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FrbSigningIdentity(
+      secretKey: dco_decode_list_prim_u_8_strict(arr[0]),
+      publicKey: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  FrbSpacePlan dco_decode_frb_space_plan(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return FrbSpacePlan(
+      outputBytes: dco_decode_u_64(arr[0]),
+      outputFree: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      tempBytes: dco_decode_u_64(arr[2]),
+      tempFree: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      sharedVolume: dco_decode_bool(arr[4]),
+      estimated: dco_decode_bool(arr[5]),
+      shortfall: dco_decode_opt_box_autoadd_frb_space_shortfall(arr[6]),
+    );
+  }
+
+  @protected
+  FrbSpaceShortfall dco_decode_frb_space_shortfall(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FrbSpaceShortfall(
+      path: dco_decode_String(arr[0]),
+      needed: dco_decode_u_64(arr[1]),
+      available: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  FrbStreamDecryptOptions dco_decode_frb_stream_decrypt_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FrbStreamDecryptOptions(
+      streamId: dco_decode_String(arr[0]),
+      inputPath: dco_decode_String(arr[1]),
+      password: dco_decode_String(arr[2]),
+      window: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  FrbStreamDecryptResult dco_decode_frb_stream_decrypt_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FrbStreamDecryptResult(
+      bytesWritten: dco_decode_u_64(arr[0]),
+      metadata: dco_decode_frb_decrypted_metadata(arr[1]),
+      signature: dco_decode_frb_signature_status(arr[2]),
+    );
+  }
+
+  @protected
+  FrbStreamEncryptOptions dco_decode_frb_stream_encrypt_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return FrbStreamEncryptOptions(
+      streamId: dco_decode_String(arr[0]),
+      outputPath: dco_decode_String(arr[1]),
+      password: dco_decode_String(arr[2]),
+      originalFilename: dco_decode_String(arr[3]),
+      originalExtension: dco_decode_String(arr[4]),
+      gzipCompressed: dco_decode_bool(arr[5]),
+      metadata: dco_decode_list_frb_key_value(arr[6]),
+      chunkSize: dco_decode_frb_chunk_size_preset(arr[7]),
+      cipherSuite: dco_decode_frb_cipher_suite(arr[8]),
+      signingKey: dco_decode_opt_list_prim_u_8_strict(arr[9]),
+    );
+  }
+
+  @protected
+  FrbStreamEncryptResult dco_decode_frb_stream_encrypt_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FrbStreamEncryptResult(
+      outputPath: dco_decode_String(arr[0]),
+      uuid: dco_decode_String(arr[1]),
+      bytesRead: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  FrbVerifyResult dco_decode_frb_verify_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FrbVerifyResult(
+      metadata: dco_decode_frb_decrypted_metadata(arr[0]),
+      chunkCount: dco_decode_u_64(arr[1]),
+      repairedChunks: dco_decode_u_64(arr[2]),
+      signature: dco_decode_frb_signature_status(arr[3]),
+    );
+  }
+
   @protected
   int dco_decode_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2132,25 +3491,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  List<FrbKeepassGroup> dco_decode_list_frb_keepass_group(dynamic raw) {
+  List<FrbKeepassGroup> dco_decode_list_frb_keepass_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_frb_keepass_group).toList();
+  }
+
+  @protected
+  List<FrbKeepassHistoryEntry> dco_decode_list_frb_keepass_history_entry(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_frb_keepass_history_entry)
+        .toList();
+  }
+
+  @protected
+  List<FrbKeyValue> dco_decode_list_frb_key_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_frb_key_value).toList();
+  }
+
+  @protected
+  List<FrbReencryptResult> dco_decode_list_frb_reencrypt_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_frb_keepass_group).toList();
+    return (raw as List<dynamic>).map(dco_decode_frb_reencrypt_result).toList();
   }
 
   @protected
-  List<FrbKeepassHistoryEntry> dco_decode_list_frb_keepass_history_entry(
-    dynamic raw,
-  ) {
+  List<FrbShredEntry> dco_decode_list_frb_shred_entry(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_frb_keepass_history_entry)
-        .toList();
+    return (raw as List<dynamic>).map(dco_decode_frb_shred_entry).toList();
   }
 
   @protected
-  List<FrbKeyValue> dco_decode_list_frb_key_value(dynamic raw) {
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_frb_key_value).toList();
+    return raw as List<int>;
   }
 
   @protected
@@ -2185,6 +3562,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_bool(raw);
   }
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  FrbArgon2Params? dco_decode_opt_box_autoadd_frb_argon_2_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_frb_argon_2_params(raw);
+  }
+
+  @protected
+  FrbChunkSizePreset? dco_decode_opt_box_autoadd_frb_chunk_size_preset(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_frb_chunk_size_preset(raw);
+  }
+
+  @protected
+  FrbFecParams? dco_decode_opt_box_autoadd_frb_fec_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_frb_fec_params(raw);
+  }
+
   @protected
   FrbKeepassAutoType? dco_decode_opt_box_autoadd_frb_keepass_auto_type(
     dynamic raw,
@@ -2210,6 +3615,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_frb_keepass_otp(raw);
   }
 
+  @protected
+  FrbShredReport? dco_decode_opt_box_autoadd_frb_shred_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_frb_shred_report(raw);
+  }
+
+  @protected
+  FrbSignatureAlgorithm? dco_decode_opt_box_autoadd_frb_signature_algorithm(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_frb_signature_algorithm(raw);
+  }
+
+  @protected
+  FrbSpaceShortfall? dco_decode_opt_box_autoadd_frb_space_shortfall(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_frb_space_shortfall(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2234,6 +3663,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
   }
 
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2283,6 +3718,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<FrbBatchReencryptEvent>
+  sse_decode_StreamSink_frb_batch_reencrypt_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FrbDecryptChunk> sse_decode_StreamSink_frb_decrypt_chunk_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<FrbDecryptEvent> sse_decode_StreamSink_frb_decrypt_event_Sse(
     SseDeserializer deserializer,
@@ -2335,6 +3787,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_bool(deserializer));
   }
 
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  FrbArgon2Params sse_decode_box_autoadd_frb_argon_2_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_argon_2_params(deserializer));
+  }
+
   @protected
   FrbBatchDecryptOptions sse_decode_box_autoadd_frb_batch_decrypt_options(
     SseDeserializer deserializer,
@@ -2367,6 +3833,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_frb_batch_encrypt_result(deserializer));
   }
 
+  @protected
+  FrbBatchReencryptOptions sse_decode_box_autoadd_frb_batch_reencrypt_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_batch_reencrypt_options(deserializer));
+  }
+
+  @protected
+  FrbBatchReencryptResult sse_decode_box_autoadd_frb_batch_reencrypt_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_batch_reencrypt_result(deserializer));
+  }
+
+  @protected
+  FrbChunkSizePreset sse_decode_box_autoadd_frb_chunk_size_preset(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_chunk_size_preset(deserializer));
+  }
+
   @protected
   FrbDecryptOptions sse_decode_box_autoadd_frb_decrypt_options(
     SseDeserializer deserializer,
@@ -2383,6 +3873,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_frb_decrypt_result(deserializer));
   }
 
+  @protected
+  FrbDecryptedCacheOptions sse_decode_box_autoadd_frb_decrypted_cache_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_decrypted_cache_options(deserializer));
+  }
+
   @protected
   FrbEncryptOptions sse_decode_box_autoadd_frb_encrypt_options(
     SseDeserializer deserializer,
@@ -2399,6 +3897,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_frb_encrypt_result(deserializer));
   }
 
+  @protected
+  FrbFecParams sse_decode_box_autoadd_frb_fec_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_fec_params(deserializer));
+  }
+
   @protected
   FrbIconPackError sse_decode_box_autoadd_frb_icon_pack_error(
     SseDeserializer deserializer,
@@ -2457,6 +3963,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_frb_keepass_otp(deserializer));
   }
 
+  @protected
+  FrbMetadataPatch sse_decode_box_autoadd_frb_metadata_patch(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_metadata_patch(deserializer));
+  }
+
   @protected
   FrbProgressEvent sse_decode_box_autoadd_frb_progress_event(
     SseDeserializer deserializer,
@@ -2465,6 +3979,62 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_frb_progress_event(deserializer));
   }
 
+  @protected
+  FrbReencryptResult sse_decode_box_autoadd_frb_reencrypt_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_reencrypt_result(deserializer));
+  }
+
+  @protected
+  FrbShredReport sse_decode_box_autoadd_frb_shred_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_shred_report(deserializer));
+  }
+
+  @protected
+  FrbSignatureAlgorithm sse_decode_box_autoadd_frb_signature_algorithm(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_signature_algorithm(deserializer));
+  }
+
+  @protected
+  FrbSpaceShortfall sse_decode_box_autoadd_frb_space_shortfall(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_space_shortfall(deserializer));
+  }
+
+  @protected
+  FrbStreamDecryptOptions sse_decode_box_autoadd_frb_stream_decrypt_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_stream_decrypt_options(deserializer));
+  }
+
+  @protected
+  FrbStreamDecryptResult sse_decode_box_autoadd_frb_stream_decrypt_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_stream_decrypt_result(deserializer));
+  }
+
+  @protected
+  FrbStreamEncryptOptions sse_decode_box_autoadd_frb_stream_encrypt_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frb_stream_encrypt_options(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2489,6 +4059,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  FrbArgon2Params sse_decode_frb_argon_2_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tCost = sse_decode_u_32(deserializer);
+    var var_mCostKib = sse_decode_u_32(deserializer);
+    var var_parallelism = sse_decode_u_32(deserializer);
+    return FrbArgon2Params(
+      tCost: var_tCost,
+      mCostKib: var_mCostKib,
+      parallelism: var_parallelism,
+    );
+  }
+
   @protected
   FrbBatchDecryptEvent sse_decode_frb_batch_decrypt_event(
     SseDeserializer deserializer,
@@ -2628,6 +4211,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_tempDir = sse_decode_opt_String(deserializer);
     var var_metadata = sse_decode_list_frb_key_value(deserializer);
     var var_chunkSize = sse_decode_frb_chunk_size_preset(deserializer);
+    var var_cipherSuite = sse_decode_frb_cipher_suite(deserializer);
+    var var_signingKey = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_hideLengths = sse_decode_bool(deserializer);
+    var var_volumeSize = sse_decode_opt_box_autoadd_u_64(deserializer);
     return FrbBatchEncryptOptions(
       inputPaths: var_inputPaths,
       outputDir: var_outputDir,
@@ -2636,6 +4223,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       tempDir: var_tempDir,
       metadata: var_metadata,
       chunkSize: var_chunkSize,
+      cipherSuite: var_cipherSuite,
+      signingKey: var_signingKey,
+      hideLengths: var_hideLengths,
+      volumeSize: var_volumeSize,
     );
   }
 
@@ -2657,6 +4248,93 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return FrbBatchError(inputPath: var_inputPath, error: var_error);
   }
 
+  @protected
+  FrbBatchReencryptEvent sse_decode_frb_batch_reencrypt_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_fileIndex = sse_decode_u_32(deserializer);
+        var var_totalFiles = sse_decode_u_32(deserializer);
+        var var_currentFile = sse_decode_String(deserializer);
+        var var_progress = sse_decode_box_autoadd_frb_progress_event(
+          deserializer,
+        );
+        return FrbBatchReencryptEvent_FileProgress(
+          fileIndex: var_fileIndex,
+          totalFiles: var_totalFiles,
+          currentFile: var_currentFile,
+          progress: var_progress,
+        );
+      case 1:
+        var var_fileIndex = sse_decode_u_32(deserializer);
+        var var_result = sse_decode_box_autoadd_frb_reencrypt_result(
+          deserializer,
+        );
+        return FrbBatchReencryptEvent_FileDone(
+          fileIndex: var_fileIndex,
+          result: var_result,
+        );
+      case 2:
+        var var_fileIndex = sse_decode_u_32(deserializer);
+        var var_inputPath = sse_decode_String(deserializer);
+        var var_error = sse_decode_String(deserializer);
+        return FrbBatchReencryptEvent_FileError(
+          fileIndex: var_fileIndex,
+          inputPath: var_inputPath,
+          error: var_error,
+        );
+      case 3:
+        var var_field0 = sse_decode_box_autoadd_frb_batch_reencrypt_result(
+          deserializer,
+        );
+        return FrbBatchReencryptEvent_AllDone(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  FrbBatchReencryptOptions sse_decode_frb_batch_reencrypt_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_inputPaths = sse_decode_list_String(deserializer);
+    var var_oldPassword = sse_decode_String(deserializer);
+    var var_newPassword = sse_decode_String(deserializer);
+    var var_argon2Params = sse_decode_opt_box_autoadd_frb_argon_2_params(
+      deserializer,
+    );
+    var var_chunkSize = sse_decode_opt_box_autoadd_frb_chunk_size_preset(
+      deserializer,
+    );
+    var var_signingKey = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return FrbBatchReencryptOptions(
+      inputPaths: var_inputPaths,
+      oldPassword: var_oldPassword,
+      newPassword: var_newPassword,
+      argon2Params: var_argon2Params,
+      chunkSize: var_chunkSize,
+      signingKey: var_signingKey,
+    );
+  }
+
+  @protected
+  FrbBatchReencryptResult sse_decode_frb_batch_reencrypt_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_succeeded = sse_decode_list_frb_reencrypt_result(deserializer);
+    var var_failed = sse_decode_list_frb_batch_error(deserializer);
+    return FrbBatchReencryptResult(
+      succeeded: var_succeeded,
+      failed: var_failed,
+    );
+  }
+
   @protected
   FrbChunkSizePreset sse_decode_frb_chunk_size_preset(
     SseDeserializer deserializer,
@@ -2677,6 +4355,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  FrbCipherSuite sse_decode_frb_cipher_suite(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FrbCipherSuite.values[inner];
+  }
+
+  @protected
+  FrbDecryptChunk sse_decode_frb_decrypt_chunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+        return FrbDecryptChunk_Data(var_field0);
+      case 1:
+        var var_field0 = sse_decode_box_autoadd_frb_stream_decrypt_result(
+          deserializer,
+        );
+        return FrbDecryptChunk_Done(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return FrbDecryptChunk_Error(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   FrbDecryptEvent sse_decode_frb_decrypt_event(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2721,11 +4428,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  FrbDecryptResult sse_decode_frb_decrypt_result(SseDeserializer deserializer) {
+  FrbDecryptResult sse_decode_frb_decrypt_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_outputPath = sse_decode_String(deserializer);
+    var var_metadata = sse_decode_frb_decrypted_metadata(deserializer);
+    var var_signature = sse_decode_frb_signature_status(deserializer);
+    return FrbDecryptResult(
+      outputPath: var_outputPath,
+      metadata: var_metadata,
+      signature: var_signature,
+    );
+  }
+
+  @protected
+  FrbDecryptedCacheOptions sse_decode_frb_decrypted_cache_options(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_outputPath = sse_decode_String(deserializer);
-    var var_metadata = sse_decode_frb_decrypted_metadata(deserializer);
-    return FrbDecryptResult(outputPath: var_outputPath, metadata: var_metadata);
+    var var_rootDir = sse_decode_String(deserializer);
+    var var_maxBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_ttlSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return FrbDecryptedCacheOptions(
+      rootDir: var_rootDir,
+      maxBytes: var_maxBytes,
+      ttlSecs: var_ttlSecs,
+    );
+  }
+
+  @protected
+  FrbDecryptedCacheStats sse_decode_frb_decrypted_cache_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rootDir = sse_decode_String(deserializer);
+    var var_entryCount = sse_decode_u_64(deserializer);
+    var var_totalBytes = sse_decode_u_64(deserializer);
+    return FrbDecryptedCacheStats(
+      rootDir: var_rootDir,
+      entryCount: var_entryCount,
+      totalBytes: var_totalBytes,
+    );
   }
 
   @protected
@@ -2739,6 +4481,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_originalSize = sse_decode_u_64(deserializer);
     var var_uuid = sse_decode_String(deserializer);
     var var_metadata = sse_decode_list_frb_key_value(deserializer);
+    var var_signerPublicKey = sse_decode_opt_list_prim_u_8_strict(deserializer);
     return FrbDecryptedMetadata(
       originalFilename: var_originalFilename,
       originalExtension: var_originalExtension,
@@ -2746,6 +4489,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       originalSize: var_originalSize,
       uuid: var_uuid,
       metadata: var_metadata,
+      signerPublicKey: var_signerPublicKey,
     );
   }
 
@@ -2787,6 +4531,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_tempDir = sse_decode_opt_String(deserializer);
     var var_metadata = sse_decode_list_frb_key_value(deserializer);
     var var_chunkSize = sse_decode_frb_chunk_size_preset(deserializer);
+    var var_cipherSuite = sse_decode_frb_cipher_suite(deserializer);
+    var var_signingKey = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_shredSource = sse_decode_bool(deserializer);
+    var var_hideLengths = sse_decode_bool(deserializer);
+    var var_volumeSize = sse_decode_opt_box_autoadd_u_64(deserializer);
     return FrbEncryptOptions(
       inputPath: var_inputPath,
       outputDir: var_outputDir,
@@ -2797,6 +4546,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       tempDir: var_tempDir,
       metadata: var_metadata,
       chunkSize: var_chunkSize,
+      cipherSuite: var_cipherSuite,
+      signingKey: var_signingKey,
+      shredSource: var_shredSource,
+      hideLengths: var_hideLengths,
+      volumeSize: var_volumeSize,
     );
   }
 
@@ -2804,12 +4558,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   FrbEncryptResult sse_decode_frb_encrypt_result(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_outputPath = sse_decode_String(deserializer);
+    var var_volumes = sse_decode_list_String(deserializer);
     var var_uuid = sse_decode_String(deserializer);
     var var_originalSize = sse_decode_u_64(deserializer);
+    var var_shred = sse_decode_opt_box_autoadd_frb_shred_report(deserializer);
     return FrbEncryptResult(
       outputPath: var_outputPath,
+      volumes: var_volumes,
       uuid: var_uuid,
       originalSize: var_originalSize,
+      shred: var_shred,
+    );
+  }
+
+  @protected
+  FrbFecParams sse_decode_frb_fec_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dataShards = sse_decode_u_8(deserializer);
+    var var_parityShards = sse_decode_u_8(deserializer);
+    return FrbFecParams(
+      dataShards: var_dataShards,
+      parityShards: var_parityShards,
     );
   }
 
@@ -2910,6 +4679,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  FrbInspectResult sse_decode_frb_inspect_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_version = sse_decode_u_16(deserializer);
+    var var_argon2Params = sse_decode_frb_argon_2_params(deserializer);
+    var var_chunkSize = sse_decode_u_32(deserializer);
+    var var_fec = sse_decode_opt_box_autoadd_frb_fec_params(deserializer);
+    var var_signature = sse_decode_opt_box_autoadd_frb_signature_algorithm(
+      deserializer,
+    );
+    var var_cipher = sse_decode_frb_cipher_suite(deserializer);
+    var var_keyCommitment = sse_decode_bool(deserializer);
+    var var_fileLen = sse_decode_u_64(deserializer);
+    var var_ciphertextLen = sse_decode_u_64(deserializer);
+    var var_chunkCount = sse_decode_u_64(deserializer);
+    var var_estimatedPlaintextSize = sse_decode_u_64(deserializer);
+    var var_wholeChunks = sse_decode_bool(deserializer);
+    return FrbInspectResult(
+      version: var_version,
+      argon2Params: var_argon2Params,
+      chunkSize: var_chunkSize,
+      fec: var_fec,
+      signature: var_signature,
+      cipher: var_cipher,
+      keyCommitment: var_keyCommitment,
+      fileLen: var_fileLen,
+      ciphertextLen: var_ciphertextLen,
+      chunkCount: var_chunkCount,
+      estimatedPlaintextSize: var_estimatedPlaintextSize,
+      wholeChunks: var_wholeChunks,
+    );
+  }
+
   @protected
   FrbKeepassAttachment sse_decode_frb_keepass_attachment(
     SseDeserializer deserializer,
@@ -3351,6 +5153,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return FrbKeyValue(key: var_key, value: var_value);
   }
 
+  @protected
+  FrbMediaServerInfo sse_decode_frb_media_server_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_port = sse_decode_u_16(deserializer);
+    var var_token = sse_decode_String(deserializer);
+    return FrbMediaServerInfo(port: var_port, token: var_token);
+  }
+
+  @protected
+  FrbMediaSource sse_decode_frb_media_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sourceId = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_metadata = sse_decode_frb_decrypted_metadata(deserializer);
+    return FrbMediaSource(
+      sourceId: var_sourceId,
+      url: var_url,
+      size: var_size,
+      mimeType: var_mimeType,
+      metadata: var_metadata,
+    );
+  }
+
+  @protected
+  FrbMetadataPatch sse_decode_frb_metadata_patch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_remove = sse_decode_list_String(deserializer);
+    var var_set_ = sse_decode_list_frb_key_value(deserializer);
+    return FrbMetadataPatch(remove: var_remove, set_: var_set_);
+  }
+
+  @protected
+  FrbMetadataUpdateResult sse_decode_frb_metadata_update_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_metadata = sse_decode_frb_decrypted_metadata(deserializer);
+    var var_inPlace = sse_decode_bool(deserializer);
+    return FrbMetadataUpdateResult(
+      metadata: var_metadata,
+      inPlace: var_inPlace,
+    );
+  }
+
   @protected
   FrbProgressEvent sse_decode_frb_progress_event(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3358,11 +5208,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_bytesProcessed = sse_decode_u_64(deserializer);
     var var_totalBytes = sse_decode_u_64(deserializer);
     var var_percentage = sse_decode_f_64(deserializer);
+    var var_filesProcessed = sse_decode_u_64(deserializer);
+    var var_totalFiles = sse_decode_u_64(deserializer);
+    var var_bytesPerSecond = sse_decode_f_64(deserializer);
+    var var_etaSeconds = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_overallPercentage = sse_decode_f_64(deserializer);
+    var var_stageIndex = sse_decode_u_32(deserializer);
+    var var_stageCount = sse_decode_u_32(deserializer);
     return FrbProgressEvent(
       stage: var_stage,
       bytesProcessed: var_bytesProcessed,
       totalBytes: var_totalBytes,
       percentage: var_percentage,
+      filesProcessed: var_filesProcessed,
+      totalFiles: var_totalFiles,
+      bytesPerSecond: var_bytesPerSecond,
+      etaSeconds: var_etaSeconds,
+      overallPercentage: var_overallPercentage,
+      stageIndex: var_stageIndex,
+      stageCount: var_stageCount,
     );
   }
 
@@ -3373,6 +5237,211 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return FrbProgressStage.values[inner];
   }
 
+  @protected
+  FrbReencryptResult sse_decode_frb_reencrypt_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_outputPath = sse_decode_String(deserializer);
+    var var_chunkCount = sse_decode_u_64(deserializer);
+    var var_sourceSignature = sse_decode_frb_signature_status(deserializer);
+    return FrbReencryptResult(
+      outputPath: var_outputPath,
+      chunkCount: var_chunkCount,
+      sourceSignature: var_sourceSignature,
+    );
+  }
+
+  @protected
+  FrbShredEntry sse_decode_frb_shred_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return FrbShredEntry(path: var_path, error: var_error);
+  }
+
+  @protected
+  FrbShredReport sse_decode_frb_shred_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_entries = sse_decode_list_frb_shred_entry(deserializer);
+    var var_allShredded = sse_decode_bool(deserializer);
+    var var_caveat = sse_decode_String(deserializer);
+    return FrbShredReport(
+      entries: var_entries,
+      allShredded: var_allShredded,
+      caveat: var_caveat,
+    );
+  }
+
+  @protected
+  FrbSignatureAlgorithm sse_decode_frb_signature_algorithm(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FrbSignatureAlgorithm.values[inner];
+  }
+
+  @protected
+  FrbSignatureStatus sse_decode_frb_signature_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return const FrbSignatureStatus_Unsigned();
+      case 1:
+        var var_publicKey = sse_decode_list_prim_u_8_strict(deserializer);
+        return FrbSignatureStatus_Valid(publicKey: var_publicKey);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  FrbSigningIdentity sse_decode_frb_signing_identity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_secretKey = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_publicKey = sse_decode_list_prim_u_8_strict(deserializer);
+    return FrbSigningIdentity(
+      secretKey: var_secretKey,
+      publicKey: var_publicKey,
+    );
+  }
+
+  @protected
+  FrbSpacePlan sse_decode_frb_space_plan(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_outputBytes = sse_decode_u_64(deserializer);
+    var var_outputFree = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_tempBytes = sse_decode_u_64(deserializer);
+    var var_tempFree = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_sharedVolume = sse_decode_bool(deserializer);
+    var var_estimated = sse_decode_bool(deserializer);
+    var var_shortfall = sse_decode_opt_box_autoadd_frb_space_shortfall(
+      deserializer,
+    );
+    return FrbSpacePlan(
+      outputBytes: var_outputBytes,
+      outputFree: var_outputFree,
+      tempBytes: var_tempBytes,
+      tempFree: var_tempFree,
+      sharedVolume: var_sharedVolume,
+      estimated: var_estimated,
+      shortfall: var_shortfall,
+    );
+  }
+
+  @protected
+  FrbSpaceShortfall sse_decode_frb_space_shortfall(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_needed = sse_decode_u_64(deserializer);
+    var var_available = sse_decode_u_64(deserializer);
+    return FrbSpaceShortfall(
+      path: var_path,
+      needed: var_needed,
+      available: var_available,
+    );
+  }
+
+  @protected
+  FrbStreamDecryptOptions sse_decode_frb_stream_decrypt_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_streamId = sse_decode_String(deserializer);
+    var var_inputPath = sse_decode_String(deserializer);
+    var var_password = sse_decode_String(deserializer);
+    var var_window = sse_decode_u_32(deserializer);
+    return FrbStreamDecryptOptions(
+      streamId: var_streamId,
+      inputPath: var_inputPath,
+      password: var_password,
+      window: var_window,
+    );
+  }
+
+  @protected
+  FrbStreamDecryptResult sse_decode_frb_stream_decrypt_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytesWritten = sse_decode_u_64(deserializer);
+    var var_metadata = sse_decode_frb_decrypted_metadata(deserializer);
+    var var_signature = sse_decode_frb_signature_status(deserializer);
+    return FrbStreamDecryptResult(
+      bytesWritten: var_bytesWritten,
+      metadata: var_metadata,
+      signature: var_signature,
+    );
+  }
+
+  @protected
+  FrbStreamEncryptOptions sse_decode_frb_stream_encrypt_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_streamId = sse_decode_String(deserializer);
+    var var_outputPath = sse_decode_String(deserializer);
+    var var_password = sse_decode_String(deserializer);
+    var var_originalFilename = sse_decode_String(deserializer);
+    var var_originalExtension = sse_decode_String(deserializer);
+    var var_gzipCompressed = sse_decode_bool(deserializer);
+    var var_metadata = sse_decode_list_frb_key_value(deserializer);
+    var var_chunkSize = sse_decode_frb_chunk_size_preset(deserializer);
+    var var_cipherSuite = sse_decode_frb_cipher_suite(deserializer);
+    var var_signingKey = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return FrbStreamEncryptOptions(
+      streamId: var_streamId,
+      outputPath: var_outputPath,
+      password: var_password,
+      originalFilename: var_originalFilename,
+      originalExtension: var_originalExtension,
+      gzipCompressed: var_gzipCompressed,
+      metadata: var_metadata,
+      chunkSize: var_chunkSize,
+      cipherSuite: var_cipherSuite,
+      signingKey: var_signingKey,
+    );
+  }
+
+  @protected
+  FrbStreamEncryptResult sse_decode_frb_stream_encrypt_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_outputPath = sse_decode_String(deserializer);
+    var var_uuid = sse_decode_String(deserializer);
+    var var_bytesRead = sse_decode_u_64(deserializer);
+    return FrbStreamEncryptResult(
+      outputPath: var_outputPath,
+      uuid: var_uuid,
+      bytesRead: var_bytesRead,
+    );
+  }
+
+  @protected
+  FrbVerifyResult sse_decode_frb_verify_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_metadata = sse_decode_frb_decrypted_metadata(deserializer);
+    var var_chunkCount = sse_decode_u_64(deserializer);
+    var var_repairedChunks = sse_decode_u_64(deserializer);
+    var var_signature = sse_decode_frb_signature_status(deserializer);
+    return FrbVerifyResult(
+      metadata: var_metadata,
+      chunkCount: var_chunkCount,
+      repairedChunks: var_repairedChunks,
+      signature: var_signature,
+    );
+  }
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3594,6 +5663,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<FrbReencryptResult> sse_decode_list_frb_reencrypt_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FrbReencryptResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_frb_reencrypt_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FrbShredEntry> sse_decode_list_frb_shred_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FrbShredEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_frb_shred_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3604,35 +5708,85 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_timeMillis = sse_decode_i_64(deserializer);
-    var var_level = sse_decode_i_32(deserializer);
-    var var_tag = sse_decode_String(deserializer);
-    var var_msg = sse_decode_String(deserializer);
-    return LogEntry(
-      timeMillis: var_timeMillis,
-      level: var_level,
-      tag: var_tag,
-      msg: var_msg,
-    );
+    var var_timeMillis = sse_decode_i_64(deserializer);
+    var var_level = sse_decode_i_32(deserializer);
+    var var_tag = sse_decode_String(deserializer);
+    var var_msg = sse_decode_String(deserializer);
+    return LogEntry(
+      timeMillis: var_timeMillis,
+      level: var_level,
+      tag: var_tag,
+      msg: var_msg,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FrbArgon2Params? sse_decode_opt_box_autoadd_frb_argon_2_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_frb_argon_2_params(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  FrbChunkSizePreset? sse_decode_opt_box_autoadd_frb_chunk_size_preset(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
+      return (sse_decode_box_autoadd_frb_chunk_size_preset(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+  FrbFecParams? sse_decode_opt_box_autoadd_frb_fec_params(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
+      return (sse_decode_box_autoadd_frb_fec_params(deserializer));
     } else {
       return null;
     }
@@ -3680,6 +5834,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  FrbShredReport? sse_decode_opt_box_autoadd_frb_shred_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_frb_shred_report(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FrbSignatureAlgorithm? sse_decode_opt_box_autoadd_frb_signature_algorithm(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_frb_signature_algorithm(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FrbSpaceShortfall? sse_decode_opt_box_autoadd_frb_space_shortfall(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_frb_space_shortfall(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3724,6 +5917,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3790,6 +5989,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_frb_batch_reencrypt_event_Sse(
+    RustStreamSink<FrbBatchReencryptEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_batch_reencrypt_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_frb_decrypt_chunk_Sse(
+    RustStreamSink<FrbDecryptChunk> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frb_decrypt_chunk,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_frb_decrypt_event_Sse(
     RustStreamSink<FrbDecryptEvent> self,
@@ -3876,6 +6109,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_frb_argon_2_params(
+    FrbArgon2Params self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_argon_2_params(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_frb_batch_decrypt_options(
     FrbBatchDecryptOptions self,
@@ -3912,6 +6160,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_frb_batch_encrypt_result(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_frb_batch_reencrypt_options(
+    FrbBatchReencryptOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_batch_reencrypt_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_frb_batch_reencrypt_result(
+    FrbBatchReencryptResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_batch_reencrypt_result(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_frb_chunk_size_preset(
+    FrbChunkSizePreset self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_chunk_size_preset(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_frb_decrypt_options(
     FrbDecryptOptions self,
@@ -3930,6 +6205,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_frb_decrypt_result(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_frb_decrypted_cache_options(
+    FrbDecryptedCacheOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_decrypted_cache_options(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_frb_encrypt_options(
     FrbEncryptOptions self,
@@ -3948,6 +6232,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_frb_encrypt_result(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_frb_fec_params(
+    FrbFecParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_fec_params(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_frb_icon_pack_error(
     FrbIconPackError self,
@@ -4011,6 +6304,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_frb_keepass_otp(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_frb_metadata_patch(
+    FrbMetadataPatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_metadata_patch(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_frb_progress_event(
     FrbProgressEvent self,
@@ -4020,6 +6322,69 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_frb_progress_event(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_frb_reencrypt_result(
+    FrbReencryptResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_reencrypt_result(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_frb_shred_report(
+    FrbShredReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_shred_report(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_frb_signature_algorithm(
+    FrbSignatureAlgorithm self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_signature_algorithm(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_frb_space_shortfall(
+    FrbSpaceShortfall self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_space_shortfall(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_frb_stream_decrypt_options(
+    FrbStreamDecryptOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_stream_decrypt_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_frb_stream_decrypt_result(
+    FrbStreamDecryptResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_stream_decrypt_result(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_frb_stream_encrypt_options(
+    FrbStreamEncryptOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_stream_encrypt_options(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4044,6 +6409,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_frb_argon_2_params(
+    FrbArgon2Params self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.tCost, serializer);
+    sse_encode_u_32(self.mCostKib, serializer);
+    sse_encode_u_32(self.parallelism, serializer);
+  }
+
   @protected
   void sse_encode_frb_batch_decrypt_event(
     FrbBatchDecryptEvent self,
@@ -4160,6 +6536,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.tempDir, serializer);
     sse_encode_list_frb_key_value(self.metadata, serializer);
     sse_encode_frb_chunk_size_preset(self.chunkSize, serializer);
+    sse_encode_frb_cipher_suite(self.cipherSuite, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.signingKey, serializer);
+    sse_encode_bool(self.hideLengths, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.volumeSize, serializer);
   }
 
   @protected
@@ -4183,19 +6563,117 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_frb_chunk_size_preset(
-    FrbChunkSizePreset self,
+  void sse_encode_frb_batch_reencrypt_event(
+    FrbBatchReencryptEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case FrbBatchReencryptEvent_FileProgress(
+        fileIndex: final fileIndex,
+        totalFiles: final totalFiles,
+        currentFile: final currentFile,
+        progress: final progress,
+      ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_32(fileIndex, serializer);
+        sse_encode_u_32(totalFiles, serializer);
+        sse_encode_String(currentFile, serializer);
+        sse_encode_box_autoadd_frb_progress_event(progress, serializer);
+      case FrbBatchReencryptEvent_FileDone(
+        fileIndex: final fileIndex,
+        result: final result,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(fileIndex, serializer);
+        sse_encode_box_autoadd_frb_reencrypt_result(result, serializer);
+      case FrbBatchReencryptEvent_FileError(
+        fileIndex: final fileIndex,
+        inputPath: final inputPath,
+        error: final error,
+      ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_32(fileIndex, serializer);
+        sse_encode_String(inputPath, serializer);
+        sse_encode_String(error, serializer);
+      case FrbBatchReencryptEvent_AllDone(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_box_autoadd_frb_batch_reencrypt_result(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_frb_batch_reencrypt_options(
+    FrbBatchReencryptOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.inputPaths, serializer);
+    sse_encode_String(self.oldPassword, serializer);
+    sse_encode_String(self.newPassword, serializer);
+    sse_encode_opt_box_autoadd_frb_argon_2_params(
+      self.argon2Params,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_frb_chunk_size_preset(
+      self.chunkSize,
+      serializer,
+    );
+    sse_encode_opt_list_prim_u_8_strict(self.signingKey, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_batch_reencrypt_result(
+    FrbBatchReencryptResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_frb_reencrypt_result(self.succeeded, serializer);
+    sse_encode_list_frb_batch_error(self.failed, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_chunk_size_preset(
+    FrbChunkSizePreset self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case FrbChunkSizePreset_Desktop():
+        sse_encode_i_32(0, serializer);
+      case FrbChunkSizePreset_Mobile():
+        sse_encode_i_32(1, serializer);
+      case FrbChunkSizePreset_Custom(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_32(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_frb_cipher_suite(
+    FrbCipherSuite self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_decrypt_chunk(
+    FrbDecryptChunk self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     switch (self) {
-      case FrbChunkSizePreset_Desktop():
+      case FrbDecryptChunk_Data(field0: final field0):
         sse_encode_i_32(0, serializer);
-      case FrbChunkSizePreset_Mobile():
+        sse_encode_list_prim_u_8_strict(field0, serializer);
+      case FrbDecryptChunk_Done(field0: final field0):
         sse_encode_i_32(1, serializer);
-      case FrbChunkSizePreset_Custom(field0: final field0):
+        sse_encode_box_autoadd_frb_stream_decrypt_result(field0, serializer);
+      case FrbDecryptChunk_Error(field0: final field0):
         sse_encode_i_32(2, serializer);
-        sse_encode_u_32(field0, serializer);
+        sse_encode_String(field0, serializer);
     }
   }
 
@@ -4239,6 +6717,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.outputPath, serializer);
     sse_encode_frb_decrypted_metadata(self.metadata, serializer);
+    sse_encode_frb_signature_status(self.signature, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_decrypted_cache_options(
+    FrbDecryptedCacheOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.rootDir, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxBytes, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.ttlSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_decrypted_cache_stats(
+    FrbDecryptedCacheStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.rootDir, serializer);
+    sse_encode_u_64(self.entryCount, serializer);
+    sse_encode_u_64(self.totalBytes, serializer);
   }
 
   @protected
@@ -4253,6 +6754,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.originalSize, serializer);
     sse_encode_String(self.uuid, serializer);
     sse_encode_list_frb_key_value(self.metadata, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.signerPublicKey, serializer);
   }
 
   @protected
@@ -4289,6 +6791,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.tempDir, serializer);
     sse_encode_list_frb_key_value(self.metadata, serializer);
     sse_encode_frb_chunk_size_preset(self.chunkSize, serializer);
+    sse_encode_frb_cipher_suite(self.cipherSuite, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.signingKey, serializer);
+    sse_encode_bool(self.shredSource, serializer);
+    sse_encode_bool(self.hideLengths, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.volumeSize, serializer);
   }
 
   @protected
@@ -4298,8 +6805,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.outputPath, serializer);
+    sse_encode_list_String(self.volumes, serializer);
     sse_encode_String(self.uuid, serializer);
     sse_encode_u_64(self.originalSize, serializer);
+    sse_encode_opt_box_autoadd_frb_shred_report(self.shred, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_fec_params(FrbFecParams self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self.dataShards, serializer);
+    sse_encode_u_8(self.parityShards, serializer);
   }
 
   @protected
@@ -4374,6 +6890,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.iconCount, serializer);
   }
 
+  @protected
+  void sse_encode_frb_inspect_result(
+    FrbInspectResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self.version, serializer);
+    sse_encode_frb_argon_2_params(self.argon2Params, serializer);
+    sse_encode_u_32(self.chunkSize, serializer);
+    sse_encode_opt_box_autoadd_frb_fec_params(self.fec, serializer);
+    sse_encode_opt_box_autoadd_frb_signature_algorithm(
+      self.signature,
+      serializer,
+    );
+    sse_encode_frb_cipher_suite(self.cipher, serializer);
+    sse_encode_bool(self.keyCommitment, serializer);
+    sse_encode_u_64(self.fileLen, serializer);
+    sse_encode_u_64(self.ciphertextLen, serializer);
+    sse_encode_u_64(self.chunkCount, serializer);
+    sse_encode_u_64(self.estimatedPlaintextSize, serializer);
+    sse_encode_bool(self.wholeChunks, serializer);
+  }
+
   @protected
   void sse_encode_frb_keepass_attachment(
     FrbKeepassAttachment self,
@@ -4655,6 +7194,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.value, serializer);
   }
 
+  @protected
+  void sse_encode_frb_media_server_info(
+    FrbMediaServerInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_String(self.token, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_media_source(
+    FrbMediaSource self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sourceId, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_frb_decrypted_metadata(self.metadata, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_metadata_patch(
+    FrbMetadataPatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.remove, serializer);
+    sse_encode_list_frb_key_value(self.set_, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_metadata_update_result(
+    FrbMetadataUpdateResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_decrypted_metadata(self.metadata, serializer);
+    sse_encode_bool(self.inPlace, serializer);
+  }
+
   @protected
   void sse_encode_frb_progress_event(
     FrbProgressEvent self,
@@ -4665,6 +7247,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.bytesProcessed, serializer);
     sse_encode_u_64(self.totalBytes, serializer);
     sse_encode_f_64(self.percentage, serializer);
+    sse_encode_u_64(self.filesProcessed, serializer);
+    sse_encode_u_64(self.totalFiles, serializer);
+    sse_encode_f_64(self.bytesPerSecond, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.etaSeconds, serializer);
+    sse_encode_f_64(self.overallPercentage, serializer);
+    sse_encode_u_32(self.stageIndex, serializer);
+    sse_encode_u_32(self.stageCount, serializer);
   }
 
   @protected
@@ -4676,6 +7265,159 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_frb_reencrypt_result(
+    FrbReencryptResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.outputPath, serializer);
+    sse_encode_u_64(self.chunkCount, serializer);
+    sse_encode_frb_signature_status(self.sourceSignature, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_shred_entry(
+    FrbShredEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_shred_report(
+    FrbShredReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_frb_shred_entry(self.entries, serializer);
+    sse_encode_bool(self.allShredded, serializer);
+    sse_encode_String(self.caveat, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_signature_algorithm(
+    FrbSignatureAlgorithm self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_signature_status(
+    FrbSignatureStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case FrbSignatureStatus_Unsigned():
+        sse_encode_i_32(0, serializer);
+      case FrbSignatureStatus_Valid(publicKey: final publicKey):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_prim_u_8_strict(publicKey, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_frb_signing_identity(
+    FrbSigningIdentity self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.secretKey, serializer);
+    sse_encode_list_prim_u_8_strict(self.publicKey, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_space_plan(FrbSpacePlan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.outputBytes, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.outputFree, serializer);
+    sse_encode_u_64(self.tempBytes, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.tempFree, serializer);
+    sse_encode_bool(self.sharedVolume, serializer);
+    sse_encode_bool(self.estimated, serializer);
+    sse_encode_opt_box_autoadd_frb_space_shortfall(self.shortfall, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_space_shortfall(
+    FrbSpaceShortfall self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_64(self.needed, serializer);
+    sse_encode_u_64(self.available, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_stream_decrypt_options(
+    FrbStreamDecryptOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.streamId, serializer);
+    sse_encode_String(self.inputPath, serializer);
+    sse_encode_String(self.password, serializer);
+    sse_encode_u_32(self.window, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_stream_decrypt_result(
+    FrbStreamDecryptResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.bytesWritten, serializer);
+    sse_encode_frb_decrypted_metadata(self.metadata, serializer);
+    sse_encode_frb_signature_status(self.signature, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_stream_encrypt_options(
+    FrbStreamEncryptOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.streamId, serializer);
+    sse_encode_String(self.outputPath, serializer);
+    sse_encode_String(self.password, serializer);
+    sse_encode_String(self.originalFilename, serializer);
+    sse_encode_String(self.originalExtension, serializer);
+    sse_encode_bool(self.gzipCompressed, serializer);
+    sse_encode_list_frb_key_value(self.metadata, serializer);
+    sse_encode_frb_chunk_size_preset(self.chunkSize, serializer);
+    sse_encode_frb_cipher_suite(self.cipherSuite, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.signingKey, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_stream_encrypt_result(
+    FrbStreamEncryptResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.outputPath, serializer);
+    sse_encode_String(self.uuid, serializer);
+    sse_encode_u_64(self.bytesRead, serializer);
+  }
+
+  @protected
+  void sse_encode_frb_verify_result(
+    FrbVerifyResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frb_decrypted_metadata(self.metadata, serializer);
+    sse_encode_u_64(self.chunkCount, serializer);
+    sse_encode_u_64(self.repairedChunks, serializer);
+    sse_encode_frb_signature_status(self.signature, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4865,6 +7607,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_frb_reencrypt_result(
+    List<FrbReencryptResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_frb_reencrypt_result(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_frb_shred_entry(
+    List<FrbShredEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_frb_shred_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -4904,6 +7682,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_frb_argon_2_params(
+    FrbArgon2Params? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_frb_argon_2_params(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_frb_chunk_size_preset(
+    FrbChunkSizePreset? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_frb_chunk_size_preset(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_frb_fec_params(
+    FrbFecParams? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_frb_fec_params(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_frb_keepass_auto_type(
     FrbKeepassAutoType? self,
@@ -4943,6 +7770,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_frb_shred_report(
+    FrbShredReport? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_frb_shred_report(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_frb_signature_algorithm(
+    FrbSignatureAlgorithm? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_frb_signature_algorithm(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_frb_space_shortfall(
+    FrbSpaceShortfall? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_frb_space_shortfall(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4986,6 +7852,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -36,6 +36,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<FrbBatchEncryptEvent>
   dco_decode_StreamSink_frb_batch_encrypt_event_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<FrbBatchReencryptEvent>
+  dco_decode_StreamSink_frb_batch_reencrypt_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<FrbDecryptChunk> dco_decode_StreamSink_frb_decrypt_chunk_Sse(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<FrbDecryptEvent> dco_decode_StreamSink_frb_decrypt_event_Sse(
     dynamic raw,
@@ -62,6 +71,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw);
 
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FrbArgon2Params dco_decode_box_autoadd_frb_argon_2_params(dynamic raw);
+
   @protected
   FrbBatchDecryptOptions dco_decode_box_autoadd_frb_batch_decrypt_options(
     dynamic raw,
@@ -82,18 +97,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  FrbBatchReencryptOptions dco_decode_box_autoadd_frb_batch_reencrypt_options(
+    dynamic raw,
+  );
+
+  @protected
+  FrbBatchReencryptResult dco_decode_box_autoadd_frb_batch_reencrypt_result(
+    dynamic raw,
+  );
+
+  @protected
+  FrbChunkSizePreset dco_decode_box_autoadd_frb_chunk_size_preset(dynamic raw);
+
   @protected
   FrbDecryptOptions dco_decode_box_autoadd_frb_decrypt_options(dynamic raw);
 
   @protected
   FrbDecryptResult dco_decode_box_autoadd_frb_decrypt_result(dynamic raw);
 
+  @protected
+  FrbDecryptedCacheOptions dco_decode_box_autoadd_frb_decrypted_cache_options(
+    dynamic raw,
+  );
+
   @protected
   FrbEncryptOptions dco_decode_box_autoadd_frb_encrypt_options(dynamic raw);
 
   @protected
   FrbEncryptResult dco_decode_box_autoadd_frb_encrypt_result(dynamic raw);
 
+  @protected
+  FrbFecParams dco_decode_box_autoadd_frb_fec_params(dynamic raw);
+
   @protected
   FrbIconPackError dco_decode_box_autoadd_frb_icon_pack_error(dynamic raw);
 
@@ -119,9 +155,41 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbKeepassOtp dco_decode_box_autoadd_frb_keepass_otp(dynamic raw);
 
+  @protected
+  FrbMetadataPatch dco_decode_box_autoadd_frb_metadata_patch(dynamic raw);
+
   @protected
   FrbProgressEvent dco_decode_box_autoadd_frb_progress_event(dynamic raw);
 
+  @protected
+  FrbReencryptResult dco_decode_box_autoadd_frb_reencrypt_result(dynamic raw);
+
+  @protected
+  FrbShredReport dco_decode_box_autoadd_frb_shred_report(dynamic raw);
+
+  @protected
+  FrbSignatureAlgorithm dco_decode_box_autoadd_frb_signature_algorithm(
+    dynamic raw,
+  );
+
+  @protected
+  FrbSpaceShortfall dco_decode_box_autoadd_frb_space_shortfall(dynamic raw);
+
+  @protected
+  FrbStreamDecryptOptions dco_decode_box_autoadd_frb_stream_decrypt_options(
+    dynamic raw,
+  );
+
+  @protected
+  FrbStreamDecryptResult dco_decode_box_autoadd_frb_stream_decrypt_result(
+    dynamic raw,
+  );
+
+  @protected
+  FrbStreamEncryptOptions dco_decode_box_autoadd_frb_stream_encrypt_options(
+    dynamic raw,
+  );
+
   @protected
   int dco_decode_box_autoadd_i_32(dynamic raw);
 
@@ -134,6 +202,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  FrbArgon2Params dco_decode_frb_argon_2_params(dynamic raw);
+
   @protected
   FrbBatchDecryptEvent dco_decode_frb_batch_decrypt_event(dynamic raw);
 
@@ -155,9 +226,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbBatchError dco_decode_frb_batch_error(dynamic raw);
 
+  @protected
+  FrbBatchReencryptEvent dco_decode_frb_batch_reencrypt_event(dynamic raw);
+
+  @protected
+  FrbBatchReencryptOptions dco_decode_frb_batch_reencrypt_options(dynamic raw);
+
+  @protected
+  FrbBatchReencryptResult dco_decode_frb_batch_reencrypt_result(dynamic raw);
+
   @protected
   FrbChunkSizePreset dco_decode_frb_chunk_size_preset(dynamic raw);
 
+  @protected
+  FrbCipherSuite dco_decode_frb_cipher_suite(dynamic raw);
+
+  @protected
+  FrbDecryptChunk dco_decode_frb_decrypt_chunk(dynamic raw);
+
   @protected
   FrbDecryptEvent dco_decode_frb_decrypt_event(dynamic raw);
 
@@ -167,6 +253,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbDecryptResult dco_decode_frb_decrypt_result(dynamic raw);
 
+  @protected
+  FrbDecryptedCacheOptions dco_decode_frb_decrypted_cache_options(dynamic raw);
+
+  @protected
+  FrbDecryptedCacheStats dco_decode_frb_decrypted_cache_stats(dynamic raw);
+
   @protected
   FrbDecryptedMetadata dco_decode_frb_decrypted_metadata(dynamic raw);
 
@@ -179,6 +271,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbEncryptResult dco_decode_frb_encrypt_result(dynamic raw);
 
+  @protected
+  FrbFecParams dco_decode_frb_fec_params(dynamic raw);
+
   @protected
   FrbIconPackEntry dco_decode_frb_icon_pack_entry(dynamic raw);
 
@@ -196,6 +291,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbIconPackSummary dco_decode_frb_icon_pack_summary(dynamic raw);
 
+  @protected
+  FrbInspectResult dco_decode_frb_inspect_result(dynamic raw);
+
   @protected
   FrbKeepassAttachment dco_decode_frb_keepass_attachment(dynamic raw);
 
@@ -251,12 +349,63 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbKeyValue dco_decode_frb_key_value(dynamic raw);
 
+  @protected
+  FrbMediaServerInfo dco_decode_frb_media_server_info(dynamic raw);
+
+  @protected
+  FrbMediaSource dco_decode_frb_media_source(dynamic raw);
+
+  @protected
+  FrbMetadataPatch dco_decode_frb_metadata_patch(dynamic raw);
+
+  @protected
+  FrbMetadataUpdateResult dco_decode_frb_metadata_update_result(dynamic raw);
+
   @protected
   FrbProgressEvent dco_decode_frb_progress_event(dynamic raw);
 
   @protected
   FrbProgressStage dco_decode_frb_progress_stage(dynamic raw);
 
+  @protected
+  FrbReencryptResult dco_decode_frb_reencrypt_result(dynamic raw);
+
+  @protected
+  FrbShredEntry dco_decode_frb_shred_entry(dynamic raw);
+
+  @protected
+  FrbShredReport dco_decode_frb_shred_report(dynamic raw);
+
+  @protected
+  FrbSignatureAlgorithm dco_decode_frb_signature_algorithm(dynamic raw);
+
+  @protected
+  FrbSignatureStatus dco_decode_frb_signature_status(dynamic raw);
+
+  @protected
+  FrbSigningIdentity dco_decode_frb_signing_identity(dynamic raw);
+
+  @protected
+  FrbSpacePlan dco_decode_frb_space_plan(dynamic raw);
+
+  @protected
+  FrbSpaceShortfall dco_decode_frb_space_shortfall(dynamic raw);
+
+  @protected
+  FrbStreamDecryptOptions dco_decode_frb_stream_decrypt_options(dynamic raw);
+
+  @protected
+  FrbStreamDecryptResult dco_decode_frb_stream_decrypt_result(dynamic raw);
+
+  @protected
+  FrbStreamEncryptOptions dco_decode_frb_stream_encrypt_options(dynamic raw);
+
+  @protected
+  FrbStreamEncryptResult dco_decode_frb_stream_encrypt_result(dynamic raw);
+
+  @protected
+  FrbVerifyResult dco_decode_frb_verify_result(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
@@ -317,6 +466,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<FrbKeyValue> dco_decode_list_frb_key_value(dynamic raw);
 
+  @protected
+  List<FrbReencryptResult> dco_decode_list_frb_reencrypt_result(dynamic raw);
+
+  @protected
+  List<FrbShredEntry> dco_decode_list_frb_shred_entry(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
@@ -329,6 +487,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FrbArgon2Params? dco_decode_opt_box_autoadd_frb_argon_2_params(dynamic raw);
+
+  @protected
+  FrbChunkSizePreset? dco_decode_opt_box_autoadd_frb_chunk_size_preset(
+    dynamic raw,
+  );
+
+  @protected
+  FrbFecParams? dco_decode_opt_box_autoadd_frb_fec_params(dynamic raw);
+
   @protected
   FrbKeepassAutoType? dco_decode_opt_box_autoadd_frb_keepass_auto_type(
     dynamic raw,
@@ -341,6 +513,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbKeepassOtp? dco_decode_opt_box_autoadd_frb_keepass_otp(dynamic raw);
 
+  @protected
+  FrbShredReport? dco_decode_opt_box_autoadd_frb_shred_report(dynamic raw);
+
+  @protected
+  FrbSignatureAlgorithm? dco_decode_opt_box_autoadd_frb_signature_algorithm(
+    dynamic raw,
+  );
+
+  @protected
+  FrbSpaceShortfall? dco_decode_opt_box_autoadd_frb_space_shortfall(
+    dynamic raw,
+  );
+
   @protected
   int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
@@ -353,6 +538,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -380,6 +568,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<FrbBatchReencryptEvent>
+  sse_decode_StreamSink_frb_batch_reencrypt_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FrbDecryptChunk> sse_decode_StreamSink_frb_decrypt_chunk_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<FrbDecryptEvent> sse_decode_StreamSink_frb_decrypt_event_Sse(
     SseDeserializer deserializer,
@@ -410,6 +609,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FrbArgon2Params sse_decode_box_autoadd_frb_argon_2_params(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FrbBatchDecryptOptions sse_decode_box_autoadd_frb_batch_decrypt_options(
     SseDeserializer deserializer,
@@ -430,6 +637,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  FrbBatchReencryptOptions sse_decode_box_autoadd_frb_batch_reencrypt_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbBatchReencryptResult sse_decode_box_autoadd_frb_batch_reencrypt_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbChunkSizePreset sse_decode_box_autoadd_frb_chunk_size_preset(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FrbDecryptOptions sse_decode_box_autoadd_frb_decrypt_options(
     SseDeserializer deserializer,
@@ -440,6 +662,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  FrbDecryptedCacheOptions sse_decode_box_autoadd_frb_decrypted_cache_options(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FrbEncryptOptions sse_decode_box_autoadd_frb_encrypt_options(
     SseDeserializer deserializer,
@@ -450,6 +677,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  FrbFecParams sse_decode_box_autoadd_frb_fec_params(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FrbIconPackError sse_decode_box_autoadd_frb_icon_pack_error(
     SseDeserializer deserializer,
@@ -487,11 +719,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  FrbMetadataPatch sse_decode_box_autoadd_frb_metadata_patch(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FrbProgressEvent sse_decode_box_autoadd_frb_progress_event(
     SseDeserializer deserializer,
   );
 
+  @protected
+  FrbReencryptResult sse_decode_box_autoadd_frb_reencrypt_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbShredReport sse_decode_box_autoadd_frb_shred_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbSignatureAlgorithm sse_decode_box_autoadd_frb_signature_algorithm(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbSpaceShortfall sse_decode_box_autoadd_frb_space_shortfall(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbStreamDecryptOptions sse_decode_box_autoadd_frb_stream_decrypt_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbStreamDecryptResult sse_decode_box_autoadd_frb_stream_decrypt_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbStreamEncryptOptions sse_decode_box_autoadd_frb_stream_encrypt_options(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
 
@@ -504,6 +776,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  FrbArgon2Params sse_decode_frb_argon_2_params(SseDeserializer deserializer);
+
   @protected
   FrbBatchDecryptEvent sse_decode_frb_batch_decrypt_event(
     SseDeserializer deserializer,
@@ -537,11 +812,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbBatchError sse_decode_frb_batch_error(SseDeserializer deserializer);
 
+  @protected
+  FrbBatchReencryptEvent sse_decode_frb_batch_reencrypt_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbBatchReencryptOptions sse_decode_frb_batch_reencrypt_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbBatchReencryptResult sse_decode_frb_batch_reencrypt_result(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FrbChunkSizePreset sse_decode_frb_chunk_size_preset(
     SseDeserializer deserializer,
   );
 
+  @protected
+  FrbCipherSuite sse_decode_frb_cipher_suite(SseDeserializer deserializer);
+
+  @protected
+  FrbDecryptChunk sse_decode_frb_decrypt_chunk(SseDeserializer deserializer);
+
   @protected
   FrbDecryptEvent sse_decode_frb_decrypt_event(SseDeserializer deserializer);
 
@@ -553,6 +849,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbDecryptResult sse_decode_frb_decrypt_result(SseDeserializer deserializer);
 
+  @protected
+  FrbDecryptedCacheOptions sse_decode_frb_decrypted_cache_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbDecryptedCacheStats sse_decode_frb_decrypted_cache_stats(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FrbDecryptedMetadata sse_decode_frb_decrypted_metadata(
     SseDeserializer deserializer,
@@ -569,6 +875,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbEncryptResult sse_decode_frb_encrypt_result(SseDeserializer deserializer);
 
+  @protected
+  FrbFecParams sse_decode_frb_fec_params(SseDeserializer deserializer);
+
   @protected
   FrbIconPackEntry sse_decode_frb_icon_pack_entry(SseDeserializer deserializer);
 
@@ -590,6 +899,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  FrbInspectResult sse_decode_frb_inspect_result(SseDeserializer deserializer);
+
   @protected
   FrbKeepassAttachment sse_decode_frb_keepass_attachment(
     SseDeserializer deserializer,
@@ -659,6 +971,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrbKeyValue sse_decode_frb_key_value(SseDeserializer deserializer);
 
+  @protected
+  FrbMediaServerInfo sse_decode_frb_media_server_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbMediaSource sse_decode_frb_media_source(SseDeserializer deserializer);
+
+  @protected
+  FrbMetadataPatch sse_decode_frb_metadata_patch(SseDeserializer deserializer);
+
+  @protected
+  FrbMetadataUpdateResult sse_decode_frb_metadata_update_result(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FrbProgressEvent sse_decode_frb_progress_event(SseDeserializer deserializer);
 
@@ -666,52 +994,109 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   FrbProgressStage sse_decode_frb_progress_stage(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  FrbReencryptResult sse_decode_frb_reencrypt_result(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  FrbShredEntry sse_decode_frb_shred_entry(SseDeserializer deserializer);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  FrbShredReport sse_decode_frb_shred_report(SseDeserializer deserializer);
 
   @protected
-  List<FrbBatchError> sse_decode_list_frb_batch_error(
+  FrbSignatureAlgorithm sse_decode_frb_signature_algorithm(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<FrbDecryptResult> sse_decode_list_frb_decrypt_result(
+  FrbSignatureStatus sse_decode_frb_signature_status(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<FrbEncryptResult> sse_decode_list_frb_encrypt_result(
+  FrbSigningIdentity sse_decode_frb_signing_identity(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<FrbIconPackEntry> sse_decode_list_frb_icon_pack_entry(
+  FrbSpacePlan sse_decode_frb_space_plan(SseDeserializer deserializer);
+
+  @protected
+  FrbSpaceShortfall sse_decode_frb_space_shortfall(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<FrbIconPackSummary> sse_decode_list_frb_icon_pack_summary(
+  FrbStreamDecryptOptions sse_decode_frb_stream_decrypt_options(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<FrbKeepassAttachment> sse_decode_list_frb_keepass_attachment(
+  FrbStreamDecryptResult sse_decode_frb_stream_decrypt_result(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<FrbKeepassAutoTypeAssociation>
-  sse_decode_list_frb_keepass_auto_type_association(
+  FrbStreamEncryptOptions sse_decode_frb_stream_encrypt_options(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<FrbKeepassCustomDataItem> sse_decode_list_frb_keepass_custom_data_item(
+  FrbStreamEncryptResult sse_decode_frb_stream_encrypt_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbVerifyResult sse_decode_frb_verify_result(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<FrbBatchError> sse_decode_list_frb_batch_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FrbDecryptResult> sse_decode_list_frb_decrypt_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FrbEncryptResult> sse_decode_list_frb_encrypt_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FrbIconPackEntry> sse_decode_list_frb_icon_pack_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FrbIconPackSummary> sse_decode_list_frb_icon_pack_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FrbKeepassAttachment> sse_decode_list_frb_keepass_attachment(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FrbKeepassAutoTypeAssociation>
+  sse_decode_list_frb_keepass_auto_type_association(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FrbKeepassCustomDataItem> sse_decode_list_frb_keepass_custom_data_item(
     SseDeserializer deserializer,
   );
 
@@ -743,6 +1128,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<FrbKeyValue> sse_decode_list_frb_key_value(SseDeserializer deserializer);
 
+  @protected
+  List<FrbReencryptResult> sse_decode_list_frb_reencrypt_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FrbShredEntry> sse_decode_list_frb_shred_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -755,6 +1153,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FrbArgon2Params? sse_decode_opt_box_autoadd_frb_argon_2_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbChunkSizePreset? sse_decode_opt_box_autoadd_frb_chunk_size_preset(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbFecParams? sse_decode_opt_box_autoadd_frb_fec_params(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FrbKeepassAutoType? sse_decode_opt_box_autoadd_frb_keepass_auto_type(
     SseDeserializer deserializer,
@@ -771,6 +1187,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  FrbShredReport? sse_decode_opt_box_autoadd_frb_shred_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbSignatureAlgorithm? sse_decode_opt_box_autoadd_frb_signature_algorithm(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrbSpaceShortfall? sse_decode_opt_box_autoadd_frb_space_shortfall(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
 
@@ -783,6 +1214,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -813,6 +1247,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_frb_batch_reencrypt_event_Sse(
+    RustStreamSink<FrbBatchReencryptEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_frb_decrypt_chunk_Sse(
+    RustStreamSink<FrbDecryptChunk> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_frb_decrypt_event_Sse(
     RustStreamSink<FrbDecryptEvent> self,
@@ -846,6 +1292,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_frb_argon_2_params(
+    FrbArgon2Params self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_frb_batch_decrypt_options(
     FrbBatchDecryptOptions self,
@@ -870,6 +1325,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_frb_batch_reencrypt_options(
+    FrbBatchReencryptOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_frb_batch_reencrypt_result(
+    FrbBatchReencryptResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_frb_chunk_size_preset(
+    FrbChunkSizePreset self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_frb_decrypt_options(
     FrbDecryptOptions self,
@@ -882,6 +1355,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_frb_decrypted_cache_options(
+    FrbDecryptedCacheOptions self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_frb_encrypt_options(
     FrbEncryptOptions self,
@@ -894,6 +1373,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_frb_fec_params(
+    FrbFecParams self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_frb_icon_pack_error(
     FrbIconPackError self,
@@ -936,12 +1421,60 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_frb_metadata_patch(
+    FrbMetadataPatch self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_frb_progress_event(
     FrbProgressEvent self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_frb_reencrypt_result(
+    FrbReencryptResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_frb_shred_report(
+    FrbShredReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_frb_signature_algorithm(
+    FrbSignatureAlgorithm self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_frb_space_shortfall(
+    FrbSpaceShortfall self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_frb_stream_decrypt_options(
+    FrbStreamDecryptOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_frb_stream_decrypt_result(
+    FrbStreamDecryptResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_frb_stream_encrypt_options(
+    FrbStreamEncryptOptions self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
 
@@ -954,6 +1487,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_frb_argon_2_params(
+    FrbArgon2Params self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_frb_batch_decrypt_event(
     FrbBatchDecryptEvent self,
@@ -993,12 +1532,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_frb_batch_error(FrbBatchError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_frb_batch_reencrypt_event(
+    FrbBatchReencryptEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_batch_reencrypt_options(
+    FrbBatchReencryptOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_batch_reencrypt_result(
+    FrbBatchReencryptResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_frb_chunk_size_preset(
     FrbChunkSizePreset self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_frb_cipher_suite(
+    FrbCipherSuite self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_decrypt_chunk(
+    FrbDecryptChunk self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_frb_decrypt_event(
     FrbDecryptEvent self,
@@ -1017,6 +1586,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_frb_decrypted_cache_options(
+    FrbDecryptedCacheOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_decrypted_cache_stats(
+    FrbDecryptedCacheStats self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_frb_decrypted_metadata(
     FrbDecryptedMetadata self,
@@ -1041,6 +1622,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_frb_fec_params(FrbFecParams self, SseSerializer serializer);
+
   @protected
   void sse_encode_frb_icon_pack_entry(
     FrbIconPackEntry self,
@@ -1071,6 +1655,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_frb_inspect_result(
+    FrbInspectResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_frb_keepass_attachment(
     FrbKeepassAttachment self,
@@ -1167,6 +1757,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_frb_key_value(FrbKeyValue self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_frb_media_server_info(
+    FrbMediaServerInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_media_source(
+    FrbMediaSource self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_metadata_patch(
+    FrbMetadataPatch self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_metadata_update_result(
+    FrbMetadataUpdateResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_frb_progress_event(
     FrbProgressEvent self,
@@ -1179,6 +1793,81 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_frb_reencrypt_result(
+    FrbReencryptResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_shred_entry(
+    FrbShredEntry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_shred_report(
+    FrbShredReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_signature_algorithm(
+    FrbSignatureAlgorithm self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_signature_status(
+    FrbSignatureStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_signing_identity(
+    FrbSigningIdentity self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_space_plan(FrbSpacePlan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_frb_space_shortfall(
+    FrbSpaceShortfall self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_stream_decrypt_options(
+    FrbStreamDecryptOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_stream_decrypt_result(
+    FrbStreamDecryptResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_stream_encrypt_options(
+    FrbStreamEncryptOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_stream_encrypt_result(
+    FrbStreamEncryptResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frb_verify_result(
+    FrbVerifyResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
@@ -1272,6 +1961,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_frb_reencrypt_result(
+    List<FrbReencryptResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_frb_shred_entry(
+    List<FrbShredEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -1287,6 +1991,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_frb_argon_2_params(
+    FrbArgon2Params? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_frb_chunk_size_preset(
+    FrbChunkSizePreset? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_frb_fec_params(
+    FrbFecParams? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_frb_keepass_auto_type(
     FrbKeepassAutoType? self,
@@ -1305,6 +2030,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_frb_shred_report(
+    FrbShredReport? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_frb_signature_algorithm(
+    FrbSignatureAlgorithm? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_frb_space_shortfall(
+    FrbSpaceShortfall? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
@@ -1320,6 +2063,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
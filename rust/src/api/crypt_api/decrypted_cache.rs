@@ -0,0 +1,371 @@
+//! Bookkeeping for plaintext written to the decrypted-attachments
+//! directory, so it does not outlive its use.
+//!
+//! Every `decrypt_file` / `decrypt_batch` output inside the configured root
+//! is recorded with its last access time. Entries are shredded once the
+//! cache exceeds its byte budget (least recently used first), once they
+//! have not been accessed for the TTL, and all at once by `purge_all`,
+//! which the app calls when it locks or closes the store.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
+
+use anyhow::Context;
+use hoplixi_file_crypt::ShredReport;
+use hoplixi_file_crypt::shred::{SHRED_CAVEAT, shred_path};
+use tokio::task::JoinHandle;
+
+use super::types::{FrbDecryptedCacheOptions, FrbDecryptedCacheStats, FrbShredReport};
+
+/// How often expired entries are looked for in the background.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    size: u64,
+    last_access: SystemTime,
+}
+
+/// Tracked outputs and the limits applied to them.
+struct Cache {
+    root: PathBuf,
+    max_bytes: Option<u64>,
+    ttl: Option<Duration>,
+    entries: HashMap<PathBuf, Entry>,
+}
+
+impl Cache {
+    /// Create `root` if needed and adopt every file already in it, e.g.
+    /// left behind by a crash, with its modification time as last access.
+    fn open(root: &Path, max_bytes: Option<u64>, ttl: Option<Duration>) -> io::Result<Self> {
+        fs::create_dir_all(root)?;
+        let root = fs::canonicalize(root)?;
+        let mut entries = HashMap::new();
+        adopt_files(&root, &mut entries)?;
+        Ok(Self {
+            root,
+            max_bytes,
+            ttl,
+            entries,
+        })
+    }
+
+    /// Track `path` if it lies inside the root. Returns the canonical path
+    /// when it was recorded.
+    fn record(&mut self, path: &Path, now: SystemTime) -> Option<PathBuf> {
+        let path = fs::canonicalize(path).ok()?;
+        if path == self.root || !path.starts_with(&self.root) {
+            return None;
+        }
+        let entry = Entry {
+            size: disk_size(&path),
+            last_access: now,
+        };
+        self.entries.insert(path.clone(), entry);
+        Some(path)
+    }
+
+    fn touch(&mut self, path: &Path, now: SystemTime) {
+        let Ok(path) = fs::canonicalize(path) else {
+            return;
+        };
+        if let Some(entry) = self.entries.get_mut(&path) {
+            entry.last_access = now;
+        }
+    }
+
+    fn total_bytes(&self) -> u64 {
+        self.entries.values().map(|e| e.size).sum()
+    }
+
+    /// Remove and return the entries past the TTL, then the least recently
+    /// used ones until the rest fits the budget. `keep` is never chosen, so
+    /// a file larger than the budget survives until it is no longer the
+    /// newest.
+    fn evict(&mut self, now: SystemTime, keep: Option<&Path>) -> Vec<(PathBuf, Entry)> {
+        let mut candidates: Vec<(SystemTime, PathBuf)> = self
+            .entries
+            .iter()
+            .filter(|(path, _)| Some(path.as_path()) != keep)
+            .map(|(path, entry)| (entry.last_access, path.clone()))
+            .collect();
+        candidates.sort();
+
+        let mut total = self.total_bytes();
+        let mut victims = Vec::new();
+        for (last_access, path) in candidates {
+            let expired = self
+                .ttl
+                .is_some_and(|ttl| now.duration_since(last_access).is_ok_and(|age| age >= ttl));
+            let over_budget = self.max_bytes.is_some_and(|max| total > max);
+            if !expired && !over_budget {
+                continue;
+            }
+            let entry = self.entries.remove(&path).expect("candidate is tracked");
+            total -= entry.size;
+            victims.push((path, entry));
+        }
+        victims
+    }
+
+    /// Forget every entry and return everything directly inside the root,
+    /// tracked or not.
+    fn drain(&mut self) -> io::Result<Vec<(PathBuf, Entry)>> {
+        self.entries.clear();
+        fs::read_dir(&self.root)?
+            .map(|child| {
+                let path = child?.path();
+                let entry = Entry {
+                    size: disk_size(&path),
+                    last_access: SystemTime::UNIX_EPOCH,
+                };
+                Ok((path, entry))
+            })
+            .collect()
+    }
+}
+
+struct State {
+    cache: Cache,
+    sweeper: JoinHandle<()>,
+}
+
+fn state() -> &'static Mutex<Option<State>> {
+    static STATE: OnceLock<Mutex<Option<State>>> = OnceLock::new();
+    STATE.get_or_init(Default::default)
+}
+
+/// Set (or replace) the cache root and limits, then apply them to what is
+/// already on disk.
+pub(super) async fn configure(opts: FrbDecryptedCacheOptions) -> anyhow::Result<FrbShredReport> {
+    let root = PathBuf::from(&opts.root_dir);
+    let ttl = opts.ttl_secs.map(Duration::from_secs);
+    let cache = tokio::task::spawn_blocking(move || Cache::open(&root, opts.max_bytes, ttl))
+        .await?
+        .with_context(|| format!("cannot open cache directory {}", opts.root_dir))?;
+
+    let sweeper = tokio::spawn(async {
+        loop {
+            tokio::time::sleep(SWEEP_INTERVAL).await;
+            let report = purge_expired().await;
+            if !report.all_shredded {
+                log::warn!("decrypted cache: some expired entries could not be shredded");
+            }
+        }
+    });
+    if let Some(old) = state().lock().unwrap().replace(State { cache, sweeper }) {
+        old.sweeper.abort();
+    }
+    Ok(purge_expired().await)
+}
+
+/// Track a decrypted output and enforce the limits, sparing the new entry.
+pub(super) async fn record(path: &Path) {
+    let victims = {
+        let mut state = state().lock().unwrap();
+        let Some(state) = state.as_mut() else {
+            return;
+        };
+        let now = SystemTime::now();
+        let Some(recorded) = state.cache.record(path, now) else {
+            return;
+        };
+        state.cache.evict(now, Some(&recorded))
+    };
+    if !victims.is_empty() {
+        shred(victims).await;
+    }
+}
+
+/// Mark a tracked output as just used.
+pub(super) fn touch(path: &str) {
+    if let Some(state) = state().lock().unwrap().as_mut() {
+        state.cache.touch(Path::new(path), SystemTime::now());
+    }
+}
+
+/// Shred the entries past the TTL or over the byte budget.
+pub(super) async fn purge_expired() -> FrbShredReport {
+    let victims = match state().lock().unwrap().as_mut() {
+        Some(state) => state.cache.evict(SystemTime::now(), None),
+        None => Vec::new(),
+    };
+    shred(victims).await
+}
+
+/// Shred everything in the cache root, tracked or not.
+pub(super) async fn purge_all() -> anyhow::Result<FrbShredReport> {
+    let victims = state()
+        .lock()
+        .unwrap()
+        .as_mut()
+        .context("decrypted cache is not configured")?
+        .cache
+        .drain()
+        .context("cannot list the cache directory")?;
+    Ok(shred(victims).await)
+}
+
+pub(super) fn stats() -> anyhow::Result<FrbDecryptedCacheStats> {
+    let state = state().lock().unwrap();
+    let cache = &state
+        .as_ref()
+        .context("decrypted cache is not configured")?
+        .cache;
+    Ok(FrbDecryptedCacheStats {
+        root_dir: cache.root.to_string_lossy().into_owned(),
+        entry_count: cache.entries.len() as u64,
+        total_bytes: cache.total_bytes(),
+    })
+}
+
+/// Shred `victims` on a blocking thread. Entries that still exist
+/// afterwards (e.g. held open by a viewer on Windows) are tracked again so
+/// the next purge retries them.
+async fn shred(victims: Vec<(PathBuf, Entry)>) -> FrbShredReport {
+    let (report, survivors) = tokio::task::spawn_blocking(move || {
+        let mut entries = Vec::new();
+        let mut survivors = Vec::new();
+        for (path, entry) in victims {
+            entries.extend(shred_path(&path).entries);
+            if fs::symlink_metadata(&path).is_ok() {
+                survivors.push((path, entry));
+            }
+        }
+        let report = ShredReport {
+            entries,
+            caveat: SHRED_CAVEAT,
+        };
+        (report, survivors)
+    })
+    .await
+    .expect("shred task panicked");
+
+    if let Some(state) = state().lock().unwrap().as_mut() {
+        state.cache.entries.extend(survivors);
+    }
+    report.into()
+}
+
+/// Track every file below `dir`.
+fn adopt_files(dir: &Path, entries: &mut HashMap<PathBuf, Entry>) -> io::Result<()> {
+    for child in fs::read_dir(dir)? {
+        let child = child?;
+        let metadata = child.metadata()?;
+        if metadata.is_dir() {
+            adopt_files(&child.path(), entries)?;
+        } else {
+            let entry = Entry {
+                size: metadata.len(),
+                last_access: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            };
+            entries.insert(child.path(), entry);
+        }
+    }
+    Ok(())
+}
+
+/// Bytes of a file, or of every file below a directory.
+fn disk_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    fs::read_dir(path)
+        .map(|children| {
+            children
+                .flatten()
+                .map(|child| disk_size(&child.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    use super::Cache;
+
+    fn temp_root() -> PathBuf {
+        let mut name = [0u8; 8];
+        rand::fill(&mut name);
+        let name: String = name.iter().map(|b| format!("{b:02x}")).collect();
+        std::env::temp_dir().join(format!("hoplixi-cache-{name}"))
+    }
+
+    #[test]
+    fn evicts_least_recently_used_over_budget() {
+        let root = temp_root();
+        let mut cache = Cache::open(&root, Some(250), None).unwrap();
+        let t0 = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        for (i, name) in ["a", "b", "c"].iter().enumerate() {
+            fs::write(root.join(name), [0u8; 100]).unwrap();
+            cache.record(&root.join(name), t0 + Duration::from_secs(i as u64));
+        }
+        cache.touch(&root.join("a"), t0 + Duration::from_secs(10));
+
+        let keep = cache.root.join("c");
+        let victims = cache.evict(t0 + Duration::from_secs(11), Some(&keep));
+        let names: Vec<_> = victims
+            .iter()
+            .map(|(p, _)| p.file_name().unwrap())
+            .collect();
+        assert_eq!(names, ["b"]);
+        assert_eq!(cache.total_bytes(), 200);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn evicts_expired_and_ignores_paths_outside_root() {
+        let root = temp_root();
+        let outside = temp_root();
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("export.pdf"), b"keep").unwrap();
+
+        let mut cache = Cache::open(&root, None, Some(Duration::from_secs(60))).unwrap();
+        let t0 = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        fs::write(root.join("old.pdf"), b"old").unwrap();
+        fs::write(root.join("new.pdf"), b"new").unwrap();
+        cache.record(&root.join("old.pdf"), t0);
+        cache.record(&root.join("new.pdf"), t0 + Duration::from_secs(50));
+        assert!(cache.record(&outside.join("export.pdf"), t0).is_none());
+
+        let victims = cache.evict(t0 + Duration::from_secs(70), None);
+        assert_eq!(victims.len(), 1);
+        assert!(victims[0].0.ends_with("old.pdf"));
+
+        fs::remove_dir_all(&root).unwrap();
+        fs::remove_dir_all(&outside).unwrap();
+    }
+
+    #[test]
+    fn adopts_leftovers_and_drains_untracked_entries() {
+        let root = temp_root();
+        fs::create_dir_all(root.join("folder/nested")).unwrap();
+        fs::write(root.join("folder/nested/left.txt"), [1u8; 40]).unwrap();
+        fs::write(root.join("crash.jpg"), [2u8; 60]).unwrap();
+
+        let mut cache = Cache::open(&root, None, None).unwrap();
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.total_bytes(), 100);
+
+        fs::create_dir(root.join("empty")).unwrap();
+        let victims = cache.drain().unwrap();
+        // The folder holding an adopted file, the file at the top and the
+        // untracked empty directory.
+        assert_eq!(victims.len(), 3);
+        assert!(cache.entries.is_empty());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,507 @@
+//! Localhost HTTP server that lets media players read encrypted files by
+//! URL, with `Range` support for seeking.
+//!
+//! Only 127.0.0.1 is bound, on a port picked by the OS, and every request
+//! must carry the random per-session token. Each response is read through
+//! a `RandomAccessReader`, so only the chunks covering the requested bytes
+//! are decrypted.
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use anyhow::Context;
+use hoplixi_file_crypt::{AsyncFileCrypt, RandomAccessReader};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::{JoinHandle, JoinSet};
+
+use super::operations::metadata_to_frb;
+use super::types::{FrbMediaServerInfo, FrbMediaSource};
+
+/// Largest request head accepted.
+const MAX_HEAD_LEN: usize = 8 * 1024;
+
+/// Time a client gets to send its request head.
+const HEAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Plaintext bytes decrypted and written per step of a response body.
+const BODY_BLOCK_LEN: u64 = 256 * 1024;
+
+/// A registered file. The reader is shared by all connections; each
+/// block is read under the lock, so ranges interleave block by block.
+struct Source {
+    reader: Mutex<RandomAccessReader>,
+    len: u64,
+    mime_type: String,
+}
+
+type Sources = Arc<Mutex<HashMap<String, Arc<Source>>>>;
+
+struct Server {
+    info: FrbMediaServerInfo,
+    sources: Sources,
+    task: JoinHandle<()>,
+}
+
+fn server() -> &'static Mutex<Option<Server>> {
+    static SERVER: OnceLock<Mutex<Option<Server>>> = OnceLock::new();
+    SERVER.get_or_init(Default::default)
+}
+
+/// Start the server, or return the running one.
+pub(super) async fn start() -> anyhow::Result<FrbMediaServerInfo> {
+    if let Some(running) = server().lock().unwrap().as_ref() {
+        return Ok(running.info.clone());
+    }
+
+    let listener = TcpListener::bind(("127.0.0.1", 0))
+        .await
+        .context("cannot bind the media server")?;
+    let info = FrbMediaServerInfo {
+        port: listener.local_addr()?.port(),
+        token: random_hex(32),
+    };
+    let sources = Sources::default();
+
+    let mut slot = server().lock().unwrap();
+    if let Some(running) = slot.as_ref() {
+        // Lost a race with another `start`.
+        return Ok(running.info.clone());
+    }
+    let task = tokio::spawn(serve(listener, info.token.clone(), Arc::clone(&sources)));
+    *slot = Some(Server {
+        info: info.clone(),
+        sources,
+        task,
+    });
+    Ok(info)
+}
+
+/// Stop the server, closing open connections and forgetting every source
+/// (and with it the keys of its file).
+pub(super) fn stop() {
+    if let Some(server) = server().lock().unwrap().take() {
+        server.task.abort();
+    }
+}
+
+/// Unlock `input_path` and make it available under a new URL.
+pub(super) async fn add(input_path: String, password: String) -> anyhow::Result<FrbMediaSource> {
+    let (info, sources) = {
+        let slot = server().lock().unwrap();
+        let running = slot.as_ref().context("media server is not running")?;
+        (running.info.clone(), Arc::clone(&running.sources))
+    };
+
+    let reader = AsyncFileCrypt::default()
+        .open_random_access(PathBuf::from(&input_path), password)
+        .await
+        .with_context(|| format!("cannot open {input_path}"))?;
+    let metadata = reader.metadata().clone();
+    let mime_type = mime_type(&metadata.original_extension).to_string();
+    let len = reader.len();
+
+    let source_id = random_hex(16);
+    sources.lock().unwrap().insert(
+        source_id.clone(),
+        Arc::new(Source {
+            reader: Mutex::new(reader),
+            len,
+            mime_type: mime_type.clone(),
+        }),
+    );
+
+    Ok(FrbMediaSource {
+        url: format!("http://127.0.0.1:{}/{}/{source_id}", info.port, info.token),
+        source_id,
+        size: len,
+        mime_type,
+        metadata: metadata_to_frb(metadata),
+    })
+}
+
+/// Forget a source; its URL answers 404 from now on.
+pub(super) fn remove(source_id: &str) {
+    if let Some(running) = server().lock().unwrap().as_ref() {
+        running.sources.lock().unwrap().remove(source_id);
+    }
+}
+
+async fn serve(listener: TcpListener, token: String, sources: Sources) {
+    // Owned by this task: aborting it drops the set, which aborts every
+    // connection.
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    connections.spawn(handle(stream, token.clone(), Arc::clone(&sources)));
+                }
+                Err(e) => {
+                    log::warn!("media server: accept failed: {e}");
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+            },
+            Some(_) = connections.join_next() => {}
+        }
+    }
+}
+
+/// Serve one request, then close the connection.
+async fn handle(mut stream: TcpStream, token: String, sources: Sources) {
+    let Ok(Some(head)) = tokio::time::timeout(HEAD_TIMEOUT, read_head(&mut stream)).await else {
+        return;
+    };
+    let response = route(&head, &token, &sources);
+    if let Err(e) = respond(&mut stream, response).await {
+        log::debug!("media server: response aborted: {e}");
+    }
+}
+
+/// Read up to the blank line ending the request head. `None` if the
+/// client disconnects first or the head is too large.
+async fn read_head(stream: &mut TcpStream) -> Option<String> {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.ends_with(b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await.ok()?;
+        if n == 0 || head.len() + n > MAX_HEAD_LEN {
+            return None;
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    String::from_utf8(head).ok()
+}
+
+enum Response {
+    /// Status line only.
+    Status(u16, &'static str),
+    /// 416 for a file of the given length.
+    Unsatisfiable(u64),
+    /// 200 or 206 with the plaintext of `range` (inclusive).
+    Body {
+        source: Arc<Source>,
+        range: Option<(u64, u64)>,
+        head_only: bool,
+    },
+}
+
+enum ByteRange {
+    /// No usable `Range` header: serve the whole file.
+    Whole,
+    /// First and last byte, inclusive.
+    Part(u64, u64),
+    Unsatisfiable,
+}
+
+fn route(head: &str, token: &str, sources: &Sources) -> Response {
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split(' ');
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        return Response::Status(400, "Bad Request");
+    };
+
+    let path = target.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    let [request_token, source_id] = segments[..] else {
+        return Response::Status(404, "Not Found");
+    };
+    if !same_token(request_token.as_bytes(), token.as_bytes()) {
+        return Response::Status(403, "Forbidden");
+    }
+    let head_only = match method {
+        "GET" => false,
+        "HEAD" => true,
+        _ => return Response::Status(405, "Method Not Allowed"),
+    };
+    let Some(source) = sources.lock().unwrap().get(source_id).cloned() else {
+        return Response::Status(404, "Not Found");
+    };
+
+    let range = lines
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.trim()
+                .eq_ignore_ascii_case("range")
+                .then(|| parse_range(value.trim(), source.len))
+        })
+        .unwrap_or(ByteRange::Whole);
+    let range = match range {
+        ByteRange::Whole => None,
+        ByteRange::Part(first, last) => Some((first, last)),
+        ByteRange::Unsatisfiable => return Response::Unsatisfiable(source.len),
+    };
+    Response::Body {
+        source,
+        range,
+        head_only,
+    }
+}
+
+/// Parse a single `bytes=` range (RFC 9110 §14.1.2). Multiple ranges and
+/// malformed values are ignored, as the RFC allows.
+fn parse_range(value: &str, len: u64) -> ByteRange {
+    let Some(spec) = value.strip_prefix("bytes=") else {
+        return ByteRange::Whole;
+    };
+    if spec.contains(',') {
+        return ByteRange::Whole;
+    }
+    let Some((first, last)) = spec.trim().split_once('-') else {
+        return ByteRange::Whole;
+    };
+
+    if first.is_empty() {
+        // Suffix range: the last `n` bytes.
+        return match last.parse::<u64>() {
+            Ok(0) => ByteRange::Unsatisfiable,
+            Ok(_) if len == 0 => ByteRange::Unsatisfiable,
+            Ok(n) => ByteRange::Part(len - n.min(len), len - 1),
+            Err(_) => ByteRange::Whole,
+        };
+    }
+    let Ok(first) = first.parse::<u64>() else {
+        return ByteRange::Whole;
+    };
+    let last = if last.is_empty() {
+        u64::MAX
+    } else {
+        match last.parse::<u64>() {
+            Ok(last) if last >= first => last,
+            _ => return ByteRange::Whole,
+        }
+    };
+    if first >= len {
+        return ByteRange::Unsatisfiable;
+    }
+    ByteRange::Part(first, last.min(len - 1))
+}
+
+async fn respond(stream: &mut TcpStream, response: Response) -> std::io::Result<()> {
+    let (source, range, head_only) = match response {
+        Response::Status(code, reason) => return write_status(stream, code, reason).await,
+        Response::Unsatisfiable(len) => {
+            let head = format!(
+                "HTTP/1.1 416 Range Not Satisfiable\r\nContent-Range: bytes */{len}\r\n\
+                 Content-Length: 0\r\nConnection: close\r\n\r\n"
+            );
+            return stream.write_all(head.as_bytes()).await;
+        }
+        Response::Body {
+            source,
+            range,
+            head_only,
+        } => (source, range, head_only),
+    };
+
+    let (start, end) = match range {
+        Some((first, last)) => (first, last + 1),
+        None => (0, source.len),
+    };
+
+    // Decrypt the first block before committing to a status, so a damaged
+    // file still gets a proper error.
+    let mut offset = start;
+    let mut block = Vec::new();
+    if !head_only && offset < end {
+        let len = BODY_BLOCK_LEN.min(end - offset);
+        block = match read_block(Arc::clone(&source), offset, len).await {
+            Ok(block) => block,
+            Err(e) => {
+                log::warn!("media server: cannot decrypt: {e}");
+                return write_status(stream, 500, "Internal Server Error").await;
+            }
+        };
+    }
+
+    let mut head = match range {
+        Some((first, last)) => format!(
+            "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes {first}-{last}/{}\r\n",
+            source.len
+        ),
+        None => "HTTP/1.1 200 OK\r\n".to_string(),
+    };
+    head.push_str(&format!(
+        "Content-Type: {}\r\nContent-Length: {}\r\nAccept-Ranges: bytes\r\n\
+         Cache-Control: no-store\r\nConnection: close\r\n\r\n",
+        source.mime_type,
+        end - start
+    ));
+    stream.write_all(head.as_bytes()).await?;
+    if head_only {
+        return Ok(());
+    }
+
+    loop {
+        stream.write_all(&block).await?;
+        offset += block.len() as u64;
+        if offset >= end {
+            return stream.flush().await;
+        }
+        let len = BODY_BLOCK_LEN.min(end - offset);
+        block = read_block(Arc::clone(&source), offset, len).await?;
+    }
+}
+
+async fn write_status(stream: &mut TcpStream, code: u16, reason: &str) -> std::io::Result<()> {
+    let head =
+        format!("HTTP/1.1 {code} {reason}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+    stream.write_all(head.as_bytes()).await
+}
+
+/// Decrypt `len` plaintext bytes at `offset` on a blocking thread.
+async fn read_block(source: Arc<Source>, offset: u64, len: u64) -> std::io::Result<Vec<u8>> {
+    tokio::task::spawn_blocking(move || {
+        let mut reader = source.reader.lock().unwrap();
+        reader.seek(SeekFrom::Start(offset))?;
+        let mut block = vec![0u8; len as usize];
+        reader.read_exact(&mut block)?;
+        Ok(block)
+    })
+    .await
+    .map_err(std::io::Error::other)?
+}
+
+/// Compare tokens without leaking the position of the first mismatch.
+fn same_token(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn random_hex(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    rand::fill(&mut bytes[..]);
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// `Content-Type` for an original file extension.
+fn mime_type(extension: &str) -> &'static str {
+    match extension.to_ascii_lowercase().as_str() {
+        "mp4" | "m4v" => "video/mp4",
+        "mov" => "video/quicktime",
+        "webm" => "video/webm",
+        "mkv" => "video/x-matroska",
+        "avi" => "video/x-msvideo",
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "aac" => "audio/aac",
+        "ogg" | "oga" | "opus" => "audio/ogg",
+        "wav" => "audio/wav",
+        "flac" => "audio/flac",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "pdf" => "application/pdf",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use hoplixi_file_crypt::{Argon2Params, EncryptOptions, FileCrypt};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+
+    use super::{add, random_hex, remove, start, stop};
+
+    /// Send a raw request and split the response into head and body.
+    async fn request(port: u16, head: &str) -> (String, Vec<u8>) {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        stream.write_all(head.as_bytes()).await.unwrap();
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).await.unwrap();
+        let split = response.windows(4).position(|w| w == b"\r\n\r\n").unwrap();
+        let body = response.split_off(split + 4);
+        (String::from_utf8(response).unwrap(), body)
+    }
+
+    #[tokio::test]
+    async fn serves_byte_ranges_of_an_encrypted_file() {
+        let dir = std::env::temp_dir().join(format!("hoplixi-media-{}", random_hex(8)));
+        fs::create_dir_all(&dir).unwrap();
+        let original = dir.join("clip.mp4");
+        let data: Vec<u8> = (0..5000).map(|i| (i * 31 % 251) as u8).collect();
+        fs::write(&original, &data).unwrap();
+        let encrypted = FileCrypt::new(
+            256,
+            Argon2Params {
+                t_cost: 1,
+                m_cost_kib: 64,
+                parallelism: 1,
+            },
+        )
+        .encrypt(EncryptOptions {
+            input_path: original,
+            output_dir: dir.clone(),
+            uuid: None,
+            password: "play".to_string(),
+            progress: None,
+            gzip_compressed: false,
+            output_extension: None,
+            temp_dir: None,
+            metadata: None,
+            fec: None,
+            signer: None,
+            recipient: None,
+            shred_source: false,
+            hide_lengths: false,
+            volume_size: None,
+            filter: None,
+        })
+        .unwrap();
+
+        let info = start().await.unwrap();
+        assert_eq!(start().await.unwrap().token, info.token);
+        let source = add(
+            encrypted.output_path.to_string_lossy().into_owned(),
+            "play".to_string(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(source.size, 5000);
+        assert_eq!(source.mime_type, "video/mp4");
+        let path = format!("/{}/{}", info.token, source.source_id);
+        let get = |range: &str| format!("GET {path} HTTP/1.1\r\nHost: x\r\n{range}\r\n");
+
+        let (head, body) = request(info.port, &get("Range: bytes=1000-2999\r\n")).await;
+        assert!(head.starts_with("HTTP/1.1 206"), "{head}");
+        assert!(head.contains("Content-Range: bytes 1000-2999/5000"));
+        assert_eq!(body, data[1000..3000]);
+
+        let (head, body) = request(info.port, &get("range: bytes=-10\r\n")).await;
+        assert!(head.starts_with("HTTP/1.1 206"), "{head}");
+        assert_eq!(body, data[4990..]);
+
+        let (head, body) = request(info.port, &get("")).await;
+        assert!(head.starts_with("HTTP/1.1 200"), "{head}");
+        assert_eq!(body, data);
+
+        let (head, body) = request(info.port, &format!("HEAD {path} HTTP/1.1\r\n\r\n")).await;
+        assert!(head.contains("Content-Length: 5000"), "{head}");
+        assert!(body.is_empty());
+
+        let (head, _) = request(info.port, &get("Range: bytes=5000-\r\n")).await;
+        assert!(head.starts_with("HTTP/1.1 416"), "{head}");
+        assert!(head.contains("Content-Range: bytes */5000"));
+
+        let forged = format!(
+            "GET /{}/{} HTTP/1.1\r\n\r\n",
+            random_hex(32),
+            source.source_id
+        );
+        let (head, _) = request(info.port, &forged).await;
+        assert!(head.starts_with("HTTP/1.1 403"), "{head}");
+
+        remove(&source.source_id);
+        let (head, _) = request(info.port, &get("")).await;
+        assert!(head.starts_with("HTTP/1.1 404"), "{head}");
+
+        stop();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
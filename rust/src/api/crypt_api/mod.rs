@@ -1,4 +1,7 @@
+mod decrypted_cache;
+mod media_server;
 pub mod operations;
+mod streams;
 pub mod types;
 
 use crate::frb_generated::StreamSink;
@@ -18,6 +21,11 @@ impl FrbEncryptOptions {
             temp_dir: None,
             metadata: Vec::new(),
             chunk_size: FrbChunkSizePreset::Desktop,
+            cipher_suite: FrbCipherSuite::XChaCha20Poly1305,
+            signing_key: None,
+            shred_source: false,
+            hide_lengths: false,
+            volume_size: None,
         }
     }
 }
@@ -77,6 +85,84 @@ pub async fn decrypt_file(opts: FrbDecryptOptions, sink: StreamSink<FrbDecryptEv
     operations::decrypt_file(opts, sink).await;
 }
 
+/// Stream the plaintext of an encrypted file as it is decrypted, e.g. for
+/// media playback, without writing it to disk.
+///
+/// Blocks arrive in file order as `FrbDecryptChunk::Data`. At most
+/// `opts.window` blocks are sent ahead: call `decrypt_stream_ack` with
+/// `opts.stream_id` once a block has been consumed. While no block is
+/// acknowledged, an empty one is sent every few seconds to check that the
+/// stream is still listened to; acknowledge it like any other. Data reaches
+/// Dart before the whole file is authenticated; if the stream ends with
+/// `FrbDecryptChunk::Error` instead of `Done`, discard what was received.
+///
+/// # Dart usage
+/// ```dart
+/// await for (final chunk in api.decryptStream(opts: opts)) {
+///   switch (chunk) {
+///     case FrbDecryptChunk_Data(:final field0):
+///       await player.feed(field0);
+///       decryptStreamAck(streamId: opts.streamId);
+///     case FrbDecryptChunk_Done(:final field0):
+///       player.finish(field0);
+///     case FrbDecryptChunk_Error(:final field0):
+///       player.abort(field0);
+///   }
+/// }
+/// ```
+pub async fn decrypt_stream(opts: FrbStreamDecryptOptions, sink: StreamSink<FrbDecryptChunk>) {
+    operations::decrypt_stream(opts, sink).await;
+}
+
+/// Acknowledge one block received from `decrypt_stream`, allowing the next
+/// one to be sent.
+#[flutter_rust_bridge::frb(sync)]
+pub fn decrypt_stream_ack(stream_id: String) {
+    streams::ack_decrypt(&stream_id);
+}
+
+/// Stop a running `decrypt_stream`; it ends with `FrbDecryptChunk::Error`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn decrypt_stream_cancel(stream_id: String) {
+    streams::cancel_decrypt(&stream_id);
+}
+
+/// Start encrypting data pushed from Dart into `opts.output_path`, e.g. a
+/// recording or an upload received in pieces.
+///
+/// Feed the plaintext with `encrypt_stream_push` and complete the file with
+/// `encrypt_stream_finish`. The output is written to `<output_path>.part`
+/// and only renamed into place when the stream finishes successfully.
+///
+/// # Dart usage
+/// ```dart
+/// await encryptStream(opts: opts);
+/// await for (final block in source) {
+///   await encryptStreamPush(streamId: opts.streamId, data: block);
+/// }
+/// final result = await encryptStreamFinish(streamId: opts.streamId);
+/// ```
+pub async fn encrypt_stream(opts: FrbStreamEncryptOptions) -> anyhow::Result<()> {
+    operations::encrypt_stream(opts).await
+}
+
+/// Queue the next block of plaintext. Completes once the encryptor has
+/// room for it, so awaiting each push keeps memory bounded.
+pub async fn encrypt_stream_push(stream_id: String, data: Vec<u8>) -> anyhow::Result<()> {
+    streams::push_encrypt(&stream_id, data).await
+}
+
+/// End the input of an encrypt stream and wait for the file to be complete.
+pub async fn encrypt_stream_finish(stream_id: String) -> anyhow::Result<FrbStreamEncryptResult> {
+    streams::finish_encrypt(&stream_id).await
+}
+
+/// Abandon an encrypt stream; its partial output is removed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn encrypt_stream_abort(stream_id: String) {
+    streams::abort_encrypt(&stream_id);
+}
+
 /// Encrypt multiple files sequentially.
 ///
 /// Events (per-file progress, per-file results, final summary) are emitted
@@ -112,6 +198,20 @@ pub async fn decrypt_batch(
     operations::decrypt_batch(opts, sink).await
 }
 
+/// Re-encrypt `.enc` files in place under a new password, e.g. for a
+/// vault-wide password change or to raise the Argon2 cost.
+///
+/// Files are streamed chunk by chunk into a temporary file and atomically
+/// swapped in; no plaintext reaches the disk and UUIDs and metadata are
+/// kept. Events are emitted like `decrypt_batch`; a failed file is left
+/// untouched and processing continues.
+pub async fn reencrypt_batch(
+    opts: FrbBatchReencryptOptions,
+    sink: StreamSink<FrbBatchReencryptEvent>,
+) -> anyhow::Result<()> {
+    operations::reencrypt_batch(opts, sink).await
+}
+
 /// Read only the header of an encrypted file without decrypting the data.
 ///
 /// Returns the decoded metadata (filename, extension, UUID, tags, etc.)
@@ -125,3 +225,167 @@ pub async fn read_encrypted_header(
 ) -> anyhow::Result<FrbDecryptedMetadata> {
     operations::read_encrypted_header(input_path, password).await
 }
+
+/// Edit the tags and other key/value metadata of an encrypted file without
+/// re-encrypting its payload.
+///
+/// Usually only the header is rewritten; a file whose metadata outgrows the
+/// space reserved for it is copied once. Signed files are refused, as their
+/// signature covers the metadata.
+pub async fn update_metadata(
+    input_path: String,
+    password: String,
+    patch: FrbMetadataPatch,
+) -> anyhow::Result<FrbMetadataUpdateResult> {
+    operations::update_metadata(input_path, password, patch).await
+}
+
+/// Decrypt a file straight into memory, e.g. to show a photo or PDF
+/// without leaving plaintext in `attachments_decrypted/`.
+///
+/// Gzip is undone in memory. Fails if the decrypted content would exceed
+/// `max_size` bytes; use `read_encrypted_header` for the file name.
+pub async fn decrypt_to_memory(
+    input_path: String,
+    password: String,
+    max_size: u64,
+) -> anyhow::Result<Vec<u8>> {
+    operations::decrypt_to_memory(input_path, password, max_size).await
+}
+
+/// Estimate the disk space `encrypt_file` needs with `opts` and compare it
+/// with the free space of the output and temp directories.
+///
+/// Sizes assume compression gains nothing, so they are upper bounds. A
+/// directory is walked to add up its size. `encrypt_file` fails fast with
+/// a "not enough space" error when `shortfall` would be set.
+pub async fn plan_encrypt(opts: FrbEncryptOptions) -> anyhow::Result<FrbSpacePlan> {
+    operations::plan_encrypt(opts).await
+}
+
+/// Estimate the disk space `decrypt_file` needs with `opts`.
+///
+/// Unlocks the header with the password to learn the sizes. Compressed
+/// content grows when unpacked by an amount only known afterwards; such
+/// plans are marked `estimated`.
+pub async fn plan_decrypt(opts: FrbDecryptOptions) -> anyhow::Result<FrbSpacePlan> {
+    operations::plan_decrypt(opts).await
+}
+
+/// Read the public header of an encrypted file without the password.
+///
+/// Reports the format version, Argon2id cost, chunk size, parity layer,
+/// signature scheme and an estimate of the payload size. Useful to show a
+/// KDF cost warning before asking for the password, or to spot truncated
+/// files (`whole_chunks == false`).
+pub async fn inspect_encrypted_file(input_path: String) -> anyhow::Result<FrbInspectResult> {
+    operations::inspect_encrypted_file(input_path).await
+}
+
+/// Generate a new Ed25519 identity for signing encrypted files.
+///
+/// Keep `secret_key` in secure storage and share `public_key` with
+/// recipients so they can check `FrbDecryptResult.signature`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn generate_signing_identity() -> FrbSigningIdentity {
+    operations::generate_signing_identity()
+}
+
+/// Derive the 32-byte public key of a stored Ed25519 secret key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn signing_public_key(secret_key: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+    operations::signing_public_key(secret_key)
+}
+
+/// Authenticate every chunk of an encrypted file and check its signature
+/// without writing any plaintext to disk.
+///
+/// Damaged chunks of files with a parity layer are rebuilt in memory and
+/// counted in `repaired_chunks`. A forged signature fails the call.
+pub async fn verify_file(input_path: String, password: String) -> anyhow::Result<FrbVerifyResult> {
+    operations::verify_file(input_path, password).await
+}
+
+/// Start the local media server, or return the running one.
+///
+/// It listens on 127.0.0.1 only, on a random port, and rejects requests
+/// without the per-session token. Register files with `media_server_add`
+/// and hand the returned URL to a video or audio player.
+pub async fn media_server_start() -> anyhow::Result<FrbMediaServerInfo> {
+    media_server::start().await
+}
+
+/// Stop the media server. Every source is forgotten and open connections
+/// are closed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn media_server_stop() {
+    media_server::stop()
+}
+
+/// Unlock an encrypted file and serve its plaintext with HTTP `Range`
+/// support, decrypting only the chunks each request covers.
+///
+/// The server must be running. Gzip-compressed files are refused since
+/// their offsets cannot be mapped to chunks. The file's keys stay in memory
+/// until `media_server_remove` or `media_server_stop`.
+///
+/// # Dart usage
+/// ```dart
+/// await mediaServerStart();
+/// final source = await mediaServerAdd(inputPath: path, password: pw);
+/// await player.open(Media(source.url));
+/// // ...
+/// mediaServerRemove(sourceId: source.sourceId);
+/// ```
+pub async fn media_server_add(
+    input_path: String,
+    password: String,
+) -> anyhow::Result<FrbMediaSource> {
+    media_server::add(input_path, password).await
+}
+
+/// Stop serving a source; its URL answers 404 from now on.
+#[flutter_rust_bridge::frb(sync)]
+pub fn media_server_remove(source_id: String) {
+    media_server::remove(&source_id)
+}
+
+/// Track and bound the plaintext in the decrypted-attachments directory.
+///
+/// From now on every `decrypt_file` / `decrypt_batch` output inside
+/// `opts.root_dir` is recorded. Entries are shredded once the cache exceeds
+/// `max_bytes` (least recently used first) or has not been accessed for
+/// `ttl_secs`; expired entries are also swept every minute. Calling it
+/// again replaces the settings. Returns what was shredded right away.
+pub async fn decrypted_cache_configure(
+    opts: FrbDecryptedCacheOptions,
+) -> anyhow::Result<FrbShredReport> {
+    decrypted_cache::configure(opts).await
+}
+
+/// Mark a decrypted file as just opened, so the TTL and LRU order start
+/// over for it.
+#[flutter_rust_bridge::frb(sync)]
+pub fn decrypted_cache_touch(path: String) {
+    decrypted_cache::touch(&path)
+}
+
+/// Shred the entries past their TTL or over the byte budget now.
+pub async fn decrypted_cache_purge_expired() -> FrbShredReport {
+    decrypted_cache::purge_expired().await
+}
+
+/// Shred everything in the cache directory, tracked or not. Call it on
+/// auto-lock and when the store is closed.
+///
+/// Check `all_shredded`: a file still held open by a viewer may survive and
+/// is retried by the next purge.
+pub async fn decrypted_cache_purge_all() -> anyhow::Result<FrbShredReport> {
+    decrypted_cache::purge_all().await
+}
+
+/// Size of the tracked cache. Fails if it is not configured.
+#[flutter_rust_bridge::frb(sync)]
+pub fn decrypted_cache_stats() -> anyhow::Result<FrbDecryptedCacheStats> {
+    decrypted_cache::stats()
+}
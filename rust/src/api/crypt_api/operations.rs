@@ -1,13 +1,19 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::Context;
 use hoplixi_file_crypt::progress::ProgressEvent;
-use hoplixi_file_crypt::{DecryptOptions, EncryptOptions, FileCrypt};
+use hoplixi_file_crypt::{
+    AsyncFileCrypt, DecryptOptions, EncryptOptions, EncryptedMetadata, FileCrypt, MetadataPatch,
+    ReencryptParams, RekeyOptions, SigningIdentity, StreamDecryptOptions, StreamEncryptOptions,
+};
 
 use crate::frb_generated::StreamSink;
 
+use super::decrypted_cache;
+use super::streams::{self, ChannelReader, SinkWriter};
 use super::types::*;
 
 fn kv_to_map(pairs: Vec<FrbKeyValue>) -> HashMap<String, String> {
@@ -20,17 +26,37 @@ fn map_to_kv(map: HashMap<String, String>) -> Vec<FrbKeyValue> {
         .collect()
 }
 
+pub(super) fn metadata_to_frb(metadata: EncryptedMetadata) -> FrbDecryptedMetadata {
+    FrbDecryptedMetadata {
+        original_filename: metadata.original_filename,
+        original_extension: metadata.original_extension,
+        gzip_compressed: metadata.gzip_compressed,
+        original_size: metadata.original_size,
+        uuid: metadata.uuid,
+        metadata: map_to_kv(metadata.metadata),
+        signer_public_key: metadata.signer_public_key.map(|key| key.to_vec()),
+    }
+}
+
+fn signer_from_key(signing_key: Option<&[u8]>) -> anyhow::Result<Option<SigningIdentity>> {
+    signing_key
+        .map(SigningIdentity::from_secret_slice)
+        .transpose()
+        .context("invalid signing key")
+}
+
 fn build_encrypt_opts(
     opts: FrbEncryptOptions,
     progress_cb: Option<hoplixi_file_crypt::progress::ProgressCallback>,
-) -> EncryptOptions {
+) -> anyhow::Result<EncryptOptions> {
+    let signer = signer_from_key(opts.signing_key.as_deref())?;
     let metadata = if opts.metadata.is_empty() {
         None
     } else {
         Some(kv_to_map(opts.metadata))
     };
 
-    EncryptOptions {
+    Ok(EncryptOptions {
         input_path: PathBuf::from(&opts.input_path),
         output_dir: PathBuf::from(&opts.output_dir),
         password: opts.password,
@@ -40,7 +66,14 @@ fn build_encrypt_opts(
         temp_dir: opts.temp_dir.map(PathBuf::from),
         metadata,
         progress: progress_cb,
-    }
+        fec: None,
+        signer,
+        recipient: None,
+        shred_source: opts.shred_source,
+        hide_lengths: opts.hide_lengths,
+        volume_size: opts.volume_size,
+        filter: None,
+    })
 }
 
 fn build_decrypt_opts(
@@ -53,6 +86,7 @@ fn build_decrypt_opts(
         password: opts.password,
         temp_dir: opts.temp_dir.map(PathBuf::from),
         progress: progress_cb,
+        identity: None,
     }
 }
 
@@ -76,6 +110,7 @@ fn build_decrypt_opts(
 /// ```
 pub(super) async fn encrypt_file(opts: FrbEncryptOptions, sink: StreamSink<FrbEncryptEvent>) {
     let chunk_size = opts.chunk_size.bytes();
+    let cipher_suite = opts.cipher_suite.into();
     let sink = Arc::new(sink);
     let sink_clone = Arc::clone(&sink);
 
@@ -84,17 +119,18 @@ pub(super) async fn encrypt_file(opts: FrbEncryptOptions, sink: StreamSink<FrbEn
             let _ = sink_clone.add(FrbEncryptEvent::Progress(event.into()));
         });
 
-    let engine = FileCrypt::with_chunk_size(chunk_size);
-    let internal_opts = build_encrypt_opts(opts, Some(progress_cb));
+    let engine = AsyncFileCrypt::with_chunk_size(chunk_size).with_cipher_suite(cipher_suite);
+    let internal_opts = match build_encrypt_opts(opts, Some(progress_cb)) {
+        Ok(internal_opts) => internal_opts,
+        Err(e) => {
+            let _ = sink.add(FrbEncryptEvent::Error(format!("{e:#}")));
+            return;
+        }
+    };
 
     match engine.encrypt(internal_opts).await {
         Ok(result) => {
-            let frb_result = FrbEncryptResult {
-                output_path: result.output_path.to_string_lossy().into_owned(),
-                uuid: result.uuid,
-                original_size: result.original_size,
-            };
-            let _ = sink.add(FrbEncryptEvent::Done(frb_result));
+            let _ = sink.add(FrbEncryptEvent::Done(result.into()));
         }
         Err(e) => {
             let _ = sink.add(FrbEncryptEvent::Error(format!("{e:#}")));
@@ -128,22 +164,16 @@ pub(super) async fn decrypt_file(opts: FrbDecryptOptions, sink: StreamSink<FrbDe
             let _ = sink_clone.add(FrbDecryptEvent::Progress(event.into()));
         });
 
-    let engine = FileCrypt::with_chunk_size(chunk_size);
+    let engine = AsyncFileCrypt::with_chunk_size(chunk_size);
     let internal_opts = build_decrypt_opts(opts, Some(progress_cb));
 
     match engine.decrypt(internal_opts).await {
         Ok(result) => {
-            let frb_metadata = FrbDecryptedMetadata {
-                original_filename: result.metadata.original_filename,
-                original_extension: result.metadata.original_extension,
-                gzip_compressed: result.metadata.gzip_compressed,
-                original_size: result.metadata.original_size,
-                uuid: result.metadata.uuid,
-                metadata: map_to_kv(result.metadata.metadata),
-            };
+            decrypted_cache::record(&result.output_path).await;
             let frb_result = FrbDecryptResult {
                 output_path: result.output_path.to_string_lossy().into_owned(),
-                metadata: frb_metadata,
+                metadata: metadata_to_frb(result.metadata),
+                signature: result.signature.into(),
             };
             let _ = sink.add(FrbDecryptEvent::Done(frb_result));
         }
@@ -153,6 +183,122 @@ pub(super) async fn decrypt_file(opts: FrbDecryptOptions, sink: StreamSink<FrbDe
     }
 }
 
+/// Stream the plaintext of an encrypted file to Dart in acknowledged
+/// blocks.
+pub(super) async fn decrypt_stream(
+    opts: FrbStreamDecryptOptions,
+    sink: StreamSink<FrbDecryptChunk>,
+) {
+    let credits = match streams::open_decrypt(&opts.stream_id, opts.window) {
+        Ok(credits) => credits,
+        Err(e) => {
+            let _ = sink.add(FrbDecryptChunk::Error(format!("{e:#}")));
+            return;
+        }
+    };
+    let sink = Arc::new(sink);
+    let writer = SinkWriter::new(Arc::clone(&sink), credits);
+    let input_path = PathBuf::from(&opts.input_path);
+    let internal_opts = StreamDecryptOptions {
+        password: opts.password,
+        progress: None,
+        identity: None,
+    };
+
+    // Waiting for acknowledgements blocks, so the chunk loop runs on a
+    // blocking thread.
+    let result = tokio::task::spawn_blocking(move || {
+        let input = File::open(&input_path)?;
+        FileCrypt::default().decrypt_stream(input, writer, internal_opts)
+    })
+    .await;
+    streams::close_decrypt(&opts.stream_id);
+
+    match result {
+        Ok(Ok(result)) => {
+            let _ = sink.add(FrbDecryptChunk::Done(FrbStreamDecryptResult {
+                bytes_written: result.bytes_written,
+                metadata: metadata_to_frb(result.metadata),
+                signature: result.signature.into(),
+            }));
+        }
+        Ok(Err(e)) => {
+            let _ = sink.add(FrbDecryptChunk::Error(format!("{e:#}")));
+        }
+        Err(e) => {
+            let _ = sink.add(FrbDecryptChunk::Error(format!(
+                "decrypt_stream task failed: {e}"
+            )));
+        }
+    }
+}
+
+/// Open an encrypt stream fed by `encrypt_stream_push`.
+pub(super) async fn encrypt_stream(opts: FrbStreamEncryptOptions) -> anyhow::Result<()> {
+    let signer = signer_from_key(opts.signing_key.as_deref())?;
+    let metadata = if opts.metadata.is_empty() {
+        None
+    } else {
+        Some(kv_to_map(opts.metadata))
+    };
+    let internal_opts = StreamEncryptOptions {
+        original_filename: opts.original_filename,
+        original_extension: opts.original_extension,
+        uuid: None,
+        password: opts.password,
+        progress: None,
+        gzip_compressed: opts.gzip_compressed,
+        metadata,
+        fec: None,
+        signer,
+        recipient: None,
+    };
+    let engine = FileCrypt::with_chunk_size(opts.chunk_size.bytes())
+        .with_cipher_suite(opts.cipher_suite.into());
+    let output_path = PathBuf::from(&opts.output_path);
+
+    streams::open_encrypt(&opts.stream_id, move |reader| {
+        tokio::task::spawn_blocking(move || {
+            write_encrypt_stream(&engine, reader, &output_path, internal_opts)
+        })
+    })
+}
+
+/// Encrypt everything pushed into `reader` to `<output_path>.part` and
+/// rename it into place once the input has ended.
+fn write_encrypt_stream(
+    engine: &FileCrypt,
+    reader: ChannelReader,
+    output_path: &Path,
+    opts: StreamEncryptOptions,
+) -> anyhow::Result<FrbStreamEncryptResult> {
+    let mut partial = output_path.as_os_str().to_owned();
+    partial.push(".part");
+    let partial = PathBuf::from(partial);
+
+    let file =
+        File::create(&partial).with_context(|| format!("cannot create {}", partial.display()))?;
+    let result = engine
+        .encrypt_stream(reader, file, opts)
+        .and_then(|result| {
+            fs::rename(&partial, output_path)?;
+            Ok(result)
+        });
+    let result = match result {
+        Ok(result) => result,
+        Err(e) => {
+            let _ = fs::remove_file(&partial);
+            return Err(e.into());
+        }
+    };
+
+    Ok(FrbStreamEncryptResult {
+        output_path: output_path.to_string_lossy().into_owned(),
+        uuid: result.uuid,
+        bytes_read: result.bytes_read,
+    })
+}
+
 /// Encrypt multiple files sequentially.
 ///
 /// Events (per-file progress, per-file results, final summary) are emitted
@@ -175,6 +321,7 @@ pub(super) async fn encrypt_batch(
     sink: StreamSink<FrbBatchEncryptEvent>,
 ) -> anyhow::Result<()> {
     let total_files = opts.input_paths.len() as u32;
+    let signer = signer_from_key(opts.signing_key.as_deref())?;
     let sink = Arc::new(sink);
 
     let mut succeeded = Vec::new();
@@ -213,17 +360,21 @@ pub(super) async fn encrypt_batch(
             temp_dir: opts.temp_dir.as_deref().map(PathBuf::from),
             metadata,
             progress: Some(progress_cb),
+            fec: None,
+            signer: signer.clone(),
+            recipient: None,
+            shred_source: false,
+            hide_lengths: opts.hide_lengths,
+            volume_size: opts.volume_size,
+            filter: None,
         };
 
-        let engine = FileCrypt::with_chunk_size(opts.chunk_size.bytes());
+        let engine = AsyncFileCrypt::with_chunk_size(opts.chunk_size.bytes())
+            .with_cipher_suite(opts.cipher_suite.into());
 
         match engine.encrypt(internal_opts).await {
             Ok(result) => {
-                let frb_result = FrbEncryptResult {
-                    output_path: result.output_path.to_string_lossy().into_owned(),
-                    uuid: result.uuid,
-                    original_size: result.original_size,
-                };
+                let frb_result = FrbEncryptResult::from(result);
                 let _ = sink.add(FrbBatchEncryptEvent::FileDone {
                     file_index,
                     result: frb_result.clone(),
@@ -286,23 +437,18 @@ pub(super) async fn decrypt_batch(
             password: opts.password.clone(),
             temp_dir: opts.temp_dir.as_deref().map(PathBuf::from),
             progress: Some(progress_cb),
+            identity: None,
         };
 
-        let engine = FileCrypt::with_chunk_size(opts.chunk_size.bytes());
+        let engine = AsyncFileCrypt::with_chunk_size(opts.chunk_size.bytes());
 
         match engine.decrypt(internal_opts).await {
             Ok(result) => {
-                let frb_metadata = FrbDecryptedMetadata {
-                    original_filename: result.metadata.original_filename,
-                    original_extension: result.metadata.original_extension,
-                    gzip_compressed: result.metadata.gzip_compressed,
-                    original_size: result.metadata.original_size,
-                    uuid: result.metadata.uuid,
-                    metadata: map_to_kv(result.metadata.metadata),
-                };
+                decrypted_cache::record(&result.output_path).await;
                 let frb_result = FrbDecryptResult {
                     output_path: result.output_path.to_string_lossy().into_owned(),
-                    metadata: frb_metadata,
+                    metadata: metadata_to_frb(result.metadata),
+                    signature: result.signature.into(),
                 };
                 let _ = sink.add(FrbBatchDecryptEvent::FileDone {
                     file_index,
@@ -329,6 +475,81 @@ pub(super) async fn decrypt_batch(
     Ok(())
 }
 
+/// Re-encrypt multiple `.enc` files in place, sequentially.
+///
+/// Each file is streamed into a temporary file next to it and atomically
+/// swapped in, so a failure leaves that file as it was. Processing
+/// continues even if individual files fail.
+pub(super) async fn reencrypt_batch(
+    opts: FrbBatchReencryptOptions,
+    sink: StreamSink<FrbBatchReencryptEvent>,
+) -> anyhow::Result<()> {
+    let total_files = opts.input_paths.len() as u32;
+    let signer = signer_from_key(opts.signing_key.as_deref())?;
+    let params = ReencryptParams {
+        argon2_params: opts.argon2_params.map(Into::into),
+        chunk_size: opts.chunk_size.map(FrbChunkSizePreset::bytes),
+    };
+    let sink = Arc::new(sink);
+
+    let mut succeeded = Vec::new();
+    let mut failed = Vec::new();
+
+    for (idx, input_path) in opts.input_paths.iter().enumerate() {
+        let file_index = idx as u32;
+        let current_file = input_path.clone();
+
+        let sink_file = Arc::clone(&sink);
+        let current_file_clone = current_file.clone();
+
+        let progress_cb: hoplixi_file_crypt::progress::ProgressCallback =
+            Arc::new(move |event: ProgressEvent| {
+                let _ = sink_file.add(FrbBatchReencryptEvent::FileProgress {
+                    file_index,
+                    total_files,
+                    current_file: current_file_clone.clone(),
+                    progress: event.into(),
+                });
+            });
+
+        let internal_opts = RekeyOptions {
+            input_path: PathBuf::from(input_path),
+            output_path: None,
+            old_password: opts.old_password.clone(),
+            new_password: opts.new_password.clone(),
+            params,
+            signer: signer.clone(),
+            progress: Some(progress_cb),
+        };
+
+        match AsyncFileCrypt::default().rekey(internal_opts).await {
+            Ok(result) => {
+                let frb_result = FrbReencryptResult::from(result);
+                let _ = sink.add(FrbBatchReencryptEvent::FileDone {
+                    file_index,
+                    result: frb_result.clone(),
+                });
+                succeeded.push(frb_result);
+            }
+            Err(e) => {
+                let _ = sink.add(FrbBatchReencryptEvent::FileError {
+                    file_index,
+                    input_path: current_file.clone(),
+                    error: e.to_string(),
+                });
+                failed.push(FrbBatchError {
+                    input_path: current_file,
+                    error: e.to_string(),
+                });
+            }
+        }
+    }
+
+    let summary = FrbBatchReencryptResult { succeeded, failed };
+    let _ = sink.add(FrbBatchReencryptEvent::AllDone(summary));
+    Ok(())
+}
+
 /// Read only the header of an encrypted file without decrypting the data.
 ///
 /// Returns the decoded metadata (filename, extension, UUID, tags, etc.)
@@ -340,19 +561,137 @@ pub(super) async fn read_encrypted_header(
     input_path: String,
     password: String,
 ) -> anyhow::Result<FrbDecryptedMetadata> {
-    let engine = FileCrypt::default();
+    let engine = AsyncFileCrypt::default();
 
     let metadata = engine
         .decrypt_header(PathBuf::from(&input_path), password)
         .await
         .context("read_encrypted_header failed")?;
 
-    Ok(FrbDecryptedMetadata {
-        original_filename: metadata.original_filename,
-        original_extension: metadata.original_extension,
-        gzip_compressed: metadata.gzip_compressed,
-        original_size: metadata.original_size,
-        uuid: metadata.uuid,
-        metadata: map_to_kv(metadata.metadata),
+    Ok(metadata_to_frb(metadata))
+}
+
+/// Edit the user-defined metadata of an encrypted file in place.
+pub(super) async fn update_metadata(
+    input_path: String,
+    password: String,
+    patch: FrbMetadataPatch,
+) -> anyhow::Result<FrbMetadataUpdateResult> {
+    let patch = MetadataPatch {
+        remove: patch.remove,
+        set: kv_to_map(patch.set),
+    };
+
+    let result = AsyncFileCrypt::default()
+        .update_metadata(PathBuf::from(&input_path), password, patch)
+        .await
+        .context("update_metadata failed")?;
+
+    Ok(FrbMetadataUpdateResult {
+        metadata: metadata_to_frb(result.metadata),
+        in_place: result.in_place,
+    })
+}
+
+/// Decrypt a file into memory, refusing content over `max_size` bytes.
+pub(super) async fn decrypt_to_memory(
+    input_path: String,
+    password: String,
+    max_size: u64,
+) -> anyhow::Result<Vec<u8>> {
+    let engine = AsyncFileCrypt::default();
+
+    let result = engine
+        .decrypt_to_memory(PathBuf::from(&input_path), password, max_size)
+        .await
+        .context("decrypt_to_memory failed")?;
+
+    Ok(result.data)
+}
+
+/// Read the public header and layout of an encrypted file without the
+/// password.
+pub(super) async fn plan_encrypt(opts: FrbEncryptOptions) -> anyhow::Result<FrbSpacePlan> {
+    let engine = AsyncFileCrypt::with_chunk_size(opts.chunk_size.bytes());
+    let plan = engine
+        .plan_encrypt(build_encrypt_opts(opts, None)?)
+        .await
+        .context("plan_encrypt failed")?;
+    Ok(plan.into())
+}
+
+pub(super) async fn plan_decrypt(opts: FrbDecryptOptions) -> anyhow::Result<FrbSpacePlan> {
+    let engine = AsyncFileCrypt::with_chunk_size(opts.chunk_size.bytes());
+    let plan = engine
+        .plan_decrypt(build_decrypt_opts(opts, None))
+        .await
+        .context("plan_decrypt failed")?;
+    Ok(plan.into())
+}
+
+pub(super) async fn inspect_encrypted_file(input_path: String) -> anyhow::Result<FrbInspectResult> {
+    let engine = AsyncFileCrypt::default();
+
+    let info = engine
+        .inspect(PathBuf::from(&input_path))
+        .await
+        .context("inspect_encrypted_file failed")?;
+
+    Ok(FrbInspectResult {
+        version: info.version,
+        argon2_params: FrbArgon2Params {
+            t_cost: info.argon2_params.t_cost,
+            m_cost_kib: info.argon2_params.m_cost_kib,
+            parallelism: info.argon2_params.parallelism,
+        },
+        chunk_size: info.chunk_size,
+        fec: info.fec.map(|fec| FrbFecParams {
+            data_shards: fec.data_shards,
+            parity_shards: fec.parity_shards,
+        }),
+        signature: info.signature.map(Into::into),
+        cipher: info.cipher.into(),
+        key_commitment: info.key_commitment,
+        file_len: info.file_len,
+        ciphertext_len: info.ciphertext_len,
+        chunk_count: info.chunk_count,
+        estimated_plaintext_size: info.estimated_plaintext_size,
+        whole_chunks: info.whole_chunks,
+    })
+}
+
+/// Generate a new Ed25519 signing identity.
+pub(super) fn generate_signing_identity() -> FrbSigningIdentity {
+    let identity = SigningIdentity::generate();
+    FrbSigningIdentity {
+        secret_key: identity.secret_key().to_vec(),
+        public_key: identity.public_key().to_vec(),
+    }
+}
+
+/// Derive the public key for a stored Ed25519 secret key.
+pub(super) fn signing_public_key(secret_key: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+    let identity = signer_from_key(Some(&secret_key))?.context("signing key is required")?;
+    Ok(identity.public_key().to_vec())
+}
+
+/// Authenticate every chunk and check the signature without writing
+/// any plaintext to disk.
+pub(super) async fn verify_file(
+    input_path: String,
+    password: String,
+) -> anyhow::Result<FrbVerifyResult> {
+    let engine = AsyncFileCrypt::default();
+
+    let result = engine
+        .verify(PathBuf::from(&input_path), password)
+        .await
+        .context("verify_file failed")?;
+
+    Ok(FrbVerifyResult {
+        metadata: metadata_to_frb(result.metadata),
+        chunk_count: result.chunk_count,
+        repaired_chunks: result.repaired_chunks,
+        signature: result.signature.into(),
     })
 }
@@ -0,0 +1,401 @@
+//! Plumbing behind `encrypt_stream` / `decrypt_stream`: the registries of
+//! open streams and the `Read` / `Write` adapters that connect Dart to the
+//! chunk loop of `FileCrypt::encrypt_stream` / `FileCrypt::decrypt_stream`.
+
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::time::Duration;
+
+use anyhow::{Context, bail};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::frb_generated::StreamSink;
+
+use super::types::{FrbDecryptChunk, FrbStreamEncryptResult};
+
+/// Plaintext bytes per `FrbDecryptChunk::Data` block.
+const DECRYPT_BLOCK_LEN: usize = 64 * 1024;
+
+/// How long a decrypt stream waits for a credit before checking that
+/// Dart still listens to it.
+const CREDIT_PROBE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Blocks pushed from Dart that may wait for the encryptor.
+const ENCRYPT_QUEUE_LEN: usize = 4;
+
+// ── decrypt_stream ───────────────────────────────────────
+
+/// Blocks Dart may still receive before it has to acknowledge one.
+pub(super) struct Credits {
+    state: Mutex<CreditState>,
+    changed: Condvar,
+    probe_interval: Duration,
+}
+
+struct CreditState {
+    available: u32,
+    /// Probes sent but not acknowledged yet.
+    probes: u32,
+    cancelled: bool,
+}
+
+impl Credits {
+    fn new(window: u32, probe_interval: Duration) -> Self {
+        Self {
+            state: Mutex::new(CreditState {
+                available: window,
+                probes: 0,
+                cancelled: false,
+            }),
+            changed: Condvar::new(),
+            probe_interval,
+        }
+    }
+
+    /// Wait for a credit and spend it. Fails once the stream is
+    /// cancelled, or once `probe`, called after each quiet
+    /// `probe_interval`, reports that Dart no longer listens. A probe
+    /// that got through is acknowledged like a block.
+    fn take(&self, mut probe: impl FnMut() -> bool) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        while state.available == 0 && !state.cancelled {
+            let (next, wait) = self
+                .changed
+                .wait_timeout(state, self.probe_interval)
+                .unwrap();
+            state = next;
+            if !wait.timed_out() || state.available > 0 || state.cancelled {
+                continue;
+            }
+            // The lock is held so the acknowledgement of the probe
+            // cannot arrive before it is counted.
+            if !probe() {
+                state.cancelled = true;
+                return Err(io::Error::new(
+                    io::ErrorKind::BrokenPipe,
+                    "Dart stream closed",
+                ));
+            }
+            state.probes += 1;
+        }
+        if state.cancelled {
+            return Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "stream cancelled",
+            ));
+        }
+        state.available -= 1;
+        Ok(())
+    }
+
+    /// Count an acknowledgement. Those owed to probes add no credit;
+    /// acknowledgements arrive in order, so the first ones stand in.
+    fn grant(&self) {
+        let mut state = self.state.lock().unwrap();
+        match state.probes {
+            0 => state.available += 1,
+            _ => state.probes -= 1,
+        }
+        drop(state);
+        self.changed.notify_one();
+    }
+
+    fn cancel(&self) {
+        self.state.lock().unwrap().cancelled = true;
+        self.changed.notify_one();
+    }
+}
+
+fn decrypt_streams() -> &'static Mutex<HashMap<String, Arc<Credits>>> {
+    static STREAMS: OnceLock<Mutex<HashMap<String, Arc<Credits>>>> = OnceLock::new();
+    STREAMS.get_or_init(Default::default)
+}
+
+/// Register a decrypt stream with `window` blocks of credit.
+pub(super) fn open_decrypt(stream_id: &str, window: u32) -> anyhow::Result<Arc<Credits>> {
+    if window == 0 {
+        bail!("window must be at least 1");
+    }
+    let mut streams = decrypt_streams().lock().unwrap();
+    if streams.contains_key(stream_id) {
+        bail!("stream id `{stream_id}` is already in use");
+    }
+    let credits = Arc::new(Credits::new(window, CREDIT_PROBE_INTERVAL));
+    streams.insert(stream_id.to_string(), Arc::clone(&credits));
+    Ok(credits)
+}
+
+pub(super) fn close_decrypt(stream_id: &str) {
+    decrypt_streams().lock().unwrap().remove(stream_id);
+}
+
+/// Return one credit to a decrypt stream. Unknown ids are ignored: the
+/// stream may have ended before the last block was acknowledged.
+pub(super) fn ack_decrypt(stream_id: &str) {
+    if let Some(credits) = decrypt_streams().lock().unwrap().get(stream_id) {
+        credits.grant();
+    }
+}
+
+pub(super) fn cancel_decrypt(stream_id: &str) {
+    if let Some(credits) = decrypt_streams().lock().unwrap().get(stream_id) {
+        credits.cancel();
+    }
+}
+
+/// Cuts the plaintext into blocks and sends each one to Dart, waiting
+/// for a credit first. While it waits, empty blocks probe whether Dart
+/// still listens.
+pub(super) struct SinkWriter {
+    sink: Arc<StreamSink<FrbDecryptChunk>>,
+    credits: Arc<Credits>,
+    block: Vec<u8>,
+}
+
+impl SinkWriter {
+    pub(super) fn new(sink: Arc<StreamSink<FrbDecryptChunk>>, credits: Arc<Credits>) -> Self {
+        Self {
+            sink,
+            credits,
+            block: Vec::with_capacity(DECRYPT_BLOCK_LEN),
+        }
+    }
+
+    fn emit(&mut self) -> io::Result<()> {
+        let sink = &self.sink;
+        self.credits
+            .take(|| sink.add(FrbDecryptChunk::Data(Vec::new())).is_ok())?;
+        let block = std::mem::replace(&mut self.block, Vec::with_capacity(DECRYPT_BLOCK_LEN));
+        self.sink
+            .add(FrbDecryptChunk::Data(block))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "Dart stream closed"))
+    }
+}
+
+impl Write for SinkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = buf.len().min(DECRYPT_BLOCK_LEN - self.block.len());
+        self.block.extend_from_slice(&buf[..n]);
+        if self.block.len() == DECRYPT_BLOCK_LEN {
+            self.emit()?;
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.block.is_empty() {
+            return Ok(());
+        }
+        self.emit()
+    }
+}
+
+// ── encrypt_stream ───────────────────────────────────────
+
+/// `Some(block)` carries data, `None` marks the end of the input.
+type Block = Option<Vec<u8>>;
+
+struct EncryptStream {
+    tx: mpsc::Sender<Block>,
+    task: JoinHandle<anyhow::Result<FrbStreamEncryptResult>>,
+}
+
+fn encrypt_streams() -> &'static Mutex<HashMap<String, EncryptStream>> {
+    static STREAMS: OnceLock<Mutex<HashMap<String, EncryptStream>>> = OnceLock::new();
+    STREAMS.get_or_init(Default::default)
+}
+
+/// Register an encrypt stream. `start` spawns the encryptor over the
+/// reader of the new channel; it is only called if the id is free.
+pub(super) fn open_encrypt(
+    stream_id: &str,
+    start: impl FnOnce(ChannelReader) -> JoinHandle<anyhow::Result<FrbStreamEncryptResult>>,
+) -> anyhow::Result<()> {
+    let mut streams = encrypt_streams().lock().unwrap();
+    if streams.contains_key(stream_id) {
+        bail!("stream id `{stream_id}` is already in use");
+    }
+    let (tx, rx) = mpsc::channel(ENCRYPT_QUEUE_LEN);
+    let task = start(ChannelReader {
+        rx,
+        block: Vec::new(),
+        pos: 0,
+        done: false,
+    });
+    streams.insert(stream_id.to_string(), EncryptStream { tx, task });
+    Ok(())
+}
+
+fn take_encrypt(stream_id: &str) -> anyhow::Result<EncryptStream> {
+    encrypt_streams()
+        .lock()
+        .unwrap()
+        .remove(stream_id)
+        .with_context(|| format!("no open encrypt stream `{stream_id}`"))
+}
+
+/// Queue a block, waiting while the encryptor is `ENCRYPT_QUEUE_LEN`
+/// blocks behind. If the encryptor has failed, its error is returned and
+/// the stream is closed.
+pub(super) async fn push_encrypt(stream_id: &str, data: Vec<u8>) -> anyhow::Result<()> {
+    let tx = encrypt_streams()
+        .lock()
+        .unwrap()
+        .get(stream_id)
+        .map(|stream| stream.tx.clone())
+        .with_context(|| format!("no open encrypt stream `{stream_id}`"))?;
+    if tx.send(Some(data)).await.is_ok() {
+        return Ok(());
+    }
+    drop(tx);
+    let stream = take_encrypt(stream_id)?;
+    stream.task.await.context("encrypt_stream task failed")??;
+    bail!("encrypt stream `{stream_id}` ended early")
+}
+
+/// Mark the end of the input and wait for the file to be written.
+pub(super) async fn finish_encrypt(stream_id: &str) -> anyhow::Result<FrbStreamEncryptResult> {
+    let EncryptStream { tx, task } = take_encrypt(stream_id)?;
+    let _ = tx.send(None).await;
+    drop(tx);
+    task.await.context("encrypt_stream task failed")?
+}
+
+/// Drop the input channel without an end marker: the encryptor fails and
+/// removes its partial output.
+pub(super) fn abort_encrypt(stream_id: &str) {
+    encrypt_streams().lock().unwrap().remove(stream_id);
+}
+
+/// Blocking `Read` over the blocks pushed from Dart.
+pub(super) struct ChannelReader {
+    rx: mpsc::Receiver<Block>,
+    block: Vec<u8>,
+    pos: usize,
+    done: bool,
+}
+
+impl Read for ChannelReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pos == self.block.len() {
+            if self.done {
+                return Ok(0);
+            }
+            match self.rx.blocking_recv() {
+                Some(Some(block)) => {
+                    self.block = block;
+                    self.pos = 0;
+                }
+                Some(None) => self.done = true,
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "stream aborted",
+                    ));
+                }
+            }
+        }
+        let n = buf.len().min(self.block.len() - self.pos);
+        buf[..n].copy_from_slice(&self.block[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{ErrorKind, Read};
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{ChannelReader, Credits, mpsc};
+    use super::{ack_decrypt, cancel_decrypt, close_decrypt, open_decrypt};
+
+    #[test]
+    fn channel_reader_joins_blocks_until_end_marker() {
+        let (tx, rx) = mpsc::channel(4);
+        let mut reader = ChannelReader {
+            rx,
+            block: Vec::new(),
+            pos: 0,
+            done: false,
+        };
+        tx.blocking_send(Some(b"hello ".to_vec())).unwrap();
+        tx.blocking_send(Some(Vec::new())).unwrap();
+        tx.blocking_send(Some(b"world".to_vec())).unwrap();
+        tx.blocking_send(None).unwrap();
+
+        let mut out = String::new();
+        reader.read_to_string(&mut out).unwrap();
+        assert_eq!(out, "hello world");
+    }
+
+    #[test]
+    fn channel_reader_fails_when_sender_is_dropped() {
+        let (tx, rx) = mpsc::channel(4);
+        let mut reader = ChannelReader {
+            rx,
+            block: Vec::new(),
+            pos: 0,
+            done: false,
+        };
+        tx.blocking_send(Some(b"partial".to_vec())).unwrap();
+        drop(tx);
+
+        let mut out = Vec::new();
+        assert!(reader.read_to_end(&mut out).is_err());
+    }
+
+    #[test]
+    fn credits_block_until_ack_and_fail_on_cancel() {
+        let credits = open_decrypt("credits-test", 1).unwrap();
+        assert!(open_decrypt("credits-test", 1).is_err());
+
+        credits.take(|| true).unwrap();
+        let waiter = {
+            let credits = credits.clone();
+            thread::spawn(move || credits.take(|| true))
+        };
+        ack_decrypt("credits-test");
+        waiter.join().unwrap().unwrap();
+
+        let waiter = {
+            let credits = credits.clone();
+            thread::spawn(move || credits.take(|| true))
+        };
+        cancel_decrypt("credits-test");
+        assert!(waiter.join().unwrap().is_err());
+
+        close_decrypt("credits-test");
+        assert!(open_decrypt("credits-test", 1).is_ok());
+        close_decrypt("credits-test");
+    }
+
+    #[test]
+    fn credits_probe_the_sink_while_waiting() {
+        let credits = Credits::new(1, Duration::from_millis(10));
+        credits.take(|| true).unwrap();
+
+        // The first probe gets through, the second finds Dart gone.
+        let mut probes = 0;
+        let err = credits
+            .take(|| {
+                probes += 1;
+                probes == 1
+            })
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+        assert_eq!(probes, 2);
+        assert!(credits.take(|| true).is_err());
+
+        // The acknowledgement of a probe adds no credit.
+        let credits = Credits::new(0, Duration::from_millis(10));
+        credits.state.lock().unwrap().probes = 1;
+        credits.grant();
+        assert_eq!(credits.state.lock().unwrap().available, 0);
+        credits.grant();
+        credits.take(|| false).unwrap();
+    }
+}
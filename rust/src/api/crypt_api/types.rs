@@ -1,5 +1,9 @@
 use hoplixi_file_crypt::config::{DEFAULT_DESKTOP_CHUNK_SIZE, DEFAULT_MOBILE_CHUNK_SIZE};
 use hoplixi_file_crypt::progress::{ProgressEvent, ProgressStage};
+use hoplixi_file_crypt::{
+    Argon2Params, CipherSuite, CryptError, EncryptResult, RekeyResult, ShredReport,
+    SignatureAlgorithm, SignatureStatus, SpacePlan,
+};
 
 /// Stage of the encryption/decryption pipeline.
 #[derive(Debug, Clone)]
@@ -38,6 +42,20 @@ pub struct FrbProgressEvent {
     pub total_bytes: u64,
     /// Completion percentage 0.0-100.0. 0 when total is unknown.
     pub percentage: f64,
+    /// Files added to / extracted from a 7z archive so far.
+    pub files_processed: u64,
+    /// Files in the archive (0 outside the directory stages).
+    pub total_files: u64,
+    /// Throughput of the current stage.
+    pub bytes_per_second: f64,
+    /// Estimated seconds until the operation is done, if known.
+    pub eta_seconds: Option<f64>,
+    /// Completion of the whole operation across all stages.
+    pub overall_percentage: f64,
+    /// Position of the current stage (0-based).
+    pub stage_index: u32,
+    /// Number of stages in the operation.
+    pub stage_count: u32,
 }
 
 impl From<ProgressEvent> for FrbProgressEvent {
@@ -47,6 +65,13 @@ impl From<ProgressEvent> for FrbProgressEvent {
             bytes_processed: e.bytes_processed,
             total_bytes: e.total_bytes,
             percentage: e.percentage(),
+            files_processed: e.files_processed,
+            total_files: e.total_files,
+            bytes_per_second: e.bytes_per_second,
+            eta_seconds: e.eta_seconds,
+            overall_percentage: e.overall_percentage,
+            stage_index: e.stage_index,
+            stage_count: e.stage_count,
         }
     }
 }
@@ -84,6 +109,20 @@ pub enum FrbDecryptEvent {
     Error(String),
 }
 
+/// Event emitted by `decrypt_stream`.
+#[derive(Debug, Clone)]
+pub enum FrbDecryptChunk {
+    /// Next block of plaintext, in file order, or an empty probe. Call
+    /// `decrypt_stream_ack` once it has been consumed.
+    Data(Vec<u8>),
+    /// Emitted exactly once after the last block, when the whole file
+    /// (and its signature) has been authenticated.
+    Done(FrbStreamDecryptResult),
+    /// Operation failed; discard the blocks received so far. Always the
+    /// last event in the stream.
+    Error(String),
+}
+
 /// Event emitted by `encrypt_batch`.
 #[derive(Debug, Clone)]
 pub enum FrbBatchEncryptEvent {
@@ -134,6 +173,32 @@ pub enum FrbBatchDecryptEvent {
     AllDone(FrbBatchDecryptResult),
 }
 
+/// Event emitted by `reencrypt_batch`.
+#[derive(Debug, Clone)]
+pub enum FrbBatchReencryptEvent {
+    /// Progress for the currently processed file.
+    FileProgress {
+        file_index: u32,
+        total_files: u32,
+        current_file: String,
+        progress: FrbProgressEvent,
+    },
+    /// One file was re-encrypted and replaced.
+    FileDone {
+        file_index: u32,
+        result: FrbReencryptResult,
+    },
+    /// One file failed and was left untouched; processing continues for
+    /// the rest.
+    FileError {
+        file_index: u32,
+        input_path: String,
+        error: String,
+    },
+    /// Emitted once when all files are processed.
+    AllDone(FrbBatchReencryptResult),
+}
+
 /// Key-value metadata entry stored in the encrypted header.
 #[derive(Debug, Clone)]
 pub struct FrbKeyValue {
@@ -185,6 +250,59 @@ pub struct FrbEncryptOptions {
     pub metadata: Vec<FrbKeyValue>,
     /// Chunk-size preset (desktop is the default).
     pub chunk_size: FrbChunkSizePreset,
+    /// AEAD for the new file (XChaCha20-Poly1305 is the default).
+    pub cipher_suite: FrbCipherSuite,
+    /// Optional Ed25519 secret key (32 bytes) used to sign the file.
+    pub signing_key: Option<Vec<u8>>,
+    /// Overwrite and delete the input file or directory once the
+    /// encrypted file is in place. Never runs if encryption fails.
+    pub shred_source: bool,
+    /// Pad the payload and metadata so the output size does not give
+    /// away the exact input size.
+    pub hide_lengths: bool,
+    /// Split the output into `.001`, `.002`, … volumes of at most this
+    /// many bytes.
+    pub volume_size: Option<u64>,
+}
+
+/// Options for encrypting data pushed from Dart with
+/// `encrypt_stream_push`.
+#[derive(Debug, Clone)]
+pub struct FrbStreamEncryptOptions {
+    /// Caller-chosen id used by `encrypt_stream_push` / `_finish`.
+    pub stream_id: String,
+    /// Path of the encrypted file to create.
+    pub output_path: String,
+    /// User password used for key derivation.
+    pub password: String,
+    /// File name recorded in the metadata, without extension.
+    pub original_filename: String,
+    /// Extension recorded in the metadata, without the dot.
+    pub original_extension: String,
+    /// Whether to apply Gzip compression before encryption.
+    pub gzip_compressed: bool,
+    /// Additional metadata to embed in the encrypted header.
+    pub metadata: Vec<FrbKeyValue>,
+    /// Chunk-size preset (desktop is the default).
+    pub chunk_size: FrbChunkSizePreset,
+    /// AEAD for the new file (XChaCha20-Poly1305 is the default).
+    pub cipher_suite: FrbCipherSuite,
+    /// Optional Ed25519 secret key (32 bytes) used to sign the file.
+    pub signing_key: Option<Vec<u8>>,
+}
+
+/// Options for streaming the plaintext of an encrypted file to Dart.
+#[derive(Debug, Clone)]
+pub struct FrbStreamDecryptOptions {
+    /// Caller-chosen id used by `decrypt_stream_ack` / `_cancel`.
+    pub stream_id: String,
+    /// Path to the encrypted `.enc` file.
+    pub input_path: String,
+    /// User password.
+    pub password: String,
+    /// Blocks that may be in flight before Dart acknowledges them.
+    /// At least 1.
+    pub window: u32,
 }
 
 /// Options for decrypting a single `.enc` file.
@@ -219,6 +337,14 @@ pub struct FrbBatchEncryptOptions {
     pub metadata: Vec<FrbKeyValue>,
     /// Chunk-size preset.
     pub chunk_size: FrbChunkSizePreset,
+    /// AEAD for the new files.
+    pub cipher_suite: FrbCipherSuite,
+    /// Optional Ed25519 secret key (32 bytes) used to sign every file.
+    pub signing_key: Option<Vec<u8>>,
+    /// Pad every output so its size does not give away the input size.
+    pub hide_lengths: bool,
+    /// Split every output into volumes of at most this many bytes.
+    pub volume_size: Option<u64>,
 }
 
 /// Options for decrypting multiple `.enc` files in a batch.
@@ -236,15 +362,93 @@ pub struct FrbBatchDecryptOptions {
     pub chunk_size: FrbChunkSizePreset,
 }
 
+/// Options for re-encrypting `.enc` files in place, e.g. for a vault-wide
+/// password change.
+#[derive(Debug, Clone)]
+pub struct FrbBatchReencryptOptions {
+    /// Paths to encrypted `.enc` files; each is replaced atomically.
+    pub input_paths: Vec<String>,
+    /// Current password.
+    pub old_password: String,
+    /// New password.
+    pub new_password: String,
+    /// Argon2id cost for the new password. Library defaults if `None`.
+    pub argon2_params: Option<FrbArgon2Params>,
+    /// New chunk size. Each file keeps its own if `None`.
+    pub chunk_size: Option<FrbChunkSizePreset>,
+    /// Optional Ed25519 secret key (32 bytes) used to re-sign every file.
+    /// Without it existing signatures are dropped.
+    pub signing_key: Option<Vec<u8>>,
+}
+
 /// Returned after a successful encryption.
 #[derive(Debug, Clone)]
 pub struct FrbEncryptResult {
-    /// Absolute path to the encrypted output file.
+    /// Absolute path to the encrypted output file, or to the first
+    /// volume.
     pub output_path: String,
+    /// Every volume written, in order; empty unless split.
+    pub volumes: Vec<String>,
     /// UUID embedded in the encrypted header.
     pub uuid: String,
     /// Original file size (bytes) before any compression.
     pub original_size: u64,
+    /// Outcome of shredding the input, if `shred_source` was set.
+    pub shred: Option<FrbShredReport>,
+}
+
+impl From<EncryptResult> for FrbEncryptResult {
+    fn from(result: EncryptResult) -> Self {
+        Self {
+            output_path: result.output_path.to_string_lossy().into_owned(),
+            volumes: result
+                .volumes
+                .iter()
+                .map(|path| path.to_string_lossy().into_owned())
+                .collect(),
+            uuid: result.uuid,
+            original_size: result.original_size,
+            shred: result.shred.map(Into::into),
+        }
+    }
+}
+
+/// Outcome of securely deleting the source of an encryption.
+#[derive(Debug, Clone)]
+pub struct FrbShredReport {
+    /// Every file, link and directory processed, depth-first.
+    pub entries: Vec<FrbShredEntry>,
+    /// `true` if every entry was overwritten and removed.
+    pub all_shredded: bool,
+    /// Why overwriting in place is best effort on SSDs and
+    /// journaling or copy-on-write file systems. Show it to the user.
+    pub caveat: String,
+}
+
+/// One path removed while shredding.
+#[derive(Debug, Clone)]
+pub struct FrbShredEntry {
+    /// Path before it was renamed.
+    pub path: String,
+    /// Why the path could not be shredded, if it failed.
+    pub error: Option<String>,
+}
+
+impl From<ShredReport> for FrbShredReport {
+    fn from(report: ShredReport) -> Self {
+        Self {
+            all_shredded: report.all_shredded(),
+            caveat: report.caveat.to_string(),
+            entries: report
+                .entries
+                .into_iter()
+                .map(|e| FrbShredEntry {
+                    path: e.path.to_string_lossy().into_owned(),
+                    error: e.error,
+                })
+                .collect(),
+        }
+    }
 }
 
 /// Metadata decoded from an encrypted header.
@@ -256,6 +460,27 @@ pub struct FrbDecryptedMetadata {
     pub original_size: u64,
     pub uuid: String,
     pub metadata: Vec<FrbKeyValue>,
+    /// Ed25519 public key of the signer (signed files only).
+    pub signer_public_key: Option<Vec<u8>>,
+}
+
+/// Changes passed to `update_metadata`.
+#[derive(Debug, Clone)]
+pub struct FrbMetadataPatch {
+    /// Keys to remove.
+    pub remove: Vec<String>,
+    /// Entries to add or overwrite, applied after `remove`.
+    pub set: Vec<FrbKeyValue>,
+}
+
+/// Returned by `update_metadata`.
+#[derive(Debug, Clone)]
+pub struct FrbMetadataUpdateResult {
+    /// The metadata as now stored.
+    pub metadata: FrbDecryptedMetadata,
+    /// `true` if only the header was rewritten; `false` if the file had to
+    /// be copied because the metadata outgrew its reserved space.
+    pub in_place: bool,
 }
 
 /// Returned after a successful decryption.
@@ -265,6 +490,227 @@ pub struct FrbDecryptResult {
     pub output_path: String,
     /// Decoded metadata from the encrypted header.
     pub metadata: FrbDecryptedMetadata,
+    /// Signature check outcome.
+    pub signature: FrbSignatureStatus,
+}
+
+/// Returned by `encrypt_stream_finish`.
+#[derive(Debug, Clone)]
+pub struct FrbStreamEncryptResult {
+    /// Path of the encrypted file.
+    pub output_path: String,
+    /// UUID embedded in the header.
+    pub uuid: String,
+    /// Plaintext bytes pushed from Dart.
+    pub bytes_read: u64,
+}
+
+/// Final event of `decrypt_stream`.
+#[derive(Debug, Clone)]
+pub struct FrbStreamDecryptResult {
+    /// Plaintext bytes emitted, after decompression.
+    pub bytes_written: u64,
+    /// Decoded metadata from the encrypted header.
+    pub metadata: FrbDecryptedMetadata,
+    /// Signature check outcome.
+    pub signature: FrbSignatureStatus,
+}
+
+/// Ed25519 identity used to sign encrypted files.
+#[derive(Debug, Clone)]
+pub struct FrbSigningIdentity {
+    /// 32-byte secret key. Store it in secure storage.
+    pub secret_key: Vec<u8>,
+    /// 32-byte public key shared with recipients.
+    pub public_key: Vec<u8>,
+}
+
+/// Outcome of checking the signature of an encrypted file.
+///
+/// A forged or broken signature fails the operation instead.
+#[derive(Debug, Clone)]
+pub enum FrbSignatureStatus {
+    /// The file carries no signature.
+    Unsigned,
+    /// The file was signed by `public_key` and is unmodified.
+    Valid { public_key: Vec<u8> },
+}
+
+impl From<SignatureStatus> for FrbSignatureStatus {
+    fn from(status: SignatureStatus) -> Self {
+        match status {
+            SignatureStatus::Unsigned => FrbSignatureStatus::Unsigned,
+            SignatureStatus::Valid { public_key } => FrbSignatureStatus::Valid {
+                public_key: public_key.to_vec(),
+            },
+        }
+    }
+}
+
+/// Returned by `verify_file`.
+#[derive(Debug, Clone)]
+pub struct FrbVerifyResult {
+    /// Decoded metadata from the encrypted header.
+    pub metadata: FrbDecryptedMetadata,
+    /// Number of authenticated data chunks.
+    pub chunk_count: u64,
+    /// Data chunks rebuilt from parity while verifying.
+    pub repaired_chunks: u64,
+    /// Signature check outcome.
+    pub signature: FrbSignatureStatus,
+}
+
+/// Argon2id parameters stored in the public header.
+#[derive(Debug, Clone)]
+pub struct FrbArgon2Params {
+    pub t_cost: u32,
+    pub m_cost_kib: u32,
+    pub parallelism: u32,
+}
+
+impl From<FrbArgon2Params> for Argon2Params {
+    fn from(params: FrbArgon2Params) -> Self {
+        Self {
+            t_cost: params.t_cost,
+            m_cost_kib: params.m_cost_kib,
+            parallelism: params.parallelism,
+        }
+    }
+}
+
+/// Reed–Solomon parity layout stored in the public header.
+#[derive(Debug, Clone)]
+pub struct FrbFecParams {
+    /// Data chunks per parity group.
+    pub data_shards: u8,
+    /// Parity blocks written after each group.
+    pub parity_shards: u8,
+}
+
+/// Signature scheme recorded in the public header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrbSignatureAlgorithm {
+    Ed25519,
+}
+
+impl From<SignatureAlgorithm> for FrbSignatureAlgorithm {
+    fn from(algorithm: SignatureAlgorithm) -> Self {
+        match algorithm {
+            SignatureAlgorithm::Ed25519 => FrbSignatureAlgorithm::Ed25519,
+        }
+    }
+}
+
+/// AEAD recorded in the public header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrbCipherSuite {
+    /// XChaCha20-Poly1305 (default).
+    XChaCha20Poly1305,
+    /// AES-256-GCM-SIV, faster on CPUs with AES instructions.
+    Aes256GcmSiv,
+}
+
+impl From<CipherSuite> for FrbCipherSuite {
+    fn from(suite: CipherSuite) -> Self {
+        match suite {
+            CipherSuite::XChaCha20Poly1305 => FrbCipherSuite::XChaCha20Poly1305,
+            CipherSuite::Aes256GcmSiv => FrbCipherSuite::Aes256GcmSiv,
+        }
+    }
+}
+
+impl From<FrbCipherSuite> for CipherSuite {
+    fn from(suite: FrbCipherSuite) -> Self {
+        match suite {
+            FrbCipherSuite::XChaCha20Poly1305 => CipherSuite::XChaCha20Poly1305,
+            FrbCipherSuite::Aes256GcmSiv => CipherSuite::Aes256GcmSiv,
+        }
+    }
+}
+
+/// Returned by `inspect_encrypted_file`. Read without the password.
+#[derive(Debug, Clone)]
+pub struct FrbInspectResult {
+    /// Format version of the file.
+    pub version: u16,
+    /// Argon2id parameters the password must be run through.
+    pub argon2_params: FrbArgon2Params,
+    /// Plaintext bytes per chunk.
+    pub chunk_size: u32,
+    /// Reed–Solomon parity parameters, if present.
+    pub fec: Option<FrbFecParams>,
+    /// Signature scheme, if the file is signed.
+    pub signature: Option<FrbSignatureAlgorithm>,
+    /// AEAD protecting the metadata and the chunks.
+    pub cipher: FrbCipherSuite,
+    /// Whether the header commits to the key (checked on unlock).
+    pub key_commitment: bool,
+    /// Total length of the file on disk.
+    pub file_len: u64,
+    /// Length of the encrypted payload (data chunks and parity).
+    pub ciphertext_len: u64,
+    /// Number of encrypted data chunks (0 if the length is invalid).
+    pub chunk_count: u64,
+    /// Plaintext bytes carried by the payload, after compression.
+    pub estimated_plaintext_size: u64,
+    /// `false` if the payload length does not match whole chunks,
+    /// which indicates truncation or trailing garbage.
+    pub whole_chunks: bool,
+}
+
+/// Returned by `plan_encrypt` and `plan_decrypt`: the disk space an
+/// operation needs, to warn before starting it.
+#[derive(Debug, Clone)]
+pub struct FrbSpacePlan {
+    /// Bytes written to the output directory.
+    pub output_bytes: u64,
+    /// Free bytes on the output file system, if known.
+    pub output_free: Option<u64>,
+    /// Most bytes held in the temp directory at once.
+    pub temp_bytes: u64,
+    /// Free bytes on the temp file system, if known.
+    pub temp_free: Option<u64>,
+    /// Whether output and temp files draw on the same free space.
+    pub shared_volume: bool,
+    /// `true` when decompressed sizes are only known to be at least
+    /// the counts above (decrypting compressed data).
+    pub estimated: bool,
+    /// Where the space falls short, if it is known to.
+    pub shortfall: Option<FrbSpaceShortfall>,
+}
+
+/// A directory without room for what an operation writes there.
+#[derive(Debug, Clone)]
+pub struct FrbSpaceShortfall {
+    pub path: String,
+    pub needed: u64,
+    pub available: u64,
+}
+
+impl From<SpacePlan> for FrbSpacePlan {
+    fn from(plan: SpacePlan) -> Self {
+        let shortfall = match plan.check() {
+            Err(CryptError::InsufficientSpace {
+                path,
+                needed,
+                available,
+            }) => Some(FrbSpaceShortfall {
+                path: path.to_string_lossy().into_owned(),
+                needed,
+                available,
+            }),
+            _ => None,
+        };
+        Self {
+            output_bytes: plan.output_bytes,
+            output_free: plan.output_free,
+            temp_bytes: plan.temp_bytes,
+            temp_free: plan.temp_free,
+            shared_volume: plan.shared_volume,
+            estimated: plan.estimated,
+            shortfall,
+        }
+    }
 }
 
 /// Error record for a single failed item in a batch operation.
@@ -287,3 +733,79 @@ pub struct FrbBatchDecryptResult {
     pub succeeded: Vec<FrbDecryptResult>,
     pub failed: Vec<FrbBatchError>,
 }
+
+/// Returned for each file re-encrypted by `reencrypt_batch`.
+#[derive(Debug, Clone)]
+pub struct FrbReencryptResult {
+    /// Path of the re-encrypted file (the input, replaced).
+    pub output_path: String,
+    /// Number of data chunks in the new file.
+    pub chunk_count: u64,
+    /// Signature check outcome of the original file.
+    pub source_signature: FrbSignatureStatus,
+}
+
+impl From<RekeyResult> for FrbReencryptResult {
+    fn from(result: RekeyResult) -> Self {
+        Self {
+            output_path: result.output_path.to_string_lossy().into_owned(),
+            chunk_count: result.chunk_count,
+            source_signature: result.source_signature.into(),
+        }
+    }
+}
+
+/// Summary returned as the final `AllDone` event of `reencrypt_batch`.
+#[derive(Debug, Clone)]
+pub struct FrbBatchReencryptResult {
+    pub succeeded: Vec<FrbReencryptResult>,
+    pub failed: Vec<FrbBatchError>,
+}
+
+/// Address of the local media server, returned by `media_server_start`.
+#[derive(Debug, Clone)]
+pub struct FrbMediaServerInfo {
+    /// Port on 127.0.0.1, chosen by the OS.
+    pub port: u16,
+    /// Random per-session token; every URL carries it as its first path
+    /// segment.
+    pub token: String,
+}
+
+/// A file registered with `media_server_add`.
+#[derive(Debug, Clone)]
+pub struct FrbMediaSource {
+    /// Id to pass to `media_server_remove`.
+    pub source_id: String,
+    /// `http://127.0.0.1:<port>/<token>/<source_id>`, to hand to a player.
+    pub url: String,
+    /// Plaintext size in bytes.
+    pub size: u64,
+    /// `Content-Type` served, derived from the original extension.
+    pub mime_type: String,
+    /// Decoded metadata from the encrypted header.
+    pub metadata: FrbDecryptedMetadata,
+}
+
+/// Settings of the decrypted-attachments cache.
+#[derive(Debug, Clone)]
+pub struct FrbDecryptedCacheOptions {
+    /// Directory decrypted attachments are written to. Only outputs inside
+    /// it are tracked; files already in it are adopted.
+    pub root_dir: String,
+    /// Byte budget; least recently used entries are shredded beyond it.
+    pub max_bytes: Option<u64>,
+    /// Entries not accessed for this many seconds are shredded.
+    pub ttl_secs: Option<u64>,
+}
+
+/// Current state of the decrypted-attachments cache.
+#[derive(Debug, Clone)]
+pub struct FrbDecryptedCacheStats {
+    /// Canonical cache directory.
+    pub root_dir: String,
+    /// Tracked files and directories.
+    pub entry_count: u64,
+    /// Bytes they occupy.
+    pub total_bytes: u64,
+}
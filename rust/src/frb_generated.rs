@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.12.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -626618812;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1518262841;
 
 // Section: executor
 
@@ -170,7 +170,7 @@ fn wire__crate__api__crypt_api__decrypt_file_impl(
         },
     )
 }
-fn wire__crate__api__icon_pack_catalog_api__delete_pack_impl(
+fn wire__crate__api__crypt_api__decrypt_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -178,7 +178,7 @@ fn wire__crate__api__icon_pack_catalog_api__delete_pack_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_pack",
+            debug_name: "decrypt_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -192,17 +192,20 @@ fn wire__crate__api__icon_pack_catalog_api__delete_pack_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_root_path = <String>::sse_decode(&mut deserializer);
-            let api_pack_key = <String>::sse_decode(&mut deserializer);
+            let api_opts = <crate::api::crypt_api::types::FrbStreamDecryptOptions>::sse_decode(
+                &mut deserializer,
+            );
+            let api_sink = <StreamSink<
+                crate::api::crypt_api::types::FrbDecryptChunk,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = crate::api::icon_pack_catalog_api::delete_pack(
-                            api_root_path,
-                            api_pack_key,
-                        )
-                        .await?;
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::crypt_api::decrypt_stream(api_opts, api_sink).await;
+                        })?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -211,17 +214,16 @@ fn wire__crate__api__icon_pack_catalog_api__delete_pack_impl(
         },
     )
 }
-fn wire__crate__api__crypt_api__encrypt_batch_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__crypt_api__decrypt_stream_ack_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "encrypt_batch",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "decrypt_stream_ack",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -233,28 +235,50 @@ fn wire__crate__api__crypt_api__encrypt_batch_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_opts = <crate::api::crypt_api::types::FrbBatchEncryptOptions>::sse_decode(
-                &mut deserializer,
-            );
-            let api_sink = <StreamSink<
-                crate::api::crypt_api::types::FrbBatchEncryptEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_stream_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::crypt_api::encrypt_batch(api_opts, api_sink).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::crypt_api::decrypt_stream_ack(api_stream_id);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__crypt_api__decrypt_stream_cancel_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "decrypt_stream_cancel",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
-            }
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::crypt_api::decrypt_stream_cancel(api_stream_id);
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__crypt_api__encrypt_file_impl(
+fn wire__crate__api__crypt_api__decrypt_to_memory_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -262,7 +286,7 @@ fn wire__crate__api__crypt_api__encrypt_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "encrypt_file",
+            debug_name: "decrypt_to_memory",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -276,19 +300,19 @@ fn wire__crate__api__crypt_api__encrypt_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_opts =
-                <crate::api::crypt_api::types::FrbEncryptOptions>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::crypt_api::types::FrbEncryptEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_max_size = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::crypt_api::encrypt_file(api_opts, api_sink).await;
-                        })?;
+                        let output_ok = crate::api::crypt_api::decrypt_to_memory(
+                            api_input_path,
+                            api_password,
+                            api_max_size,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -297,7 +321,7 @@ fn wire__crate__api__crypt_api__encrypt_file_impl(
         },
     )
 }
-fn wire__crate__api__keepass_api__export_keepass_database_impl(
+fn wire__crate__api__crypt_api__decrypted_cache_configure_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -305,7 +329,7 @@ fn wire__crate__api__keepass_api__export_keepass_database_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_keepass_database",
+            debug_name: "decrypted_cache_configure",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -319,7 +343,7 @@ fn wire__crate__api__keepass_api__export_keepass_database_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_opts = <crate::api::keepass_api::types::FrbKeepassExportOptions>::sse_decode(
+            let api_opts = <crate::api::crypt_api::types::FrbDecryptedCacheOptions>::sse_decode(
                 &mut deserializer,
             );
             deserializer.end();
@@ -327,7 +351,7 @@ fn wire__crate__api__keepass_api__export_keepass_database_impl(
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
                         let output_ok =
-                            crate::api::keepass_api::export_keepass_database(api_opts).await?;
+                            crate::api::crypt_api::decrypted_cache_configure(api_opts).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -336,15 +360,15 @@ fn wire__crate__api__keepass_api__export_keepass_database_impl(
         },
     )
 }
-fn wire__crate__api__crypt_api__types__frb_decrypt_options_simple_impl(
+fn wire__crate__api__crypt_api__decrypted_cache_purge_all_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "frb_decrypt_options_simple",
+            debug_name: "decrypted_cache_purge_all",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -358,34 +382,28 @@ fn wire__crate__api__crypt_api__types__frb_decrypt_options_simple_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_input_path = <String>::sse_decode(&mut deserializer);
-            let api_output_dir = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::crypt_api::types::FrbDecryptOptions::simple(
-                            api_input_path,
-                            api_output_dir,
-                            api_password,
-                        ),
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::crypt_api::decrypted_cache_purge_all().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__crypt_api__types__frb_encrypt_options_simple_impl(
+fn wire__crate__api__crypt_api__decrypted_cache_purge_expired_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "frb_encrypt_options_simple",
+            debug_name: "decrypted_cache_purge_expired",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -399,36 +417,31 @@ fn wire__crate__api__crypt_api__types__frb_encrypt_options_simple_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_input_path = <String>::sse_decode(&mut deserializer);
-            let api_output_dir = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::crypt_api::types::FrbEncryptOptions::simple(
-                            api_input_path,
-                            api_output_dir,
-                            api_password,
-                        ),
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::crypt_api::decrypted_cache_purge_expired().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__keepass_api__types__frb_keepass_export_options_simple_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__crypt_api__decrypted_cache_stats_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "frb_keepass_export_options_simple",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "decrypted_cache_stats",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -440,31 +453,24 @@ fn wire__crate__api__keepass_api__types__frb_keepass_export_options_simple_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_input_path = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::keepass_api::types::FrbKeepassExportOptions::simple(
-                            api_input_path,
-                            api_password,
-                        ),
-                    )?;
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::crypt_api::decrypted_cache_stats()?;
                     Ok(output_ok)
-                })())
-            }
+                })(),
+            )
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__crypt_api__decrypted_cache_touch_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
+            debug_name: "decrypted_cache_touch",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -478,16 +484,18 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::crypt_api::decrypted_cache_touch(api_path);
+                })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__icon_pack_catalog_api__import_directory_impl(
+fn wire__crate__api__icon_pack_catalog_api__delete_pack_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -495,7 +503,7 @@ fn wire__crate__api__icon_pack_catalog_api__import_directory_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "import_directory",
+            debug_name: "delete_pack",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -510,25 +518,16 @@ fn wire__crate__api__icon_pack_catalog_api__import_directory_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_root_path = <String>::sse_decode(&mut deserializer);
-            let api_directory_path = <String>::sse_decode(&mut deserializer);
-            let api_display_name = <String>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_pack_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::icon_pack_catalog_api::import_directory(
-                                api_root_path,
-                                api_directory_path,
-                                api_display_name,
-                                api_sink,
-                            )
-                            .await;
-                        })?;
+                        let output_ok = crate::api::icon_pack_catalog_api::delete_pack(
+                            api_root_path,
+                            api_pack_key,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -537,7 +536,7 @@ fn wire__crate__api__icon_pack_catalog_api__import_directory_impl(
         },
     )
 }
-fn wire__crate__api__icon_pack_catalog_api__import_pack_impl(
+fn wire__crate__api__crypt_api__encrypt_batch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -545,7 +544,7 @@ fn wire__crate__api__icon_pack_catalog_api__import_pack_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "import_pack",
+            debug_name: "encrypt_batch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -559,26 +558,19 @@ fn wire__crate__api__icon_pack_catalog_api__import_pack_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_root_path = <String>::sse_decode(&mut deserializer);
-            let api_archive_path = <String>::sse_decode(&mut deserializer);
-            let api_display_name = <String>::sse_decode(&mut deserializer);
+            let api_opts = <crate::api::crypt_api::types::FrbBatchEncryptOptions>::sse_decode(
+                &mut deserializer,
+            );
             let api_sink = <StreamSink<
-                crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent,
+                crate::api::crypt_api::types::FrbBatchEncryptEvent,
                 flutter_rust_bridge::for_generated::SseCodec,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::icon_pack_catalog_api::import_pack(
-                                api_root_path,
-                                api_archive_path,
-                                api_display_name,
-                                api_sink,
-                            )
-                            .await;
-                        })?;
+                        let output_ok =
+                            crate::api::crypt_api::encrypt_batch(api_opts, api_sink).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -587,15 +579,15 @@ fn wire__crate__api__icon_pack_catalog_api__import_pack_impl(
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__crypt_api__encrypt_file_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "encrypt_file",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -609,99 +601,28 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_opts =
+                <crate::api::crypt_api::types::FrbEncryptOptions>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::crypt_api::types::FrbEncryptEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::crypt_api::encrypt_file(api_opts, api_sink).await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__logging__install_rust_log_bridge_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "install_rust_log_bridge",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_level = <i32>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::logging::install_rust_log_bridge(api_level)?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__icon_pack_catalog_api__list_icons_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_icons",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_root_path = <String>::sse_decode(&mut deserializer);
-            let api_pack_key = <Option<String>>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_offset = <i32>::sse_decode(&mut deserializer);
-            let api_limit = <i32>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::icon_pack_catalog_api::list_icons(
-                            api_root_path,
-                            api_pack_key,
-                            api_query,
-                            api_offset,
-                            api_limit,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__icon_pack_catalog_api__list_packs_impl(
+fn wire__crate__api__crypt_api__encrypt_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -709,7 +630,7 @@ fn wire__crate__api__icon_pack_catalog_api__list_packs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_packs",
+            debug_name: "encrypt_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -723,13 +644,14 @@ fn wire__crate__api__icon_pack_catalog_api__list_packs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_root_path = <String>::sse_decode(&mut deserializer);
+            let api_opts = <crate::api::crypt_api::types::FrbStreamEncryptOptions>::sse_decode(
+                &mut deserializer,
+            );
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::icon_pack_catalog_api::list_packs(api_root_path).await?;
+                        let output_ok = crate::api::crypt_api::encrypt_stream(api_opts).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -738,14 +660,14 @@ fn wire__crate__api__icon_pack_catalog_api__list_packs_impl(
         },
     )
 }
-fn wire__crate__api__icon_pack_catalog_api__normalize_icon_path_without_extension_impl(
+fn wire__crate__api__crypt_api__encrypt_stream_abort_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "normalize_icon_path_without_extension",
+            debug_name: "encrypt_stream_abort",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -759,29 +681,28 @@ fn wire__crate__api__icon_pack_catalog_api__normalize_icon_path_without_extensio
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_value = <String>::sse_decode(&mut deserializer);
+            let api_stream_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::icon_pack_catalog_api::normalize_icon_path_without_extension(
-                        api_value,
-                    ),
-                )?;
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::crypt_api::encrypt_stream_abort(api_stream_id);
+                })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__icon_pack_catalog_api__normalize_pack_key_impl(
+fn wire__crate__api__crypt_api__encrypt_stream_finish_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "normalize_pack_key",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "encrypt_stream_finish",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -793,18 +714,22 @@ fn wire__crate__api__icon_pack_catalog_api__normalize_pack_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_value = <String>::sse_decode(&mut deserializer);
+            let api_stream_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::icon_pack_catalog_api::normalize_pack_key(api_value),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::crypt_api::encrypt_stream_finish(api_stream_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__crypt_api__read_encrypted_header_impl(
+fn wire__crate__api__crypt_api__encrypt_stream_push_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -812,7 +737,7 @@ fn wire__crate__api__crypt_api__read_encrypted_header_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "read_encrypted_header",
+            debug_name: "encrypt_stream_push",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -826,17 +751,15 @@ fn wire__crate__api__crypt_api__read_encrypted_header_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_input_path = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_stream_id = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::crypt_api::read_encrypted_header(
-                            api_input_path,
-                            api_password,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::crypt_api::encrypt_stream_push(api_stream_id, api_data)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -845,7 +768,7 @@ fn wire__crate__api__crypt_api__read_encrypted_header_impl(
         },
     )
 }
-fn wire__crate__api__icon_pack_catalog_api__read_svg_by_key_impl(
+fn wire__crate__api__keepass_api__export_keepass_database_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -853,7 +776,7 @@ fn wire__crate__api__icon_pack_catalog_api__read_svg_by_key_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "read_svg_by_key",
+            debug_name: "export_keepass_database",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -867,17 +790,15 @@ fn wire__crate__api__icon_pack_catalog_api__read_svg_by_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_root_path = <String>::sse_decode(&mut deserializer);
-            let api_icon_key = <String>::sse_decode(&mut deserializer);
+            let api_opts = <crate::api::keepass_api::types::FrbKeepassExportOptions>::sse_decode(
+                &mut deserializer,
+            );
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::icon_pack_catalog_api::read_svg_by_key(
-                            api_root_path,
-                            api_icon_key,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::keepass_api::export_keepass_database(api_opts).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -886,7 +807,7 @@ fn wire__crate__api__icon_pack_catalog_api__read_svg_by_key_impl(
         },
     )
 }
-fn wire__crate__api__logging__rust_log_impl(
+fn wire__crate__api__crypt_api__types__frb_decrypt_options_simple_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -894,7 +815,7 @@ fn wire__crate__api__logging__rust_log_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rust_log",
+            debug_name: "frb_decrypt_options_simple",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -908,22 +829,26 @@ fn wire__crate__api__logging__rust_log_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_level = <i32>::sse_decode(&mut deserializer);
-            let api_tag = <String>::sse_decode(&mut deserializer);
-            let api_msg = <String>::sse_decode(&mut deserializer);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logging::rust_log(api_level, api_tag, api_msg);
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::crypt_api::types::FrbDecryptOptions::simple(
+                            api_input_path,
+                            api_output_dir,
+                            api_password,
+                        ),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logging__rust_log_debug_impl(
+fn wire__crate__api__crypt_api__types__frb_encrypt_options_simple_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -931,7 +856,7 @@ fn wire__crate__api__logging__rust_log_debug_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rust_log_debug",
+            debug_name: "frb_encrypt_options_simple",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -945,21 +870,26 @@ fn wire__crate__api__logging__rust_log_debug_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tag = <String>::sse_decode(&mut deserializer);
-            let api_msg = <String>::sse_decode(&mut deserializer);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logging::rust_log_debug(api_tag, api_msg);
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::crypt_api::types::FrbEncryptOptions::simple(
+                            api_input_path,
+                            api_output_dir,
+                            api_password,
+                        ),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logging__rust_log_error_impl(
+fn wire__crate__api__keepass_api__types__frb_keepass_export_options_simple_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -967,7 +897,7 @@ fn wire__crate__api__logging__rust_log_error_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rust_log_error",
+            debug_name: "frb_keepass_export_options_simple",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -981,31 +911,33 @@ fn wire__crate__api__logging__rust_log_error_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tag = <String>::sse_decode(&mut deserializer);
-            let api_msg = <String>::sse_decode(&mut deserializer);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logging::rust_log_error(api_tag, api_msg);
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::keepass_api::types::FrbKeepassExportOptions::simple(
+                            api_input_path,
+                            api_password,
+                        ),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logging__rust_log_fatal_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__crypt_api__generate_signing_identity_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rust_log_fatal",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "generate_signing_identity",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1017,31 +949,25 @@ fn wire__crate__api__logging__rust_log_fatal_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tag = <String>::sse_decode(&mut deserializer);
-            let api_msg = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logging::rust_log_fatal(api_tag, api_msg);
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::crypt_api::generate_signing_identity())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__logging__rust_log_info_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__greet_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rust_log_info",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1053,21 +979,116 @@ fn wire__crate__api__logging__rust_log_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tag = <String>::sse_decode(&mut deserializer);
-            let api_msg = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logging::rust_log_info(api_tag, api_msg);
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__logging__rust_log_warning_impl(
+fn wire__crate__api__icon_pack_catalog_api__import_directory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_directory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_path = <String>::sse_decode(&mut deserializer);
+            let api_directory_path = <String>::sse_decode(&mut deserializer);
+            let api_display_name = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::icon_pack_catalog_api::import_directory(
+                                api_root_path,
+                                api_directory_path,
+                                api_display_name,
+                                api_sink,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__icon_pack_catalog_api__import_pack_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_pack",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_path = <String>::sse_decode(&mut deserializer);
+            let api_archive_path = <String>::sse_decode(&mut deserializer);
+            let api_display_name = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::icon_pack_catalog_api::import_pack(
+                                api_root_path,
+                                api_archive_path,
+                                api_display_name,
+                                api_sink,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1075,7 +1096,7 @@ fn wire__crate__api__logging__rust_log_warning_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rust_log_warning",
+            debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1089,13 +1110,11 @@ fn wire__crate__api__logging__rust_log_warning_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tag = <String>::sse_decode(&mut deserializer);
-            let api_msg = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logging::rust_log_warning(api_tag, api_msg);
+                        crate::api::simple::init_app();
                     })?;
                     Ok(output_ok)
                 })())
@@ -1103,528 +1122,3247 @@ fn wire__crate__api__logging__rust_log_warning_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
+fn wire__crate__api__crypt_api__inspect_encrypted_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "inspect_encrypted_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::crypt_api::inspect_encrypted_file(api_input_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode
-    for StreamSink<
-        crate::api::crypt_api::types::FrbBatchDecryptEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
+fn wire__crate__api__logging__install_rust_log_bridge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "install_rust_log_bridge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::logging::install_rust_log_bridge(api_level)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode
-    for StreamSink<
-        crate::api::crypt_api::types::FrbBatchEncryptEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
+fn wire__crate__api__icon_pack_catalog_api__list_icons_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_icons",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_path = <String>::sse_decode(&mut deserializer);
+            let api_pack_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_offset = <i32>::sse_decode(&mut deserializer);
+            let api_limit = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::icon_pack_catalog_api::list_icons(
+                            api_root_path,
+                            api_pack_key,
+                            api_query,
+                            api_offset,
+                            api_limit,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode
-    for StreamSink<
-        crate::api::crypt_api::types::FrbDecryptEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
+fn wire__crate__api__icon_pack_catalog_api__list_packs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_packs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::icon_pack_catalog_api::list_packs(api_root_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__crypt_api__media_server_add_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "media_server_add",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::crypt_api::media_server_add(api_input_path, api_password)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__crypt_api__media_server_remove_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "media_server_remove",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::crypt_api::media_server_remove(api_source_id);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__crypt_api__media_server_start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "media_server_start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::crypt_api::media_server_start().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__crypt_api__media_server_stop_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "media_server_stop",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::crypt_api::media_server_stop();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__icon_pack_catalog_api__normalize_icon_path_without_extension_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "normalize_icon_path_without_extension",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_value = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::icon_pack_catalog_api::normalize_icon_path_without_extension(
+                        api_value,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__icon_pack_catalog_api__normalize_pack_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "normalize_pack_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_value = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::icon_pack_catalog_api::normalize_pack_key(api_value),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__crypt_api__plan_decrypt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "plan_decrypt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_opts =
+                <crate::api::crypt_api::types::FrbDecryptOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::crypt_api::plan_decrypt(api_opts).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__crypt_api__plan_encrypt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "plan_encrypt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_opts =
+                <crate::api::crypt_api::types::FrbEncryptOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::crypt_api::plan_encrypt(api_opts).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__crypt_api__read_encrypted_header_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "read_encrypted_header",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::crypt_api::read_encrypted_header(
+                            api_input_path,
+                            api_password,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__icon_pack_catalog_api__read_svg_by_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "read_svg_by_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_path = <String>::sse_decode(&mut deserializer);
+            let api_icon_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::icon_pack_catalog_api::read_svg_by_key(
+                            api_root_path,
+                            api_icon_key,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__crypt_api__reencrypt_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reencrypt_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_opts = <crate::api::crypt_api::types::FrbBatchReencryptOptions>::sse_decode(
+                &mut deserializer,
+            );
+            let api_sink = <StreamSink<
+                crate::api::crypt_api::types::FrbBatchReencryptEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::crypt_api::reencrypt_batch(api_opts, api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__logging__rust_log_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rust_log",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level = <i32>::sse_decode(&mut deserializer);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            let api_msg = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logging::rust_log(api_level, api_tag, api_msg);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logging__rust_log_debug_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rust_log_debug",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            let api_msg = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logging::rust_log_debug(api_tag, api_msg);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logging__rust_log_error_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rust_log_error",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            let api_msg = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logging::rust_log_error(api_tag, api_msg);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logging__rust_log_fatal_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rust_log_fatal",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            let api_msg = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logging::rust_log_fatal(api_tag, api_msg);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logging__rust_log_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rust_log_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            let api_msg = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logging::rust_log_info(api_tag, api_msg);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logging__rust_log_warning_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rust_log_warning",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            let api_msg = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logging::rust_log_warning(api_tag, api_msg);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__crypt_api__signing_public_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "signing_public_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::crypt_api::signing_public_key(api_secret_key)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__crypt_api__update_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_patch =
+                <crate::api::crypt_api::types::FrbMetadataPatch>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::crypt_api::update_metadata(
+                            api_input_path,
+                            api_password,
+                            api_patch,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__crypt_api__verify_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::crypt_api::verify_file(api_input_path, api_password)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::crypt_api::types::FrbBatchDecryptEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::crypt_api::types::FrbBatchEncryptEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::crypt_api::types::FrbBatchReencryptEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::crypt_api::types::FrbDecryptChunk,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::crypt_api::types::FrbDecryptEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::crypt_api::types::FrbEncryptEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::logging::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbArgon2Params {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tCost = <u32>::sse_decode(deserializer);
+        let mut var_mCostKib = <u32>::sse_decode(deserializer);
+        let mut var_parallelism = <u32>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbArgon2Params {
+            t_cost: var_tCost,
+            m_cost_kib: var_mCostKib,
+            parallelism: var_parallelism,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbBatchDecryptEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_fileIndex = <u32>::sse_decode(deserializer);
+                let mut var_totalFiles = <u32>::sse_decode(deserializer);
+                let mut var_currentFile = <String>::sse_decode(deserializer);
+                let mut var_progress =
+                    <crate::api::crypt_api::types::FrbProgressEvent>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchDecryptEvent::FileProgress {
+                    file_index: var_fileIndex,
+                    total_files: var_totalFiles,
+                    current_file: var_currentFile,
+                    progress: var_progress,
+                };
+            }
+            1 => {
+                let mut var_fileIndex = <u32>::sse_decode(deserializer);
+                let mut var_result =
+                    <crate::api::crypt_api::types::FrbDecryptResult>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchDecryptEvent::FileDone {
+                    file_index: var_fileIndex,
+                    result: var_result,
+                };
+            }
+            2 => {
+                let mut var_fileIndex = <u32>::sse_decode(deserializer);
+                let mut var_inputPath = <String>::sse_decode(deserializer);
+                let mut var_error = <String>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchDecryptEvent::FileError {
+                    file_index: var_fileIndex,
+                    input_path: var_inputPath,
+                    error: var_error,
+                };
+            }
+            3 => {
+                let mut var_field0 =
+                    <crate::api::crypt_api::types::FrbBatchDecryptResult>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchDecryptEvent::AllDone(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbBatchDecryptOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_inputPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_outputDir = <String>::sse_decode(deserializer);
+        let mut var_password = <String>::sse_decode(deserializer);
+        let mut var_tempDir = <Option<String>>::sse_decode(deserializer);
+        let mut var_chunkSize =
+            <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbBatchDecryptOptions {
+            input_paths: var_inputPaths,
+            output_dir: var_outputDir,
+            password: var_password,
+            temp_dir: var_tempDir,
+            chunk_size: var_chunkSize,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbBatchDecryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_succeeded =
+            <Vec<crate::api::crypt_api::types::FrbDecryptResult>>::sse_decode(deserializer);
+        let mut var_failed =
+            <Vec<crate::api::crypt_api::types::FrbBatchError>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbBatchDecryptResult {
+            succeeded: var_succeeded,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbBatchEncryptEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_fileIndex = <u32>::sse_decode(deserializer);
+                let mut var_totalFiles = <u32>::sse_decode(deserializer);
+                let mut var_currentFile = <String>::sse_decode(deserializer);
+                let mut var_progress =
+                    <crate::api::crypt_api::types::FrbProgressEvent>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchEncryptEvent::FileProgress {
+                    file_index: var_fileIndex,
+                    total_files: var_totalFiles,
+                    current_file: var_currentFile,
+                    progress: var_progress,
+                };
+            }
+            1 => {
+                let mut var_fileIndex = <u32>::sse_decode(deserializer);
+                let mut var_result =
+                    <crate::api::crypt_api::types::FrbEncryptResult>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchEncryptEvent::FileDone {
+                    file_index: var_fileIndex,
+                    result: var_result,
+                };
+            }
+            2 => {
+                let mut var_fileIndex = <u32>::sse_decode(deserializer);
+                let mut var_inputPath = <String>::sse_decode(deserializer);
+                let mut var_error = <String>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchEncryptEvent::FileError {
+                    file_index: var_fileIndex,
+                    input_path: var_inputPath,
+                    error: var_error,
+                };
+            }
+            3 => {
+                let mut var_field0 =
+                    <crate::api::crypt_api::types::FrbBatchEncryptResult>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchEncryptEvent::AllDone(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbBatchEncryptOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_inputPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_outputDir = <String>::sse_decode(deserializer);
+        let mut var_password = <String>::sse_decode(deserializer);
+        let mut var_gzipCompressed = <bool>::sse_decode(deserializer);
+        let mut var_tempDir = <Option<String>>::sse_decode(deserializer);
+        let mut var_metadata =
+            <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_decode(deserializer);
+        let mut var_chunkSize =
+            <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_decode(deserializer);
+        let mut var_cipherSuite =
+            <crate::api::crypt_api::types::FrbCipherSuite>::sse_decode(deserializer);
+        let mut var_signingKey = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_hideLengths = <bool>::sse_decode(deserializer);
+        let mut var_volumeSize = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbBatchEncryptOptions {
+            input_paths: var_inputPaths,
+            output_dir: var_outputDir,
+            password: var_password,
+            gzip_compressed: var_gzipCompressed,
+            temp_dir: var_tempDir,
+            metadata: var_metadata,
+            chunk_size: var_chunkSize,
+            cipher_suite: var_cipherSuite,
+            signing_key: var_signingKey,
+            hide_lengths: var_hideLengths,
+            volume_size: var_volumeSize,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbBatchEncryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_succeeded =
+            <Vec<crate::api::crypt_api::types::FrbEncryptResult>>::sse_decode(deserializer);
+        let mut var_failed =
+            <Vec<crate::api::crypt_api::types::FrbBatchError>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbBatchEncryptResult {
+            succeeded: var_succeeded,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbBatchError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_inputPath = <String>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbBatchError {
+            input_path: var_inputPath,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbBatchReencryptEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_fileIndex = <u32>::sse_decode(deserializer);
+                let mut var_totalFiles = <u32>::sse_decode(deserializer);
+                let mut var_currentFile = <String>::sse_decode(deserializer);
+                let mut var_progress =
+                    <crate::api::crypt_api::types::FrbProgressEvent>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchReencryptEvent::FileProgress {
+                    file_index: var_fileIndex,
+                    total_files: var_totalFiles,
+                    current_file: var_currentFile,
+                    progress: var_progress,
+                };
+            }
+            1 => {
+                let mut var_fileIndex = <u32>::sse_decode(deserializer);
+                let mut var_result =
+                    <crate::api::crypt_api::types::FrbReencryptResult>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchReencryptEvent::FileDone {
+                    file_index: var_fileIndex,
+                    result: var_result,
+                };
+            }
+            2 => {
+                let mut var_fileIndex = <u32>::sse_decode(deserializer);
+                let mut var_inputPath = <String>::sse_decode(deserializer);
+                let mut var_error = <String>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbBatchReencryptEvent::FileError {
+                    file_index: var_fileIndex,
+                    input_path: var_inputPath,
+                    error: var_error,
+                };
+            }
+            3 => {
+                let mut var_field0 =
+                    <crate::api::crypt_api::types::FrbBatchReencryptResult>::sse_decode(
+                        deserializer,
+                    );
+                return crate::api::crypt_api::types::FrbBatchReencryptEvent::AllDone(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbBatchReencryptOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_inputPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_oldPassword = <String>::sse_decode(deserializer);
+        let mut var_newPassword = <String>::sse_decode(deserializer);
+        let mut var_argon2Params =
+            <Option<crate::api::crypt_api::types::FrbArgon2Params>>::sse_decode(deserializer);
+        let mut var_chunkSize =
+            <Option<crate::api::crypt_api::types::FrbChunkSizePreset>>::sse_decode(deserializer);
+        let mut var_signingKey = <Option<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbBatchReencryptOptions {
+            input_paths: var_inputPaths,
+            old_password: var_oldPassword,
+            new_password: var_newPassword,
+            argon2_params: var_argon2Params,
+            chunk_size: var_chunkSize,
+            signing_key: var_signingKey,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbBatchReencryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_succeeded =
+            <Vec<crate::api::crypt_api::types::FrbReencryptResult>>::sse_decode(deserializer);
+        let mut var_failed =
+            <Vec<crate::api::crypt_api::types::FrbBatchError>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbBatchReencryptResult {
+            succeeded: var_succeeded,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbChunkSizePreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::crypt_api::types::FrbChunkSizePreset::Desktop;
+            }
+            1 => {
+                return crate::api::crypt_api::types::FrbChunkSizePreset::Mobile;
+            }
+            2 => {
+                let mut var_field0 = <u32>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbChunkSizePreset::Custom(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbCipherSuite {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::crypt_api::types::FrbCipherSuite::XChaCha20Poly1305,
+            1 => crate::api::crypt_api::types::FrbCipherSuite::Aes256GcmSiv,
+            _ => unreachable!("Invalid variant for FrbCipherSuite: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbDecryptChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbDecryptChunk::Data(var_field0);
+            }
+            1 => {
+                let mut var_field0 =
+                    <crate::api::crypt_api::types::FrbStreamDecryptResult>::sse_decode(
+                        deserializer,
+                    );
+                return crate::api::crypt_api::types::FrbDecryptChunk::Done(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbDecryptChunk::Error(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbDecryptEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 =
+                    <crate::api::crypt_api::types::FrbProgressEvent>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbDecryptEvent::Progress(var_field0);
+            }
+            1 => {
+                let mut var_field0 =
+                    <crate::api::crypt_api::types::FrbDecryptResult>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbDecryptEvent::Done(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbDecryptEvent::Error(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbDecryptOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_inputPath = <String>::sse_decode(deserializer);
+        let mut var_outputDir = <String>::sse_decode(deserializer);
+        let mut var_password = <String>::sse_decode(deserializer);
+        let mut var_tempDir = <Option<String>>::sse_decode(deserializer);
+        let mut var_chunkSize =
+            <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbDecryptOptions {
+            input_path: var_inputPath,
+            output_dir: var_outputDir,
+            password: var_password,
+            temp_dir: var_tempDir,
+            chunk_size: var_chunkSize,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbDecryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outputPath = <String>::sse_decode(deserializer);
+        let mut var_metadata =
+            <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_decode(deserializer);
+        let mut var_signature =
+            <crate::api::crypt_api::types::FrbSignatureStatus>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbDecryptResult {
+            output_path: var_outputPath,
+            metadata: var_metadata,
+            signature: var_signature,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbDecryptedCacheOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rootDir = <String>::sse_decode(deserializer);
+        let mut var_maxBytes = <Option<u64>>::sse_decode(deserializer);
+        let mut var_ttlSecs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbDecryptedCacheOptions {
+            root_dir: var_rootDir,
+            max_bytes: var_maxBytes,
+            ttl_secs: var_ttlSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbDecryptedCacheStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rootDir = <String>::sse_decode(deserializer);
+        let mut var_entryCount = <u64>::sse_decode(deserializer);
+        let mut var_totalBytes = <u64>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbDecryptedCacheStats {
+            root_dir: var_rootDir,
+            entry_count: var_entryCount,
+            total_bytes: var_totalBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbDecryptedMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_originalFilename = <String>::sse_decode(deserializer);
+        let mut var_originalExtension = <String>::sse_decode(deserializer);
+        let mut var_gzipCompressed = <bool>::sse_decode(deserializer);
+        let mut var_originalSize = <u64>::sse_decode(deserializer);
+        let mut var_uuid = <String>::sse_decode(deserializer);
+        let mut var_metadata =
+            <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_decode(deserializer);
+        let mut var_signerPublicKey = <Option<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbDecryptedMetadata {
+            original_filename: var_originalFilename,
+            original_extension: var_originalExtension,
+            gzip_compressed: var_gzipCompressed,
+            original_size: var_originalSize,
+            uuid: var_uuid,
+            metadata: var_metadata,
+            signer_public_key: var_signerPublicKey,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbEncryptEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 =
+                    <crate::api::crypt_api::types::FrbProgressEvent>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbEncryptEvent::Progress(var_field0);
+            }
+            1 => {
+                let mut var_field0 =
+                    <crate::api::crypt_api::types::FrbEncryptResult>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbEncryptEvent::Done(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbEncryptEvent::Error(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbEncryptOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_inputPath = <String>::sse_decode(deserializer);
+        let mut var_outputDir = <String>::sse_decode(deserializer);
+        let mut var_password = <String>::sse_decode(deserializer);
+        let mut var_gzipCompressed = <bool>::sse_decode(deserializer);
+        let mut var_uuid = <Option<String>>::sse_decode(deserializer);
+        let mut var_outputExtension = <Option<String>>::sse_decode(deserializer);
+        let mut var_tempDir = <Option<String>>::sse_decode(deserializer);
+        let mut var_metadata =
+            <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_decode(deserializer);
+        let mut var_chunkSize =
+            <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_decode(deserializer);
+        let mut var_cipherSuite =
+            <crate::api::crypt_api::types::FrbCipherSuite>::sse_decode(deserializer);
+        let mut var_signingKey = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_shredSource = <bool>::sse_decode(deserializer);
+        let mut var_hideLengths = <bool>::sse_decode(deserializer);
+        let mut var_volumeSize = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbEncryptOptions {
+            input_path: var_inputPath,
+            output_dir: var_outputDir,
+            password: var_password,
+            gzip_compressed: var_gzipCompressed,
+            uuid: var_uuid,
+            output_extension: var_outputExtension,
+            temp_dir: var_tempDir,
+            metadata: var_metadata,
+            chunk_size: var_chunkSize,
+            cipher_suite: var_cipherSuite,
+            signing_key: var_signingKey,
+            shred_source: var_shredSource,
+            hide_lengths: var_hideLengths,
+            volume_size: var_volumeSize,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbEncryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outputPath = <String>::sse_decode(deserializer);
+        let mut var_volumes = <Vec<String>>::sse_decode(deserializer);
+        let mut var_uuid = <String>::sse_decode(deserializer);
+        let mut var_originalSize = <u64>::sse_decode(deserializer);
+        let mut var_shred =
+            <Option<crate::api::crypt_api::types::FrbShredReport>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbEncryptResult {
+            output_path: var_outputPath,
+            volumes: var_volumes,
+            uuid: var_uuid,
+            original_size: var_originalSize,
+            shred: var_shred,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbFecParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dataShards = <u8>::sse_decode(deserializer);
+        let mut var_parityShards = <u8>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbFecParams {
+            data_shards: var_dataShards,
+            parity_shards: var_parityShards,
+        };
+    }
+}
+
+impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_packKey = <String>::sse_decode(deserializer);
+        let mut var_packName = <String>::sse_decode(deserializer);
+        let mut var_iconKey = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_relativePath = <String>::sse_decode(deserializer);
+        let mut var_svgPath = <String>::sse_decode(deserializer);
+        let mut var_importedAtMillis = <i64>::sse_decode(deserializer);
+        return crate::api::icon_pack_catalog_api::types::FrbIconPackEntry {
+            key: var_key,
+            pack_key: var_packKey,
+            pack_name: var_packName,
+            icon_key: var_iconKey,
+            name: var_name,
+            relative_path: var_relativePath,
+            svg_path: var_svgPath,
+            imported_at_millis: var_importedAtMillis,
+        };
+    }
+}
+
+impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_code = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::icon_pack_catalog_api::types::FrbIconPackError {
+            code: var_code,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress>::sse_decode(deserializer);
+                return crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Progress(
+                    var_field0,
+                );
+            }
+            1 => {
+                let mut var_field0 =
+                    <crate::api::icon_pack_catalog_api::types::FrbIconPackSummary>::sse_decode(
+                        deserializer,
+                    );
+                return crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Done(
+                    var_field0,
+                );
+            }
+            2 => {
+                let mut var_field0 =
+                    <crate::api::icon_pack_catalog_api::types::FrbIconPackError>::sse_decode(
+                        deserializer,
+                    );
+                return crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Error(
+                    var_field0,
+                );
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_current = <i32>::sse_decode(deserializer);
+        let mut var_total = <i32>::sse_decode(deserializer);
+        let mut var_currentFile = <String>::sse_decode(deserializer);
+        return crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress {
+            current: var_current,
+            total: var_total,
+            current_file: var_currentFile,
+        };
+    }
+}
+
+impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_packKey = <String>::sse_decode(deserializer);
+        let mut var_displayName = <String>::sse_decode(deserializer);
+        let mut var_sourceArchiveName = <String>::sse_decode(deserializer);
+        let mut var_importedAtMillis = <i64>::sse_decode(deserializer);
+        let mut var_iconCount = <i32>::sse_decode(deserializer);
+        return crate::api::icon_pack_catalog_api::types::FrbIconPackSummary {
+            pack_key: var_packKey,
+            display_name: var_displayName,
+            source_archive_name: var_sourceArchiveName,
+            imported_at_millis: var_importedAtMillis,
+            icon_count: var_iconCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbInspectResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_version = <u16>::sse_decode(deserializer);
+        let mut var_argon2Params =
+            <crate::api::crypt_api::types::FrbArgon2Params>::sse_decode(deserializer);
+        let mut var_chunkSize = <u32>::sse_decode(deserializer);
+        let mut var_fec =
+            <Option<crate::api::crypt_api::types::FrbFecParams>>::sse_decode(deserializer);
+        let mut var_signature =
+            <Option<crate::api::crypt_api::types::FrbSignatureAlgorithm>>::sse_decode(deserializer);
+        let mut var_cipher =
+            <crate::api::crypt_api::types::FrbCipherSuite>::sse_decode(deserializer);
+        let mut var_keyCommitment = <bool>::sse_decode(deserializer);
+        let mut var_fileLen = <u64>::sse_decode(deserializer);
+        let mut var_ciphertextLen = <u64>::sse_decode(deserializer);
+        let mut var_chunkCount = <u64>::sse_decode(deserializer);
+        let mut var_estimatedPlaintextSize = <u64>::sse_decode(deserializer);
+        let mut var_wholeChunks = <bool>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbInspectResult {
+            version: var_version,
+            argon2_params: var_argon2Params,
+            chunk_size: var_chunkSize,
+            fec: var_fec,
+            signature: var_signature,
+            cipher: var_cipher,
+            key_commitment: var_keyCommitment,
+            file_len: var_fileLen,
+            ciphertext_len: var_ciphertextLen,
+            chunk_count: var_chunkCount,
+            estimated_plaintext_size: var_estimatedPlaintextSize,
+            whole_chunks: var_wholeChunks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassAttachment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_protected = <bool>::sse_decode(deserializer);
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassAttachment {
+            key: var_key,
+            size: var_size,
+            protected: var_protected,
+            data: var_data,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassAutoType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_defaultSequence = <Option<String>>::sse_decode(deserializer);
+        let mut var_dataTransferObfuscation = <Option<bool>>::sse_decode(deserializer);
+        let mut var_associations = <Vec<
+            crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation,
+        >>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassAutoType {
+            enabled: var_enabled,
+            default_sequence: var_defaultSequence,
+            data_transfer_obfuscation: var_dataTransferObfuscation,
+            associations: var_associations,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_window = <String>::sse_decode(deserializer);
+        let mut var_sequence = <String>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation {
+            window: var_window,
+            sequence: var_sequence,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_databaseVersion = <String>::sse_decode(deserializer);
+        let mut var_outerCipher = <String>::sse_decode(deserializer);
+        let mut var_innerCipher = <String>::sse_decode(deserializer);
+        let mut var_compression = <String>::sse_decode(deserializer);
+        let mut var_kdfName = <String>::sse_decode(deserializer);
+        let mut var_kdfDescription = <String>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassConfig {
+            database_version: var_databaseVersion,
+            outer_cipher: var_outerCipher,
+            inner_cipher: var_innerCipher,
+            compression: var_compression,
+            kdf_name: var_kdfName,
+            kdf_description: var_kdfDescription,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassCustomDataItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_valueKind = <String>::sse_decode(deserializer);
+        let mut var_stringValue = <Option<String>>::sse_decode(deserializer);
+        let mut var_binaryValue = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_lastModificationTime = <Option<String>>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassCustomDataItem {
+            key: var_key,
+            value_kind: var_valueKind,
+            string_value: var_stringValue,
+            binary_value: var_binaryValue,
+            last_modification_time: var_lastModificationTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassDatabaseExport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourcePath = <String>::sse_decode(deserializer);
+        let mut var_config =
+            <crate::api::keepass_api::types::FrbKeepassConfig>::sse_decode(deserializer);
+        let mut var_meta =
+            <crate::api::keepass_api::types::FrbKeepassMeta>::sse_decode(deserializer);
+        let mut var_rootGroupUuid = <String>::sse_decode(deserializer);
+        let mut var_groups =
+            <Vec<crate::api::keepass_api::types::FrbKeepassGroup>>::sse_decode(deserializer);
+        let mut var_entries =
+            <Vec<crate::api::keepass_api::types::FrbKeepassEntry>>::sse_decode(deserializer);
+        let mut var_deletedObjects =
+            <Vec<crate::api::keepass_api::types::FrbKeepassDeletedObject>>::sse_decode(
+                deserializer,
+            );
+        return crate::api::keepass_api::types::FrbKeepassDatabaseExport {
+            source_path: var_sourcePath,
+            config: var_config,
+            meta: var_meta,
+            root_group_uuid: var_rootGroupUuid,
+            groups: var_groups,
+            entries: var_entries,
+            deleted_objects: var_deletedObjects,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassDeletedObject {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_uuid = <String>::sse_decode(deserializer);
+        let mut var_deletionTime = <Option<String>>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassDeletedObject {
+            uuid: var_uuid,
+            deletion_time: var_deletionTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_uuid = <String>::sse_decode(deserializer);
+        let mut var_groupUuid = <String>::sse_decode(deserializer);
+        let mut var_groupPath = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_username = <Option<String>>::sse_decode(deserializer);
+        let mut var_password = <Option<String>>::sse_decode(deserializer);
+        let mut var_url = <Option<String>>::sse_decode(deserializer);
+        let mut var_notes = <Option<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_fields =
+            <Vec<crate::api::keepass_api::types::FrbKeepassField>>::sse_decode(deserializer);
+        let mut var_times =
+            <crate::api::keepass_api::types::FrbKeepassTimes>::sse_decode(deserializer);
+        let mut var_customData =
+            <Vec<crate::api::keepass_api::types::FrbKeepassCustomDataItem>>::sse_decode(
+                deserializer,
+            );
+        let mut var_iconId = <Option<u32>>::sse_decode(deserializer);
+        let mut var_customIconUuid = <Option<String>>::sse_decode(deserializer);
+        let mut var_customIconData = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_foregroundColor = <Option<String>>::sse_decode(deserializer);
+        let mut var_backgroundColor = <Option<String>>::sse_decode(deserializer);
+        let mut var_overrideUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_qualityCheck = <Option<bool>>::sse_decode(deserializer);
+        let mut var_attachments =
+            <Vec<crate::api::keepass_api::types::FrbKeepassAttachment>>::sse_decode(deserializer);
+        let mut var_autotype =
+            <Option<crate::api::keepass_api::types::FrbKeepassAutoType>>::sse_decode(deserializer);
+        let mut var_otp =
+            <Option<crate::api::keepass_api::types::FrbKeepassOtp>>::sse_decode(deserializer);
+        let mut var_history =
+            <Vec<crate::api::keepass_api::types::FrbKeepassHistoryEntry>>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassEntry {
+            uuid: var_uuid,
+            group_uuid: var_groupUuid,
+            group_path: var_groupPath,
+            title: var_title,
+            username: var_username,
+            password: var_password,
+            url: var_url,
+            notes: var_notes,
+            tags: var_tags,
+            fields: var_fields,
+            times: var_times,
+            custom_data: var_customData,
+            icon_id: var_iconId,
+            custom_icon_uuid: var_customIconUuid,
+            custom_icon_data: var_customIconData,
+            foreground_color: var_foregroundColor,
+            background_color: var_backgroundColor,
+            override_url: var_overrideUrl,
+            quality_check: var_qualityCheck,
+            attachments: var_attachments,
+            autotype: var_autotype,
+            otp: var_otp,
+            history: var_history,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassExportOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_inputPath = <String>::sse_decode(deserializer);
+        let mut var_password = <Option<String>>::sse_decode(deserializer);
+        let mut var_keyfilePath = <Option<String>>::sse_decode(deserializer);
+        let mut var_includeHistory = <bool>::sse_decode(deserializer);
+        let mut var_includeAttachments = <bool>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassExportOptions {
+            input_path: var_inputPath,
+            password: var_password,
+            keyfile_path: var_keyfilePath,
+            include_history: var_includeHistory,
+            include_attachments: var_includeAttachments,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        let mut var_protected = <bool>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassField {
+            key: var_key,
+            value: var_value,
+            protected: var_protected,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_uuid = <String>::sse_decode(deserializer);
+        let mut var_parentUuid = <Option<String>>::sse_decode(deserializer);
+        let mut var_isRoot = <bool>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_notes = <Option<String>>::sse_decode(deserializer);
+        let mut var_iconId = <Option<u32>>::sse_decode(deserializer);
+        let mut var_customIconUuid = <Option<String>>::sse_decode(deserializer);
+        let mut var_times =
+            <crate::api::keepass_api::types::FrbKeepassTimes>::sse_decode(deserializer);
+        let mut var_customData =
+            <Vec<crate::api::keepass_api::types::FrbKeepassCustomDataItem>>::sse_decode(
+                deserializer,
+            );
+        let mut var_isExpanded = <bool>::sse_decode(deserializer);
+        let mut var_defaultAutotypeSequence = <Option<String>>::sse_decode(deserializer);
+        let mut var_enableAutotype = <Option<bool>>::sse_decode(deserializer);
+        let mut var_enableSearching = <Option<bool>>::sse_decode(deserializer);
+        let mut var_lastTopVisibleEntry = <Option<String>>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassGroup {
+            uuid: var_uuid,
+            parent_uuid: var_parentUuid,
+            is_root: var_isRoot,
+            path: var_path,
+            name: var_name,
+            notes: var_notes,
+            icon_id: var_iconId,
+            custom_icon_uuid: var_customIconUuid,
+            times: var_times,
+            custom_data: var_customData,
+            is_expanded: var_isExpanded,
+            default_autotype_sequence: var_defaultAutotypeSequence,
+            enable_autotype: var_enableAutotype,
+            enable_searching: var_enableSearching,
+            last_top_visible_entry: var_lastTopVisibleEntry,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassHistoryEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_uuid = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_username = <Option<String>>::sse_decode(deserializer);
+        let mut var_password = <Option<String>>::sse_decode(deserializer);
+        let mut var_url = <Option<String>>::sse_decode(deserializer);
+        let mut var_notes = <Option<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_fields =
+            <Vec<crate::api::keepass_api::types::FrbKeepassField>>::sse_decode(deserializer);
+        let mut var_times =
+            <crate::api::keepass_api::types::FrbKeepassTimes>::sse_decode(deserializer);
+        let mut var_customData =
+            <Vec<crate::api::keepass_api::types::FrbKeepassCustomDataItem>>::sse_decode(
+                deserializer,
+            );
+        let mut var_iconId = <Option<u32>>::sse_decode(deserializer);
+        let mut var_customIconUuid = <Option<String>>::sse_decode(deserializer);
+        let mut var_customIconData = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_foregroundColor = <Option<String>>::sse_decode(deserializer);
+        let mut var_backgroundColor = <Option<String>>::sse_decode(deserializer);
+        let mut var_overrideUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_qualityCheck = <Option<bool>>::sse_decode(deserializer);
+        let mut var_attachments =
+            <Vec<crate::api::keepass_api::types::FrbKeepassAttachment>>::sse_decode(deserializer);
+        let mut var_autotype =
+            <Option<crate::api::keepass_api::types::FrbKeepassAutoType>>::sse_decode(deserializer);
+        let mut var_otp =
+            <Option<crate::api::keepass_api::types::FrbKeepassOtp>>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassHistoryEntry {
+            uuid: var_uuid,
+            title: var_title,
+            username: var_username,
+            password: var_password,
+            url: var_url,
+            notes: var_notes,
+            tags: var_tags,
+            fields: var_fields,
+            times: var_times,
+            custom_data: var_customData,
+            icon_id: var_iconId,
+            custom_icon_uuid: var_customIconUuid,
+            custom_icon_data: var_customIconData,
+            foreground_color: var_foregroundColor,
+            background_color: var_backgroundColor,
+            override_url: var_overrideUrl,
+            quality_check: var_qualityCheck,
+            attachments: var_attachments,
+            autotype: var_autotype,
+            otp: var_otp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassMemoryProtection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_protectTitle = <bool>::sse_decode(deserializer);
+        let mut var_protectUsername = <bool>::sse_decode(deserializer);
+        let mut var_protectPassword = <bool>::sse_decode(deserializer);
+        let mut var_protectUrl = <bool>::sse_decode(deserializer);
+        let mut var_protectNotes = <bool>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassMemoryProtection {
+            protect_title: var_protectTitle,
+            protect_username: var_protectUsername,
+            protect_password: var_protectPassword,
+            protect_url: var_protectUrl,
+            protect_notes: var_protectNotes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassMeta {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_generator = <Option<String>>::sse_decode(deserializer);
+        let mut var_databaseName = <Option<String>>::sse_decode(deserializer);
+        let mut var_databaseNameChanged = <Option<String>>::sse_decode(deserializer);
+        let mut var_databaseDescription = <Option<String>>::sse_decode(deserializer);
+        let mut var_databaseDescriptionChanged = <Option<String>>::sse_decode(deserializer);
+        let mut var_defaultUsername = <Option<String>>::sse_decode(deserializer);
+        let mut var_defaultUsernameChanged = <Option<String>>::sse_decode(deserializer);
+        let mut var_maintenanceHistoryDays = <Option<u32>>::sse_decode(deserializer);
+        let mut var_color = <Option<String>>::sse_decode(deserializer);
+        let mut var_masterKeyChanged = <Option<String>>::sse_decode(deserializer);
+        let mut var_masterKeyChangeRec = <Option<i32>>::sse_decode(deserializer);
+        let mut var_masterKeyChangeForce = <Option<i32>>::sse_decode(deserializer);
+        let mut var_memoryProtection = <Option<
+            crate::api::keepass_api::types::FrbKeepassMemoryProtection,
+        >>::sse_decode(deserializer);
+        let mut var_recyclebinEnabled = <Option<bool>>::sse_decode(deserializer);
+        let mut var_recyclebinUuid = <Option<String>>::sse_decode(deserializer);
+        let mut var_recyclebinChanged = <Option<String>>::sse_decode(deserializer);
+        let mut var_entryTemplatesGroup = <Option<String>>::sse_decode(deserializer);
+        let mut var_entryTemplatesGroupChanged = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastSelectedGroup = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastTopVisibleGroup = <Option<String>>::sse_decode(deserializer);
+        let mut var_historyMaxItems = <Option<i32>>::sse_decode(deserializer);
+        let mut var_historyMaxSize = <Option<i32>>::sse_decode(deserializer);
+        let mut var_settingsChanged = <Option<String>>::sse_decode(deserializer);
+        let mut var_customData =
+            <Vec<crate::api::keepass_api::types::FrbKeepassCustomDataItem>>::sse_decode(
+                deserializer,
+            );
+        return crate::api::keepass_api::types::FrbKeepassMeta {
+            generator: var_generator,
+            database_name: var_databaseName,
+            database_name_changed: var_databaseNameChanged,
+            database_description: var_databaseDescription,
+            database_description_changed: var_databaseDescriptionChanged,
+            default_username: var_defaultUsername,
+            default_username_changed: var_defaultUsernameChanged,
+            maintenance_history_days: var_maintenanceHistoryDays,
+            color: var_color,
+            master_key_changed: var_masterKeyChanged,
+            master_key_change_rec: var_masterKeyChangeRec,
+            master_key_change_force: var_masterKeyChangeForce,
+            memory_protection: var_memoryProtection,
+            recyclebin_enabled: var_recyclebinEnabled,
+            recyclebin_uuid: var_recyclebinUuid,
+            recyclebin_changed: var_recyclebinChanged,
+            entry_templates_group: var_entryTemplatesGroup,
+            entry_templates_group_changed: var_entryTemplatesGroupChanged,
+            last_selected_group: var_lastSelectedGroup,
+            last_top_visible_group: var_lastTopVisibleGroup,
+            history_max_items: var_historyMaxItems,
+            history_max_size: var_historyMaxSize,
+            settings_changed: var_settingsChanged,
+            custom_data: var_customData,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassOtp {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rawValue = <String>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_issuer = <Option<String>>::sse_decode(deserializer);
+        let mut var_secret = <Option<String>>::sse_decode(deserializer);
+        let mut var_period = <Option<u64>>::sse_decode(deserializer);
+        let mut var_digits = <Option<u32>>::sse_decode(deserializer);
+        let mut var_algorithm = <Option<String>>::sse_decode(deserializer);
+        let mut var_parseError = <Option<String>>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassOtp {
+            raw_value: var_rawValue,
+            label: var_label,
+            issuer: var_issuer,
+            secret: var_secret,
+            period: var_period,
+            digits: var_digits,
+            algorithm: var_algorithm,
+            parse_error: var_parseError,
+        };
+    }
+}
+
+impl SseDecode for crate::api::keepass_api::types::FrbKeepassTimes {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_creation = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastModification = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastAccess = <Option<String>>::sse_decode(deserializer);
+        let mut var_expiry = <Option<String>>::sse_decode(deserializer);
+        let mut var_locationChanged = <Option<String>>::sse_decode(deserializer);
+        let mut var_expires = <Option<bool>>::sse_decode(deserializer);
+        let mut var_usageCount = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::keepass_api::types::FrbKeepassTimes {
+            creation: var_creation,
+            last_modification: var_lastModification,
+            last_access: var_lastAccess,
+            expiry: var_expiry,
+            location_changed: var_locationChanged,
+            expires: var_expires,
+            usage_count: var_usageCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbKeyValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbKeyValue {
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbMediaServerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_token = <String>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbMediaServerInfo {
+            port: var_port,
+            token: var_token,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbMediaSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceId = <String>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_metadata =
+            <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbMediaSource {
+            source_id: var_sourceId,
+            url: var_url,
+            size: var_size,
+            mime_type: var_mimeType,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbMetadataPatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_remove = <Vec<String>>::sse_decode(deserializer);
+        let mut var_set_ =
+            <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbMetadataPatch {
+            remove: var_remove,
+            set: var_set_,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbMetadataUpdateResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_metadata =
+            <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_decode(deserializer);
+        let mut var_inPlace = <bool>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbMetadataUpdateResult {
+            metadata: var_metadata,
+            in_place: var_inPlace,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbProgressEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stage =
+            <crate::api::crypt_api::types::FrbProgressStage>::sse_decode(deserializer);
+        let mut var_bytesProcessed = <u64>::sse_decode(deserializer);
+        let mut var_totalBytes = <u64>::sse_decode(deserializer);
+        let mut var_percentage = <f64>::sse_decode(deserializer);
+        let mut var_filesProcessed = <u64>::sse_decode(deserializer);
+        let mut var_totalFiles = <u64>::sse_decode(deserializer);
+        let mut var_bytesPerSecond = <f64>::sse_decode(deserializer);
+        let mut var_etaSeconds = <Option<f64>>::sse_decode(deserializer);
+        let mut var_overallPercentage = <f64>::sse_decode(deserializer);
+        let mut var_stageIndex = <u32>::sse_decode(deserializer);
+        let mut var_stageCount = <u32>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbProgressEvent {
+            stage: var_stage,
+            bytes_processed: var_bytesProcessed,
+            total_bytes: var_totalBytes,
+            percentage: var_percentage,
+            files_processed: var_filesProcessed,
+            total_files: var_totalFiles,
+            bytes_per_second: var_bytesPerSecond,
+            eta_seconds: var_etaSeconds,
+            overall_percentage: var_overallPercentage,
+            stage_index: var_stageIndex,
+            stage_count: var_stageCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbProgressStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::crypt_api::types::FrbProgressStage::CompressingDirectory,
+            1 => crate::api::crypt_api::types::FrbProgressStage::CompressingGzip,
+            2 => crate::api::crypt_api::types::FrbProgressStage::Encrypting,
+            3 => crate::api::crypt_api::types::FrbProgressStage::Decrypting,
+            4 => crate::api::crypt_api::types::FrbProgressStage::DecompressingGzip,
+            5 => crate::api::crypt_api::types::FrbProgressStage::DecompressingDirectory,
+            6 => crate::api::crypt_api::types::FrbProgressStage::Done,
+            _ => unreachable!("Invalid variant for FrbProgressStage: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbReencryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outputPath = <String>::sse_decode(deserializer);
+        let mut var_chunkCount = <u64>::sse_decode(deserializer);
+        let mut var_sourceSignature =
+            <crate::api::crypt_api::types::FrbSignatureStatus>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbReencryptResult {
+            output_path: var_outputPath,
+            chunk_count: var_chunkCount,
+            source_signature: var_sourceSignature,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbShredEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbShredEntry {
+            path: var_path,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbShredReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_entries =
+            <Vec<crate::api::crypt_api::types::FrbShredEntry>>::sse_decode(deserializer);
+        let mut var_allShredded = <bool>::sse_decode(deserializer);
+        let mut var_caveat = <String>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbShredReport {
+            entries: var_entries,
+            all_shredded: var_allShredded,
+            caveat: var_caveat,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbSignatureAlgorithm {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::crypt_api::types::FrbSignatureAlgorithm::Ed25519,
+            _ => unreachable!("Invalid variant for FrbSignatureAlgorithm: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbSignatureStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::crypt_api::types::FrbSignatureStatus::Unsigned;
+            }
+            1 => {
+                let mut var_publicKey = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::crypt_api::types::FrbSignatureStatus::Valid {
+                    public_key: var_publicKey,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbSigningIdentity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_secretKey = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_publicKey = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbSigningIdentity {
+            secret_key: var_secretKey,
+            public_key: var_publicKey,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbSpacePlan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outputBytes = <u64>::sse_decode(deserializer);
+        let mut var_outputFree = <Option<u64>>::sse_decode(deserializer);
+        let mut var_tempBytes = <u64>::sse_decode(deserializer);
+        let mut var_tempFree = <Option<u64>>::sse_decode(deserializer);
+        let mut var_sharedVolume = <bool>::sse_decode(deserializer);
+        let mut var_estimated = <bool>::sse_decode(deserializer);
+        let mut var_shortfall =
+            <Option<crate::api::crypt_api::types::FrbSpaceShortfall>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbSpacePlan {
+            output_bytes: var_outputBytes,
+            output_free: var_outputFree,
+            temp_bytes: var_tempBytes,
+            temp_free: var_tempFree,
+            shared_volume: var_sharedVolume,
+            estimated: var_estimated,
+            shortfall: var_shortfall,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbSpaceShortfall {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_needed = <u64>::sse_decode(deserializer);
+        let mut var_available = <u64>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbSpaceShortfall {
+            path: var_path,
+            needed: var_needed,
+            available: var_available,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbStreamDecryptOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_streamId = <String>::sse_decode(deserializer);
+        let mut var_inputPath = <String>::sse_decode(deserializer);
+        let mut var_password = <String>::sse_decode(deserializer);
+        let mut var_window = <u32>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbStreamDecryptOptions {
+            stream_id: var_streamId,
+            input_path: var_inputPath,
+            password: var_password,
+            window: var_window,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbStreamDecryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bytesWritten = <u64>::sse_decode(deserializer);
+        let mut var_metadata =
+            <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_decode(deserializer);
+        let mut var_signature =
+            <crate::api::crypt_api::types::FrbSignatureStatus>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbStreamDecryptResult {
+            bytes_written: var_bytesWritten,
+            metadata: var_metadata,
+            signature: var_signature,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbStreamEncryptOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_streamId = <String>::sse_decode(deserializer);
+        let mut var_outputPath = <String>::sse_decode(deserializer);
+        let mut var_password = <String>::sse_decode(deserializer);
+        let mut var_originalFilename = <String>::sse_decode(deserializer);
+        let mut var_originalExtension = <String>::sse_decode(deserializer);
+        let mut var_gzipCompressed = <bool>::sse_decode(deserializer);
+        let mut var_metadata =
+            <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_decode(deserializer);
+        let mut var_chunkSize =
+            <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_decode(deserializer);
+        let mut var_cipherSuite =
+            <crate::api::crypt_api::types::FrbCipherSuite>::sse_decode(deserializer);
+        let mut var_signingKey = <Option<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbStreamEncryptOptions {
+            stream_id: var_streamId,
+            output_path: var_outputPath,
+            password: var_password,
+            original_filename: var_originalFilename,
+            original_extension: var_originalExtension,
+            gzip_compressed: var_gzipCompressed,
+            metadata: var_metadata,
+            chunk_size: var_chunkSize,
+            cipher_suite: var_cipherSuite,
+            signing_key: var_signingKey,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbStreamEncryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outputPath = <String>::sse_decode(deserializer);
+        let mut var_uuid = <String>::sse_decode(deserializer);
+        let mut var_bytesRead = <u64>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbStreamEncryptResult {
+            output_path: var_outputPath,
+            uuid: var_uuid,
+            bytes_read: var_bytesRead,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crypt_api::types::FrbVerifyResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_metadata =
+            <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_decode(deserializer);
+        let mut var_chunkCount = <u64>::sse_decode(deserializer);
+        let mut var_repairedChunks = <u64>::sse_decode(deserializer);
+        let mut var_signature =
+            <crate::api::crypt_api::types::FrbSignatureStatus>::sse_decode(deserializer);
+        return crate::api::crypt_api::types::FrbVerifyResult {
+            metadata: var_metadata,
+            chunk_count: var_chunkCount,
+            repaired_chunks: var_repairedChunks,
+            signature: var_signature,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::crypt_api::types::FrbBatchError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::crypt_api::types::FrbBatchError>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::crypt_api::types::FrbDecryptResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::crypt_api::types::FrbDecryptResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::crypt_api::types::FrbEncryptResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::crypt_api::types::FrbEncryptResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::icon_pack_catalog_api::types::FrbIconPackEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::icon_pack_catalog_api::types::FrbIconPackEntry>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::icon_pack_catalog_api::types::FrbIconPackSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::icon_pack_catalog_api::types::FrbIconPackSummary>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassAttachment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::keepass_api::types::FrbKeepassAttachment>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassCustomDataItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::keepass_api::types::FrbKeepassCustomDataItem>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassDeletedObject> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::keepass_api::types::FrbKeepassDeletedObject>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::keepass_api::types::FrbKeepassEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::keepass_api::types::FrbKeepassField>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::keepass_api::types::FrbKeepassGroup>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassHistoryEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::keepass_api::types::FrbKeepassHistoryEntry>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::crypt_api::types::FrbKeyValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::crypt_api::types::FrbKeyValue>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::crypt_api::types::FrbReencryptResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::crypt_api::types::FrbReencryptResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::crypt_api::types::FrbShredEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::crypt_api::types::FrbShredEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::logging::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timeMillis = <i64>::sse_decode(deserializer);
+        let mut var_level = <i32>::sse_decode(deserializer);
+        let mut var_tag = <String>::sse_decode(deserializer);
+        let mut var_msg = <String>::sse_decode(deserializer);
+        return crate::api::logging::LogEntry {
+            time_millis: var_timeMillis,
+            level: var_level,
+            tag: var_tag,
+            msg: var_msg,
+        };
     }
 }
 
-impl SseDecode
-    for StreamSink<
-        crate::api::crypt_api::types::FrbEncryptEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode
-    for StreamSink<
-        crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseDecode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode
-    for StreamSink<crate::api::logging::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseDecode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for String {
+impl SseDecode for Option<crate::api::crypt_api::types::FrbArgon2Params> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::crypt_api::types::FrbArgon2Params>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for bool {
+impl SseDecode for Option<crate::api::crypt_api::types::FrbChunkSizePreset> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for f64 {
+impl SseDecode for Option<crate::api::crypt_api::types::FrbFecParams> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::crypt_api::types::FrbFecParams>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbBatchDecryptEvent {
+impl SseDecode for Option<crate::api::keepass_api::types::FrbKeepassAutoType> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_fileIndex = <u32>::sse_decode(deserializer);
-                let mut var_totalFiles = <u32>::sse_decode(deserializer);
-                let mut var_currentFile = <String>::sse_decode(deserializer);
-                let mut var_progress =
-                    <crate::api::crypt_api::types::FrbProgressEvent>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbBatchDecryptEvent::FileProgress {
-                    file_index: var_fileIndex,
-                    total_files: var_totalFiles,
-                    current_file: var_currentFile,
-                    progress: var_progress,
-                };
-            }
-            1 => {
-                let mut var_fileIndex = <u32>::sse_decode(deserializer);
-                let mut var_result =
-                    <crate::api::crypt_api::types::FrbDecryptResult>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbBatchDecryptEvent::FileDone {
-                    file_index: var_fileIndex,
-                    result: var_result,
-                };
-            }
-            2 => {
-                let mut var_fileIndex = <u32>::sse_decode(deserializer);
-                let mut var_inputPath = <String>::sse_decode(deserializer);
-                let mut var_error = <String>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbBatchDecryptEvent::FileError {
-                    file_index: var_fileIndex,
-                    input_path: var_inputPath,
-                    error: var_error,
-                };
-            }
-            3 => {
-                let mut var_field0 =
-                    <crate::api::crypt_api::types::FrbBatchDecryptResult>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbBatchDecryptEvent::AllDone(var_field0);
-            }
-            _ => {
-                unimplemented!("");
-            }
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::keepass_api::types::FrbKeepassAutoType>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
         }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbBatchDecryptOptions {
+impl SseDecode for Option<crate::api::keepass_api::types::FrbKeepassMemoryProtection> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_inputPaths = <Vec<String>>::sse_decode(deserializer);
-        let mut var_outputDir = <String>::sse_decode(deserializer);
-        let mut var_password = <String>::sse_decode(deserializer);
-        let mut var_tempDir = <Option<String>>::sse_decode(deserializer);
-        let mut var_chunkSize =
-            <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbBatchDecryptOptions {
-            input_paths: var_inputPaths,
-            output_dir: var_outputDir,
-            password: var_password,
-            temp_dir: var_tempDir,
-            chunk_size: var_chunkSize,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::keepass_api::types::FrbKeepassMemoryProtection>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbBatchDecryptResult {
+impl SseDecode for Option<crate::api::keepass_api::types::FrbKeepassOtp> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_succeeded =
-            <Vec<crate::api::crypt_api::types::FrbDecryptResult>>::sse_decode(deserializer);
-        let mut var_failed =
-            <Vec<crate::api::crypt_api::types::FrbBatchError>>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbBatchDecryptResult {
-            succeeded: var_succeeded,
-            failed: var_failed,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::keepass_api::types::FrbKeepassOtp>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbBatchEncryptEvent {
+impl SseDecode for Option<crate::api::crypt_api::types::FrbShredReport> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_fileIndex = <u32>::sse_decode(deserializer);
-                let mut var_totalFiles = <u32>::sse_decode(deserializer);
-                let mut var_currentFile = <String>::sse_decode(deserializer);
-                let mut var_progress =
-                    <crate::api::crypt_api::types::FrbProgressEvent>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbBatchEncryptEvent::FileProgress {
-                    file_index: var_fileIndex,
-                    total_files: var_totalFiles,
-                    current_file: var_currentFile,
-                    progress: var_progress,
-                };
-            }
-            1 => {
-                let mut var_fileIndex = <u32>::sse_decode(deserializer);
-                let mut var_result =
-                    <crate::api::crypt_api::types::FrbEncryptResult>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbBatchEncryptEvent::FileDone {
-                    file_index: var_fileIndex,
-                    result: var_result,
-                };
-            }
-            2 => {
-                let mut var_fileIndex = <u32>::sse_decode(deserializer);
-                let mut var_inputPath = <String>::sse_decode(deserializer);
-                let mut var_error = <String>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbBatchEncryptEvent::FileError {
-                    file_index: var_fileIndex,
-                    input_path: var_inputPath,
-                    error: var_error,
-                };
-            }
-            3 => {
-                let mut var_field0 =
-                    <crate::api::crypt_api::types::FrbBatchEncryptResult>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbBatchEncryptEvent::AllDone(var_field0);
-            }
-            _ => {
-                unimplemented!("");
-            }
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::crypt_api::types::FrbShredReport>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
         }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbBatchEncryptOptions {
+impl SseDecode for Option<crate::api::crypt_api::types::FrbSignatureAlgorithm> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_inputPaths = <Vec<String>>::sse_decode(deserializer);
-        let mut var_outputDir = <String>::sse_decode(deserializer);
-        let mut var_password = <String>::sse_decode(deserializer);
-        let mut var_gzipCompressed = <bool>::sse_decode(deserializer);
-        let mut var_tempDir = <Option<String>>::sse_decode(deserializer);
-        let mut var_metadata =
-            <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_decode(deserializer);
-        let mut var_chunkSize =
-            <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbBatchEncryptOptions {
-            input_paths: var_inputPaths,
-            output_dir: var_outputDir,
-            password: var_password,
-            gzip_compressed: var_gzipCompressed,
-            temp_dir: var_tempDir,
-            metadata: var_metadata,
-            chunk_size: var_chunkSize,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::crypt_api::types::FrbSignatureAlgorithm>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbBatchEncryptResult {
+impl SseDecode for Option<crate::api::crypt_api::types::FrbSpaceShortfall> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_succeeded =
-            <Vec<crate::api::crypt_api::types::FrbEncryptResult>>::sse_decode(deserializer);
-        let mut var_failed =
-            <Vec<crate::api::crypt_api::types::FrbBatchError>>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbBatchEncryptResult {
-            succeeded: var_succeeded,
-            failed: var_failed,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::crypt_api::types::FrbSpaceShortfall>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbBatchError {
+impl SseDecode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_inputPath = <String>::sse_decode(deserializer);
-        let mut var_error = <String>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbBatchError {
-            input_path: var_inputPath,
-            error: var_error,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbChunkSizePreset {
+impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                return crate::api::crypt_api::types::FrbChunkSizePreset::Desktop;
-            }
-            1 => {
-                return crate::api::crypt_api::types::FrbChunkSizePreset::Mobile;
-            }
-            2 => {
-                let mut var_field0 = <u32>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbChunkSizePreset::Custom(var_field0);
-            }
-            _ => {
-                unimplemented!("");
-            }
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
         }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbDecryptEvent {
+impl SseDecode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 =
-                    <crate::api::crypt_api::types::FrbProgressEvent>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbDecryptEvent::Progress(var_field0);
-            }
-            1 => {
-                let mut var_field0 =
-                    <crate::api::crypt_api::types::FrbDecryptResult>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbDecryptEvent::Done(var_field0);
-            }
-            2 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbDecryptEvent::Error(var_field0);
-            }
-            _ => {
-                unimplemented!("");
-            }
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
         }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbDecryptOptions {
+impl SseDecode for Option<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_inputPath = <String>::sse_decode(deserializer);
-        let mut var_outputDir = <String>::sse_decode(deserializer);
-        let mut var_password = <String>::sse_decode(deserializer);
-        let mut var_tempDir = <Option<String>>::sse_decode(deserializer);
-        let mut var_chunkSize =
-            <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbDecryptOptions {
-            input_path: var_inputPath,
-            output_dir: var_outputDir,
-            password: var_password,
-            temp_dir: var_tempDir,
-            chunk_size: var_chunkSize,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbDecryptResult {
+impl SseDecode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_outputPath = <String>::sse_decode(deserializer);
-        let mut var_metadata =
-            <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbDecryptResult {
-            output_path: var_outputPath,
-            metadata: var_metadata,
-        };
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbDecryptedMetadata {
+impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_originalFilename = <String>::sse_decode(deserializer);
-        let mut var_originalExtension = <String>::sse_decode(deserializer);
-        let mut var_gzipCompressed = <bool>::sse_decode(deserializer);
-        let mut var_originalSize = <u64>::sse_decode(deserializer);
-        let mut var_uuid = <String>::sse_decode(deserializer);
-        let mut var_metadata =
-            <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbDecryptedMetadata {
-            original_filename: var_originalFilename,
-            original_extension: var_originalExtension,
-            gzip_compressed: var_gzipCompressed,
-            original_size: var_originalSize,
-            uuid: var_uuid,
-            metadata: var_metadata,
-        };
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbEncryptEvent {
+impl SseDecode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 =
-                    <crate::api::crypt_api::types::FrbProgressEvent>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbEncryptEvent::Progress(var_field0);
-            }
-            1 => {
-                let mut var_field0 =
-                    <crate::api::crypt_api::types::FrbEncryptResult>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbEncryptEvent::Done(var_field0);
-            }
-            2 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::crypt_api::types::FrbEncryptEvent::Error(var_field0);
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbEncryptOptions {
+impl SseDecode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_inputPath = <String>::sse_decode(deserializer);
-        let mut var_outputDir = <String>::sse_decode(deserializer);
-        let mut var_password = <String>::sse_decode(deserializer);
-        let mut var_gzipCompressed = <bool>::sse_decode(deserializer);
-        let mut var_uuid = <Option<String>>::sse_decode(deserializer);
-        let mut var_outputExtension = <Option<String>>::sse_decode(deserializer);
-        let mut var_tempDir = <Option<String>>::sse_decode(deserializer);
-        let mut var_metadata =
-            <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_decode(deserializer);
-        let mut var_chunkSize =
-            <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbEncryptOptions {
-            input_path: var_inputPath,
-            output_dir: var_outputDir,
-            password: var_password,
-            gzip_compressed: var_gzipCompressed,
-            uuid: var_uuid,
-            output_extension: var_outputExtension,
-            temp_dir: var_tempDir,
-            metadata: var_metadata,
-            chunk_size: var_chunkSize,
-        };
+        deserializer.cursor.read_u8().unwrap()
     }
 }
 
-impl SseDecode for crate::api::crypt_api::types::FrbEncryptResult {
+impl SseDecode for () {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_outputPath = <String>::sse_decode(deserializer);
-        let mut var_uuid = <String>::sse_decode(deserializer);
-        let mut var_originalSize = <u64>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbEncryptResult {
-            output_path: var_outputPath,
-            uuid: var_uuid,
-            original_size: var_originalSize,
-        };
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__logging__create_log_stream_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__crypt_api__decrypt_batch_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__crypt_api__decrypt_file_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__crypt_api__decrypt_stream_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__crypt_api__decrypt_to_memory_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__crypt_api__decrypted_cache_configure_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__crypt_api__decrypted_cache_purge_all_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__crypt_api__decrypted_cache_purge_expired_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__icon_pack_catalog_api__delete_pack_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__crypt_api__encrypt_batch_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__crypt_api__encrypt_file_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__crypt_api__encrypt_stream_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__crypt_api__encrypt_stream_finish_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        19 => {
+            wire__crate__api__crypt_api__encrypt_stream_push_impl(port, ptr, rust_vec_len, data_len)
+        }
+        20 => wire__crate__api__keepass_api__export_keepass_database_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__crypt_api__types__frb_decrypt_options_simple_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__crypt_api__types__frb_encrypt_options_simple_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__keepass_api__types__frb_keepass_export_options_simple_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => wire__crate__api__icon_pack_catalog_api__import_directory_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__icon_pack_catalog_api__import_pack_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__crypt_api__inspect_encrypted_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => wire__crate__api__logging__install_rust_log_bridge_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__icon_pack_catalog_api__list_icons_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__icon_pack_catalog_api__list_packs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__crypt_api__media_server_add_impl(port, ptr, rust_vec_len, data_len),
+        35 => {
+            wire__crate__api__crypt_api__media_server_start_impl(port, ptr, rust_vec_len, data_len)
+        }
+        39 => wire__crate__api__crypt_api__plan_decrypt_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__crypt_api__plan_encrypt_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__crypt_api__read_encrypted_header_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        42 => wire__crate__api__icon_pack_catalog_api__read_svg_by_key_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__crypt_api__reencrypt_batch_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__logging__rust_log_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__logging__rust_log_debug_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__logging__rust_log_error_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__logging__rust_log_fatal_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__logging__rust_log_info_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__logging__rust_log_warning_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__crypt_api__update_metadata_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__crypt_api__verify_file_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
     }
 }
 
-impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackEntry {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_key = <String>::sse_decode(deserializer);
-        let mut var_packKey = <String>::sse_decode(deserializer);
-        let mut var_packName = <String>::sse_decode(deserializer);
-        let mut var_iconKey = <String>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_relativePath = <String>::sse_decode(deserializer);
-        let mut var_svgPath = <String>::sse_decode(deserializer);
-        let mut var_importedAtMillis = <i64>::sse_decode(deserializer);
-        return crate::api::icon_pack_catalog_api::types::FrbIconPackEntry {
-            key: var_key,
-            pack_key: var_packKey,
-            pack_name: var_packName,
-            icon_key: var_iconKey,
-            name: var_name,
-            relative_path: var_relativePath,
-            svg_path: var_svgPath,
-            imported_at_millis: var_importedAtMillis,
-        };
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        5 => wire__crate__api__crypt_api__decrypt_stream_ack_impl(ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__crypt_api__decrypt_stream_cancel_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__crypt_api__decrypted_cache_stats_impl(ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__crypt_api__decrypted_cache_touch_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__crypt_api__encrypt_stream_abort_impl(ptr, rust_vec_len, data_len),
+        24 => {
+            wire__crate__api__crypt_api__generate_signing_identity_impl(ptr, rust_vec_len, data_len)
+        }
+        25 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__crypt_api__media_server_remove_impl(ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__crypt_api__media_server_stop_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__icon_pack_catalog_api__normalize_icon_path_without_extension_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__icon_pack_catalog_api__normalize_pack_key_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__api__crypt_api__signing_public_key_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
     }
 }
 
-impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackError {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_code = <String>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        return crate::api::icon_pack_catalog_api::types::FrbIconPackError {
-            code: var_code,
-            message: var_message,
-        };
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbArgon2Params {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.t_cost.into_into_dart().into_dart(),
+            self.m_cost_kib.into_into_dart().into_dart(),
+            self.parallelism.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 = <crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress>::sse_decode(deserializer);
-                return crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Progress(
-                    var_field0,
-                );
-            }
-            1 => {
-                let mut var_field0 =
-                    <crate::api::icon_pack_catalog_api::types::FrbIconPackSummary>::sse_decode(
-                        deserializer,
-                    );
-                return crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Done(
-                    var_field0,
-                );
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbArgon2Params
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbArgon2Params>
+    for crate::api::crypt_api::types::FrbArgon2Params
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbArgon2Params {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchDecryptEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::crypt_api::types::FrbBatchDecryptEvent::FileProgress {
+                file_index,
+                total_files,
+                current_file,
+                progress,
+            } => [
+                0.into_dart(),
+                file_index.into_into_dart().into_dart(),
+                total_files.into_into_dart().into_dart(),
+                current_file.into_into_dart().into_dart(),
+                progress.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::crypt_api::types::FrbBatchDecryptEvent::FileDone { file_index, result } => {
+                [
+                    1.into_dart(),
+                    file_index.into_into_dart().into_dart(),
+                    result.into_into_dart().into_dart(),
+                ]
+                .into_dart()
             }
-            2 => {
-                let mut var_field0 =
-                    <crate::api::icon_pack_catalog_api::types::FrbIconPackError>::sse_decode(
-                        deserializer,
-                    );
-                return crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Error(
-                    var_field0,
-                );
+            crate::api::crypt_api::types::FrbBatchDecryptEvent::FileError {
+                file_index,
+                input_path,
+                error,
+            } => [
+                2.into_dart(),
+                file_index.into_into_dart().into_dart(),
+                input_path.into_into_dart().into_dart(),
+                error.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::crypt_api::types::FrbBatchDecryptEvent::AllDone(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
             }
             _ => {
                 unimplemented!("");
@@ -1632,1069 +4370,688 @@ impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackImportEv
         }
     }
 }
-
-impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_current = <i32>::sse_decode(deserializer);
-        let mut var_total = <i32>::sse_decode(deserializer);
-        let mut var_currentFile = <String>::sse_decode(deserializer);
-        return crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress {
-            current: var_current,
-            total: var_total,
-            current_file: var_currentFile,
-        };
-    }
-}
-
-impl SseDecode for crate::api::icon_pack_catalog_api::types::FrbIconPackSummary {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_packKey = <String>::sse_decode(deserializer);
-        let mut var_displayName = <String>::sse_decode(deserializer);
-        let mut var_sourceArchiveName = <String>::sse_decode(deserializer);
-        let mut var_importedAtMillis = <i64>::sse_decode(deserializer);
-        let mut var_iconCount = <i32>::sse_decode(deserializer);
-        return crate::api::icon_pack_catalog_api::types::FrbIconPackSummary {
-            pack_key: var_packKey,
-            display_name: var_displayName,
-            source_archive_name: var_sourceArchiveName,
-            imported_at_millis: var_importedAtMillis,
-            icon_count: var_iconCount,
-        };
-    }
-}
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassAttachment {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_key = <String>::sse_decode(deserializer);
-        let mut var_size = <u64>::sse_decode(deserializer);
-        let mut var_protected = <bool>::sse_decode(deserializer);
-        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassAttachment {
-            key: var_key,
-            size: var_size,
-            protected: var_protected,
-            data: var_data,
-        };
-    }
-}
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassAutoType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_enabled = <bool>::sse_decode(deserializer);
-        let mut var_defaultSequence = <Option<String>>::sse_decode(deserializer);
-        let mut var_dataTransferObfuscation = <Option<bool>>::sse_decode(deserializer);
-        let mut var_associations = <Vec<
-            crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation,
-        >>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassAutoType {
-            enabled: var_enabled,
-            default_sequence: var_defaultSequence,
-            data_transfer_obfuscation: var_dataTransferObfuscation,
-            associations: var_associations,
-        };
-    }
-}
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_window = <String>::sse_decode(deserializer);
-        let mut var_sequence = <String>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation {
-            window: var_window,
-            sequence: var_sequence,
-        };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbBatchDecryptEvent
+{
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassConfig {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_databaseVersion = <String>::sse_decode(deserializer);
-        let mut var_outerCipher = <String>::sse_decode(deserializer);
-        let mut var_innerCipher = <String>::sse_decode(deserializer);
-        let mut var_compression = <String>::sse_decode(deserializer);
-        let mut var_kdfName = <String>::sse_decode(deserializer);
-        let mut var_kdfDescription = <String>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassConfig {
-            database_version: var_databaseVersion,
-            outer_cipher: var_outerCipher,
-            inner_cipher: var_innerCipher,
-            compression: var_compression,
-            kdf_name: var_kdfName,
-            kdf_description: var_kdfDescription,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchDecryptEvent>
+    for crate::api::crypt_api::types::FrbBatchDecryptEvent
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchDecryptEvent {
+        self
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassCustomDataItem {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_key = <String>::sse_decode(deserializer);
-        let mut var_valueKind = <String>::sse_decode(deserializer);
-        let mut var_stringValue = <Option<String>>::sse_decode(deserializer);
-        let mut var_binaryValue = <Option<Vec<u8>>>::sse_decode(deserializer);
-        let mut var_lastModificationTime = <Option<String>>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassCustomDataItem {
-            key: var_key,
-            value_kind: var_valueKind,
-            string_value: var_stringValue,
-            binary_value: var_binaryValue,
-            last_modification_time: var_lastModificationTime,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchDecryptOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.input_paths.into_into_dart().into_dart(),
+            self.output_dir.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.temp_dir.into_into_dart().into_dart(),
+            self.chunk_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassDatabaseExport {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_sourcePath = <String>::sse_decode(deserializer);
-        let mut var_config =
-            <crate::api::keepass_api::types::FrbKeepassConfig>::sse_decode(deserializer);
-        let mut var_meta =
-            <crate::api::keepass_api::types::FrbKeepassMeta>::sse_decode(deserializer);
-        let mut var_rootGroupUuid = <String>::sse_decode(deserializer);
-        let mut var_groups =
-            <Vec<crate::api::keepass_api::types::FrbKeepassGroup>>::sse_decode(deserializer);
-        let mut var_entries =
-            <Vec<crate::api::keepass_api::types::FrbKeepassEntry>>::sse_decode(deserializer);
-        let mut var_deletedObjects =
-            <Vec<crate::api::keepass_api::types::FrbKeepassDeletedObject>>::sse_decode(
-                deserializer,
-            );
-        return crate::api::keepass_api::types::FrbKeepassDatabaseExport {
-            source_path: var_sourcePath,
-            config: var_config,
-            meta: var_meta,
-            root_group_uuid: var_rootGroupUuid,
-            groups: var_groups,
-            entries: var_entries,
-            deleted_objects: var_deletedObjects,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbBatchDecryptOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchDecryptOptions>
+    for crate::api::crypt_api::types::FrbBatchDecryptOptions
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchDecryptOptions {
+        self
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassDeletedObject {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_uuid = <String>::sse_decode(deserializer);
-        let mut var_deletionTime = <Option<String>>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassDeletedObject {
-            uuid: var_uuid,
-            deletion_time: var_deletionTime,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchDecryptResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.succeeded.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassEntry {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_uuid = <String>::sse_decode(deserializer);
-        let mut var_groupUuid = <String>::sse_decode(deserializer);
-        let mut var_groupPath = <String>::sse_decode(deserializer);
-        let mut var_title = <Option<String>>::sse_decode(deserializer);
-        let mut var_username = <Option<String>>::sse_decode(deserializer);
-        let mut var_password = <Option<String>>::sse_decode(deserializer);
-        let mut var_url = <Option<String>>::sse_decode(deserializer);
-        let mut var_notes = <Option<String>>::sse_decode(deserializer);
-        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
-        let mut var_fields =
-            <Vec<crate::api::keepass_api::types::FrbKeepassField>>::sse_decode(deserializer);
-        let mut var_times =
-            <crate::api::keepass_api::types::FrbKeepassTimes>::sse_decode(deserializer);
-        let mut var_customData =
-            <Vec<crate::api::keepass_api::types::FrbKeepassCustomDataItem>>::sse_decode(
-                deserializer,
-            );
-        let mut var_iconId = <Option<u32>>::sse_decode(deserializer);
-        let mut var_customIconUuid = <Option<String>>::sse_decode(deserializer);
-        let mut var_customIconData = <Option<Vec<u8>>>::sse_decode(deserializer);
-        let mut var_foregroundColor = <Option<String>>::sse_decode(deserializer);
-        let mut var_backgroundColor = <Option<String>>::sse_decode(deserializer);
-        let mut var_overrideUrl = <Option<String>>::sse_decode(deserializer);
-        let mut var_qualityCheck = <Option<bool>>::sse_decode(deserializer);
-        let mut var_attachments =
-            <Vec<crate::api::keepass_api::types::FrbKeepassAttachment>>::sse_decode(deserializer);
-        let mut var_autotype =
-            <Option<crate::api::keepass_api::types::FrbKeepassAutoType>>::sse_decode(deserializer);
-        let mut var_otp =
-            <Option<crate::api::keepass_api::types::FrbKeepassOtp>>::sse_decode(deserializer);
-        let mut var_history =
-            <Vec<crate::api::keepass_api::types::FrbKeepassHistoryEntry>>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassEntry {
-            uuid: var_uuid,
-            group_uuid: var_groupUuid,
-            group_path: var_groupPath,
-            title: var_title,
-            username: var_username,
-            password: var_password,
-            url: var_url,
-            notes: var_notes,
-            tags: var_tags,
-            fields: var_fields,
-            times: var_times,
-            custom_data: var_customData,
-            icon_id: var_iconId,
-            custom_icon_uuid: var_customIconUuid,
-            custom_icon_data: var_customIconData,
-            foreground_color: var_foregroundColor,
-            background_color: var_backgroundColor,
-            override_url: var_overrideUrl,
-            quality_check: var_qualityCheck,
-            attachments: var_attachments,
-            autotype: var_autotype,
-            otp: var_otp,
-            history: var_history,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbBatchDecryptResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchDecryptResult>
+    for crate::api::crypt_api::types::FrbBatchDecryptResult
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchDecryptResult {
+        self
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassExportOptions {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_inputPath = <String>::sse_decode(deserializer);
-        let mut var_password = <Option<String>>::sse_decode(deserializer);
-        let mut var_keyfilePath = <Option<String>>::sse_decode(deserializer);
-        let mut var_includeHistory = <bool>::sse_decode(deserializer);
-        let mut var_includeAttachments = <bool>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassExportOptions {
-            input_path: var_inputPath,
-            password: var_password,
-            keyfile_path: var_keyfilePath,
-            include_history: var_includeHistory,
-            include_attachments: var_includeAttachments,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchEncryptEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::crypt_api::types::FrbBatchEncryptEvent::FileProgress {
+                file_index,
+                total_files,
+                current_file,
+                progress,
+            } => [
+                0.into_dart(),
+                file_index.into_into_dart().into_dart(),
+                total_files.into_into_dart().into_dart(),
+                current_file.into_into_dart().into_dart(),
+                progress.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::crypt_api::types::FrbBatchEncryptEvent::FileDone { file_index, result } => {
+                [
+                    1.into_dart(),
+                    file_index.into_into_dart().into_dart(),
+                    result.into_into_dart().into_dart(),
+                ]
+                .into_dart()
+            }
+            crate::api::crypt_api::types::FrbBatchEncryptEvent::FileError {
+                file_index,
+                input_path,
+                error,
+            } => [
+                2.into_dart(),
+                file_index.into_into_dart().into_dart(),
+                input_path.into_into_dart().into_dart(),
+                error.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::crypt_api::types::FrbBatchEncryptEvent::AllDone(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassField {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_key = <String>::sse_decode(deserializer);
-        let mut var_value = <String>::sse_decode(deserializer);
-        let mut var_protected = <bool>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassField {
-            key: var_key,
-            value: var_value,
-            protected: var_protected,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbBatchEncryptEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchEncryptEvent>
+    for crate::api::crypt_api::types::FrbBatchEncryptEvent
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchEncryptEvent {
+        self
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassGroup {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_uuid = <String>::sse_decode(deserializer);
-        let mut var_parentUuid = <Option<String>>::sse_decode(deserializer);
-        let mut var_isRoot = <bool>::sse_decode(deserializer);
-        let mut var_path = <String>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_notes = <Option<String>>::sse_decode(deserializer);
-        let mut var_iconId = <Option<u32>>::sse_decode(deserializer);
-        let mut var_customIconUuid = <Option<String>>::sse_decode(deserializer);
-        let mut var_times =
-            <crate::api::keepass_api::types::FrbKeepassTimes>::sse_decode(deserializer);
-        let mut var_customData =
-            <Vec<crate::api::keepass_api::types::FrbKeepassCustomDataItem>>::sse_decode(
-                deserializer,
-            );
-        let mut var_isExpanded = <bool>::sse_decode(deserializer);
-        let mut var_defaultAutotypeSequence = <Option<String>>::sse_decode(deserializer);
-        let mut var_enableAutotype = <Option<bool>>::sse_decode(deserializer);
-        let mut var_enableSearching = <Option<bool>>::sse_decode(deserializer);
-        let mut var_lastTopVisibleEntry = <Option<String>>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassGroup {
-            uuid: var_uuid,
-            parent_uuid: var_parentUuid,
-            is_root: var_isRoot,
-            path: var_path,
-            name: var_name,
-            notes: var_notes,
-            icon_id: var_iconId,
-            custom_icon_uuid: var_customIconUuid,
-            times: var_times,
-            custom_data: var_customData,
-            is_expanded: var_isExpanded,
-            default_autotype_sequence: var_defaultAutotypeSequence,
-            enable_autotype: var_enableAutotype,
-            enable_searching: var_enableSearching,
-            last_top_visible_entry: var_lastTopVisibleEntry,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchEncryptOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.input_paths.into_into_dart().into_dart(),
+            self.output_dir.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.gzip_compressed.into_into_dart().into_dart(),
+            self.temp_dir.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.chunk_size.into_into_dart().into_dart(),
+            self.cipher_suite.into_into_dart().into_dart(),
+            self.signing_key.into_into_dart().into_dart(),
+            self.hide_lengths.into_into_dart().into_dart(),
+            self.volume_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassHistoryEntry {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_uuid = <String>::sse_decode(deserializer);
-        let mut var_title = <Option<String>>::sse_decode(deserializer);
-        let mut var_username = <Option<String>>::sse_decode(deserializer);
-        let mut var_password = <Option<String>>::sse_decode(deserializer);
-        let mut var_url = <Option<String>>::sse_decode(deserializer);
-        let mut var_notes = <Option<String>>::sse_decode(deserializer);
-        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
-        let mut var_fields =
-            <Vec<crate::api::keepass_api::types::FrbKeepassField>>::sse_decode(deserializer);
-        let mut var_times =
-            <crate::api::keepass_api::types::FrbKeepassTimes>::sse_decode(deserializer);
-        let mut var_customData =
-            <Vec<crate::api::keepass_api::types::FrbKeepassCustomDataItem>>::sse_decode(
-                deserializer,
-            );
-        let mut var_iconId = <Option<u32>>::sse_decode(deserializer);
-        let mut var_customIconUuid = <Option<String>>::sse_decode(deserializer);
-        let mut var_customIconData = <Option<Vec<u8>>>::sse_decode(deserializer);
-        let mut var_foregroundColor = <Option<String>>::sse_decode(deserializer);
-        let mut var_backgroundColor = <Option<String>>::sse_decode(deserializer);
-        let mut var_overrideUrl = <Option<String>>::sse_decode(deserializer);
-        let mut var_qualityCheck = <Option<bool>>::sse_decode(deserializer);
-        let mut var_attachments =
-            <Vec<crate::api::keepass_api::types::FrbKeepassAttachment>>::sse_decode(deserializer);
-        let mut var_autotype =
-            <Option<crate::api::keepass_api::types::FrbKeepassAutoType>>::sse_decode(deserializer);
-        let mut var_otp =
-            <Option<crate::api::keepass_api::types::FrbKeepassOtp>>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassHistoryEntry {
-            uuid: var_uuid,
-            title: var_title,
-            username: var_username,
-            password: var_password,
-            url: var_url,
-            notes: var_notes,
-            tags: var_tags,
-            fields: var_fields,
-            times: var_times,
-            custom_data: var_customData,
-            icon_id: var_iconId,
-            custom_icon_uuid: var_customIconUuid,
-            custom_icon_data: var_customIconData,
-            foreground_color: var_foregroundColor,
-            background_color: var_backgroundColor,
-            override_url: var_overrideUrl,
-            quality_check: var_qualityCheck,
-            attachments: var_attachments,
-            autotype: var_autotype,
-            otp: var_otp,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbBatchEncryptOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchEncryptOptions>
+    for crate::api::crypt_api::types::FrbBatchEncryptOptions
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchEncryptOptions {
+        self
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassMemoryProtection {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_protectTitle = <bool>::sse_decode(deserializer);
-        let mut var_protectUsername = <bool>::sse_decode(deserializer);
-        let mut var_protectPassword = <bool>::sse_decode(deserializer);
-        let mut var_protectUrl = <bool>::sse_decode(deserializer);
-        let mut var_protectNotes = <bool>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassMemoryProtection {
-            protect_title: var_protectTitle,
-            protect_username: var_protectUsername,
-            protect_password: var_protectPassword,
-            protect_url: var_protectUrl,
-            protect_notes: var_protectNotes,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchEncryptResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.succeeded.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassMeta {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_generator = <Option<String>>::sse_decode(deserializer);
-        let mut var_databaseName = <Option<String>>::sse_decode(deserializer);
-        let mut var_databaseNameChanged = <Option<String>>::sse_decode(deserializer);
-        let mut var_databaseDescription = <Option<String>>::sse_decode(deserializer);
-        let mut var_databaseDescriptionChanged = <Option<String>>::sse_decode(deserializer);
-        let mut var_defaultUsername = <Option<String>>::sse_decode(deserializer);
-        let mut var_defaultUsernameChanged = <Option<String>>::sse_decode(deserializer);
-        let mut var_maintenanceHistoryDays = <Option<u32>>::sse_decode(deserializer);
-        let mut var_color = <Option<String>>::sse_decode(deserializer);
-        let mut var_masterKeyChanged = <Option<String>>::sse_decode(deserializer);
-        let mut var_masterKeyChangeRec = <Option<i32>>::sse_decode(deserializer);
-        let mut var_masterKeyChangeForce = <Option<i32>>::sse_decode(deserializer);
-        let mut var_memoryProtection = <Option<
-            crate::api::keepass_api::types::FrbKeepassMemoryProtection,
-        >>::sse_decode(deserializer);
-        let mut var_recyclebinEnabled = <Option<bool>>::sse_decode(deserializer);
-        let mut var_recyclebinUuid = <Option<String>>::sse_decode(deserializer);
-        let mut var_recyclebinChanged = <Option<String>>::sse_decode(deserializer);
-        let mut var_entryTemplatesGroup = <Option<String>>::sse_decode(deserializer);
-        let mut var_entryTemplatesGroupChanged = <Option<String>>::sse_decode(deserializer);
-        let mut var_lastSelectedGroup = <Option<String>>::sse_decode(deserializer);
-        let mut var_lastTopVisibleGroup = <Option<String>>::sse_decode(deserializer);
-        let mut var_historyMaxItems = <Option<i32>>::sse_decode(deserializer);
-        let mut var_historyMaxSize = <Option<i32>>::sse_decode(deserializer);
-        let mut var_settingsChanged = <Option<String>>::sse_decode(deserializer);
-        let mut var_customData =
-            <Vec<crate::api::keepass_api::types::FrbKeepassCustomDataItem>>::sse_decode(
-                deserializer,
-            );
-        return crate::api::keepass_api::types::FrbKeepassMeta {
-            generator: var_generator,
-            database_name: var_databaseName,
-            database_name_changed: var_databaseNameChanged,
-            database_description: var_databaseDescription,
-            database_description_changed: var_databaseDescriptionChanged,
-            default_username: var_defaultUsername,
-            default_username_changed: var_defaultUsernameChanged,
-            maintenance_history_days: var_maintenanceHistoryDays,
-            color: var_color,
-            master_key_changed: var_masterKeyChanged,
-            master_key_change_rec: var_masterKeyChangeRec,
-            master_key_change_force: var_masterKeyChangeForce,
-            memory_protection: var_memoryProtection,
-            recyclebin_enabled: var_recyclebinEnabled,
-            recyclebin_uuid: var_recyclebinUuid,
-            recyclebin_changed: var_recyclebinChanged,
-            entry_templates_group: var_entryTemplatesGroup,
-            entry_templates_group_changed: var_entryTemplatesGroupChanged,
-            last_selected_group: var_lastSelectedGroup,
-            last_top_visible_group: var_lastTopVisibleGroup,
-            history_max_items: var_historyMaxItems,
-            history_max_size: var_historyMaxSize,
-            settings_changed: var_settingsChanged,
-            custom_data: var_customData,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbBatchEncryptResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchEncryptResult>
+    for crate::api::crypt_api::types::FrbBatchEncryptResult
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchEncryptResult {
+        self
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassOtp {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_rawValue = <String>::sse_decode(deserializer);
-        let mut var_label = <Option<String>>::sse_decode(deserializer);
-        let mut var_issuer = <Option<String>>::sse_decode(deserializer);
-        let mut var_secret = <Option<String>>::sse_decode(deserializer);
-        let mut var_period = <Option<u64>>::sse_decode(deserializer);
-        let mut var_digits = <Option<u32>>::sse_decode(deserializer);
-        let mut var_algorithm = <Option<String>>::sse_decode(deserializer);
-        let mut var_parseError = <Option<String>>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassOtp {
-            raw_value: var_rawValue,
-            label: var_label,
-            issuer: var_issuer,
-            secret: var_secret,
-            period: var_period,
-            digits: var_digits,
-            algorithm: var_algorithm,
-            parse_error: var_parseError,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.input_path.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::keepass_api::types::FrbKeepassTimes {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_creation = <Option<String>>::sse_decode(deserializer);
-        let mut var_lastModification = <Option<String>>::sse_decode(deserializer);
-        let mut var_lastAccess = <Option<String>>::sse_decode(deserializer);
-        let mut var_expiry = <Option<String>>::sse_decode(deserializer);
-        let mut var_locationChanged = <Option<String>>::sse_decode(deserializer);
-        let mut var_expires = <Option<bool>>::sse_decode(deserializer);
-        let mut var_usageCount = <Option<u32>>::sse_decode(deserializer);
-        return crate::api::keepass_api::types::FrbKeepassTimes {
-            creation: var_creation,
-            last_modification: var_lastModification,
-            last_access: var_lastAccess,
-            expiry: var_expiry,
-            location_changed: var_locationChanged,
-            expires: var_expires,
-            usage_count: var_usageCount,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbBatchError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchError>
+    for crate::api::crypt_api::types::FrbBatchError
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchError {
+        self
     }
 }
-
-impl SseDecode for crate::api::crypt_api::types::FrbKeyValue {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_key = <String>::sse_decode(deserializer);
-        let mut var_value = <String>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbKeyValue {
-            key: var_key,
-            value: var_value,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchReencryptEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::crypt_api::types::FrbBatchReencryptEvent::FileProgress {
+                file_index,
+                total_files,
+                current_file,
+                progress,
+            } => [
+                0.into_dart(),
+                file_index.into_into_dart().into_dart(),
+                total_files.into_into_dart().into_dart(),
+                current_file.into_into_dart().into_dart(),
+                progress.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::crypt_api::types::FrbBatchReencryptEvent::FileDone {
+                file_index,
+                result,
+            } => [
+                1.into_dart(),
+                file_index.into_into_dart().into_dart(),
+                result.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::crypt_api::types::FrbBatchReencryptEvent::FileError {
+                file_index,
+                input_path,
+                error,
+            } => [
+                2.into_dart(),
+                file_index.into_into_dart().into_dart(),
+                input_path.into_into_dart().into_dart(),
+                error.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::crypt_api::types::FrbBatchReencryptEvent::AllDone(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-
-impl SseDecode for crate::api::crypt_api::types::FrbProgressEvent {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_stage =
-            <crate::api::crypt_api::types::FrbProgressStage>::sse_decode(deserializer);
-        let mut var_bytesProcessed = <u64>::sse_decode(deserializer);
-        let mut var_totalBytes = <u64>::sse_decode(deserializer);
-        let mut var_percentage = <f64>::sse_decode(deserializer);
-        return crate::api::crypt_api::types::FrbProgressEvent {
-            stage: var_stage,
-            bytes_processed: var_bytesProcessed,
-            total_bytes: var_totalBytes,
-            percentage: var_percentage,
-        };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbBatchReencryptEvent
+{
 }
-
-impl SseDecode for crate::api::crypt_api::types::FrbProgressStage {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::crypt_api::types::FrbProgressStage::CompressingDirectory,
-            1 => crate::api::crypt_api::types::FrbProgressStage::CompressingGzip,
-            2 => crate::api::crypt_api::types::FrbProgressStage::Encrypting,
-            3 => crate::api::crypt_api::types::FrbProgressStage::Decrypting,
-            4 => crate::api::crypt_api::types::FrbProgressStage::DecompressingGzip,
-            5 => crate::api::crypt_api::types::FrbProgressStage::DecompressingDirectory,
-            6 => crate::api::crypt_api::types::FrbProgressStage::Done,
-            _ => unreachable!("Invalid variant for FrbProgressStage: {}", inner),
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchReencryptEvent>
+    for crate::api::crypt_api::types::FrbBatchReencryptEvent
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchReencryptEvent {
+        self
     }
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchReencryptOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.input_paths.into_into_dart().into_dart(),
+            self.old_password.into_into_dart().into_dart(),
+            self.new_password.into_into_dart().into_dart(),
+            self.argon2_params.into_into_dart().into_dart(),
+            self.chunk_size.into_into_dart().into_dart(),
+            self.signing_key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for i64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbBatchReencryptOptions
+{
 }
-
-impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchReencryptOptions>
+    for crate::api::crypt_api::types::FrbBatchReencryptOptions
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchReencryptOptions {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::crypt_api::types::FrbBatchError> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::crypt_api::types::FrbBatchError>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchReencryptResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.succeeded.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::api::crypt_api::types::FrbDecryptResult> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::crypt_api::types::FrbDecryptResult>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbBatchReencryptResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchReencryptResult>
+    for crate::api::crypt_api::types::FrbBatchReencryptResult
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchReencryptResult {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::crypt_api::types::FrbEncryptResult> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::crypt_api::types::FrbEncryptResult>::sse_decode(deserializer));
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbChunkSizePreset {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::crypt_api::types::FrbChunkSizePreset::Desktop => {
+                [0.into_dart()].into_dart()
+            }
+            crate::api::crypt_api::types::FrbChunkSizePreset::Mobile => [1.into_dart()].into_dart(),
+            crate::api::crypt_api::types::FrbChunkSizePreset::Custom(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
-
-impl SseDecode for Vec<crate::api::icon_pack_catalog_api::types::FrbIconPackEntry> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::icon_pack_catalog_api::types::FrbIconPackEntry>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbChunkSizePreset
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbChunkSizePreset>
+    for crate::api::crypt_api::types::FrbChunkSizePreset
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbChunkSizePreset {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::icon_pack_catalog_api::types::FrbIconPackSummary> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::icon_pack_catalog_api::types::FrbIconPackSummary>::sse_decode(
-                    deserializer,
-                ),
-            );
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbCipherSuite {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::XChaCha20Poly1305 => 0.into_dart(),
+            Self::Aes256GcmSiv => 1.into_dart(),
+            _ => unreachable!(),
         }
-        return ans_;
     }
 }
-
-impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassAttachment> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::keepass_api::types::FrbKeepassAttachment>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbCipherSuite
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbCipherSuite>
+    for crate::api::crypt_api::types::FrbCipherSuite
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbCipherSuite {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation>::sse_decode(
-                    deserializer,
-                ),
-            );
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptChunk {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::crypt_api::types::FrbDecryptChunk::Data(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::crypt_api::types::FrbDecryptChunk::Done(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::crypt_api::types::FrbDecryptChunk::Error(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
-
-impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassCustomDataItem> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::keepass_api::types::FrbKeepassCustomDataItem>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbDecryptChunk
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptChunk>
+    for crate::api::crypt_api::types::FrbDecryptChunk
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptChunk {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassDeletedObject> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::keepass_api::types::FrbKeepassDeletedObject>::sse_decode(deserializer),
-            );
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::crypt_api::types::FrbDecryptEvent::Progress(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::crypt_api::types::FrbDecryptEvent::Done(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::crypt_api::types::FrbDecryptEvent::Error(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
-
-impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassEntry> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::keepass_api::types::FrbKeepassEntry>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbDecryptEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptEvent>
+    for crate::api::crypt_api::types::FrbDecryptEvent
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptEvent {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassField> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::keepass_api::types::FrbKeepassField>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.input_path.into_into_dart().into_dart(),
+            self.output_dir.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.temp_dir.into_into_dart().into_dart(),
+            self.chunk_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassGroup> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::keepass_api::types::FrbKeepassGroup>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbDecryptOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptOptions>
+    for crate::api::crypt_api::types::FrbDecryptOptions
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptOptions {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::keepass_api::types::FrbKeepassHistoryEntry> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::keepass_api::types::FrbKeepassHistoryEntry>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.output_path.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.signature.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::api::crypt_api::types::FrbKeyValue> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::crypt_api::types::FrbKeyValue>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbDecryptResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptResult>
+    for crate::api::crypt_api::types::FrbDecryptResult
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptResult {
+        self
     }
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptedCacheOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.root_dir.into_into_dart().into_dart(),
+            self.max_bytes.into_into_dart().into_dart(),
+            self.ttl_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::logging::LogEntry {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_timeMillis = <i64>::sse_decode(deserializer);
-        let mut var_level = <i32>::sse_decode(deserializer);
-        let mut var_tag = <String>::sse_decode(deserializer);
-        let mut var_msg = <String>::sse_decode(deserializer);
-        return crate::api::logging::LogEntry {
-            time_millis: var_timeMillis,
-            level: var_level,
-            tag: var_tag,
-            msg: var_msg,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbDecryptedCacheOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptedCacheOptions>
+    for crate::api::crypt_api::types::FrbDecryptedCacheOptions
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptedCacheOptions {
+        self
     }
 }
-
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptedCacheStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.root_dir.into_into_dart().into_dart(),
+            self.entry_count.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<bool> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<bool>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbDecryptedCacheStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptedCacheStats>
+    for crate::api::crypt_api::types::FrbDecryptedCacheStats
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptedCacheStats {
+        self
     }
 }
-
-impl SseDecode for Option<crate::api::keepass_api::types::FrbKeepassAutoType> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::keepass_api::types::FrbKeepassAutoType>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptedMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.original_filename.into_into_dart().into_dart(),
+            self.original_extension.into_into_dart().into_dart(),
+            self.gzip_compressed.into_into_dart().into_dart(),
+            self.original_size.into_into_dart().into_dart(),
+            self.uuid.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.signer_public_key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<crate::api::keepass_api::types::FrbKeepassMemoryProtection> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::keepass_api::types::FrbKeepassMemoryProtection>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbDecryptedMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptedMetadata>
+    for crate::api::crypt_api::types::FrbDecryptedMetadata
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptedMetadata {
+        self
     }
 }
-
-impl SseDecode for Option<crate::api::keepass_api::types::FrbKeepassOtp> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::keepass_api::types::FrbKeepassOtp>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbEncryptEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::crypt_api::types::FrbEncryptEvent::Progress(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::crypt_api::types::FrbEncryptEvent::Done(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::crypt_api::types::FrbEncryptEvent::Error(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
-
-impl SseDecode for Option<i32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbEncryptEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbEncryptEvent>
+    for crate::api::crypt_api::types::FrbEncryptEvent
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbEncryptEvent {
+        self
     }
 }
-
-impl SseDecode for Option<u32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbEncryptOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.input_path.into_into_dart().into_dart(),
+            self.output_dir.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.gzip_compressed.into_into_dart().into_dart(),
+            self.uuid.into_into_dart().into_dart(),
+            self.output_extension.into_into_dart().into_dart(),
+            self.temp_dir.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.chunk_size.into_into_dart().into_dart(),
+            self.cipher_suite.into_into_dart().into_dart(),
+            self.signing_key.into_into_dart().into_dart(),
+            self.shred_source.into_into_dart().into_dart(),
+            self.hide_lengths.into_into_dart().into_dart(),
+            self.volume_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<u64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbEncryptOptions
+{
 }
-
-impl SseDecode for Option<Vec<u8>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<u8>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbEncryptOptions>
+    for crate::api::crypt_api::types::FrbEncryptOptions
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbEncryptOptions {
+        self
     }
 }
-
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbEncryptResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.output_path.into_into_dart().into_dart(),
+            self.volumes.into_into_dart().into_dart(),
+            self.uuid.into_into_dart().into_dart(),
+            self.original_size.into_into_dart().into_dart(),
+            self.shred.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbEncryptResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbEncryptResult>
+    for crate::api::crypt_api::types::FrbEncryptResult
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbEncryptResult {
+        self
     }
 }
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbFecParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.data_shards.into_into_dart().into_dart(),
+            self.parity_shards.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbFecParams
+{
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__logging__create_log_stream_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__crypt_api__decrypt_batch_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__crypt_api__decrypt_file_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__icon_pack_catalog_api__delete_pack_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__crypt_api__encrypt_batch_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__crypt_api__encrypt_file_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__keepass_api__export_keepass_database_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        8 => wire__crate__api__crypt_api__types__frb_decrypt_options_simple_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__api__crypt_api__types__frb_encrypt_options_simple_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        10 => wire__crate__api__keepass_api__types__frb_keepass_export_options_simple_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        12 => wire__crate__api__icon_pack_catalog_api__import_directory_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        13 => wire__crate__api__icon_pack_catalog_api__import_pack_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        14 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__logging__install_rust_log_bridge_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        16 => wire__crate__api__icon_pack_catalog_api__list_icons_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        17 => wire__crate__api__icon_pack_catalog_api__list_packs_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        20 => wire__crate__api__crypt_api__read_encrypted_header_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        21 => wire__crate__api__icon_pack_catalog_api__read_svg_by_key_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        22 => wire__crate__api__logging__rust_log_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__logging__rust_log_debug_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__logging__rust_log_error_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__logging__rust_log_fatal_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__logging__rust_log_info_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__logging__rust_log_warning_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbFecParams>
+    for crate::api::crypt_api::types::FrbFecParams
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbFecParams {
+        self
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        11 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__icon_pack_catalog_api__normalize_icon_path_without_extension_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        19 => wire__crate__api__icon_pack_catalog_api__normalize_pack_key_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::icon_pack_catalog_api::types::FrbIconPackEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.pack_key.into_into_dart().into_dart(),
+            self.pack_name.into_into_dart().into_dart(),
+            self.icon_key.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.relative_path.into_into_dart().into_dart(),
+            self.svg_path.into_into_dart().into_dart(),
+            self.imported_at_millis.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::icon_pack_catalog_api::types::FrbIconPackEntry>
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackEntry
+{
+    fn into_into_dart(self) -> crate::api::icon_pack_catalog_api::types::FrbIconPackEntry {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchDecryptEvent {
+impl flutter_rust_bridge::IntoDart for crate::api::icon_pack_catalog_api::types::FrbIconPackError {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::crypt_api::types::FrbBatchDecryptEvent::FileProgress {
-                file_index,
-                total_files,
-                current_file,
-                progress,
-            } => [
-                0.into_dart(),
-                file_index.into_into_dart().into_dart(),
-                total_files.into_into_dart().into_dart(),
-                current_file.into_into_dart().into_dart(),
-                progress.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::crypt_api::types::FrbBatchDecryptEvent::FileDone { file_index, result } => {
-                [
-                    1.into_dart(),
-                    file_index.into_into_dart().into_dart(),
-                    result.into_into_dart().into_dart(),
-                ]
-                .into_dart()
+        [
+            self.code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::icon_pack_catalog_api::types::FrbIconPackError>
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackError
+{
+    fn into_into_dart(self) -> crate::api::icon_pack_catalog_api::types::FrbIconPackError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Progress(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
             }
-            crate::api::crypt_api::types::FrbBatchDecryptEvent::FileError {
-                file_index,
-                input_path,
-                error,
-            } => [
-                2.into_dart(),
-                file_index.into_into_dart().into_dart(),
-                input_path.into_into_dart().into_dart(),
-                error.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::crypt_api::types::FrbBatchDecryptEvent::AllDone(field0) => {
-                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Done(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Error(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
             }
             _ => {
                 unimplemented!("");
@@ -2703,1045 +5060,1013 @@ impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchDec
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbBatchDecryptEvent
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchDecryptEvent>
-    for crate::api::crypt_api::types::FrbBatchDecryptEvent
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent,
+    > for crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchDecryptEvent {
+    fn into_into_dart(self) -> crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchDecryptOptions {
+impl flutter_rust_bridge::IntoDart
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress
+{
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.input_paths.into_into_dart().into_dart(),
-            self.output_dir.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-            self.temp_dir.into_into_dart().into_dart(),
-            self.chunk_size.into_into_dart().into_dart(),
+            self.current.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.current_file.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbBatchDecryptOptions
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchDecryptOptions>
-    for crate::api::crypt_api::types::FrbBatchDecryptOptions
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress,
+    > for crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchDecryptOptions {
+    fn into_into_dart(self) -> crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchDecryptResult {
+impl flutter_rust_bridge::IntoDart
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackSummary
+{
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.succeeded.into_into_dart().into_dart(),
-            self.failed.into_into_dart().into_dart(),
+            self.pack_key.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.source_archive_name.into_into_dart().into_dart(),
+            self.imported_at_millis.into_into_dart().into_dart(),
+            self.icon_count.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbBatchDecryptResult
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackSummary
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchDecryptResult>
-    for crate::api::crypt_api::types::FrbBatchDecryptResult
+impl flutter_rust_bridge::IntoIntoDart<crate::api::icon_pack_catalog_api::types::FrbIconPackSummary>
+    for crate::api::icon_pack_catalog_api::types::FrbIconPackSummary
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchDecryptResult {
+    fn into_into_dart(self) -> crate::api::icon_pack_catalog_api::types::FrbIconPackSummary {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchEncryptEvent {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbInspectResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::crypt_api::types::FrbBatchEncryptEvent::FileProgress {
-                file_index,
-                total_files,
-                current_file,
-                progress,
-            } => [
-                0.into_dart(),
-                file_index.into_into_dart().into_dart(),
-                total_files.into_into_dart().into_dart(),
-                current_file.into_into_dart().into_dart(),
-                progress.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::crypt_api::types::FrbBatchEncryptEvent::FileDone { file_index, result } => {
-                [
-                    1.into_dart(),
-                    file_index.into_into_dart().into_dart(),
-                    result.into_into_dart().into_dart(),
-                ]
-                .into_dart()
-            }
-            crate::api::crypt_api::types::FrbBatchEncryptEvent::FileError {
-                file_index,
-                input_path,
-                error,
-            } => [
-                2.into_dart(),
-                file_index.into_into_dart().into_dart(),
-                input_path.into_into_dart().into_dart(),
-                error.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::crypt_api::types::FrbBatchEncryptEvent::AllDone(field0) => {
-                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        [
+            self.version.into_into_dart().into_dart(),
+            self.argon2_params.into_into_dart().into_dart(),
+            self.chunk_size.into_into_dart().into_dart(),
+            self.fec.into_into_dart().into_dart(),
+            self.signature.into_into_dart().into_dart(),
+            self.cipher.into_into_dart().into_dart(),
+            self.key_commitment.into_into_dart().into_dart(),
+            self.file_len.into_into_dart().into_dart(),
+            self.ciphertext_len.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+            self.estimated_plaintext_size.into_into_dart().into_dart(),
+            self.whole_chunks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbBatchEncryptEvent
+    for crate::api::crypt_api::types::FrbInspectResult
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchEncryptEvent>
-    for crate::api::crypt_api::types::FrbBatchEncryptEvent
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbInspectResult>
+    for crate::api::crypt_api::types::FrbInspectResult
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchEncryptEvent {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbInspectResult {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchEncryptOptions {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassAttachment {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.input_paths.into_into_dart().into_dart(),
-            self.output_dir.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-            self.gzip_compressed.into_into_dart().into_dart(),
-            self.temp_dir.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
-            self.chunk_size.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.protected.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbBatchEncryptOptions
+    for crate::api::keepass_api::types::FrbKeepassAttachment
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchEncryptOptions>
-    for crate::api::crypt_api::types::FrbBatchEncryptOptions
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassAttachment>
+    for crate::api::keepass_api::types::FrbKeepassAttachment
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchEncryptOptions {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassAttachment {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchEncryptResult {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassAutoType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.succeeded.into_into_dart().into_dart(),
-            self.failed.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+            self.default_sequence.into_into_dart().into_dart(),
+            self.data_transfer_obfuscation.into_into_dart().into_dart(),
+            self.associations.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbBatchEncryptResult
+    for crate::api::keepass_api::types::FrbKeepassAutoType
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchEncryptResult>
-    for crate::api::crypt_api::types::FrbBatchEncryptResult
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassAutoType>
+    for crate::api::keepass_api::types::FrbKeepassAutoType
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchEncryptResult {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassAutoType {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbBatchError {
+impl flutter_rust_bridge::IntoDart
+    for crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation
+{
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.input_path.into_into_dart().into_dart(),
-            self.error.into_into_dart().into_dart(),
+            self.window.into_into_dart().into_dart(),
+            self.sequence.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbBatchError
+    for crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbBatchError>
-    for crate::api::crypt_api::types::FrbBatchError
+impl
+    flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation>
+    for crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbBatchError {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbChunkSizePreset {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::crypt_api::types::FrbChunkSizePreset::Desktop => {
-                [0.into_dart()].into_dart()
-            }
-            crate::api::crypt_api::types::FrbChunkSizePreset::Mobile => [1.into_dart()].into_dart(),
-            crate::api::crypt_api::types::FrbChunkSizePreset::Custom(field0) => {
-                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        [
+            self.database_version.into_into_dart().into_dart(),
+            self.outer_cipher.into_into_dart().into_dart(),
+            self.inner_cipher.into_into_dart().into_dart(),
+            self.compression.into_into_dart().into_dart(),
+            self.kdf_name.into_into_dart().into_dart(),
+            self.kdf_description.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbChunkSizePreset
+    for crate::api::keepass_api::types::FrbKeepassConfig
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbChunkSizePreset>
-    for crate::api::crypt_api::types::FrbChunkSizePreset
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassConfig>
+    for crate::api::keepass_api::types::FrbKeepassConfig
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbChunkSizePreset {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassConfig {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptEvent {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassCustomDataItem {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::crypt_api::types::FrbDecryptEvent::Progress(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::crypt_api::types::FrbDecryptEvent::Done(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::crypt_api::types::FrbDecryptEvent::Error(field0) => {
-                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value_kind.into_into_dart().into_dart(),
+            self.string_value.into_into_dart().into_dart(),
+            self.binary_value.into_into_dart().into_dart(),
+            self.last_modification_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbDecryptEvent
+    for crate::api::keepass_api::types::FrbKeepassCustomDataItem
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptEvent>
-    for crate::api::crypt_api::types::FrbDecryptEvent
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassCustomDataItem>
+    for crate::api::keepass_api::types::FrbKeepassCustomDataItem
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptEvent {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassCustomDataItem {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptOptions {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassDatabaseExport {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.input_path.into_into_dart().into_dart(),
-            self.output_dir.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-            self.temp_dir.into_into_dart().into_dart(),
-            self.chunk_size.into_into_dart().into_dart(),
+            self.source_path.into_into_dart().into_dart(),
+            self.config.into_into_dart().into_dart(),
+            self.meta.into_into_dart().into_dart(),
+            self.root_group_uuid.into_into_dart().into_dart(),
+            self.groups.into_into_dart().into_dart(),
+            self.entries.into_into_dart().into_dart(),
+            self.deleted_objects.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbDecryptOptions
+    for crate::api::keepass_api::types::FrbKeepassDatabaseExport
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptOptions>
-    for crate::api::crypt_api::types::FrbDecryptOptions
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassDatabaseExport>
+    for crate::api::keepass_api::types::FrbKeepassDatabaseExport
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptOptions {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassDatabaseExport {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptResult {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassDeletedObject {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.output_path.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
+            self.uuid.into_into_dart().into_dart(),
+            self.deletion_time.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbDecryptResult
+    for crate::api::keepass_api::types::FrbKeepassDeletedObject
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptResult>
-    for crate::api::crypt_api::types::FrbDecryptResult
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassDeletedObject>
+    for crate::api::keepass_api::types::FrbKeepassDeletedObject
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptResult {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassDeletedObject {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbDecryptedMetadata {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassEntry {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.original_filename.into_into_dart().into_dart(),
-            self.original_extension.into_into_dart().into_dart(),
-            self.gzip_compressed.into_into_dart().into_dart(),
-            self.original_size.into_into_dart().into_dart(),
             self.uuid.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
+            self.group_uuid.into_into_dart().into_dart(),
+            self.group_path.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.notes.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.fields.into_into_dart().into_dart(),
+            self.times.into_into_dart().into_dart(),
+            self.custom_data.into_into_dart().into_dart(),
+            self.icon_id.into_into_dart().into_dart(),
+            self.custom_icon_uuid.into_into_dart().into_dart(),
+            self.custom_icon_data.into_into_dart().into_dart(),
+            self.foreground_color.into_into_dart().into_dart(),
+            self.background_color.into_into_dart().into_dart(),
+            self.override_url.into_into_dart().into_dart(),
+            self.quality_check.into_into_dart().into_dart(),
+            self.attachments.into_into_dart().into_dart(),
+            self.autotype.into_into_dart().into_dart(),
+            self.otp.into_into_dart().into_dart(),
+            self.history.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbDecryptedMetadata
+    for crate::api::keepass_api::types::FrbKeepassEntry
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbDecryptedMetadata>
-    for crate::api::crypt_api::types::FrbDecryptedMetadata
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassEntry>
+    for crate::api::keepass_api::types::FrbKeepassEntry
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbDecryptedMetadata {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassEntry {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbEncryptEvent {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassExportOptions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::crypt_api::types::FrbEncryptEvent::Progress(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::crypt_api::types::FrbEncryptEvent::Done(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::crypt_api::types::FrbEncryptEvent::Error(field0) => {
-                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        [
+            self.input_path.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.keyfile_path.into_into_dart().into_dart(),
+            self.include_history.into_into_dart().into_dart(),
+            self.include_attachments.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbEncryptEvent
+    for crate::api::keepass_api::types::FrbKeepassExportOptions
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbEncryptEvent>
-    for crate::api::crypt_api::types::FrbEncryptEvent
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassExportOptions>
+    for crate::api::keepass_api::types::FrbKeepassExportOptions
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbEncryptEvent {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassExportOptions {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbEncryptOptions {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassField {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.input_path.into_into_dart().into_dart(),
-            self.output_dir.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-            self.gzip_compressed.into_into_dart().into_dart(),
-            self.uuid.into_into_dart().into_dart(),
-            self.output_extension.into_into_dart().into_dart(),
-            self.temp_dir.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
-            self.chunk_size.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.protected.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbEncryptOptions
+    for crate::api::keepass_api::types::FrbKeepassField
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbEncryptOptions>
-    for crate::api::crypt_api::types::FrbEncryptOptions
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassField>
+    for crate::api::keepass_api::types::FrbKeepassField
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbEncryptOptions {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassField {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbEncryptResult {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassGroup {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.output_path.into_into_dart().into_dart(),
             self.uuid.into_into_dart().into_dart(),
-            self.original_size.into_into_dart().into_dart(),
+            self.parent_uuid.into_into_dart().into_dart(),
+            self.is_root.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.notes.into_into_dart().into_dart(),
+            self.icon_id.into_into_dart().into_dart(),
+            self.custom_icon_uuid.into_into_dart().into_dart(),
+            self.times.into_into_dart().into_dart(),
+            self.custom_data.into_into_dart().into_dart(),
+            self.is_expanded.into_into_dart().into_dart(),
+            self.default_autotype_sequence.into_into_dart().into_dart(),
+            self.enable_autotype.into_into_dart().into_dart(),
+            self.enable_searching.into_into_dart().into_dart(),
+            self.last_top_visible_entry.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbEncryptResult
+    for crate::api::keepass_api::types::FrbKeepassGroup
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbEncryptResult>
-    for crate::api::crypt_api::types::FrbEncryptResult
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassGroup>
+    for crate::api::keepass_api::types::FrbKeepassGroup
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbEncryptResult {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassGroup {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::icon_pack_catalog_api::types::FrbIconPackEntry {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassHistoryEntry {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.key.into_into_dart().into_dart(),
-            self.pack_key.into_into_dart().into_dart(),
-            self.pack_name.into_into_dart().into_dart(),
-            self.icon_key.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.relative_path.into_into_dart().into_dart(),
-            self.svg_path.into_into_dart().into_dart(),
-            self.imported_at_millis.into_into_dart().into_dart(),
+            self.uuid.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.notes.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.fields.into_into_dart().into_dart(),
+            self.times.into_into_dart().into_dart(),
+            self.custom_data.into_into_dart().into_dart(),
+            self.icon_id.into_into_dart().into_dart(),
+            self.custom_icon_uuid.into_into_dart().into_dart(),
+            self.custom_icon_data.into_into_dart().into_dart(),
+            self.foreground_color.into_into_dart().into_dart(),
+            self.background_color.into_into_dart().into_dart(),
+            self.override_url.into_into_dart().into_dart(),
+            self.quality_check.into_into_dart().into_dart(),
+            self.attachments.into_into_dart().into_dart(),
+            self.autotype.into_into_dart().into_dart(),
+            self.otp.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackEntry
+    for crate::api::keepass_api::types::FrbKeepassHistoryEntry
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::icon_pack_catalog_api::types::FrbIconPackEntry>
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackEntry
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassHistoryEntry>
+    for crate::api::keepass_api::types::FrbKeepassHistoryEntry
 {
-    fn into_into_dart(self) -> crate::api::icon_pack_catalog_api::types::FrbIconPackEntry {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassHistoryEntry {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::icon_pack_catalog_api::types::FrbIconPackError {
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassMemoryProtection {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.code.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
+            self.protect_title.into_into_dart().into_dart(),
+            self.protect_username.into_into_dart().into_dart(),
+            self.protect_password.into_into_dart().into_dart(),
+            self.protect_url.into_into_dart().into_dart(),
+            self.protect_notes.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackError
+    for crate::api::keepass_api::types::FrbKeepassMemoryProtection
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::icon_pack_catalog_api::types::FrbIconPackError>
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackError
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassMemoryProtection>
+    for crate::api::keepass_api::types::FrbKeepassMemoryProtection
 {
-    fn into_into_dart(self) -> crate::api::icon_pack_catalog_api::types::FrbIconPackError {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassMemoryProtection {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent
-{
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassMeta {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Progress(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Done(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent::Error(field0) => {
-                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        [
+            self.generator.into_into_dart().into_dart(),
+            self.database_name.into_into_dart().into_dart(),
+            self.database_name_changed.into_into_dart().into_dart(),
+            self.database_description.into_into_dart().into_dart(),
+            self.database_description_changed
+                .into_into_dart()
+                .into_dart(),
+            self.default_username.into_into_dart().into_dart(),
+            self.default_username_changed.into_into_dart().into_dart(),
+            self.maintenance_history_days.into_into_dart().into_dart(),
+            self.color.into_into_dart().into_dart(),
+            self.master_key_changed.into_into_dart().into_dart(),
+            self.master_key_change_rec.into_into_dart().into_dart(),
+            self.master_key_change_force.into_into_dart().into_dart(),
+            self.memory_protection.into_into_dart().into_dart(),
+            self.recyclebin_enabled.into_into_dart().into_dart(),
+            self.recyclebin_uuid.into_into_dart().into_dart(),
+            self.recyclebin_changed.into_into_dart().into_dart(),
+            self.entry_templates_group.into_into_dart().into_dart(),
+            self.entry_templates_group_changed
+                .into_into_dart()
+                .into_dart(),
+            self.last_selected_group.into_into_dart().into_dart(),
+            self.last_top_visible_group.into_into_dart().into_dart(),
+            self.history_max_items.into_into_dart().into_dart(),
+            self.history_max_size.into_into_dart().into_dart(),
+            self.settings_changed.into_into_dart().into_dart(),
+            self.custom_data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent
+    for crate::api::keepass_api::types::FrbKeepassMeta
 {
 }
-impl
-    flutter_rust_bridge::IntoIntoDart<
-        crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent,
-    > for crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassMeta>
+    for crate::api::keepass_api::types::FrbKeepassMeta
 {
-    fn into_into_dart(self) -> crate::api::icon_pack_catalog_api::types::FrbIconPackImportEvent {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassMeta {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress
-{
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassOtp {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.current.into_into_dart().into_dart(),
-            self.total.into_into_dart().into_dart(),
-            self.current_file.into_into_dart().into_dart(),
+            self.raw_value.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.issuer.into_into_dart().into_dart(),
+            self.secret.into_into_dart().into_dart(),
+            self.period.into_into_dart().into_dart(),
+            self.digits.into_into_dart().into_dart(),
+            self.algorithm.into_into_dart().into_dart(),
+            self.parse_error.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress
+    for crate::api::keepass_api::types::FrbKeepassOtp
 {
 }
-impl
-    flutter_rust_bridge::IntoIntoDart<
-        crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress,
-    > for crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassOtp>
+    for crate::api::keepass_api::types::FrbKeepassOtp
 {
-    fn into_into_dart(self) -> crate::api::icon_pack_catalog_api::types::FrbIconPackImportProgress {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassOtp {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackSummary
-{
+impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassTimes {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.pack_key.into_into_dart().into_dart(),
-            self.display_name.into_into_dart().into_dart(),
-            self.source_archive_name.into_into_dart().into_dart(),
-            self.imported_at_millis.into_into_dart().into_dart(),
-            self.icon_count.into_into_dart().into_dart(),
+            self.creation.into_into_dart().into_dart(),
+            self.last_modification.into_into_dart().into_dart(),
+            self.last_access.into_into_dart().into_dart(),
+            self.expiry.into_into_dart().into_dart(),
+            self.location_changed.into_into_dart().into_dart(),
+            self.expires.into_into_dart().into_dart(),
+            self.usage_count.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackSummary
+    for crate::api::keepass_api::types::FrbKeepassTimes
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::icon_pack_catalog_api::types::FrbIconPackSummary>
-    for crate::api::icon_pack_catalog_api::types::FrbIconPackSummary
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassTimes>
+    for crate::api::keepass_api::types::FrbKeepassTimes
 {
-    fn into_into_dart(self) -> crate::api::icon_pack_catalog_api::types::FrbIconPackSummary {
+    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassTimes {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassAttachment {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbKeyValue {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.key.into_into_dart().into_dart(),
-            self.size.into_into_dart().into_dart(),
-            self.protected.into_into_dart().into_dart(),
-            self.data.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassAttachment
+    for crate::api::crypt_api::types::FrbKeyValue
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassAttachment>
-    for crate::api::keepass_api::types::FrbKeepassAttachment
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbKeyValue>
+    for crate::api::crypt_api::types::FrbKeyValue
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassAttachment {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbKeyValue {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassAutoType {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbMediaServerInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.enabled.into_into_dart().into_dart(),
-            self.default_sequence.into_into_dart().into_dart(),
-            self.data_transfer_obfuscation.into_into_dart().into_dart(),
-            self.associations.into_into_dart().into_dart(),
+            self.port.into_into_dart().into_dart(),
+            self.token.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassAutoType
+    for crate::api::crypt_api::types::FrbMediaServerInfo
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassAutoType>
-    for crate::api::keepass_api::types::FrbKeepassAutoType
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbMediaServerInfo>
+    for crate::api::crypt_api::types::FrbMediaServerInfo
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassAutoType {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbMediaServerInfo {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart
-    for crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation
-{
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbMediaSource {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.window.into_into_dart().into_dart(),
-            self.sequence.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation
+    for crate::api::crypt_api::types::FrbMediaSource
 {
 }
-impl
-    flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation>
-    for crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbMediaSource>
+    for crate::api::crypt_api::types::FrbMediaSource
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassAutoTypeAssociation {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbMediaSource {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassConfig {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbMetadataPatch {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.database_version.into_into_dart().into_dart(),
-            self.outer_cipher.into_into_dart().into_dart(),
-            self.inner_cipher.into_into_dart().into_dart(),
-            self.compression.into_into_dart().into_dart(),
-            self.kdf_name.into_into_dart().into_dart(),
-            self.kdf_description.into_into_dart().into_dart(),
+            self.remove.into_into_dart().into_dart(),
+            self.set.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassConfig
+    for crate::api::crypt_api::types::FrbMetadataPatch
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassConfig>
-    for crate::api::keepass_api::types::FrbKeepassConfig
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbMetadataPatch>
+    for crate::api::crypt_api::types::FrbMetadataPatch
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassConfig {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbMetadataPatch {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassCustomDataItem {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbMetadataUpdateResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.key.into_into_dart().into_dart(),
-            self.value_kind.into_into_dart().into_dart(),
-            self.string_value.into_into_dart().into_dart(),
-            self.binary_value.into_into_dart().into_dart(),
-            self.last_modification_time.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.in_place.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassCustomDataItem
+    for crate::api::crypt_api::types::FrbMetadataUpdateResult
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassCustomDataItem>
-    for crate::api::keepass_api::types::FrbKeepassCustomDataItem
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbMetadataUpdateResult>
+    for crate::api::crypt_api::types::FrbMetadataUpdateResult
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassCustomDataItem {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbMetadataUpdateResult {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassDatabaseExport {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbProgressEvent {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.source_path.into_into_dart().into_dart(),
-            self.config.into_into_dart().into_dart(),
-            self.meta.into_into_dart().into_dart(),
-            self.root_group_uuid.into_into_dart().into_dart(),
-            self.groups.into_into_dart().into_dart(),
-            self.entries.into_into_dart().into_dart(),
-            self.deleted_objects.into_into_dart().into_dart(),
+            self.stage.into_into_dart().into_dart(),
+            self.bytes_processed.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+            self.percentage.into_into_dart().into_dart(),
+            self.files_processed.into_into_dart().into_dart(),
+            self.total_files.into_into_dart().into_dart(),
+            self.bytes_per_second.into_into_dart().into_dart(),
+            self.eta_seconds.into_into_dart().into_dart(),
+            self.overall_percentage.into_into_dart().into_dart(),
+            self.stage_index.into_into_dart().into_dart(),
+            self.stage_count.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassDatabaseExport
+    for crate::api::crypt_api::types::FrbProgressEvent
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassDatabaseExport>
-    for crate::api::keepass_api::types::FrbKeepassDatabaseExport
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbProgressEvent>
+    for crate::api::crypt_api::types::FrbProgressEvent
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassDatabaseExport {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbProgressEvent {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassDeletedObject {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbProgressStage {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.uuid.into_into_dart().into_dart(),
-            self.deletion_time.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        match self {
+            Self::CompressingDirectory => 0.into_dart(),
+            Self::CompressingGzip => 1.into_dart(),
+            Self::Encrypting => 2.into_dart(),
+            Self::Decrypting => 3.into_dart(),
+            Self::DecompressingGzip => 4.into_dart(),
+            Self::DecompressingDirectory => 5.into_dart(),
+            Self::Done => 6.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassDeletedObject
+    for crate::api::crypt_api::types::FrbProgressStage
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassDeletedObject>
-    for crate::api::keepass_api::types::FrbKeepassDeletedObject
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbProgressStage>
+    for crate::api::crypt_api::types::FrbProgressStage
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassDeletedObject {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbProgressStage {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassEntry {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbReencryptResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.uuid.into_into_dart().into_dart(),
-            self.group_uuid.into_into_dart().into_dart(),
-            self.group_path.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.username.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-            self.url.into_into_dart().into_dart(),
-            self.notes.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-            self.fields.into_into_dart().into_dart(),
-            self.times.into_into_dart().into_dart(),
-            self.custom_data.into_into_dart().into_dart(),
-            self.icon_id.into_into_dart().into_dart(),
-            self.custom_icon_uuid.into_into_dart().into_dart(),
-            self.custom_icon_data.into_into_dart().into_dart(),
-            self.foreground_color.into_into_dart().into_dart(),
-            self.background_color.into_into_dart().into_dart(),
-            self.override_url.into_into_dart().into_dart(),
-            self.quality_check.into_into_dart().into_dart(),
-            self.attachments.into_into_dart().into_dart(),
-            self.autotype.into_into_dart().into_dart(),
-            self.otp.into_into_dart().into_dart(),
-            self.history.into_into_dart().into_dart(),
+            self.output_path.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+            self.source_signature.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassEntry
+    for crate::api::crypt_api::types::FrbReencryptResult
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassEntry>
-    for crate::api::keepass_api::types::FrbKeepassEntry
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbReencryptResult>
+    for crate::api::crypt_api::types::FrbReencryptResult
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassEntry {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbReencryptResult {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassExportOptions {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbShredEntry {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.input_path.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-            self.keyfile_path.into_into_dart().into_dart(),
-            self.include_history.into_into_dart().into_dart(),
-            self.include_attachments.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassExportOptions
+    for crate::api::crypt_api::types::FrbShredEntry
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassExportOptions>
-    for crate::api::keepass_api::types::FrbKeepassExportOptions
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbShredEntry>
+    for crate::api::crypt_api::types::FrbShredEntry
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassExportOptions {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbShredEntry {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassField {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbShredReport {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.key.into_into_dart().into_dart(),
-            self.value.into_into_dart().into_dart(),
-            self.protected.into_into_dart().into_dart(),
+            self.entries.into_into_dart().into_dart(),
+            self.all_shredded.into_into_dart().into_dart(),
+            self.caveat.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassField
+    for crate::api::crypt_api::types::FrbShredReport
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassField>
-    for crate::api::keepass_api::types::FrbKeepassField
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbShredReport>
+    for crate::api::crypt_api::types::FrbShredReport
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassField {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbShredReport {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassGroup {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbSignatureAlgorithm {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.uuid.into_into_dart().into_dart(),
-            self.parent_uuid.into_into_dart().into_dart(),
-            self.is_root.into_into_dart().into_dart(),
-            self.path.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.notes.into_into_dart().into_dart(),
-            self.icon_id.into_into_dart().into_dart(),
-            self.custom_icon_uuid.into_into_dart().into_dart(),
-            self.times.into_into_dart().into_dart(),
-            self.custom_data.into_into_dart().into_dart(),
-            self.is_expanded.into_into_dart().into_dart(),
-            self.default_autotype_sequence.into_into_dart().into_dart(),
-            self.enable_autotype.into_into_dart().into_dart(),
-            self.enable_searching.into_into_dart().into_dart(),
-            self.last_top_visible_entry.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        match self {
+            Self::Ed25519 => 0.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassGroup
+    for crate::api::crypt_api::types::FrbSignatureAlgorithm
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassGroup>
-    for crate::api::keepass_api::types::FrbKeepassGroup
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbSignatureAlgorithm>
+    for crate::api::crypt_api::types::FrbSignatureAlgorithm
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassGroup {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbSignatureAlgorithm {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassHistoryEntry {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbSignatureStatus {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.uuid.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.username.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-            self.url.into_into_dart().into_dart(),
-            self.notes.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-            self.fields.into_into_dart().into_dart(),
-            self.times.into_into_dart().into_dart(),
-            self.custom_data.into_into_dart().into_dart(),
-            self.icon_id.into_into_dart().into_dart(),
-            self.custom_icon_uuid.into_into_dart().into_dart(),
-            self.custom_icon_data.into_into_dart().into_dart(),
-            self.foreground_color.into_into_dart().into_dart(),
-            self.background_color.into_into_dart().into_dart(),
-            self.override_url.into_into_dart().into_dart(),
-            self.quality_check.into_into_dart().into_dart(),
-            self.attachments.into_into_dart().into_dart(),
-            self.autotype.into_into_dart().into_dart(),
-            self.otp.into_into_dart().into_dart(),
+        match self {
+            crate::api::crypt_api::types::FrbSignatureStatus::Unsigned => {
+                [0.into_dart()].into_dart()
+            }
+            crate::api::crypt_api::types::FrbSignatureStatus::Valid { public_key } => {
+                [1.into_dart(), public_key.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::crypt_api::types::FrbSignatureStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbSignatureStatus>
+    for crate::api::crypt_api::types::FrbSignatureStatus
+{
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbSignatureStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbSigningIdentity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.secret_key.into_into_dart().into_dart(),
+            self.public_key.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassHistoryEntry
+    for crate::api::crypt_api::types::FrbSigningIdentity
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassHistoryEntry>
-    for crate::api::keepass_api::types::FrbKeepassHistoryEntry
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbSigningIdentity>
+    for crate::api::crypt_api::types::FrbSigningIdentity
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassHistoryEntry {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbSigningIdentity {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassMemoryProtection {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbSpacePlan {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.protect_title.into_into_dart().into_dart(),
-            self.protect_username.into_into_dart().into_dart(),
-            self.protect_password.into_into_dart().into_dart(),
-            self.protect_url.into_into_dart().into_dart(),
-            self.protect_notes.into_into_dart().into_dart(),
+            self.output_bytes.into_into_dart().into_dart(),
+            self.output_free.into_into_dart().into_dart(),
+            self.temp_bytes.into_into_dart().into_dart(),
+            self.temp_free.into_into_dart().into_dart(),
+            self.shared_volume.into_into_dart().into_dart(),
+            self.estimated.into_into_dart().into_dart(),
+            self.shortfall.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassMemoryProtection
+    for crate::api::crypt_api::types::FrbSpacePlan
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassMemoryProtection>
-    for crate::api::keepass_api::types::FrbKeepassMemoryProtection
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbSpacePlan>
+    for crate::api::crypt_api::types::FrbSpacePlan
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassMemoryProtection {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbSpacePlan {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassMeta {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbSpaceShortfall {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.generator.into_into_dart().into_dart(),
-            self.database_name.into_into_dart().into_dart(),
-            self.database_name_changed.into_into_dart().into_dart(),
-            self.database_description.into_into_dart().into_dart(),
-            self.database_description_changed
-                .into_into_dart()
-                .into_dart(),
-            self.default_username.into_into_dart().into_dart(),
-            self.default_username_changed.into_into_dart().into_dart(),
-            self.maintenance_history_days.into_into_dart().into_dart(),
-            self.color.into_into_dart().into_dart(),
-            self.master_key_changed.into_into_dart().into_dart(),
-            self.master_key_change_rec.into_into_dart().into_dart(),
-            self.master_key_change_force.into_into_dart().into_dart(),
-            self.memory_protection.into_into_dart().into_dart(),
-            self.recyclebin_enabled.into_into_dart().into_dart(),
-            self.recyclebin_uuid.into_into_dart().into_dart(),
-            self.recyclebin_changed.into_into_dart().into_dart(),
-            self.entry_templates_group.into_into_dart().into_dart(),
-            self.entry_templates_group_changed
-                .into_into_dart()
-                .into_dart(),
-            self.last_selected_group.into_into_dart().into_dart(),
-            self.last_top_visible_group.into_into_dart().into_dart(),
-            self.history_max_items.into_into_dart().into_dart(),
-            self.history_max_size.into_into_dart().into_dart(),
-            self.settings_changed.into_into_dart().into_dart(),
-            self.custom_data.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.needed.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassMeta
+    for crate::api::crypt_api::types::FrbSpaceShortfall
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassMeta>
-    for crate::api::keepass_api::types::FrbKeepassMeta
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbSpaceShortfall>
+    for crate::api::crypt_api::types::FrbSpaceShortfall
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassMeta {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbSpaceShortfall {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassOtp {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbStreamDecryptOptions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.raw_value.into_into_dart().into_dart(),
-            self.label.into_into_dart().into_dart(),
-            self.issuer.into_into_dart().into_dart(),
-            self.secret.into_into_dart().into_dart(),
-            self.period.into_into_dart().into_dart(),
-            self.digits.into_into_dart().into_dart(),
-            self.algorithm.into_into_dart().into_dart(),
-            self.parse_error.into_into_dart().into_dart(),
+            self.stream_id.into_into_dart().into_dart(),
+            self.input_path.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.window.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassOtp
+    for crate::api::crypt_api::types::FrbStreamDecryptOptions
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassOtp>
-    for crate::api::keepass_api::types::FrbKeepassOtp
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbStreamDecryptOptions>
+    for crate::api::crypt_api::types::FrbStreamDecryptOptions
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassOtp {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbStreamDecryptOptions {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keepass_api::types::FrbKeepassTimes {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbStreamDecryptResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.creation.into_into_dart().into_dart(),
-            self.last_modification.into_into_dart().into_dart(),
-            self.last_access.into_into_dart().into_dart(),
-            self.expiry.into_into_dart().into_dart(),
-            self.location_changed.into_into_dart().into_dart(),
-            self.expires.into_into_dart().into_dart(),
-            self.usage_count.into_into_dart().into_dart(),
+            self.bytes_written.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.signature.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keepass_api::types::FrbKeepassTimes
+    for crate::api::crypt_api::types::FrbStreamDecryptResult
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keepass_api::types::FrbKeepassTimes>
-    for crate::api::keepass_api::types::FrbKeepassTimes
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbStreamDecryptResult>
+    for crate::api::crypt_api::types::FrbStreamDecryptResult
 {
-    fn into_into_dart(self) -> crate::api::keepass_api::types::FrbKeepassTimes {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbStreamDecryptResult {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbKeyValue {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbStreamEncryptOptions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.key.into_into_dart().into_dart(),
-            self.value.into_into_dart().into_dart(),
+            self.stream_id.into_into_dart().into_dart(),
+            self.output_path.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.original_filename.into_into_dart().into_dart(),
+            self.original_extension.into_into_dart().into_dart(),
+            self.gzip_compressed.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.chunk_size.into_into_dart().into_dart(),
+            self.cipher_suite.into_into_dart().into_dart(),
+            self.signing_key.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbKeyValue
+    for crate::api::crypt_api::types::FrbStreamEncryptOptions
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbKeyValue>
-    for crate::api::crypt_api::types::FrbKeyValue
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbStreamEncryptOptions>
+    for crate::api::crypt_api::types::FrbStreamEncryptOptions
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbKeyValue {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbStreamEncryptOptions {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbProgressEvent {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbStreamEncryptResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.stage.into_into_dart().into_dart(),
-            self.bytes_processed.into_into_dart().into_dart(),
-            self.total_bytes.into_into_dart().into_dart(),
-            self.percentage.into_into_dart().into_dart(),
+            self.output_path.into_into_dart().into_dart(),
+            self.uuid.into_into_dart().into_dart(),
+            self.bytes_read.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbProgressEvent
+    for crate::api::crypt_api::types::FrbStreamEncryptResult
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbProgressEvent>
-    for crate::api::crypt_api::types::FrbProgressEvent
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbStreamEncryptResult>
+    for crate::api::crypt_api::types::FrbStreamEncryptResult
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbProgressEvent {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbStreamEncryptResult {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbProgressStage {
+impl flutter_rust_bridge::IntoDart for crate::api::crypt_api::types::FrbVerifyResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::CompressingDirectory => 0.into_dart(),
-            Self::CompressingGzip => 1.into_dart(),
-            Self::Encrypting => 2.into_dart(),
-            Self::Decrypting => 3.into_dart(),
-            Self::DecompressingGzip => 4.into_dart(),
-            Self::DecompressingDirectory => 5.into_dart(),
-            Self::Done => 6.into_dart(),
-            _ => unreachable!(),
-        }
+        [
+            self.metadata.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+            self.repaired_chunks.into_into_dart().into_dart(),
+            self.signature.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::crypt_api::types::FrbProgressStage
+    for crate::api::crypt_api::types::FrbVerifyResult
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbProgressStage>
-    for crate::api::crypt_api::types::FrbProgressStage
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crypt_api::types::FrbVerifyResult>
+    for crate::api::crypt_api::types::FrbVerifyResult
 {
-    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbProgressStage {
+    fn into_into_dart(self) -> crate::api::crypt_api::types::FrbVerifyResult {
         self
     }
 }
@@ -3797,6 +6122,30 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        crate::api::crypt_api::types::FrbBatchReencryptEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::crypt_api::types::FrbDecryptChunk,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         crate::api::crypt_api::types::FrbDecryptEvent,
@@ -3863,6 +6212,15 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for crate::api::crypt_api::types::FrbArgon2Params {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.t_cost, serializer);
+        <u32>::sse_encode(self.m_cost_kib, serializer);
+        <u32>::sse_encode(self.parallelism, serializer);
+    }
+}
+
 impl SseEncode for crate::api::crypt_api::types::FrbBatchDecryptEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3983,41 +6341,162 @@ impl SseEncode for crate::api::crypt_api::types::FrbBatchEncryptOptions {
         <Option<String>>::sse_encode(self.temp_dir, serializer);
         <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_encode(self.metadata, serializer);
         <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_encode(self.chunk_size, serializer);
+        <crate::api::crypt_api::types::FrbCipherSuite>::sse_encode(self.cipher_suite, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.signing_key, serializer);
+        <bool>::sse_encode(self.hide_lengths, serializer);
+        <Option<u64>>::sse_encode(self.volume_size, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbBatchEncryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::crypt_api::types::FrbEncryptResult>>::sse_encode(
+            self.succeeded,
+            serializer,
+        );
+        <Vec<crate::api::crypt_api::types::FrbBatchError>>::sse_encode(self.failed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbBatchError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.input_path, serializer);
+        <String>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbBatchReencryptEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::crypt_api::types::FrbBatchReencryptEvent::FileProgress {
+                file_index,
+                total_files,
+                current_file,
+                progress,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <u32>::sse_encode(file_index, serializer);
+                <u32>::sse_encode(total_files, serializer);
+                <String>::sse_encode(current_file, serializer);
+                <crate::api::crypt_api::types::FrbProgressEvent>::sse_encode(progress, serializer);
+            }
+            crate::api::crypt_api::types::FrbBatchReencryptEvent::FileDone {
+                file_index,
+                result,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <u32>::sse_encode(file_index, serializer);
+                <crate::api::crypt_api::types::FrbReencryptResult>::sse_encode(result, serializer);
+            }
+            crate::api::crypt_api::types::FrbBatchReencryptEvent::FileError {
+                file_index,
+                input_path,
+                error,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <u32>::sse_encode(file_index, serializer);
+                <String>::sse_encode(input_path, serializer);
+                <String>::sse_encode(error, serializer);
+            }
+            crate::api::crypt_api::types::FrbBatchReencryptEvent::AllDone(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <crate::api::crypt_api::types::FrbBatchReencryptResult>::sse_encode(
+                    field0, serializer,
+                );
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbBatchReencryptOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.input_paths, serializer);
+        <String>::sse_encode(self.old_password, serializer);
+        <String>::sse_encode(self.new_password, serializer);
+        <Option<crate::api::crypt_api::types::FrbArgon2Params>>::sse_encode(
+            self.argon2_params,
+            serializer,
+        );
+        <Option<crate::api::crypt_api::types::FrbChunkSizePreset>>::sse_encode(
+            self.chunk_size,
+            serializer,
+        );
+        <Option<Vec<u8>>>::sse_encode(self.signing_key, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbBatchReencryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::crypt_api::types::FrbReencryptResult>>::sse_encode(
+            self.succeeded,
+            serializer,
+        );
+        <Vec<crate::api::crypt_api::types::FrbBatchError>>::sse_encode(self.failed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbChunkSizePreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::crypt_api::types::FrbChunkSizePreset::Desktop => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::crypt_api::types::FrbChunkSizePreset::Mobile => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::crypt_api::types::FrbChunkSizePreset::Custom(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <u32>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for crate::api::crypt_api::types::FrbBatchEncryptResult {
+impl SseEncode for crate::api::crypt_api::types::FrbCipherSuite {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::api::crypt_api::types::FrbEncryptResult>>::sse_encode(
-            self.succeeded,
+        <i32>::sse_encode(
+            match self {
+                crate::api::crypt_api::types::FrbCipherSuite::XChaCha20Poly1305 => 0,
+                crate::api::crypt_api::types::FrbCipherSuite::Aes256GcmSiv => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
             serializer,
         );
-        <Vec<crate::api::crypt_api::types::FrbBatchError>>::sse_encode(self.failed, serializer);
-    }
-}
-
-impl SseEncode for crate::api::crypt_api::types::FrbBatchError {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.input_path, serializer);
-        <String>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode for crate::api::crypt_api::types::FrbChunkSizePreset {
+impl SseEncode for crate::api::crypt_api::types::FrbDecryptChunk {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            crate::api::crypt_api::types::FrbChunkSizePreset::Desktop => {
+            crate::api::crypt_api::types::FrbDecryptChunk::Data(field0) => {
                 <i32>::sse_encode(0, serializer);
+                <Vec<u8>>::sse_encode(field0, serializer);
             }
-            crate::api::crypt_api::types::FrbChunkSizePreset::Mobile => {
+            crate::api::crypt_api::types::FrbDecryptChunk::Done(field0) => {
                 <i32>::sse_encode(1, serializer);
+                <crate::api::crypt_api::types::FrbStreamDecryptResult>::sse_encode(
+                    field0, serializer,
+                );
             }
-            crate::api::crypt_api::types::FrbChunkSizePreset::Custom(field0) => {
+            crate::api::crypt_api::types::FrbDecryptChunk::Error(field0) => {
                 <i32>::sse_encode(2, serializer);
-                <u32>::sse_encode(field0, serializer);
+                <String>::sse_encode(field0, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -4065,6 +6544,25 @@ impl SseEncode for crate::api::crypt_api::types::FrbDecryptResult {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.output_path, serializer);
         <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_encode(self.metadata, serializer);
+        <crate::api::crypt_api::types::FrbSignatureStatus>::sse_encode(self.signature, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbDecryptedCacheOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.root_dir, serializer);
+        <Option<u64>>::sse_encode(self.max_bytes, serializer);
+        <Option<u64>>::sse_encode(self.ttl_secs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbDecryptedCacheStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.root_dir, serializer);
+        <u64>::sse_encode(self.entry_count, serializer);
+        <u64>::sse_encode(self.total_bytes, serializer);
     }
 }
 
@@ -4077,6 +6575,7 @@ impl SseEncode for crate::api::crypt_api::types::FrbDecryptedMetadata {
         <u64>::sse_encode(self.original_size, serializer);
         <String>::sse_encode(self.uuid, serializer);
         <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_encode(self.metadata, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.signer_public_key, serializer);
     }
 }
 
@@ -4115,6 +6614,11 @@ impl SseEncode for crate::api::crypt_api::types::FrbEncryptOptions {
         <Option<String>>::sse_encode(self.temp_dir, serializer);
         <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_encode(self.metadata, serializer);
         <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_encode(self.chunk_size, serializer);
+        <crate::api::crypt_api::types::FrbCipherSuite>::sse_encode(self.cipher_suite, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.signing_key, serializer);
+        <bool>::sse_encode(self.shred_source, serializer);
+        <bool>::sse_encode(self.hide_lengths, serializer);
+        <Option<u64>>::sse_encode(self.volume_size, serializer);
     }
 }
 
@@ -4122,8 +6626,18 @@ impl SseEncode for crate::api::crypt_api::types::FrbEncryptResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.output_path, serializer);
+        <Vec<String>>::sse_encode(self.volumes, serializer);
         <String>::sse_encode(self.uuid, serializer);
         <u64>::sse_encode(self.original_size, serializer);
+        <Option<crate::api::crypt_api::types::FrbShredReport>>::sse_encode(self.shred, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbFecParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.data_shards, serializer);
+        <u8>::sse_encode(self.parity_shards, serializer);
     }
 }
 
@@ -4198,6 +6712,27 @@ impl SseEncode for crate::api::icon_pack_catalog_api::types::FrbIconPackSummary
     }
 }
 
+impl SseEncode for crate::api::crypt_api::types::FrbInspectResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u16>::sse_encode(self.version, serializer);
+        <crate::api::crypt_api::types::FrbArgon2Params>::sse_encode(self.argon2_params, serializer);
+        <u32>::sse_encode(self.chunk_size, serializer);
+        <Option<crate::api::crypt_api::types::FrbFecParams>>::sse_encode(self.fec, serializer);
+        <Option<crate::api::crypt_api::types::FrbSignatureAlgorithm>>::sse_encode(
+            self.signature,
+            serializer,
+        );
+        <crate::api::crypt_api::types::FrbCipherSuite>::sse_encode(self.cipher, serializer);
+        <bool>::sse_encode(self.key_commitment, serializer);
+        <u64>::sse_encode(self.file_len, serializer);
+        <u64>::sse_encode(self.ciphertext_len, serializer);
+        <u64>::sse_encode(self.chunk_count, serializer);
+        <u64>::sse_encode(self.estimated_plaintext_size, serializer);
+        <bool>::sse_encode(self.whole_chunks, serializer);
+    }
+}
+
 impl SseEncode for crate::api::keepass_api::types::FrbKeepassAttachment {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4481,6 +7016,41 @@ impl SseEncode for crate::api::crypt_api::types::FrbKeyValue {
     }
 }
 
+impl SseEncode for crate::api::crypt_api::types::FrbMediaServerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u16>::sse_encode(self.port, serializer);
+        <String>::sse_encode(self.token, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbMediaSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.source_id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_encode(self.metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbMetadataPatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.remove, serializer);
+        <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_encode(self.set, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbMetadataUpdateResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_encode(self.metadata, serializer);
+        <bool>::sse_encode(self.in_place, serializer);
+    }
+}
+
 impl SseEncode for crate::api::crypt_api::types::FrbProgressEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4488,6 +7058,13 @@ impl SseEncode for crate::api::crypt_api::types::FrbProgressEvent {
         <u64>::sse_encode(self.bytes_processed, serializer);
         <u64>::sse_encode(self.total_bytes, serializer);
         <f64>::sse_encode(self.percentage, serializer);
+        <u64>::sse_encode(self.files_processed, serializer);
+        <u64>::sse_encode(self.total_files, serializer);
+        <f64>::sse_encode(self.bytes_per_second, serializer);
+        <Option<f64>>::sse_encode(self.eta_seconds, serializer);
+        <f64>::sse_encode(self.overall_percentage, serializer);
+        <u32>::sse_encode(self.stage_index, serializer);
+        <u32>::sse_encode(self.stage_count, serializer);
     }
 }
 
@@ -4512,6 +7089,155 @@ impl SseEncode for crate::api::crypt_api::types::FrbProgressStage {
     }
 }
 
+impl SseEncode for crate::api::crypt_api::types::FrbReencryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.output_path, serializer);
+        <u64>::sse_encode(self.chunk_count, serializer);
+        <crate::api::crypt_api::types::FrbSignatureStatus>::sse_encode(
+            self.source_signature,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbShredEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbShredReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::crypt_api::types::FrbShredEntry>>::sse_encode(self.entries, serializer);
+        <bool>::sse_encode(self.all_shredded, serializer);
+        <String>::sse_encode(self.caveat, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbSignatureAlgorithm {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::crypt_api::types::FrbSignatureAlgorithm::Ed25519 => 0,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbSignatureStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::crypt_api::types::FrbSignatureStatus::Unsigned => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::crypt_api::types::FrbSignatureStatus::Valid { public_key } => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<u8>>::sse_encode(public_key, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbSigningIdentity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.secret_key, serializer);
+        <Vec<u8>>::sse_encode(self.public_key, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbSpacePlan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.output_bytes, serializer);
+        <Option<u64>>::sse_encode(self.output_free, serializer);
+        <u64>::sse_encode(self.temp_bytes, serializer);
+        <Option<u64>>::sse_encode(self.temp_free, serializer);
+        <bool>::sse_encode(self.shared_volume, serializer);
+        <bool>::sse_encode(self.estimated, serializer);
+        <Option<crate::api::crypt_api::types::FrbSpaceShortfall>>::sse_encode(
+            self.shortfall,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbSpaceShortfall {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <u64>::sse_encode(self.needed, serializer);
+        <u64>::sse_encode(self.available, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbStreamDecryptOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.stream_id, serializer);
+        <String>::sse_encode(self.input_path, serializer);
+        <String>::sse_encode(self.password, serializer);
+        <u32>::sse_encode(self.window, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbStreamDecryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.bytes_written, serializer);
+        <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_encode(self.metadata, serializer);
+        <crate::api::crypt_api::types::FrbSignatureStatus>::sse_encode(self.signature, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbStreamEncryptOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.stream_id, serializer);
+        <String>::sse_encode(self.output_path, serializer);
+        <String>::sse_encode(self.password, serializer);
+        <String>::sse_encode(self.original_filename, serializer);
+        <String>::sse_encode(self.original_extension, serializer);
+        <bool>::sse_encode(self.gzip_compressed, serializer);
+        <Vec<crate::api::crypt_api::types::FrbKeyValue>>::sse_encode(self.metadata, serializer);
+        <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_encode(self.chunk_size, serializer);
+        <crate::api::crypt_api::types::FrbCipherSuite>::sse_encode(self.cipher_suite, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.signing_key, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbStreamEncryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.output_path, serializer);
+        <String>::sse_encode(self.uuid, serializer);
+        <u64>::sse_encode(self.bytes_read, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crypt_api::types::FrbVerifyResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::crypt_api::types::FrbDecryptedMetadata>::sse_encode(self.metadata, serializer);
+        <u64>::sse_encode(self.chunk_count, serializer);
+        <u64>::sse_encode(self.repaired_chunks, serializer);
+        <crate::api::crypt_api::types::FrbSignatureStatus>::sse_encode(self.signature, serializer);
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4684,6 +7410,26 @@ impl SseEncode for Vec<crate::api::crypt_api::types::FrbKeyValue> {
     }
 }
 
+impl SseEncode for Vec<crate::api::crypt_api::types::FrbReencryptResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::crypt_api::types::FrbReencryptResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::crypt_api::types::FrbShredEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::crypt_api::types::FrbShredEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4724,6 +7470,46 @@ impl SseEncode for Option<bool> {
     }
 }
 
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::crypt_api::types::FrbArgon2Params> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::crypt_api::types::FrbArgon2Params>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::crypt_api::types::FrbChunkSizePreset> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::crypt_api::types::FrbChunkSizePreset>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::crypt_api::types::FrbFecParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::crypt_api::types::FrbFecParams>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::keepass_api::types::FrbKeepassAutoType> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4756,6 +7542,36 @@ impl SseEncode for Option<crate::api::keepass_api::types::FrbKeepassOtp> {
     }
 }
 
+impl SseEncode for Option<crate::api::crypt_api::types::FrbShredReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::crypt_api::types::FrbShredReport>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::crypt_api::types::FrbSignatureAlgorithm> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::crypt_api::types::FrbSignatureAlgorithm>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::crypt_api::types::FrbSpaceShortfall> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::crypt_api::types::FrbSpaceShortfall>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4796,6 +7612,13 @@ impl SseEncode for Option<Vec<u8>> {
     }
 }
 
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
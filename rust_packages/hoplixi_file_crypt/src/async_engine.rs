@@ -0,0 +1,291 @@
+//! Async front end for the synchronous [`FileCrypt`] engine.
+//!
+//! Enabled by the `async` feature. Every method runs the matching
+//! [`FileCrypt`] method on a tokio blocking worker, so file I/O, key
+//! derivation and AEAD never stall the runtime.
+
+use std::path::PathBuf;
+
+use crate::crypto::cipher::CipherSuite;
+use crate::crypto::kdf;
+use crate::engine::{
+    DecryptOptions, DecryptResult, EncryptOptions, EncryptResult, FileCrypt,
+    InspectResult, MemoryDecryptResult, MetadataPatch,
+    MetadataUpdateResult, ReencryptParams, RekeyOptions, RekeyResult,
+    RepairResult, SpacePlan, VerifyResult,
+};
+use crate::error::{CryptError, Result};
+use crate::header::encrypted::EncryptedMetadata;
+use crate::random_access::RandomAccessReader;
+
+/// Run file I/O and AEAD work on a blocking worker, so the async
+/// runtime and the other tasks on it are never stalled.
+pub(crate) async fn blocking<T: Send + 'static>(
+    work: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    tokio::task::spawn_blocking(work).await.map_err(|e| {
+        CryptError::Io(std::io::Error::other(format!("Join: {e}")))
+    })?
+}
+
+/// [`FileCrypt`] for async callers.
+///
+/// Cheap to clone; configured like [`FileCrypt`] and convertible
+/// from one.
+#[derive(Debug, Clone, Default)]
+pub struct AsyncFileCrypt {
+    inner: FileCrypt,
+}
+
+impl From<FileCrypt> for AsyncFileCrypt {
+    fn from(inner: FileCrypt) -> Self {
+        Self { inner }
+    }
+}
+
+impl AsyncFileCrypt {
+    /// Create with custom chunk size.
+    pub fn with_chunk_size(chunk_size: u32) -> Self {
+        FileCrypt::with_chunk_size(chunk_size).into()
+    }
+
+    /// Create with custom Argon2 parameters.
+    pub fn with_argon2_params(params: kdf::Argon2Params) -> Self {
+        FileCrypt::with_argon2_params(params).into()
+    }
+
+    /// Create with both custom chunk size and Argon2 params.
+    pub fn new(chunk_size: u32, argon2_params: kdf::Argon2Params) -> Self {
+        FileCrypt::new(chunk_size, argon2_params).into()
+    }
+
+    /// Use `suite` for files encrypted from now on.
+    pub fn with_cipher_suite(self, suite: CipherSuite) -> Self {
+        self.inner.with_cipher_suite(suite).into()
+    }
+
+    /// The synchronous engine underneath.
+    pub fn engine(&self) -> &FileCrypt {
+        &self.inner
+    }
+
+    /// See [`FileCrypt::encrypt`].
+    pub async fn encrypt(
+        &self,
+        opts: EncryptOptions,
+    ) -> Result<EncryptResult> {
+        let engine = self.inner.clone();
+        blocking(move || engine.encrypt(opts)).await
+    }
+
+    /// See [`FileCrypt::decrypt`].
+    pub async fn decrypt(
+        &self,
+        opts: DecryptOptions,
+    ) -> Result<DecryptResult> {
+        let engine = self.inner.clone();
+        blocking(move || engine.decrypt(opts)).await
+    }
+
+    /// See [`FileCrypt::plan_encrypt`]. Takes the options by value
+    /// to move them to the worker.
+    pub async fn plan_encrypt(
+        &self,
+        opts: EncryptOptions,
+    ) -> Result<SpacePlan> {
+        let engine = self.inner.clone();
+        blocking(move || engine.plan_encrypt(&opts)).await
+    }
+
+    /// See [`FileCrypt::plan_decrypt`]. Takes the options by value
+    /// to move them to the worker.
+    pub async fn plan_decrypt(
+        &self,
+        opts: DecryptOptions,
+    ) -> Result<SpacePlan> {
+        let engine = self.inner.clone();
+        blocking(move || engine.plan_decrypt(&opts)).await
+    }
+
+    /// See [`FileCrypt::decrypt_to_memory`].
+    pub async fn decrypt_to_memory(
+        &self,
+        input_path: PathBuf,
+        password: String,
+        max_size: u64,
+    ) -> Result<MemoryDecryptResult> {
+        let engine = self.inner.clone();
+        blocking(move || {
+            engine.decrypt_to_memory(input_path, password, max_size)
+        })
+        .await
+    }
+
+    /// See [`FileCrypt::inspect`].
+    pub async fn inspect(&self, input_path: PathBuf) -> Result<InspectResult> {
+        let engine = self.inner.clone();
+        blocking(move || engine.inspect(input_path)).await
+    }
+
+    /// See [`FileCrypt::decrypt_header`].
+    pub async fn decrypt_header(
+        &self,
+        input_path: PathBuf,
+        password: String,
+    ) -> Result<EncryptedMetadata> {
+        let engine = self.inner.clone();
+        blocking(move || engine.decrypt_header(input_path, password)).await
+    }
+
+    /// See [`FileCrypt::update_metadata`].
+    pub async fn update_metadata(
+        &self,
+        input_path: PathBuf,
+        password: String,
+        patch: MetadataPatch,
+    ) -> Result<MetadataUpdateResult> {
+        let engine = self.inner.clone();
+        blocking(move || engine.update_metadata(input_path, password, patch))
+            .await
+    }
+
+    /// See [`FileCrypt::open_random_access`].
+    pub async fn open_random_access(
+        &self,
+        input_path: PathBuf,
+        password: String,
+    ) -> Result<RandomAccessReader> {
+        let engine = self.inner.clone();
+        blocking(move || engine.open_random_access(input_path, password))
+            .await
+    }
+
+    /// See [`FileCrypt::verify`].
+    pub async fn verify(
+        &self,
+        input_path: PathBuf,
+        password: String,
+    ) -> Result<VerifyResult> {
+        let engine = self.inner.clone();
+        blocking(move || engine.verify(input_path, password)).await
+    }
+
+    /// See [`FileCrypt::repair`].
+    pub async fn repair(
+        &self,
+        input_path: PathBuf,
+        password: String,
+    ) -> Result<RepairResult> {
+        let engine = self.inner.clone();
+        blocking(move || engine.repair(input_path, password)).await
+    }
+
+    /// See [`FileCrypt::rekey`].
+    pub async fn rekey(&self, opts: RekeyOptions) -> Result<RekeyResult> {
+        let engine = self.inner.clone();
+        blocking(move || engine.rekey(opts)).await
+    }
+
+    /// See [`FileCrypt::reencrypt`].
+    pub async fn reencrypt(
+        &self,
+        input_path: PathBuf,
+        old_password: String,
+        new_password: String,
+        params: ReencryptParams,
+    ) -> Result<RekeyResult> {
+        let engine = self.inner.clone();
+        blocking(move || {
+            engine.reencrypt(input_path, old_password, new_password, params)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn encrypt_opts(
+        input: &Path,
+        dir: &Path,
+        password: &str,
+    ) -> EncryptOptions {
+        EncryptOptions {
+            input_path: input.to_path_buf(),
+            output_dir: dir.to_path_buf(),
+            uuid: None,
+            password: password.to_string(),
+            progress: None,
+            gzip_compressed: false,
+            output_extension: None,
+            temp_dir: None,
+            metadata: None,
+            fec: None,
+            signer: None,
+            recipient: None,
+            shred_source: false,
+            hide_lengths: false,
+            volume_size: None,
+            filter: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_matches_sync_engine() {
+        let dir = TempDir::new().unwrap();
+        let input = dir.path().join("notes.txt");
+        let data = b"async front end ".repeat(500);
+        std::fs::write(&input, &data).unwrap();
+
+        let crypt = AsyncFileCrypt::with_chunk_size(1024);
+        let enc = crypt
+            .encrypt(encrypt_opts(&input, dir.path(), "pw"))
+            .await
+            .unwrap();
+
+        // The sync engine reads what the async one wrote.
+        let meta = crypt
+            .engine()
+            .decrypt_header(enc.output_path.clone(), "pw".to_string())
+            .unwrap();
+        assert_eq!(meta.original_filename, "notes");
+
+        let out = crypt
+            .decrypt_to_memory(
+                enc.output_path.clone(),
+                "pw".to_string(),
+                1 << 20,
+            )
+            .await
+            .unwrap();
+        assert_eq!(out.data, data);
+
+        let verified = crypt
+            .verify(enc.output_path, "pw".to_string())
+            .await
+            .unwrap();
+        assert_eq!(verified.metadata.original_size, data.len() as u64);
+    }
+
+    #[tokio::test]
+    async fn test_errors_pass_through() {
+        let dir = TempDir::new().unwrap();
+        let input = dir.path().join("a.bin");
+        std::fs::write(&input, [1u8; 64]).unwrap();
+
+        let crypt = AsyncFileCrypt::default();
+        let enc = crypt
+            .encrypt(encrypt_opts(&input, dir.path(), "right"))
+            .await
+            .unwrap();
+
+        let err = crypt
+            .decrypt_header(enc.output_path, "wrong".to_string())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CryptError::InvalidPassword));
+    }
+}
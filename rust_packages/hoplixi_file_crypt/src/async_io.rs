@@ -0,0 +1,679 @@
+//! Encryption and decryption over `tokio::io`.
+//!
+//! [`AsyncEncryptWriter`] and [`AsyncDecryptReader`] write and read
+//! the same stream format as [`FileCrypt::encrypt_stream`] and
+//! [`FileCrypt::decrypt_stream`], without ever blocking the runtime:
+//! key derivation, AEAD, parity and gzip run on blocking workers,
+//! one batch of chunks at a time, while the inner stream is polled
+//! on the task itself.
+//!
+//! Errors surface as [`io::Error`]s wrapping the [`CryptError`];
+//! I/O errors of the inner stream are passed through as they are.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
+use tokio::task::JoinHandle;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::async_engine::blocking;
+use crate::config::{
+    HEADER_EXT_LEN_SIZE, MAGIC, MAX_HEADER_EXT_LEN, PUBLIC_HEADER_SIZE,
+    TAG_LEN,
+};
+use crate::engine::FileCrypt;
+use crate::error::{CryptError, Result};
+use crate::header::encrypted::EncryptedMetadata;
+use crate::header::public::PublicHeader;
+use crate::memory::{ChunkDecryptor, ChunkEncryptor};
+use crate::progress::{ProgressStage, ProgressTracker};
+use crate::types::{
+    SignatureStatus, StreamDecryptOptions, StreamEncryptOptions,
+};
+
+/// Turn an error of a worker into the error of an I/O trait method.
+fn into_io(error: CryptError) -> io::Error {
+    match error {
+        CryptError::Io(e) => e,
+        e => io::Error::other(e),
+    }
+}
+
+fn join_error(error: tokio::task::JoinError) -> io::Error {
+    io::Error::other(format!("Join: {error}"))
+}
+
+fn failed() -> io::Error {
+    io::Error::other("the stream failed earlier")
+}
+
+// ── Encryption ───────────────────────────────────────────
+
+type EncodeJob = JoinHandle<(Box<ChunkEncryptor>, Result<Vec<u8>>)>;
+
+enum EncodeState {
+    /// Waiting for plaintext.
+    Idle(Box<ChunkEncryptor>),
+    /// A worker encrypts a batch; `last` once it finishes the stream.
+    Busy { job: EncodeJob, last: bool },
+    /// Writing the output of a batch to the inner writer.
+    Output {
+        encoder: Box<ChunkEncryptor>,
+        out: Vec<u8>,
+        pos: usize,
+        last: bool,
+    },
+    /// Everything is written; only the inner writer is shut down.
+    Finished,
+    Failed,
+}
+
+/// Encrypts everything written to it into an inner [`AsyncWrite`].
+///
+/// The header is written by [`AsyncEncryptWriter::new`]. Plaintext
+/// is encrypted a chunk at a time on a blocking worker while the
+/// next chunk is accepted. [`AsyncWriteExt::shutdown`] must be
+/// called at the end: it encrypts the last, short chunk, writes
+/// parity and the signature and shuts the inner writer down.
+/// Without it the stream is truncated and will not decrypt. A flush
+/// cannot push out a partial chunk, as that would change the
+/// chunking.
+pub struct AsyncEncryptWriter<W> {
+    inner: W,
+    state: EncodeState,
+    uuid: String,
+    /// Plaintext accepted but not yet handed to a worker.
+    input: Zeroizing<Vec<u8>>,
+    batch_len: usize,
+    bytes_written: u64,
+    progress: ProgressTracker,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncEncryptWriter<W> {
+    /// Derive the keys, seal the header with `engine`'s settings and
+    /// write it to `inner`.
+    ///
+    /// Options are those of [`FileCrypt::encrypt_stream`].
+    pub async fn new(
+        engine: &FileCrypt,
+        mut inner: W,
+        mut opts: StreamEncryptOptions,
+    ) -> Result<Self> {
+        let progress = opts.progress.take();
+        let engine = engine.clone();
+        let encoder =
+            blocking(move || ChunkEncryptor::new(&engine, opts)).await?;
+        inner.write_all(encoder.prefix()).await?;
+
+        let progress =
+            ProgressTracker::new(progress, vec![ProgressStage::Encrypting]);
+        progress.stage(ProgressStage::Encrypting, 0, 0);
+
+        let chunk_size = encoder.chunk_size();
+        Ok(Self {
+            inner,
+            uuid: encoder.uuid().to_string(),
+            state: EncodeState::Idle(Box::new(encoder)),
+            input: Zeroizing::new(Vec::with_capacity(chunk_size)),
+            batch_len: chunk_size,
+            bytes_written: 0,
+            progress,
+        })
+    }
+}
+
+impl<W> AsyncEncryptWriter<W> {
+    /// UUID recorded in the encrypted stream.
+    pub fn uuid(&self) -> &str {
+        &self.uuid
+    }
+
+    /// Plaintext bytes accepted so far, before compression.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// The inner writer. Only complete once the writer was shut down.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Hand the buffered plaintext to a worker; `last` also finishes
+    /// the stream. Only called when idle.
+    fn submit(&mut self, last: bool) {
+        let EncodeState::Idle(mut encoder) =
+            std::mem::replace(&mut self.state, EncodeState::Failed)
+        else {
+            unreachable!("a batch is submitted only when idle");
+        };
+        let input = std::mem::take(&mut self.input);
+        let job = tokio::task::spawn_blocking(move || {
+            let mut out = encoder.push(&input);
+            if last && let Ok(out) = out.as_mut() {
+                match encoder.finish() {
+                    Ok(rest) => out.extend_from_slice(&rest),
+                    Err(e) => return (encoder, Err(e)),
+                }
+            }
+            (encoder, out)
+        });
+        self.state = EncodeState::Busy { job, last };
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncEncryptWriter<W> {
+    /// Drive the current batch until the writer is idle again (or
+    /// finished), writing its output to the inner writer.
+    fn poll_idle(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            match &mut self.state {
+                EncodeState::Idle(_) | EncodeState::Finished => {
+                    return Poll::Ready(Ok(()));
+                }
+                EncodeState::Failed => return Poll::Ready(Err(failed())),
+                EncodeState::Busy { job, last } => {
+                    let last = *last;
+                    let joined = ready!(Pin::new(job).poll(cx));
+                    self.state = EncodeState::Failed;
+                    let (encoder, out) = joined.map_err(join_error)?;
+                    self.state = EncodeState::Output {
+                        encoder,
+                        out: out.map_err(into_io)?,
+                        pos: 0,
+                        last,
+                    };
+                }
+                EncodeState::Output {
+                    out, pos, last, ..
+                } => {
+                    while *pos < out.len() {
+                        let written = ready!(
+                            Pin::new(&mut self.inner)
+                                .poll_write(cx, &out[*pos..])
+                        );
+                        match written {
+                            Ok(0) => {
+                                self.state = EncodeState::Failed;
+                                return Poll::Ready(Err(
+                                    io::ErrorKind::WriteZero.into(),
+                                ));
+                            }
+                            Ok(n) => *pos += n,
+                            Err(e) => {
+                                self.state = EncodeState::Failed;
+                                return Poll::Ready(Err(e));
+                            }
+                        }
+                    }
+                    let last = *last;
+                    let state = std::mem::replace(
+                        &mut self.state,
+                        EncodeState::Failed,
+                    );
+                    self.state = match state {
+                        EncodeState::Output { .. } if last => {
+                            EncodeState::Finished
+                        }
+                        EncodeState::Output { encoder, .. } => {
+                            EncodeState::Idle(encoder)
+                        }
+                        _ => unreachable!(),
+                    };
+                }
+            }
+        }
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for AsyncEncryptWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_idle(cx))?;
+        if matches!(this.state, EncodeState::Finished) {
+            return Poll::Ready(Err(io::Error::other(
+                "write after shutdown",
+            )));
+        }
+
+        let n = buf.len().min(this.batch_len - this.input.len());
+        this.input.extend_from_slice(&buf[..n]);
+        this.bytes_written += n as u64;
+        this.progress.update(this.bytes_written, 0);
+        // Encrypt while the caller produces the next batch.
+        if this.input.len() == this.batch_len {
+            this.submit(false);
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_idle(cx))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            ready!(this.poll_idle(cx))?;
+            if let EncodeState::Finished = this.state {
+                ready!(Pin::new(&mut this.inner).poll_shutdown(cx))?;
+                this.progress.done();
+                return Poll::Ready(Ok(()));
+            }
+            this.submit(true);
+        }
+    }
+}
+
+// ── Decryption ───────────────────────────────────────────
+
+/// Plaintext of a batch, and the signature check after the last.
+type DecodeOutput = (Vec<u8>, Option<SignatureStatus>);
+
+type DecodeJob = JoinHandle<(Box<ChunkDecryptor>, Result<DecodeOutput>)>;
+
+enum DecodeState {
+    /// Collecting the next batch of ciphertext.
+    Idle(Box<ChunkDecryptor>),
+    /// A worker decrypts a batch.
+    Busy(DecodeJob),
+    /// The end of the stream was decrypted and authenticated.
+    Finished,
+    Failed,
+}
+
+/// Decrypts an encrypted stream read from an inner [`AsyncRead`].
+///
+/// The header is read and the keys derived by
+/// [`AsyncDecryptReader::new`]. Ciphertext is read a parity group
+/// (or chunk) at a time and decrypted on a blocking worker; gzip is
+/// undone on the fly and a directory archive comes out as the raw
+/// 7z bytes.
+///
+/// As with [`FileCrypt::decrypt_stream`], plaintext is returned
+/// before the end of the stream, and with it the signature, has
+/// been checked: only a read that reached the end (`Ok(0)`) vouches
+/// for everything read before it. On error, discard the output.
+pub struct AsyncDecryptReader<R> {
+    inner: R,
+    state: DecodeState,
+    metadata: EncryptedMetadata,
+    signature: Option<SignatureStatus>,
+    /// Ciphertext read but not yet handed to a worker.
+    input: Vec<u8>,
+    batch_len: usize,
+    eof: bool,
+    /// Decrypted plaintext not yet returned, from `pos` on.
+    output: Zeroizing<Vec<u8>>,
+    pos: usize,
+    bytes_read: u64,
+    progress: ProgressTracker,
+}
+
+impl<R: AsyncRead + Unpin> AsyncDecryptReader<R> {
+    /// Read the header from `inner`, derive the keys and decrypt the
+    /// metadata.
+    pub async fn new(
+        mut inner: R,
+        opts: StreamDecryptOptions,
+    ) -> Result<Self> {
+        let (header, sealed_meta) = read_prefix(&mut inner).await?;
+        let enc = header.chunk_size as usize + TAG_LEN;
+        let batch_len = enc * header.fec.map_or(1, |p| p.group_len());
+        let decoder = blocking(move || {
+            ChunkDecryptor::from_parts(
+                header,
+                sealed_meta,
+                opts.password,
+                opts.identity.as_ref(),
+            )
+        })
+        .await?;
+        let metadata = decoder.metadata().clone();
+
+        let progress = ProgressTracker::new(
+            opts.progress,
+            vec![ProgressStage::Decrypting],
+        );
+        progress.stage(ProgressStage::Decrypting, metadata.original_size, 0);
+
+        Ok(Self {
+            inner,
+            state: DecodeState::Idle(Box::new(decoder)),
+            metadata,
+            signature: None,
+            input: Vec::with_capacity(batch_len),
+            batch_len,
+            eof: false,
+            output: Zeroizing::new(Vec::new()),
+            pos: 0,
+            bytes_read: 0,
+            progress,
+        })
+    }
+}
+
+impl<R> AsyncDecryptReader<R> {
+    /// Metadata from the encrypted header.
+    pub fn metadata(&self) -> &EncryptedMetadata {
+        &self.metadata
+    }
+
+    /// Signature check outcome, known once the end of the stream was
+    /// read.
+    pub fn signature(&self) -> Option<SignatureStatus> {
+        self.signature
+    }
+
+    /// Plaintext bytes returned so far, after decompression.
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Hand the collected ciphertext to a worker, finishing the
+    /// stream at its end. Only called when idle.
+    fn submit(&mut self) {
+        let DecodeState::Idle(mut decoder) =
+            std::mem::replace(&mut self.state, DecodeState::Failed)
+        else {
+            unreachable!("a batch is submitted only when idle");
+        };
+        let input = std::mem::take(&mut self.input);
+        let last = self.eof;
+        let job = tokio::task::spawn_blocking(move || {
+            let result = decoder.push(&input).and_then(|mut out| {
+                if !last {
+                    return Ok((out, None));
+                }
+                let (rest, signature) = decoder.finish()?;
+                out.extend_from_slice(&rest);
+                Ok((out, Some(signature)))
+            });
+            (decoder, result)
+        });
+        self.state = DecodeState::Busy(job);
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for AsyncDecryptReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if this.pos < this.output.len() {
+                let n = buf.remaining().min(this.output.len() - this.pos);
+                buf.put_slice(&this.output[this.pos..this.pos + n]);
+                this.pos += n;
+                this.bytes_read += n as u64;
+                this.progress.update(this.bytes_read, 0);
+                return Poll::Ready(Ok(()));
+            }
+
+            match &mut this.state {
+                DecodeState::Finished => return Poll::Ready(Ok(())),
+                DecodeState::Failed => return Poll::Ready(Err(failed())),
+                DecodeState::Busy(job) => {
+                    let joined = ready!(Pin::new(job).poll(cx));
+                    this.state = DecodeState::Failed;
+                    let (decoder, result) = joined.map_err(join_error)?;
+                    let (out, signature) = result.map_err(into_io)?;
+                    this.output.zeroize();
+                    *this.output = out;
+                    this.pos = 0;
+                    this.state = match signature {
+                        Some(signature) => {
+                            this.signature = Some(signature);
+                            this.progress.done();
+                            DecodeState::Finished
+                        }
+                        None => DecodeState::Idle(decoder),
+                    };
+                }
+                DecodeState::Idle(_) => {
+                    while !this.eof && this.input.len() < this.batch_len {
+                        let filled = this.input.len();
+                        this.input.resize(this.batch_len, 0);
+                        let mut read = ReadBuf::new(&mut this.input[filled..]);
+                        let polled =
+                            Pin::new(&mut this.inner).poll_read(cx, &mut read);
+                        let n = read.filled().len();
+                        this.input.truncate(filled + n);
+                        match polled {
+                            Poll::Pending => return Poll::Pending,
+                            Poll::Ready(Err(e)) => {
+                                this.state = DecodeState::Failed;
+                                return Poll::Ready(Err(e));
+                            }
+                            Poll::Ready(Ok(())) => this.eof = n == 0,
+                        }
+                    }
+                    this.submit();
+                }
+            }
+        }
+    }
+}
+
+/// Read the public header and the sealed metadata without blocking.
+async fn read_prefix<R: AsyncRead + Unpin>(
+    inner: &mut R,
+) -> Result<(PublicHeader, Vec<u8>)> {
+    let mut prefix = vec![0u8; PUBLIC_HEADER_SIZE];
+    inner.read_exact(&mut prefix).await?;
+    if &prefix[..MAGIC.len()] != MAGIC {
+        return Err(CryptError::InvalidMagic);
+    }
+
+    // Version 2 and later append a length-prefixed extension area;
+    // its bounds are checked before anything is allocated for it.
+    let version =
+        u16::from_le_bytes([prefix[MAGIC.len()], prefix[MAGIC.len() + 1]]);
+    if version >= 2 {
+        let mut len = [0u8; HEADER_EXT_LEN_SIZE];
+        inner.read_exact(&mut len).await?;
+        let ext_len = u32::from_le_bytes(len);
+        if ext_len > MAX_HEADER_EXT_LEN {
+            return Err(CryptError::InvalidHeader(format!(
+                "extension length {ext_len} exceeds \
+                 max {MAX_HEADER_EXT_LEN}"
+            )));
+        }
+        prefix.extend_from_slice(&len);
+        let start = prefix.len();
+        prefix.resize(start + ext_len as usize, 0);
+        inner.read_exact(&mut prefix[start..]).await?;
+    }
+
+    let header = PublicHeader::read_from(&mut prefix.as_slice())?;
+    let mut sealed_meta = vec![0u8; header.encrypted_meta_len as usize];
+    inner.read_exact(&mut sealed_meta).await?;
+    Ok((header, sealed_meta))
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+    use crate::crypto::kdf::Argon2Params;
+    use crate::crypto::signature::SigningIdentity;
+    use crate::fec::FecParams;
+
+    fn fast_crypt() -> FileCrypt {
+        FileCrypt::new(
+            256,
+            Argon2Params {
+                t_cost: 1,
+                m_cost_kib: 64,
+                parallelism: 1,
+            },
+        )
+    }
+
+    fn encrypt_opts(
+        gzip: bool,
+        fec: Option<FecParams>,
+        signer: Option<SigningIdentity>,
+    ) -> StreamEncryptOptions {
+        StreamEncryptOptions {
+            original_filename: "dump".to_string(),
+            original_extension: "sql".to_string(),
+            uuid: None,
+            password: "async-pass".to_string(),
+            progress: None,
+            gzip_compressed: gzip,
+            metadata: None,
+            fec,
+            signer,
+            recipient: None,
+        }
+    }
+
+    fn decrypt_opts(password: &str) -> StreamDecryptOptions {
+        StreamDecryptOptions {
+            password: password.to_string(),
+            progress: None,
+            identity: None,
+        }
+    }
+
+    /// Writes in uneven pieces, so batches straddle write calls.
+    async fn encrypt_async(
+        data: &[u8],
+        opts: StreamEncryptOptions,
+    ) -> Vec<u8> {
+        let mut writer =
+            AsyncEncryptWriter::new(&fast_crypt(), Vec::new(), opts)
+                .await
+                .unwrap();
+        for piece in data.chunks(100) {
+            writer.write_all(piece).await.unwrap();
+        }
+        writer.shutdown().await.unwrap();
+        assert_eq!(writer.bytes_written(), data.len() as u64);
+        writer.into_inner()
+    }
+
+    async fn decrypt_async(
+        encrypted: &[u8],
+    ) -> io::Result<(Vec<u8>, Option<SignatureStatus>)> {
+        let mut reader =
+            AsyncDecryptReader::new(encrypted, decrypt_opts("async-pass"))
+                .await
+                .map_err(into_io)?;
+        let mut plain = Vec::new();
+        reader.read_to_end(&mut plain).await?;
+        assert_eq!(reader.metadata().original_filename, "dump");
+        Ok((plain, reader.signature()))
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_and_interop_with_sync_streams() {
+        let data: Vec<u8> = (0..5000).map(|i| (i % 251) as u8).collect();
+        let signer = SigningIdentity::generate();
+        let cases = [
+            (false, None, None),
+            (true, None, None),
+            (false, Some(FecParams::new(4, 2).unwrap()), None),
+            (true, Some(FecParams::new(4, 2).unwrap()), Some(signer)),
+        ];
+
+        for (gzip, fec, signer) in cases {
+            if gzip && !cfg!(feature = "gzip") {
+                continue;
+            }
+            let expected = match &signer {
+                Some(signer) => SignatureStatus::Valid {
+                    public_key: signer.public_key(),
+                },
+                None => SignatureStatus::Unsigned,
+            };
+            let opts = || encrypt_opts(gzip, fec, signer.clone());
+
+            // Async writer → async reader and sync decrypt_stream.
+            let encrypted = encrypt_async(&data, opts()).await;
+            let (plain, signature) = decrypt_async(&encrypted).await.unwrap();
+            assert_eq!(plain, data);
+            assert_eq!(signature, Some(expected));
+
+            let mut plain = Vec::new();
+            fast_crypt()
+                .decrypt_stream(
+                    &encrypted[..],
+                    &mut plain,
+                    decrypt_opts("async-pass"),
+                )
+                .unwrap();
+            assert_eq!(plain, data);
+
+            // Sync encrypt_stream → async reader.
+            let mut encrypted = Vec::new();
+            fast_crypt()
+                .encrypt_stream(&data[..], &mut encrypted, opts())
+                .unwrap();
+            let (plain, _) = decrypt_async(&encrypted).await.unwrap();
+            assert_eq!(plain, data);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_empty_and_whole_chunk_inputs() {
+        for len in [0, 256, 512] {
+            let data = vec![7u8; len];
+            let encrypted =
+                encrypt_async(&data, encrypt_opts(false, None, None)).await;
+            let (plain, _) = decrypt_async(&encrypted).await.unwrap();
+            assert_eq!(plain, data);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tampered_and_truncated_streams_fail() {
+        let data = vec![3u8; 1000];
+        let encrypted =
+            encrypt_async(&data, encrypt_opts(false, None, None)).await;
+
+        let mut tampered = encrypted.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        assert!(decrypt_async(&tampered).await.is_err());
+
+        let truncated = &encrypted[..encrypted.len() - 10];
+        assert!(decrypt_async(truncated).await.is_err());
+
+        let err = AsyncDecryptReader::new(&encrypted[..], decrypt_opts("no"))
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, CryptError::InvalidPassword));
+    }
+}
@@ -10,6 +10,12 @@ pub struct TempCleanup {
     pub paths: Vec<PathBuf>,
 }
 
+impl Default for TempCleanup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TempCleanup {
     pub fn new() -> Self {
         Self { paths: Vec::new() }
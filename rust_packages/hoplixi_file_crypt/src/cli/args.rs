@@ -0,0 +1,369 @@
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+/// Encrypt and decrypt files and folders in the HOPLIXI format.
+#[derive(Debug, Parser)]
+#[command(name = "hoplixi-crypt", version)]
+pub struct Cli {
+    /// Print a single JSON document instead of human-readable text.
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Do not print progress.
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Encrypt a file or directory.
+    Encrypt(EncryptArgs),
+    /// Decrypt an encrypted file.
+    Decrypt(DecryptArgs),
+    /// Show the public header of an encrypted file (no password).
+    Inspect(InspectArgs),
+    /// Authenticate every chunk and the signature without writing
+    /// plaintext.
+    Verify(VerifyArgs),
+    /// Re-encrypt a file under a new password.
+    Rekey(RekeyArgs),
+    /// Decrypt and show the metadata block only.
+    Header(HeaderArgs),
+    /// Encrypt many files, directories or glob matches; failures do
+    /// not stop the batch.
+    EncryptBatch(EncryptBatchArgs),
+    /// Decrypt many files, directories or glob matches; failures do
+    /// not stop the batch.
+    DecryptBatch(DecryptBatchArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct EncryptArgs {
+    /// File or directory to encrypt, or `-` for standard input.
+    pub input: PathBuf,
+
+    /// Directory for the encrypted file, or `-` for standard output.
+    /// Defaults to `.`, or to standard output when reading standard
+    /// input.
+    #[arg(short, long, value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+
+    /// UUID of the output file (random by default).
+    #[arg(long)]
+    pub uuid: Option<String>,
+
+    /// Filename recorded for data read from standard input, e.g.
+    /// `dump.sql` (default: `stdin`).
+    #[arg(long, value_name = "NAME")]
+    pub name: Option<String>,
+
+    #[command(flatten)]
+    pub settings: EncryptSettings,
+
+    #[command(flatten)]
+    pub filter: FilterArgs,
+
+    #[command(flatten)]
+    pub password: PasswordArgs,
+}
+
+/// Which entries of a directory input `encrypt` packs. Patterns
+/// use `.gitignore` syntax, relative to the directory.
+#[derive(Debug, Args)]
+pub struct FilterArgs {
+    /// Only encrypt the files matching this glob. Repeatable.
+    #[arg(long, value_name = "GLOB")]
+    pub include: Vec<String>,
+
+    /// Leave out the files and directories matching this glob.
+    /// Repeatable.
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
+    /// Also apply the ignore files of this name, e.g. `.gitignore`.
+    #[arg(long, value_name = "NAME")]
+    pub ignore_file: Option<String>,
+
+    /// Leave out files larger than this size, e.g. `100M`.
+    #[arg(long, value_name = "SIZE", value_parser = parse_size)]
+    pub max_file_size: Option<u64>,
+
+    /// What to do with symbolic links. FIFOs, sockets and devices
+    /// are left out, except with `error`.
+    #[arg(long, value_enum, default_value_t = LinksArg::Store)]
+    pub links: LinksArg,
+}
+
+/// Encryption settings shared by `encrypt` and `encrypt-batch`.
+#[derive(Debug, Args)]
+pub struct EncryptSettings {
+    /// Gzip the data before encryption.
+    #[arg(long)]
+    pub gzip: bool,
+
+    /// Add a Reed–Solomon parity layer.
+    #[arg(long)]
+    pub fec: bool,
+
+    /// Plaintext bytes per chunk.
+    #[arg(long, value_name = "BYTES")]
+    pub chunk_size: Option<u32>,
+
+    /// AEAD protecting the data.
+    #[arg(long, value_enum, default_value_t = CipherArg::Xchacha20Poly1305)]
+    pub cipher: CipherArg,
+
+    /// Output file extension.
+    #[arg(long, value_name = "EXT")]
+    pub extension: Option<String>,
+
+    /// Directory for intermediate files.
+    #[arg(long, value_name = "DIR")]
+    pub temp_dir: Option<PathBuf>,
+
+    /// Extra metadata entry stored in the encrypted header.
+    #[arg(
+        long = "meta",
+        value_name = "KEY=VALUE",
+        value_parser = parse_key_value
+    )]
+    pub metadata: Vec<(String, String)>,
+
+    /// File with a raw 32-byte Ed25519 secret key to sign with.
+    #[arg(long, value_name = "FILE")]
+    pub sign_key: Option<PathBuf>,
+
+    /// Overwrite and delete the input once it is encrypted. Best
+    /// effort on SSDs and journaling or copy-on-write file systems.
+    #[arg(long)]
+    pub shred: bool,
+
+    /// Pad the payload and metadata so their sizes reveal less about
+    /// the original file.
+    #[arg(long)]
+    pub hide_lengths: bool,
+
+    /// Split the output into volumes of at most this size, e.g.
+    /// `4000M` for FAT32 (K, M, G and T are powers of 1024).
+    #[arg(long, value_name = "SIZE", value_parser = parse_size)]
+    pub volume_size: Option<u64>,
+}
+
+/// Cipher suites accepted by `--cipher`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CipherArg {
+    /// XChaCha20-Poly1305 (default).
+    #[value(name = "xchacha20-poly1305")]
+    Xchacha20Poly1305,
+    /// AES-256-GCM-SIV, faster with AES-NI.
+    #[value(name = "aes-256-gcm-siv")]
+    Aes256GcmSiv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LinksArg {
+    /// Store links as links (default).
+    Store,
+    /// Pack what links point to.
+    Follow,
+    /// Leave links out.
+    Skip,
+    /// Fail on the first link or special file.
+    Error,
+}
+
+#[derive(Debug, Args)]
+pub struct DecryptArgs {
+    /// Encrypted file, or `-` for standard input.
+    pub input: PathBuf,
+
+    /// Directory for the decrypted output, or `-` for standard
+    /// output. Defaults to `.`, or to standard output when reading
+    /// standard input.
+    #[arg(short, long, value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+
+    /// Directory for intermediate files.
+    #[arg(long, value_name = "DIR")]
+    pub temp_dir: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub password: PasswordArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct InspectArgs {
+    /// Encrypted file.
+    pub input: PathBuf,
+}
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    /// Encrypted file.
+    pub input: PathBuf,
+
+    #[command(flatten)]
+    pub password: PasswordArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct HeaderArgs {
+    /// Encrypted file.
+    pub input: PathBuf,
+
+    #[command(flatten)]
+    pub password: PasswordArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct RekeyArgs {
+    /// Encrypted file.
+    pub input: PathBuf,
+
+    /// Write the re-encrypted file here instead of replacing the
+    /// input.
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Re-chunk to this many plaintext bytes per chunk.
+    #[arg(long, value_name = "BYTES")]
+    pub chunk_size: Option<u32>,
+
+    /// File with a raw 32-byte Ed25519 secret key to re-sign with.
+    #[arg(long, value_name = "FILE")]
+    pub sign_key: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub password: PasswordArgs,
+
+    #[command(flatten)]
+    pub new_password: NewPasswordArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct EncryptBatchArgs {
+    #[command(flatten)]
+    pub selection: BatchSelection,
+
+    #[command(flatten)]
+    pub settings: EncryptSettings,
+
+    #[command(flatten)]
+    pub password: PasswordArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct DecryptBatchArgs {
+    #[command(flatten)]
+    pub selection: BatchSelection,
+
+    /// Directory for intermediate files.
+    #[arg(long, value_name = "DIR")]
+    pub temp_dir: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub password: PasswordArgs,
+}
+
+/// Which files a batch command processes and where the results go.
+#[derive(Debug, Args)]
+pub struct BatchSelection {
+    /// Files, directories or quoted glob patterns (`'backups/*.sql'`).
+    #[arg(required = true, value_name = "INPUT")]
+    pub inputs: Vec<String>,
+
+    /// Root directory for the results. Files found by `--recursive`
+    /// keep their relative directory below it.
+    #[arg(short, long, default_value = ".")]
+    pub output_dir: PathBuf,
+
+    /// Process the files inside directory inputs one by one instead
+    /// of treating each directory as a single item.
+    #[arg(short, long)]
+    pub recursive: bool,
+
+    /// Only process files whose path (relative to the directory
+    /// input) matches this glob. Repeatable.
+    #[arg(long, value_name = "GLOB")]
+    pub include: Vec<String>,
+
+    /// Skip files whose relative path matches this glob. Repeatable.
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
+    /// Number of files processed concurrently.
+    #[arg(short, long, default_value = "1", value_name = "N")]
+    pub jobs: NonZeroUsize,
+
+    /// List what would be processed without touching anything.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Where to read the password from. Prompts on the terminal when
+/// none is given.
+#[derive(Debug, Args)]
+pub struct PasswordArgs {
+    /// Read the password from the first line of standard input.
+    #[arg(long, conflicts_with_all = ["password_fd", "password_env"])]
+    pub password_stdin: bool,
+
+    /// Read the password from an open file descriptor (Unix).
+    #[arg(long, value_name = "FD", conflicts_with = "password_env")]
+    pub password_fd: Option<i32>,
+
+    /// Read the password from an environment variable.
+    #[arg(long, value_name = "VAR")]
+    pub password_env: Option<String>,
+}
+
+/// Where to read the new password of `rekey` from.
+#[derive(Debug, Args)]
+pub struct NewPasswordArgs {
+    /// Read the new password from the next line of standard input.
+    #[arg(
+        long,
+        conflicts_with_all = ["new_password_fd", "new_password_env"]
+    )]
+    pub new_password_stdin: bool,
+
+    /// Read the new password from an open file descriptor (Unix).
+    #[arg(long, value_name = "FD", conflicts_with = "new_password_env")]
+    pub new_password_fd: Option<i32>,
+
+    /// Read the new password from an environment variable.
+    #[arg(long, value_name = "VAR")]
+    pub new_password_env: Option<String>,
+}
+
+fn parse_key_value(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, got `{s}`"))
+}
+
+fn parse_size(s: &str) -> Result<u64, String> {
+    let (digits, shift) = match s.char_indices().last() {
+        Some((i, unit)) if unit.is_ascii_alphabetic() => {
+            let shift = match unit.to_ascii_uppercase() {
+                'K' => 10,
+                'M' => 20,
+                'G' => 30,
+                'T' => 40,
+                _ => return Err(format!("unknown size unit in `{s}`")),
+            };
+            (&s[..i], shift)
+        }
+        _ => (s, 0),
+    };
+    digits
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(1 << shift))
+        .ok_or_else(|| format!("expected a size like 700M, got `{s}`"))
+}
@@ -0,0 +1,572 @@
+//! `encrypt-batch` / `decrypt-batch`: many inputs, one password.
+//!
+//! Like `crypt_api::encrypt_batch`, a failing item is recorded and
+//! the batch moves on; the summary lists what succeeded and what
+//! failed, and the exit code is [`EXIT_PARTIAL`] if anything failed.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use hoplixi_file_crypt::{DecryptOptions, FileCrypt};
+use serde_json::{json, Value};
+use walkdir::WalkDir;
+
+use super::args::{BatchSelection, DecryptBatchArgs, EncryptBatchArgs};
+use super::password::PasswordSource;
+use super::report::{self, Report};
+use super::{load_signer, CliError, EXIT_PARTIAL};
+
+/// Files `decrypt-batch` picks from directories when no `--include`
+/// is given.
+const DEFAULT_DECRYPT_INCLUDE: &str = "*.enc";
+
+/// One input of a batch and the directory its result goes to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchItem {
+    pub input: PathBuf,
+    pub output_dir: PathBuf,
+}
+
+/// An input that failed, either while expanding the selection or
+/// while being processed.
+#[derive(Debug)]
+pub struct BatchFailure {
+    pub input: String,
+    pub error: CliError,
+}
+
+impl BatchFailure {
+    fn new(input: impl Into<String>, error: CliError) -> Self {
+        Self {
+            input: input.into(),
+            error,
+        }
+    }
+
+    fn json(&self) -> Value {
+        json!({
+            "input_path": self.input,
+            "kind": self.error.kind,
+            "message": self.error.message,
+            "exit_code": self.error.exit_code,
+        })
+    }
+}
+
+/// Include/exclude globs, matched against the path of a file
+/// relative to the directory it was found in.
+struct Filter {
+    include: Option<GlobSet>,
+    exclude: GlobSet,
+}
+
+impl Filter {
+    fn new(
+        include: &[String],
+        exclude: &[String],
+        default_include: Option<&str>,
+    ) -> Result<Self, CliError> {
+        let include = match (include, default_include) {
+            ([], None) => None,
+            ([], Some(default)) => Some(glob_set(&[default.to_string()])?),
+            (patterns, _) => Some(glob_set(patterns)?),
+        };
+        Ok(Self {
+            include,
+            exclude: glob_set(exclude)?,
+        })
+    }
+
+    fn matches(&self, relative: &Path) -> bool {
+        self.include.as_ref().is_none_or(|set| set.is_match(relative))
+            && !self.exclude.is_match(relative)
+    }
+}
+
+fn glob_set(patterns: &[String]) -> Result<GlobSet, CliError> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = Glob::new(pattern)
+            .map_err(|e| CliError::other(format!("glob `{pattern}`: {e}")))?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|e| CliError::other(format!("glob set: {e}")))
+}
+
+fn is_pattern(input: &str) -> bool {
+    input.contains(['*', '?', '['])
+}
+
+/// Expand the inputs of a selection into items.
+///
+/// Plain files are taken as they are. A directory is a single item
+/// unless `--recursive` is set (`directory_items` says whether the
+/// command can process directories at all); with `--recursive` its
+/// files are walked, filtered and mirrored below the output
+/// directory. Inputs with glob metacharacters that do not name an
+/// existing path are expanded first.
+pub fn collect(
+    selection: &BatchSelection,
+    directory_items: bool,
+    default_include: Option<&str>,
+) -> Result<(Vec<BatchItem>, Vec<BatchFailure>), CliError> {
+    let filter = Filter::new(
+        &selection.include,
+        &selection.exclude,
+        default_include,
+    )?;
+    let mut items = Vec::new();
+    let mut failures = Vec::new();
+
+    for input in &selection.inputs {
+        let paths = if is_pattern(input) && !Path::new(input).exists() {
+            match expand_pattern(input) {
+                Ok(paths) if paths.is_empty() => {
+                    failures.push(BatchFailure::new(
+                        input,
+                        CliError::other("pattern matched no files"),
+                    ));
+                    continue;
+                }
+                Ok(paths) => paths,
+                Err(e) => {
+                    failures.push(BatchFailure::new(input, e));
+                    continue;
+                }
+            }
+        } else {
+            vec![PathBuf::from(input)]
+        };
+
+        for path in paths {
+            if path.is_dir() && selection.recursive {
+                walk(&path, &selection.output_dir, &filter, &mut items)
+                    .unwrap_or_else(|e| {
+                        failures.push(BatchFailure::new(
+                            path.display().to_string(),
+                            e,
+                        ))
+                    });
+            } else if path.is_dir() && !directory_items {
+                failures.push(BatchFailure::new(
+                    path.display().to_string(),
+                    CliError::other("is a directory (use --recursive)"),
+                ));
+            } else if path.exists() {
+                items.push(BatchItem {
+                    input: path,
+                    output_dir: selection.output_dir.clone(),
+                });
+            } else {
+                failures.push(BatchFailure::new(
+                    path.display().to_string(),
+                    CliError::other("no such file or directory"),
+                ));
+            }
+        }
+    }
+
+    items.dedup();
+    Ok((items, failures))
+}
+
+fn expand_pattern(pattern: &str) -> Result<Vec<PathBuf>, CliError> {
+    let paths = glob::glob(pattern)
+        .map_err(|e| CliError::other(format!("glob `{pattern}`: {e}")))?;
+    paths
+        .map(|entry| {
+            entry.map_err(|e| CliError::other(format!("glob: {e}")))
+        })
+        .collect()
+}
+
+fn walk(
+    root: &Path,
+    output_root: &Path,
+    filter: &Filter,
+    items: &mut Vec<BatchItem>,
+) -> Result<(), CliError> {
+    let mut found = Vec::new();
+    for entry in WalkDir::new(root).sort_by_file_name() {
+        let entry = entry.map_err(|e| CliError::other(e.to_string()))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(root)
+            .expect("walkdir entries live below the root");
+        if !filter.matches(relative) {
+            continue;
+        }
+        let output_dir = match relative.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => {
+                output_root.join(parent)
+            }
+            _ => output_root.to_path_buf(),
+        };
+        found.push(BatchItem {
+            input: entry.into_path(),
+            output_dir,
+        });
+    }
+    items.extend(found);
+    Ok(())
+}
+
+/// Run `op` over the items on at most `jobs` worker threads.
+/// Results come back in item order.
+fn run<T, F>(
+    items: &[BatchItem],
+    jobs: usize,
+    verbose: bool,
+    op: F,
+) -> Vec<Result<T, CliError>>
+where
+    T: Send,
+    F: Fn(BatchItem) -> Result<T, CliError> + Sync,
+{
+    let total = items.len();
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<T, CliError>>>> =
+        Mutex::new((0..total).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(total) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = op(item.clone());
+                if verbose {
+                    let status = match &result {
+                        Ok(_) => "ok".to_string(),
+                        Err(e) => format!("failed: {}", e.message),
+                    };
+                    eprintln!(
+                        "[{}/{total}] {}: {status}",
+                        index + 1,
+                        item.input.display()
+                    );
+                }
+                results.lock().unwrap_or_else(|e| e.into_inner())[index] =
+                    Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .map(|result| {
+            result.unwrap_or_else(|| {
+                Err(CliError::other("worker thread panicked"))
+            })
+        })
+        .collect()
+}
+
+fn create_output_dir(dir: &Path) -> Result<(), CliError> {
+    std::fs::create_dir_all(dir).map_err(|e| {
+        CliError::other(format!("output directory {}: {e}", dir.display()))
+    })
+}
+
+fn dry_run_report(
+    command: &'static str,
+    verb: &str,
+    items: &[BatchItem],
+    failures: &[BatchFailure],
+) -> Report {
+    let planned: Vec<Value> = items
+        .iter()
+        .map(|item| {
+            json!({
+                "input_path": item.input.display().to_string(),
+                "output_dir": item.output_dir.display().to_string(),
+            })
+        })
+        .collect();
+
+    let mut report = Report::new(command)
+        .field("dry_run", true)
+        .field("items", planned)
+        .field(
+            "failed",
+            failures.iter().map(BatchFailure::json).collect::<Vec<_>>(),
+        );
+    for item in items {
+        report = report.line(format!(
+            "would {verb} {} -> {}",
+            item.input.display(),
+            item.output_dir.display()
+        ));
+    }
+    summary(report, verb, items.len(), failures)
+}
+
+/// Failure lines, the totals line and the exit code.
+fn summary(
+    mut report: Report,
+    verb: &str,
+    succeeded: usize,
+    failures: &[BatchFailure],
+) -> Report {
+    for failure in failures {
+        report = report.line(format!(
+            "FAILED {}: {}",
+            failure.input, failure.error.message
+        ));
+    }
+    report = report.line(format!(
+        "{}: {succeeded} ok, {} failed",
+        if verb == "encrypt" { "Encrypted" } else { "Decrypted" },
+        failures.len()
+    ));
+    if failures.is_empty() {
+        report
+    } else {
+        report.with_exit_code(EXIT_PARTIAL)
+    }
+}
+
+pub fn encrypt_batch(
+    args: EncryptBatchArgs,
+    verbose: bool,
+) -> Result<Report, CliError> {
+    let selection = &args.selection;
+    let (items, mut failures) = collect(selection, true, None)?;
+    if selection.dry_run {
+        return Ok(dry_run_report(
+            "encrypt-batch",
+            "encrypt",
+            &items,
+            &failures,
+        ));
+    }
+
+    let settings = &args.settings;
+    let signer = load_signer(settings.sign_key.as_deref())?;
+    let password =
+        PasswordSource::from(&args.password).read("Password", true)?;
+    let crypt = settings.engine();
+
+    let results = run(&items, selection.jobs.get(), verbose, |item| {
+        create_output_dir(&item.output_dir)?;
+        let opts = settings.options(
+            item.input,
+            item.output_dir,
+            password.clone(),
+            signer.clone(),
+            None,
+        );
+        Ok(crypt.encrypt(opts)?)
+    });
+
+    let mut succeeded = Vec::new();
+    let mut report = Report::new("encrypt-batch");
+    for (item, result) in items.iter().zip(results) {
+        match result {
+            Ok(result) => {
+                report = report.line(format!(
+                    "{} -> {}",
+                    item.input.display(),
+                    result.output_path.display()
+                ));
+                succeeded.push(json!({
+                    "input_path": item.input.display().to_string(),
+                    "output_path": result.output_path.display().to_string(),
+                    "volumes": result
+                        .volumes
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect::<Vec<_>>(),
+                    "uuid": result.uuid,
+                    "original_size": result.original_size,
+                    "shred": result.shred.as_ref().map(report::shred_json),
+                }));
+            }
+            Err(e) => failures.push(BatchFailure::new(
+                item.input.display().to_string(),
+                e,
+            )),
+        }
+    }
+
+    let count = succeeded.len();
+    report = report.field("succeeded", succeeded).field(
+        "failed",
+        failures.iter().map(BatchFailure::json).collect::<Vec<_>>(),
+    );
+    Ok(summary(report, "encrypt", count, &failures))
+}
+
+pub fn decrypt_batch(
+    args: DecryptBatchArgs,
+    verbose: bool,
+) -> Result<Report, CliError> {
+    let selection = &args.selection;
+    let (items, mut failures) =
+        collect(selection, false, Some(DEFAULT_DECRYPT_INCLUDE))?;
+    if selection.dry_run {
+        return Ok(dry_run_report(
+            "decrypt-batch",
+            "decrypt",
+            &items,
+            &failures,
+        ));
+    }
+
+    let password =
+        PasswordSource::from(&args.password).read("Password", false)?;
+    let crypt = FileCrypt::default();
+
+    let results = run(&items, selection.jobs.get(), verbose, |item| {
+        create_output_dir(&item.output_dir)?;
+        let opts = DecryptOptions {
+            input_path: item.input,
+            output_dir: item.output_dir,
+            password: password.clone(),
+            progress: None,
+            temp_dir: args.temp_dir.clone(),
+            identity: None,
+        };
+        Ok(crypt.decrypt(opts)?)
+    });
+
+    let mut succeeded = Vec::new();
+    let mut report = Report::new("decrypt-batch");
+    for (item, result) in items.iter().zip(results) {
+        match result {
+            Ok(result) => {
+                report = report.line(format!(
+                    "{} -> {}",
+                    item.input.display(),
+                    result.output_path.display()
+                ));
+                succeeded.push(json!({
+                    "input_path": item.input.display().to_string(),
+                    "output_path": result.output_path.display().to_string(),
+                    "metadata": report::metadata_json(&result.metadata),
+                    "signature": report::signature_json(&result.signature),
+                }));
+            }
+            Err(e) => failures.push(BatchFailure::new(
+                item.input.display().to_string(),
+                e,
+            )),
+        }
+    }
+
+    let count = succeeded.len();
+    report = report.field("succeeded", succeeded).field(
+        "failed",
+        failures.iter().map(BatchFailure::json).collect::<Vec<_>>(),
+    );
+    Ok(summary(report, "decrypt", count, &failures))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::NonZeroUsize;
+
+    fn selection(inputs: Vec<String>, out: &Path) -> BatchSelection {
+        BatchSelection {
+            inputs,
+            output_dir: out.to_path_buf(),
+            recursive: false,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            jobs: NonZeroUsize::MIN,
+            dry_run: false,
+        }
+    }
+
+    fn touch(path: &Path) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, b"x").unwrap();
+    }
+
+    #[test]
+    fn test_recursive_mirrors_relative_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("in");
+        touch(&root.join("a.txt"));
+        touch(&root.join("sub/b.txt"));
+        touch(&root.join("sub/skip.log"));
+        let out = dir.path().join("out");
+
+        let mut sel =
+            selection(vec![root.display().to_string()], &out);
+        sel.recursive = true;
+        sel.exclude = vec!["*.log".to_string()];
+        let (items, failures) = collect(&sel, true, None).unwrap();
+
+        assert!(failures.is_empty());
+        assert_eq!(
+            items,
+            vec![
+                BatchItem {
+                    input: root.join("a.txt"),
+                    output_dir: out.clone(),
+                },
+                BatchItem {
+                    input: root.join("sub/b.txt"),
+                    output_dir: out.join("sub"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_patterns_and_missing_inputs_are_failures() {
+        let dir = tempfile::tempdir().unwrap();
+        touch(&dir.path().join("one.enc"));
+        touch(&dir.path().join("two.enc"));
+        let out = dir.path().join("out");
+
+        let sel = selection(
+            vec![
+                format!("{}/*.enc", dir.path().display()),
+                format!("{}/*.none", dir.path().display()),
+                dir.path().join("missing").display().to_string(),
+                dir.path().display().to_string(),
+            ],
+            &out,
+        );
+        let (items, failures) =
+            collect(&sel, false, Some(DEFAULT_DECRYPT_INCLUDE)).unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(failures.len(), 3);
+        assert!(failures[2].error.message.contains("--recursive"));
+    }
+
+    #[test]
+    fn test_decrypt_default_include() {
+        let dir = tempfile::tempdir().unwrap();
+        touch(&dir.path().join("x.enc"));
+        touch(&dir.path().join("nested/y.enc"));
+        touch(&dir.path().join("notes.txt"));
+
+        let mut sel = selection(
+            vec![dir.path().display().to_string()],
+            &dir.path().join("out"),
+        );
+        sel.recursive = true;
+        let (items, _) =
+            collect(&sel, false, Some(DEFAULT_DECRYPT_INCLUDE)).unwrap();
+
+        let names: Vec<_> =
+            items.iter().map(|i| i.input.file_name().unwrap()).collect();
+        assert_eq!(names, ["y.enc", "x.enc"]);
+    }
+}
@@ -0,0 +1,169 @@
+//! Command-line front end of the `hoplixi-crypt` binary.
+
+pub mod args;
+pub mod batch;
+pub mod password;
+pub mod pipe;
+pub mod report;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use hoplixi_file_crypt::{
+    CipherSuite, CryptError, DirectoryFilter, EncryptOptions, FecParams,
+    FileCrypt, LinkPolicy, ProgressCallback, SigningIdentity,
+};
+
+use args::{CipherArg, EncryptSettings, FilterArgs, LinksArg};
+
+/// Generic failure (bad key file, unreadable password, ...).
+pub const EXIT_FAILURE: i32 = 1;
+
+/// A batch finished, but some of its items failed, or the input was
+/// encrypted but could not be fully shredded.
+pub const EXIT_PARTIAL: i32 = 3;
+
+/// A CLI failure with the exit code it maps to.
+///
+/// Library errors keep the code of their [`CryptError`] variant;
+/// usage errors are reported by clap with code 2.
+#[derive(Debug)]
+pub struct CliError {
+    pub kind: &'static str,
+    pub message: String,
+    pub exit_code: i32,
+}
+
+impl CliError {
+    /// The password could not be read.
+    pub fn password(message: impl Into<String>) -> Self {
+        Self {
+            kind: "password_input",
+            message: message.into(),
+            exit_code: EXIT_FAILURE,
+        }
+    }
+
+    /// Any other failure outside the library.
+    pub fn other(message: impl Into<String>) -> Self {
+        Self {
+            kind: "failure",
+            message: message.into(),
+            exit_code: EXIT_FAILURE,
+        }
+    }
+}
+
+impl From<CryptError> for CliError {
+    fn from(e: CryptError) -> Self {
+        Self {
+            kind: e.kind(),
+            message: e.to_string(),
+            exit_code: e.exit_code(),
+        }
+    }
+}
+
+/// Load an Ed25519 identity from a file holding the raw 32-byte
+/// secret key.
+pub fn load_signer(
+    path: Option<&Path>,
+) -> Result<Option<SigningIdentity>, CliError> {
+    let Some(path) = path else {
+        return Ok(None);
+    };
+    let secret = std::fs::read(path).map_err(|e| {
+        CliError::other(format!("signing key {}: {e}", path.display()))
+    })?;
+    Ok(Some(SigningIdentity::from_secret_slice(&secret)?))
+}
+
+impl From<CipherArg> for CipherSuite {
+    fn from(arg: CipherArg) -> Self {
+        match arg {
+            CipherArg::Xchacha20Poly1305 => CipherSuite::XChaCha20Poly1305,
+            CipherArg::Aes256GcmSiv => CipherSuite::Aes256GcmSiv,
+        }
+    }
+}
+
+impl From<LinksArg> for LinkPolicy {
+    fn from(arg: LinksArg) -> Self {
+        match arg {
+            LinksArg::Store => LinkPolicy::Store,
+            LinksArg::Follow => LinkPolicy::Follow,
+            LinksArg::Skip => LinkPolicy::Skip,
+            LinksArg::Error => LinkPolicy::Error,
+        }
+    }
+}
+
+impl EncryptSettings {
+    /// Engine configured with `--chunk-size` and `--cipher`.
+    pub fn engine(&self) -> FileCrypt {
+        let engine = match self.chunk_size {
+            Some(size) => FileCrypt::with_chunk_size(size),
+            None => FileCrypt::default(),
+        };
+        engine.with_cipher_suite(self.cipher.into())
+    }
+
+    /// `--meta` entries, if any.
+    pub fn metadata_map(&self) -> Option<HashMap<String, String>> {
+        (!self.metadata.is_empty())
+            .then(|| self.metadata.iter().cloned().collect())
+    }
+
+    /// Parity layer requested by `--fec`.
+    pub fn fec(&self) -> Option<FecParams> {
+        self.fec.then(FecParams::default)
+    }
+
+    /// Options for encrypting one input with these settings.
+    pub fn options(
+        &self,
+        input_path: PathBuf,
+        output_dir: PathBuf,
+        password: String,
+        signer: Option<SigningIdentity>,
+        progress: Option<ProgressCallback>,
+    ) -> EncryptOptions {
+        EncryptOptions {
+            input_path,
+            output_dir,
+            uuid: None,
+            password,
+            progress,
+            gzip_compressed: self.gzip,
+            output_extension: self.extension.clone(),
+            temp_dir: self.temp_dir.clone(),
+            metadata: self.metadata_map(),
+            fec: self.fec(),
+            signer,
+            recipient: None,
+            shred_source: self.shred,
+            hide_lengths: self.hide_lengths,
+            volume_size: self.volume_size,
+            filter: None,
+        }
+    }
+}
+
+impl FilterArgs {
+    /// The filter these flags describe; `None` when none is given.
+    pub fn filter(&self) -> Option<DirectoryFilter> {
+        let filter = DirectoryFilter {
+            include: self.include.clone(),
+            exclude: self.exclude.clone(),
+            ignore_file: self.ignore_file.clone(),
+            max_file_size: self.max_file_size,
+            links: self.links.into(),
+        };
+        let empty = filter.include.is_empty()
+            && filter.exclude.is_empty()
+            && filter.ignore_file.is_none()
+            && filter.max_file_size.is_none()
+            && filter.links == LinkPolicy::default();
+        (!empty).then_some(filter)
+    }
+}
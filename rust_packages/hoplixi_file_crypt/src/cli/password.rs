@@ -0,0 +1,118 @@
+use std::io::BufRead;
+
+use super::args::{NewPasswordArgs, PasswordArgs};
+use super::CliError;
+
+/// Where a password comes from.
+///
+/// Passwords are never taken from the command line itself, where
+/// they would show up in `ps` and the shell history.
+#[derive(Debug)]
+pub enum PasswordSource {
+    /// Interactive prompt on the controlling terminal, without echo.
+    Tty,
+    /// Next line of standard input.
+    Stdin,
+    /// First line read from an inherited file descriptor.
+    Fd(i32),
+    /// Value of an environment variable.
+    Env(String),
+}
+
+impl From<&PasswordArgs> for PasswordSource {
+    fn from(args: &PasswordArgs) -> Self {
+        source(
+            args.password_stdin,
+            args.password_fd,
+            args.password_env.as_deref(),
+        )
+    }
+}
+
+impl From<&NewPasswordArgs> for PasswordSource {
+    fn from(args: &NewPasswordArgs) -> Self {
+        source(
+            args.new_password_stdin,
+            args.new_password_fd,
+            args.new_password_env.as_deref(),
+        )
+    }
+}
+
+fn source(stdin: bool, fd: Option<i32>, env: Option<&str>) -> PasswordSource {
+    match (stdin, fd, env) {
+        (true, _, _) => PasswordSource::Stdin,
+        (_, Some(fd), _) => PasswordSource::Fd(fd),
+        (_, _, Some(var)) => PasswordSource::Env(var.to_string()),
+        _ => PasswordSource::Tty,
+    }
+}
+
+impl PasswordSource {
+    /// Read the password. `confirm` asks twice on a terminal, for
+    /// passwords that are about to be set.
+    pub fn read(&self, label: &str, confirm: bool) -> Result<String, CliError> {
+        let password = match self {
+            PasswordSource::Tty => {
+                let password = prompt(&format!("{label}: "))?;
+                if confirm && prompt(&format!("Confirm {label}: "))? != password
+                {
+                    return Err(CliError::password("passwords do not match"));
+                }
+                password
+            }
+            PasswordSource::Stdin => {
+                let mut line = String::new();
+                std::io::stdin()
+                    .lock()
+                    .read_line(&mut line)
+                    .map_err(|e| CliError::password(format!("stdin: {e}")))?;
+                trim_newline(line)
+            }
+            PasswordSource::Fd(fd) => read_fd(*fd)?,
+            PasswordSource::Env(var) => std::env::var(var).map_err(|e| {
+                CliError::password(format!("environment variable {var}: {e}"))
+            })?,
+        };
+
+        if password.is_empty() {
+            return Err(CliError::password(format!("{label} is empty")));
+        }
+        Ok(password)
+    }
+}
+
+fn prompt(text: &str) -> Result<String, CliError> {
+    rpassword::prompt_password(text)
+        .map_err(|e| CliError::password(format!("terminal: {e}")))
+}
+
+#[cfg(unix)]
+fn read_fd(fd: i32) -> Result<String, CliError> {
+    let file = std::fs::File::open(format!("/dev/fd/{fd}"))
+        .map_err(|e| CliError::password(format!("fd {fd}: {e}")))?;
+    let mut line = String::new();
+    std::io::BufReader::new(file)
+        .read_line(&mut line)
+        .map_err(|e| CliError::password(format!("fd {fd}: {e}")))?;
+    Ok(trim_newline(line))
+}
+
+#[cfg(not(unix))]
+fn read_fd(_fd: i32) -> Result<String, CliError> {
+    Err(CliError::password(
+        "reading from a file descriptor is only supported on Unix",
+    ))
+}
+
+/// Strip one trailing `\n` or `\r\n`; other whitespace is part of
+/// the password.
+fn trim_newline(mut line: String) -> String {
+    if line.ends_with('\n') {
+        line.pop();
+        if line.ends_with('\r') {
+            line.pop();
+        }
+    }
+    line
+}
@@ -0,0 +1,254 @@
+//! `-` as the input or output of `encrypt` / `decrypt`, so the
+//! binary can sit in a shell pipeline:
+//!
+//! ```text
+//! pg_dump db | hoplixi-crypt encrypt - --name db.sql > db.enc
+//! hoplixi-crypt decrypt db.enc -o - | psql db
+//! ```
+//!
+//! Streams are handled by [`FileCrypt::encrypt_stream`] and
+//! [`FileCrypt::decrypt_stream`]; only decrypting standard input
+//! into a directory spools the ciphertext to a temp file first.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use hoplixi_file_crypt::config::DEFAULT_EXTENSION;
+use hoplixi_file_crypt::{
+    FileCrypt, ProgressCallback, StreamDecryptOptions, StreamEncryptOptions,
+};
+use tempfile::NamedTempFile;
+use uuid::Uuid;
+
+use super::args::{Command, EncryptArgs};
+use super::password::PasswordSource;
+use super::report::{self, Report};
+use super::{load_signer, CliError};
+
+/// Filename recorded for standard input when `--name` is not given.
+const STDIN_NAME: &str = "stdin";
+
+pub fn is_stdio(path: &Path) -> bool {
+    path == Path::new("-")
+}
+
+/// Resolve `-o`: `None` means standard output, which is also the
+/// default when the input is standard input.
+pub fn output_dir(input: &Path, output_dir: Option<&Path>) -> Option<PathBuf> {
+    match output_dir {
+        Some(dir) if is_stdio(dir) => None,
+        Some(dir) => Some(dir.to_path_buf()),
+        None if is_stdio(input) => None,
+        None => Some(PathBuf::from(".")),
+    }
+}
+
+/// Whether the command writes data to standard output, in which
+/// case reports and errors go to standard error.
+pub fn writes_stdout(command: &Command) -> bool {
+    match command {
+        Command::Encrypt(args) => {
+            output_dir(&args.input, args.output_dir.as_deref()).is_none()
+        }
+        Command::Decrypt(args) => {
+            output_dir(&args.input, args.output_dir.as_deref()).is_none()
+        }
+        _ => false,
+    }
+}
+
+/// Standard input cannot carry both the password and the data.
+pub fn check_password_source(
+    input: &Path,
+    source: &PasswordSource,
+) -> Result<(), CliError> {
+    if is_stdio(input) && matches!(source, PasswordSource::Stdin) {
+        return Err(CliError::password(
+            "--password-stdin cannot be used while the data comes from \
+             standard input; use --password-fd or --password-env",
+        ));
+    }
+    Ok(())
+}
+
+fn open_input(input: &Path) -> Result<Box<dyn Read>, CliError> {
+    if is_stdio(input) {
+        return Ok(Box::new(std::io::stdin().lock()));
+    }
+    if input.is_dir() {
+        return Err(CliError::other(format!(
+            "{} is a directory; directories cannot be streamed",
+            input.display()
+        )));
+    }
+    let file = File::open(input)
+        .map_err(|e| CliError::other(format!("{}: {e}", input.display())))?;
+    Ok(Box::new(file))
+}
+
+/// Encrypt when the input or the output is `-`.
+pub fn encrypt(
+    args: EncryptArgs,
+    progress: Option<ProgressCallback>,
+) -> Result<Report, CliError> {
+    let source = PasswordSource::from(&args.password);
+    check_password_source(&args.input, &source)?;
+    let settings = args.settings;
+    if settings.shred {
+        return Err(CliError::other(
+            "--shred needs a file or directory input and output",
+        ));
+    }
+    // Padding is sized from the input length, which a stream only
+    // learns at its end.
+    if settings.hide_lengths {
+        return Err(CliError::other(
+            "--hide-lengths needs a file input and output",
+        ));
+    }
+    if settings.volume_size.is_some() {
+        return Err(CliError::other(
+            "--volume-size needs a file input and output",
+        ));
+    }
+    let signer = load_signer(settings.sign_key.as_deref())?;
+
+    let reader = open_input(&args.input)?;
+    let name = match (&args.name, is_stdio(&args.input)) {
+        (Some(name), _) => PathBuf::from(name),
+        (None, true) => PathBuf::from(STDIN_NAME),
+        (None, false) => args.input.clone(),
+    };
+    let password = source.read("Password", true)?;
+
+    let uuid = args.uuid.unwrap_or_else(|| Uuid::new_v4().to_string());
+    let opts = StreamEncryptOptions {
+        original_filename: name
+            .file_stem()
+            .map_or(STDIN_NAME.into(), |s| s.to_string_lossy().into()),
+        original_extension: name
+            .extension()
+            .map_or(String::new(), |s| s.to_string_lossy().into()),
+        uuid: Some(uuid.clone()),
+        password,
+        progress,
+        gzip_compressed: settings.gzip,
+        metadata: settings.metadata_map(),
+        fec: settings.fec(),
+        signer,
+        recipient: None,
+    };
+    let crypt = settings.engine();
+
+    let Some(dir) = output_dir(&args.input, args.output_dir.as_deref())
+    else {
+        let result = crypt
+            .encrypt_stream(reader, std::io::stdout().lock(), opts)?;
+        return Ok(Report::new("encrypt")
+            .on_stderr()
+            .field("output_path", "-")
+            .field("uuid", result.uuid.clone())
+            .field("bytes_read", result.bytes_read)
+            .line(format!("UUID: {}", result.uuid))
+            .line(format!("Read: {} bytes", result.bytes_read)));
+    };
+
+    let ext = settings.extension.as_deref().unwrap_or(DEFAULT_EXTENSION);
+    let output_path = dir.join(format!("{uuid}{ext}"));
+    let temp = NamedTempFile::new_in(&dir).map_err(io_error)?;
+    let result = crypt.encrypt_stream(reader, temp.as_file(), opts)?;
+    temp.persist(&output_path).map_err(|e| io_error(e.error))?;
+
+    Ok(Report::new("encrypt")
+        .field("output_path", output_path.display().to_string())
+        .field("uuid", result.uuid.clone())
+        .field("bytes_read", result.bytes_read)
+        .line(format!("Encrypted: {}", output_path.display()))
+        .line(format!("UUID: {}", result.uuid))
+        .line(format!("Read: {} bytes", result.bytes_read)))
+}
+
+/// Decrypt `input` (a file or `-`) to standard output.
+pub fn decrypt_to_stdout(
+    input: &Path,
+    password: String,
+    progress: Option<ProgressCallback>,
+) -> Result<Report, CliError> {
+    let reader = open_input(input)?;
+    let result = FileCrypt::default()
+        .decrypt_stream(
+            reader,
+            std::io::stdout().lock(),
+            StreamDecryptOptions {
+                password,
+                progress,
+                identity: None,
+            },
+        )?;
+
+    let report = Report::new("decrypt")
+        .on_stderr()
+        .field("output_path", "-")
+        .field("bytes_written", result.bytes_written)
+        .field("metadata", report::metadata_json(&result.metadata))
+        .field("signature", report::signature_json(&result.signature))
+        .line(format!("Written: {} bytes", result.bytes_written));
+    Ok(report::metadata_lines(report, &result.metadata)
+        .line(report::signature_text(&result.signature)))
+}
+
+/// Copy standard input into a temp file below `dir`, so it can be
+/// decrypted like any other file (directory archives need a real
+/// file to extract from).
+pub fn spool_stdin(dir: &Path) -> Result<NamedTempFile, CliError> {
+    let mut spool = NamedTempFile::new_in(dir).map_err(io_error)?;
+    std::io::copy(&mut std::io::stdin().lock(), &mut spool)
+        .map_err(io_error)?;
+    Ok(spool)
+}
+
+fn io_error(e: std::io::Error) -> CliError {
+    hoplixi_file_crypt::CryptError::Io(e).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+    use crate::cli::args::Cli;
+
+    fn encrypt_args(extra: &[&str]) -> EncryptArgs {
+        let argv = ["hoplixi-crypt", "encrypt", "in.txt", "-o", "-"];
+        let cli =
+            Cli::try_parse_from(argv.iter().chain(extra)).unwrap();
+        match cli.command {
+            Command::Encrypt(args) => args,
+            _ => unreachable!(),
+        }
+    }
+
+    fn refusal(extra: &[&str]) -> String {
+        encrypt(encrypt_args(extra), None)
+            .err()
+            .expect("the flag is refused")
+            .message
+    }
+
+    #[test]
+    fn test_hide_lengths_needs_files() {
+        assert_eq!(
+            refusal(&["--hide-lengths"]),
+            "--hide-lengths needs a file input and output"
+        );
+    }
+
+    #[test]
+    fn test_volume_size_needs_files() {
+        assert_eq!(
+            refusal(&["--volume-size", "4M"]),
+            "--volume-size needs a file input and output"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use hoplixi_file_crypt::{
+    CipherSuite, EncryptedMetadata, ShredReport, SignatureStatus, SkipReason,
+    SkippedEntry,
+};
+use serde_json::{json, Map, Value};
+
+use super::CliError;
+
+/// Result of a command, printed either as text or as one JSON
+/// object on stdout.
+pub struct Report {
+    command: &'static str,
+    fields: Map<String, Value>,
+    lines: Vec<String>,
+    exit_code: i32,
+    stderr: bool,
+}
+
+impl Report {
+    pub fn new(command: &'static str) -> Self {
+        Self {
+            command,
+            fields: Map::new(),
+            lines: Vec::new(),
+            exit_code: 0,
+            stderr: false,
+        }
+    }
+
+    /// Print to stderr, for commands whose data goes to stdout.
+    pub fn on_stderr(mut self) -> Self {
+        self.stderr = true;
+        self
+    }
+
+    /// Exit with `code` after printing; the JSON document then
+    /// reports `"ok": false`.
+    pub fn with_exit_code(mut self, code: i32) -> Self {
+        self.exit_code = code;
+        self
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+
+    /// Add a JSON field.
+    pub fn field(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.fields.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Add a line of human-readable output.
+    pub fn line(mut self, text: impl Into<String>) -> Self {
+        self.lines.push(text.into());
+        self
+    }
+
+    pub fn print(mut self, json: bool) {
+        if json {
+            let mut doc = Map::new();
+            doc.insert("ok".to_string(), Value::Bool(self.exit_code == 0));
+            doc.insert("command".to_string(), self.command.into());
+            doc.extend(std::mem::take(&mut self.fields));
+            self.emit(&Value::Object(doc).to_string());
+        } else {
+            for line in &self.lines {
+                self.emit(line);
+            }
+        }
+    }
+
+    fn emit(&self, text: &str) {
+        if self.stderr {
+            eprintln!("{text}");
+        } else {
+            println!("{text}");
+        }
+    }
+}
+
+/// Print a failure: as a JSON document with `--json` (on stdout,
+/// unless stdout carries data), otherwise as a message on stderr.
+pub fn print_error(error: &CliError, json: bool, data_on_stdout: bool) {
+    if json {
+        let doc = json!({
+            "ok": false,
+            "error": {
+                "kind": error.kind,
+                "message": error.message,
+                "exit_code": error.exit_code,
+            },
+        });
+        if data_on_stdout {
+            eprintln!("{doc}");
+        } else {
+            println!("{doc}");
+        }
+    } else {
+        eprintln!("error: {}", error.message);
+    }
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+pub fn cipher_name(suite: CipherSuite) -> &'static str {
+    match suite {
+        CipherSuite::XChaCha20Poly1305 => "xchacha20-poly1305",
+        CipherSuite::Aes256GcmSiv => "aes-256-gcm-siv",
+    }
+}
+
+pub fn signature_json(status: &SignatureStatus) -> Value {
+    match status {
+        SignatureStatus::Unsigned => json!({ "status": "unsigned" }),
+        SignatureStatus::Valid { public_key } => json!({
+            "status": "valid",
+            "public_key": hex(public_key),
+        }),
+    }
+}
+
+pub fn signature_text(status: &SignatureStatus) -> String {
+    match status {
+        SignatureStatus::Unsigned => "Signature: none".to_string(),
+        SignatureStatus::Valid { public_key } => {
+            format!("Signature: valid (Ed25519 {})", hex(public_key))
+        }
+    }
+}
+
+pub fn shred_json(shred: &ShredReport) -> Value {
+    json!({
+        "all_shredded": shred.all_shredded(),
+        "entries": shred
+            .entries
+            .iter()
+            .map(|e| json!({
+                "path": e.path.display().to_string(),
+                "error": e.error,
+            }))
+            .collect::<Vec<_>>(),
+        "caveat": shred.caveat,
+    })
+}
+
+/// Human-readable lines for a shred pass: failures only, then the
+/// caveat.
+pub fn shred_lines(mut report: Report, shred: &ShredReport) -> Report {
+    let failed: Vec<_> =
+        shred.entries.iter().filter(|e| e.error.is_some()).collect();
+    report = report.line(format!(
+        "Shredded: {} of {} entries",
+        shred.entries.len() - failed.len(),
+        shred.entries.len()
+    ));
+    for entry in failed {
+        report = report.line(format!(
+            "Not shredded: {}: {}",
+            entry.path.display(),
+            entry.error.as_deref().unwrap_or_default()
+        ));
+    }
+    report.line(format!("Note: {}", shred.caveat))
+}
+
+pub fn skipped_json(skipped: &[SkippedEntry]) -> Value {
+    skipped
+        .iter()
+        .map(|entry| {
+            let size = match entry.reason {
+                SkipReason::TooLarge { size } => Some(size),
+                _ => None,
+            };
+            json!({
+                "path": entry.path.display().to_string(),
+                "reason": entry.reason.as_str(),
+                "size": size,
+            })
+        })
+        .collect()
+}
+
+/// Human-readable lines for the entries a directory filter left out.
+pub fn skipped_lines(mut report: Report, skipped: &[SkippedEntry]) -> Report {
+    for entry in skipped {
+        report = report.line(format!(
+            "Skipped: {} ({})",
+            entry.path.display(),
+            entry.reason.as_str()
+        ));
+    }
+    report
+}
+
+pub fn metadata_json(metadata: &EncryptedMetadata) -> Value {
+    json!({
+        "original_filename": metadata.original_filename,
+        "original_extension": metadata.original_extension,
+        "original_size": metadata.original_size,
+        "gzip_compressed": metadata.gzip_compressed,
+        "uuid": metadata.uuid,
+        "metadata": metadata.metadata,
+        "signer_public_key": metadata.signer_public_key.map(|k| hex(&k)),
+    })
+}
+
+/// Human-readable lines for a decrypted metadata block.
+pub fn metadata_lines(mut report: Report, metadata: &EncryptedMetadata) -> Report {
+    let name = if metadata.original_extension.is_empty() {
+        metadata.original_filename.clone()
+    } else {
+        format!(
+            "{}.{}",
+            metadata.original_filename, metadata.original_extension
+        )
+    };
+    report = report
+        .line(format!("Original: {name}"))
+        .line(format!("Size: {} bytes", metadata.original_size))
+        .line(format!("UUID: {}", metadata.uuid))
+        .line(format!("Gzip: {}", metadata.gzip_compressed));
+
+    let mut entries: Vec<_> = metadata.metadata.iter().collect();
+    entries.sort();
+    for (key, value) in entries {
+        report = report.line(format!("Meta: {key}={value}"));
+    }
+    if let Some(key) = metadata.signer_public_key {
+        report = report.line(format!("Signer: {}", hex(&key)));
+    }
+    report
+}
@@ -0,0 +1,264 @@
+//! Selecting the entries of a directory to pack (see
+//! [`DirectoryFilter`]).
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use globset::{GlobBuilder, GlobMatcher};
+
+use crate::error::{CryptError, Result};
+use crate::types::{DirectoryFilter, SkipReason};
+
+/// One pattern, read the way a `.gitignore` line is.
+struct Rule {
+    matcher: GlobMatcher,
+    /// `!pattern`: takes back an earlier match.
+    negated: bool,
+    /// `pattern/`: matches directories only.
+    dir_only: bool,
+}
+
+impl Rule {
+    /// Parse `line`, relative to the directory `base` (`/`-separated,
+    /// empty for the top). Blank lines and comments yield `None`.
+    fn parse(line: &str, base: &str) -> Result<Option<Self>> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+        let (negated, pattern) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let (dir_only, pattern) = match pattern.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, pattern),
+        };
+
+        // Without a slash the pattern matches a name at any depth;
+        // with one it is anchored to `base`.
+        let anchored = match pattern.strip_prefix('/') {
+            Some(rest) => Some(rest),
+            None => pattern.contains('/').then_some(pattern),
+        };
+        // The directory name is literal text, not part of the pattern.
+        let base = globset::escape(base);
+        let glob = match (anchored, base.is_empty()) {
+            (Some(rest), true) => rest.to_string(),
+            (Some(rest), false) => format!("{base}/{rest}"),
+            (None, true) => format!("**/{pattern}"),
+            (None, false) => format!("{base}/**/{pattern}"),
+        };
+        let matcher = GlobBuilder::new(&glob)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| {
+                CryptError::Compression(format!("pattern `{line}`: {e}"))
+            })?
+            .compile_matcher();
+        Ok(Some(Self {
+            matcher,
+            negated,
+            dir_only,
+        }))
+    }
+
+    fn matches(&self, path: &str, is_dir: bool) -> bool {
+        (is_dir || !self.dir_only) && self.matcher.is_match(path)
+    }
+}
+
+/// Whether the last rule of `rules` matching `path` is a positive
+/// one.
+fn matched(rules: &[Rule], path: &str, is_dir: bool) -> bool {
+    rules
+        .iter()
+        .rev()
+        .find(|rule| rule.matches(path, is_dir))
+        .is_some_and(|rule| !rule.negated)
+}
+
+fn parse_all<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+    base: &str,
+) -> Result<Vec<Rule>> {
+    lines
+        .into_iter()
+        .filter_map(|line| Rule::parse(line, base).transpose())
+        .collect()
+}
+
+/// A [`DirectoryFilter`] compiled for one walk over a directory.
+pub(crate) struct EntryFilter {
+    include: Vec<Rule>,
+    exclude: Vec<Rule>,
+    ignore_file: Option<String>,
+    /// Rules of every ignore file seen so far, outer before inner,
+    /// each anchored to its own directory.
+    ignore: Vec<Rule>,
+    max_file_size: Option<u64>,
+}
+
+impl EntryFilter {
+    pub(crate) fn new(filter: &DirectoryFilter) -> Result<Self> {
+        Ok(Self {
+            include: parse_all(filter.include.iter().map(String::as_str), "")?,
+            exclude: parse_all(filter.exclude.iter().map(String::as_str), "")?,
+            ignore_file: filter.ignore_file.clone(),
+            ignore: Vec::new(),
+            max_file_size: filter.max_file_size,
+        })
+    }
+
+    /// Add the rules of the ignore file in `dir`, whose path relative
+    /// to the top is `base`.
+    pub(crate) fn enter_dir(&mut self, dir: &Path, base: &str) -> Result<()> {
+        let Some(name) = &self.ignore_file else {
+            return Ok(());
+        };
+        let text = match fs::read_to_string(dir.join(name)) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        self.ignore.extend(parse_all(text.lines(), base)?);
+        Ok(())
+    }
+
+    /// Why the entry at `path` (relative, `/`-separated) is left out,
+    /// if it is. `size` is only looked at for files.
+    pub(crate) fn check(
+        &self,
+        path: &str,
+        is_dir: bool,
+        size: u64,
+    ) -> Option<SkipReason> {
+        if matched(&self.exclude, path, is_dir) {
+            return Some(SkipReason::Excluded);
+        }
+        if matched(&self.ignore, path, is_dir) {
+            return Some(SkipReason::Ignored);
+        }
+        if is_dir {
+            return None;
+        }
+        if !self.include.is_empty() && !matched(&self.include, path, false) {
+            return Some(SkipReason::NotIncluded);
+        }
+        match self.max_file_size {
+            Some(max) if size > max => Some(SkipReason::TooLarge { size }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> EntryFilter {
+        EntryFilter::new(&DirectoryFilter {
+            include: include.iter().map(|s| s.to_string()).collect(),
+            exclude: exclude.iter().map(|s| s.to_string()).collect(),
+            ignore_file: None,
+            max_file_size: Some(100),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_gitignore_style_patterns() {
+        let f = filter(&[], &[".git", "target/", "/build", "docs/*.tmp"]);
+        assert_eq!(f.check(".git", true, 0), Some(SkipReason::Excluded));
+        assert_eq!(f.check("sub/.git", true, 0), Some(SkipReason::Excluded));
+        assert_eq!(f.check("a/target", true, 0), Some(SkipReason::Excluded));
+        // `target/` only matches directories.
+        assert_eq!(f.check("a/target", false, 0), None);
+        assert_eq!(f.check("build", true, 0), Some(SkipReason::Excluded));
+        assert_eq!(f.check("src/build", true, 0), None);
+        assert_eq!(f.check("docs/a.tmp", false, 0), Some(SkipReason::Excluded));
+        assert_eq!(f.check("docs/x/a.tmp", false, 0), None);
+    }
+
+    #[test]
+    fn test_include_negation_and_size() {
+        let f = filter(&["*.rs", "!generated.rs"], &[]);
+        assert_eq!(f.check("src/main.rs", false, 10), None);
+        assert_eq!(
+            f.check("src/generated.rs", false, 10),
+            Some(SkipReason::NotIncluded)
+        );
+        assert_eq!(
+            f.check("README.md", false, 10),
+            Some(SkipReason::NotIncluded)
+        );
+        // Directories are not subject to `include`.
+        assert_eq!(f.check("src", true, 0), None);
+        assert_eq!(
+            f.check("big.rs", false, 101),
+            Some(SkipReason::TooLarge { size: 101 })
+        );
+    }
+
+    #[test]
+    fn test_ignore_files_are_scoped_to_their_directory() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir(dir.path().join("web")).unwrap();
+        fs::write(dir.path().join(".ignore"), "*.log\n# comment\n\n").unwrap();
+        fs::write(
+            dir.path().join("web/.ignore"),
+            "node_modules/\n!keep.log\n/dist\n",
+        )
+        .unwrap();
+
+        let mut f = EntryFilter::new(&DirectoryFilter {
+            ignore_file: Some(".ignore".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        f.enter_dir(dir.path(), "").unwrap();
+        f.enter_dir(&dir.path().join("web"), "web").unwrap();
+
+        let ignored = Some(SkipReason::Ignored);
+        assert_eq!(f.check("a.log", false, 0), ignored);
+        assert_eq!(f.check("web/keep.log", false, 0), None);
+        assert_eq!(f.check("keep.log", false, 0), ignored);
+        assert_eq!(f.check("web/x/node_modules", true, 0), ignored);
+        assert_eq!(f.check("node_modules", true, 0), None);
+        assert_eq!(f.check("web/dist", true, 0), ignored);
+        assert_eq!(f.check("web/x/dist", true, 0), None);
+    }
+
+    #[test]
+    fn test_ignore_file_in_a_directory_named_like_a_glob() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let base = "[draft] {v2}*";
+        fs::create_dir(dir.path().join(base)).unwrap();
+        fs::write(dir.path().join(base).join(".ignore"), "*.log\n/out\n")
+            .unwrap();
+
+        let mut f = EntryFilter::new(&DirectoryFilter {
+            ignore_file: Some(".ignore".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        f.enter_dir(&dir.path().join(base), base).unwrap();
+
+        let ignored = Some(SkipReason::Ignored);
+        assert_eq!(f.check("[draft] {v2}*/a.log", false, 0), ignored);
+        assert_eq!(f.check("[draft] {v2}*/out", true, 0), ignored);
+        assert_eq!(f.check("d v2x/a.log", false, 0), None);
+        assert_eq!(f.check("d v2/out", true, 0), None);
+    }
+
+    #[test]
+    fn test_bad_pattern() {
+        let result = EntryFilter::new(&DirectoryFilter {
+            exclude: vec!["a[".to_string()],
+            ..Default::default()
+        });
+        assert!(matches!(result, Err(CryptError::Compression(_))));
+    }
+}
@@ -2,10 +2,11 @@ use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::Path;
 
-use flate2::read::GzDecoder;
-use flate2::write::GzEncoder;
 use flate2::Compression;
+use flate2::read::{self, GzDecoder};
+use flate2::write::{self, GzEncoder};
 
+use super::{CompressProgress, ProgressReader};
 use crate::error::Result;
 
 const BUF_SIZE: usize = 64 * 1024;
@@ -14,32 +15,93 @@ const BUF_SIZE: usize = 64 * 1024;
 ///
 /// Reads from `input_path` and writes compressed data to `output_path`.
 pub fn gzip_compress(input_path: &Path, output_path: &Path) -> Result<()> {
+    gzip_compress_with_progress(input_path, output_path, |_| {})
+}
+
+/// [`gzip_compress`], reporting the input bytes read.
+pub fn gzip_compress_with_progress(
+    input_path: &Path,
+    output_path: &Path,
+    mut on_progress: impl FnMut(CompressProgress),
+) -> Result<()> {
     let input = File::open(input_path)?;
+    let total_bytes = input.metadata()?.len();
     let mut reader = BufReader::with_capacity(BUF_SIZE, input);
+    on_progress(CompressProgress {
+        total_bytes,
+        ..Default::default()
+    });
 
     let output = File::create(output_path)?;
     let writer = BufWriter::with_capacity(BUF_SIZE, output);
     let mut encoder = GzEncoder::new(writer, Compression::default());
 
     let mut buf = vec![0u8; BUF_SIZE];
+    let mut bytes = 0;
     loop {
         let n = reader.read(&mut buf)?;
         if n == 0 {
             break;
         }
         encoder.write_all(&buf[..n])?;
+        bytes += n as u64;
+        on_progress(CompressProgress {
+            bytes,
+            total_bytes,
+            ..Default::default()
+        });
     }
 
     encoder.finish()?;
     Ok(())
 }
 
+/// Reader yielding the gzip-compressed form of `reader`.
+pub fn compressing_reader<R: Read>(reader: R) -> read::GzEncoder<R> {
+    read::GzEncoder::new(reader, Compression::default())
+}
+
+/// Writer that compresses the data written to it into `writer`.
+/// Call `try_finish` once everything is written.
+pub fn compressing_writer<W: Write>(writer: W) -> write::GzEncoder<W> {
+    write::GzEncoder::new(writer, Compression::default())
+}
+
+/// Writer that decompresses the gzip data written to it into
+/// `writer`. Call `try_finish` once everything is written.
+pub fn decompressing_writer<W: Write>(writer: W) -> write::GzDecoder<W> {
+    write::GzDecoder::new(writer)
+}
+
 /// Gzip-decompress a file in streaming mode.
 ///
 /// Reads from `input_path` and writes decompressed data to `output_path`.
 pub fn gzip_decompress(input_path: &Path, output_path: &Path) -> Result<()> {
+    gzip_decompress_with_progress(input_path, output_path, |_| {})
+}
+
+/// [`gzip_decompress`], reporting the compressed bytes consumed.
+pub fn gzip_decompress_with_progress(
+    input_path: &Path,
+    output_path: &Path,
+    mut on_progress: impl FnMut(CompressProgress),
+) -> Result<()> {
     let input = File::open(input_path)?;
-    let reader = BufReader::with_capacity(BUF_SIZE, input);
+    let total_bytes = input.metadata()?.len();
+    on_progress(CompressProgress {
+        total_bytes,
+        ..Default::default()
+    });
+    let reader = ProgressReader::new(
+        BufReader::with_capacity(BUF_SIZE, input),
+        |bytes| {
+            on_progress(CompressProgress {
+                bytes,
+                total_bytes,
+                ..Default::default()
+            })
+        },
+    );
     let mut decoder = GzDecoder::new(reader);
 
     let output = File::create(output_path)?;
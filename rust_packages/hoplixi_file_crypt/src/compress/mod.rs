@@ -1,2 +1,216 @@
+#[cfg(feature = "sevenz")]
+mod filter;
+#[cfg(feature = "gzip")]
 pub mod gzip;
+#[cfg(feature = "sevenz")]
+mod names;
+#[cfg(feature = "sevenz")]
 pub mod sevenz;
+
+use std::io::{Read, Write};
+use std::path::Path;
+
+use crate::error::Result;
+use crate::types::{DirectoryFilter, SkippedEntry};
+#[cfg(not(all(feature = "gzip", feature = "sevenz")))]
+use crate::error::CryptError;
+
+/// Progress of a compression or decompression step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompressProgress {
+    /// Input bytes consumed so far (for 7z extraction: bytes
+    /// written out).
+    pub bytes: u64,
+    /// Total input bytes (0 if unknown).
+    pub total_bytes: u64,
+    /// Archive entries handled so far (7z only).
+    pub files: u64,
+    /// Files in the archive (0 for gzip).
+    pub total_files: u64,
+}
+
+/// Reader that reports its running byte count after every read.
+#[cfg(any(feature = "gzip", feature = "sevenz"))]
+pub(crate) struct ProgressReader<R, F> {
+    inner: R,
+    bytes: u64,
+    on_read: F,
+}
+
+#[cfg(any(feature = "gzip", feature = "sevenz"))]
+impl<R, F: FnMut(u64)> ProgressReader<R, F> {
+    pub(crate) fn new(inner: R, on_read: F) -> Self {
+        Self {
+            inner,
+            bytes: 0,
+            on_read,
+        }
+    }
+}
+
+#[cfg(any(feature = "gzip", feature = "sevenz"))]
+impl<R: Read, F: FnMut(u64)> Read for ProgressReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        if n > 0 {
+            self.bytes += n as u64;
+            (self.on_read)(self.bytes);
+        }
+        Ok(n)
+    }
+}
+
+// ── Feature-independent entry points ─────────────────────
+//
+// The engine calls these whatever the build; without the matching
+// feature they fail with `CryptError::Compression`.
+
+/// Error for a compression format this build leaves out.
+#[cfg(not(all(feature = "gzip", feature = "sevenz")))]
+pub(crate) fn unsupported(feature: &str) -> CryptError {
+    CryptError::Compression(format!(
+        "built without the `{feature}` feature"
+    ))
+}
+
+/// Pack the entries of the directory `dir` that `filter` selects
+/// into a 7z archive at `output`. Returns the entries left out.
+#[cfg(feature = "sevenz")]
+pub(crate) fn pack_directory(
+    dir: &Path,
+    output: &Path,
+    filter: &DirectoryFilter,
+    on_progress: impl FnMut(CompressProgress),
+) -> Result<Vec<SkippedEntry>> {
+    sevenz::compress_directory_filtered(dir, output, filter, on_progress)
+}
+
+#[cfg(not(feature = "sevenz"))]
+pub(crate) fn pack_directory(
+    _dir: &Path,
+    _output: &Path,
+    _filter: &DirectoryFilter,
+    _on_progress: impl FnMut(CompressProgress),
+) -> Result<Vec<SkippedEntry>> {
+    Err(unsupported("sevenz"))
+}
+
+/// Upper bound of the size of the archive [`pack_directory`] would
+/// write for `dir` and `filter`.
+#[cfg(feature = "sevenz")]
+pub(crate) fn archive_len_bound(
+    dir: &Path,
+    filter: &DirectoryFilter,
+) -> Result<u64> {
+    sevenz::archive_len_bound(dir, filter)
+}
+
+#[cfg(not(feature = "sevenz"))]
+pub(crate) fn archive_len_bound(
+    _dir: &Path,
+    _filter: &DirectoryFilter,
+) -> Result<u64> {
+    Err(unsupported("sevenz"))
+}
+
+/// Upper bound of the compressed size of `len` bytes, for gzip and
+/// LZMA2 alike: both store data that does not compress in blocks
+/// with a few bytes of framing each.
+pub(crate) fn compressed_len_bound(len: u64) -> u64 {
+    len + len / 1024 + 1024
+}
+
+/// Unpack the 7z archive at `archive` into `output_dir`.
+#[cfg(feature = "sevenz")]
+pub(crate) fn unpack_archive(
+    archive: &Path,
+    output_dir: &Path,
+    on_progress: impl FnMut(CompressProgress),
+) -> Result<()> {
+    sevenz::decompress_archive_with_progress(archive, output_dir, on_progress)
+}
+
+#[cfg(not(feature = "sevenz"))]
+pub(crate) fn unpack_archive(
+    _archive: &Path,
+    _output_dir: &Path,
+    _on_progress: impl FnMut(CompressProgress),
+) -> Result<()> {
+    Err(unsupported("sevenz"))
+}
+
+/// Gzip the file at `input` into `output`.
+#[cfg(feature = "gzip")]
+pub(crate) fn gzip_file(
+    input: &Path,
+    output: &Path,
+    on_progress: impl FnMut(CompressProgress),
+) -> Result<()> {
+    gzip::gzip_compress_with_progress(input, output, on_progress)
+}
+
+#[cfg(not(feature = "gzip"))]
+pub(crate) fn gzip_file(
+    _input: &Path,
+    _output: &Path,
+    _on_progress: impl FnMut(CompressProgress),
+) -> Result<()> {
+    Err(unsupported("gzip"))
+}
+
+/// Undo [`gzip_file`].
+#[cfg(feature = "gzip")]
+pub(crate) fn gunzip_file(
+    input: &Path,
+    output: &Path,
+    on_progress: impl FnMut(CompressProgress),
+) -> Result<()> {
+    gzip::gzip_decompress_with_progress(input, output, on_progress)
+}
+
+#[cfg(not(feature = "gzip"))]
+pub(crate) fn gunzip_file(
+    _input: &Path,
+    _output: &Path,
+    _on_progress: impl FnMut(CompressProgress),
+) -> Result<()> {
+    Err(unsupported("gzip"))
+}
+
+/// Run `f` over the gzip-compressed form of `reader`.
+#[cfg(feature = "gzip")]
+pub(crate) fn with_gzip_reader<R: Read, T>(
+    reader: R,
+    f: impl FnOnce(&mut dyn Read) -> Result<T>,
+) -> Result<T> {
+    f(&mut gzip::compressing_reader(reader))
+}
+
+#[cfg(not(feature = "gzip"))]
+pub(crate) fn with_gzip_reader<R: Read, T>(
+    _reader: R,
+    _f: impl FnOnce(&mut dyn Read) -> Result<T>,
+) -> Result<T> {
+    Err(unsupported("gzip"))
+}
+
+/// Run `f` with a writer that decompresses gzip into `writer`, and
+/// check that the gzip stream is complete afterwards.
+#[cfg(feature = "gzip")]
+pub(crate) fn with_gunzip_writer<W: Write, T>(
+    writer: W,
+    f: impl FnOnce(&mut dyn Write) -> Result<T>,
+) -> Result<T> {
+    let mut gz = gzip::decompressing_writer(writer);
+    let value = f(&mut gz)?;
+    gz.try_finish()?;
+    Ok(value)
+}
+
+#[cfg(not(feature = "gzip"))]
+pub(crate) fn with_gunzip_writer<W: Write, T>(
+    _writer: W,
+    _f: impl FnOnce(&mut dyn Write) -> Result<T>,
+) -> Result<T> {
+    Err(unsupported("gzip"))
+}
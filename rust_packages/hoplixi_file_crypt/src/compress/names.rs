@@ -0,0 +1,169 @@
+//! Archive entry names: portable `/`-separated strings, built from
+//! and turned back into file system paths.
+//!
+//! Names are stored in NFC, so that an archive packed on macOS
+//! (which hands out decomposed names) unpacks under the same names
+//! as one packed on Linux. On Unix, a name that is not valid UTF-8
+//! keeps its raw bytes: each byte of an invalid sequence, always
+//! `0x80..=0xFF`, is stored as the private-use character
+//! `U+F780 + (byte - 0x80)` and turned back into that byte on
+//! extraction. A name that already contains one of these characters
+//! stores it the same way, as the three bytes of its UTF-8 encoding,
+//! so that it does not come back as a single raw byte.
+
+use std::path::{Component, Path, PathBuf};
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::error::{CryptError, Result};
+
+/// Stands for the byte `0x80` of a name that is not valid UTF-8.
+#[cfg(unix)]
+const RAW_BYTE_BASE: u32 = 0xF780;
+
+/// Archive name of `path`, relative to the packed directory.
+pub(crate) fn archive_name(path: &Path) -> Result<String> {
+    let mut name = String::new();
+    for component in path.components() {
+        if !name.is_empty() {
+            name.push('/');
+        }
+        push_component(&mut name, component.as_os_str())?;
+    }
+    Ok(name.nfc().collect())
+}
+
+#[cfg(unix)]
+fn push_component(name: &mut String, part: &std::ffi::OsStr) -> Result<()> {
+    use std::os::unix::ffi::OsStrExt;
+
+    for chunk in part.as_bytes().utf8_chunks() {
+        for c in chunk.valid().chars() {
+            match is_raw_byte(c) {
+                true => push_raw(name, c.encode_utf8(&mut [0; 4]).as_bytes()),
+                false => name.push(c),
+            }
+        }
+        push_raw(name, chunk.invalid());
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn push_raw(name: &mut String, bytes: &[u8]) {
+    name.extend(bytes.iter().map(|&byte| {
+        char::from_u32(RAW_BYTE_BASE + u32::from(byte - 0x80))
+            .expect("private-use code point")
+    }));
+}
+
+#[cfg(unix)]
+fn is_raw_byte(c: char) -> bool {
+    (RAW_BYTE_BASE..RAW_BYTE_BASE + 0x80).contains(&u32::from(c))
+}
+
+#[cfg(not(unix))]
+fn push_component(name: &mut String, part: &std::ffi::OsStr) -> Result<()> {
+    let part = part.to_str().ok_or_else(|| {
+        CryptError::Compression(format!(
+            "Non-UTF8 path: {}",
+            Path::new(part).display()
+        ))
+    })?;
+    name.push_str(part);
+    Ok(())
+}
+
+/// Path of the entry `name` relative to the output directory.
+/// Refuses names that are empty, absolute or climb out with `..`.
+pub(crate) fn entry_path(name: &str) -> Result<PathBuf> {
+    let path = decode(name);
+    let safe = path.components().next().is_some()
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+    match safe {
+        true => Ok(path),
+        false => Err(CryptError::Compression(format!(
+            "Unsafe entry name: {name:?}"
+        ))),
+    }
+}
+
+#[cfg(unix)]
+fn decode(name: &str) -> PathBuf {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+
+    let mut bytes = Vec::with_capacity(name.len());
+    for c in name.chars() {
+        match is_raw_byte(c) {
+            true => bytes.push(0x80 + (u32::from(c) - RAW_BYTE_BASE) as u8),
+            false => {
+                bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes())
+            }
+        }
+    }
+    PathBuf::from(OsString::from_vec(bytes))
+}
+
+#[cfg(not(unix))]
+fn decode(name: &str) -> PathBuf {
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_are_nfc_and_slash_separated() {
+        let path = Path::new("cafe\u{301}").join("notes.txt");
+        assert_eq!(archive_name(&path).unwrap(), "caf\u{e9}/notes.txt");
+        assert_eq!(
+            entry_path("caf\u{e9}/notes.txt").unwrap(),
+            Path::new("caf\u{e9}").join("notes.txt")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_raw_bytes_round_trip() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = Path::new(OsStr::from_bytes(b"caf\xe9/\xff\xfe.bin"));
+        let name = archive_name(path).unwrap();
+        assert_eq!(name, "caf\u{f7e9}/\u{f7ff}\u{f7fe}.bin");
+        assert_eq!(entry_path(&name).unwrap(), path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_private_use_names_round_trip() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        // A real U+F7E9 next to the raw byte 0xE9 it would stand for.
+        let path = Path::new(OsStr::from_bytes(b"\xef\x9f\xa9-\xe9"));
+        let name = archive_name(path).unwrap();
+        assert_eq!(name, "\u{f7ef}\u{f79f}\u{f7a9}-\u{f7e9}");
+        assert_eq!(entry_path(&name).unwrap(), path);
+        assert_eq!(entry_path(&name).unwrap().to_str(), None);
+        assert_eq!(
+            entry_path(&archive_name(Path::new("\u{f7e9}")).unwrap())
+                .unwrap(),
+            Path::new("\u{f7e9}")
+        );
+    }
+
+    #[test]
+    fn test_unsafe_names() {
+        for name in ["", "../x", "a/../../x", "/etc/passwd", "."] {
+            assert!(
+                matches!(entry_path(name), Err(CryptError::Compression(_))),
+                "{name:?}"
+            );
+        }
+    }
+}
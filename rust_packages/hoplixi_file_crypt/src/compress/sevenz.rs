@@ -1,10 +1,17 @@
-use std::fs::File;
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
 
-use sevenz_rust2::ArchiveEntry;
+use sevenz_rust2::{ArchiveEntry, ArchiveReader, Password};
+use unicode_normalization::UnicodeNormalization;
 use walkdir::WalkDir;
 
+use super::filter::EntryFilter;
+use super::names;
+use super::{CompressProgress, ProgressReader};
 use crate::error::{CryptError, Result};
+use crate::types::{DirectoryFilter, LinkPolicy, SkipReason, SkippedEntry};
 
 /// Compress a directory into a 7z archive.
 ///
@@ -14,6 +21,33 @@ pub fn compress_directory(
     dir_path: &Path,
     output_path: &Path,
 ) -> Result<PathBuf> {
+    compress_directory_with_progress(dir_path, output_path, |_| {})
+}
+
+/// [`compress_directory`], reporting the file bytes read and the
+/// files added. Totals come from a first walk over the directory.
+pub fn compress_directory_with_progress(
+    dir_path: &Path,
+    output_path: &Path,
+    on_progress: impl FnMut(CompressProgress),
+) -> Result<PathBuf> {
+    compress_directory_filtered(
+        dir_path,
+        output_path,
+        &DirectoryFilter::default(),
+        on_progress,
+    )?;
+    Ok(output_path.to_path_buf())
+}
+
+/// [`compress_directory_with_progress`], adding only the entries
+/// `filter` selects. Returns the entries left out.
+pub fn compress_directory_filtered(
+    dir_path: &Path,
+    output_path: &Path,
+    filter: &DirectoryFilter,
+    mut on_progress: impl FnMut(CompressProgress),
+) -> Result<Vec<SkippedEntry>> {
     if !dir_path.is_dir() {
         return Err(CryptError::Compression(format!(
             "Not a directory: {}",
@@ -21,54 +55,249 @@ pub fn compress_directory(
         )));
     }
 
-    let archive_path = output_path.to_path_buf();
-    let output_file = File::create(&archive_path)?;
+    let (entries, skipped) = select_entries(dir_path, filter)?;
+    let mut progress = CompressProgress::default();
+    for entry in entries.iter().filter(|e| !matches!(e.kind, Kind::Dir)) {
+        progress.total_files += 1;
+        progress.total_bytes += entry.size;
+    }
+    on_progress(progress);
+
+    let output_file = File::create(output_path)?;
     let mut writer = sevenz_rust2::ArchiveWriter::new(output_file)
         .map_err(|e| CryptError::Compression(format!("7z writer init: {e}")))?;
 
-    for entry in WalkDir::new(dir_path) {
-        let entry =
-            entry.map_err(|e| CryptError::Compression(format!("Walk: {e}")))?;
+    for entry in &entries {
+        match &entry.kind {
+            Kind::Dir => {
+                let sz_entry = ArchiveEntry::new_directory(&entry.name);
+                writer
+                    .push_archive_entry::<&[u8]>(sz_entry, None)
+                    .map_err(|e| {
+                        CryptError::Compression(format!("7z add dir: {e}"))
+                    })?;
+            }
+            Kind::File => {
+                let base = progress.bytes;
+                let file =
+                    ProgressReader::new(File::open(&entry.path)?, |bytes| {
+                        progress.bytes = base + bytes;
+                        on_progress(progress);
+                    });
+                let sz_entry = ArchiveEntry::new_file(&entry.name);
+                writer
+                    .push_archive_entry(sz_entry, Some(file))
+                    .map_err(|e| {
+                        CryptError::Compression(format!("7z add file: {e}"))
+                    })?;
+                progress.files += 1;
+                on_progress(progress);
+            }
+            Kind::Link(target) => {
+                // The p7zip convention: Unix mode in the high half of
+                // the attributes, link target as the content.
+                let mut sz_entry = ArchiveEntry::new_file(&entry.name);
+                sz_entry.has_windows_attributes = true;
+                sz_entry.windows_attributes =
+                    UNIX_EXTENSION | ((S_IFLNK | 0o777) << 16);
+                writer
+                    .push_archive_entry(sz_entry, Some(target.as_slice()))
+                    .map_err(|e| {
+                        CryptError::Compression(format!("7z add link: {e}"))
+                    })?;
+                progress.files += 1;
+                progress.bytes += entry.size;
+                on_progress(progress);
+            }
+        }
+    }
+
+    writer
+        .finish()
+        .map_err(|e| CryptError::Compression(format!("7z finish: {e}")))?;
+
+    Ok(skipped)
+}
+
+/// Upper bound of the size of the archive
+/// [`compress_directory_filtered`] writes: the data in one stream
+/// that may not compress, plus the names (UTF-16 in the archive
+/// header) and fixed-size fields of every entry.
+pub(crate) fn archive_len_bound(
+    dir_path: &Path,
+    filter: &DirectoryFilter,
+) -> Result<u64> {
+    if !dir_path.is_dir() {
+        return Err(CryptError::Compression(format!(
+            "Not a directory: {}",
+            dir_path.display()
+        )));
+    }
+    let (entries, _) = select_entries(dir_path, filter)?;
+    let data: u64 = entries.iter().map(|e| e.size).sum();
+    let headers: u64 =
+        entries.iter().map(|e| 2 * e.name.len() as u64 + 64).sum();
+    Ok(super::compressed_len_bound(data) + headers)
+}
+
+/// Attribute flag marking a Unix mode in the high 16 bits.
+const UNIX_EXTENSION: u32 = 0x8000;
+const S_IFMT: u32 = 0o170000;
+const S_IFLNK: u32 = 0o120000;
+
+/// Whether `entry` holds a symbolic link stored by
+/// [`LinkPolicy::Store`].
+fn is_link(entry: &ArchiveEntry) -> bool {
+    let attributes = entry.windows_attributes();
+    entry.has_windows_attributes
+        && attributes & UNIX_EXTENSION != 0
+        && (attributes >> 16) & S_IFMT == S_IFLNK
+}
 
-        let abs_path = entry.path();
-        let rel_path = abs_path
+/// A directory entry selected for the archive.
+struct Entry {
+    path: PathBuf,
+    /// Archive name; see [`names`].
+    name: String,
+    kind: Kind,
+    /// Content length: file size, or link target length.
+    size: u64,
+}
+
+enum Kind {
+    Dir,
+    File,
+    /// A symbolic link, with its target.
+    Link(Vec<u8>),
+}
+
+fn walk_error(e: impl std::fmt::Display) -> CryptError {
+    CryptError::Compression(format!("Walk: {e}"))
+}
+
+/// Target of the link at `path`, as stored in the archive: in NFC
+/// like entry names when it is UTF-8, raw bytes otherwise (Unix).
+fn link_target(path: &Path) -> Result<Vec<u8>> {
+    let target = fs::read_link(path)?;
+    #[cfg(unix)]
+    let bytes = {
+        use std::os::unix::ffi::OsStringExt;
+        target.into_os_string().into_vec()
+    };
+    #[cfg(not(unix))]
+    let bytes = target
+        .to_str()
+        .ok_or_else(|| {
+            CryptError::Compression(format!(
+                "Non-UTF8 link target: {}",
+                path.display()
+            ))
+        })?
+        .replace('\\', "/")
+        .into_bytes();
+    Ok(match String::from_utf8(bytes) {
+        Ok(text) => text.nfc().collect::<String>().into_bytes(),
+        Err(e) => e.into_bytes(),
+    })
+}
+
+/// Why the symbolic link or special file at `path` is left out
+/// under `links`; `None` for a link to store.
+fn link_reason(
+    path: &Path,
+    is_symlink: bool,
+    links: LinkPolicy,
+) -> Result<Option<SkipReason>> {
+    match (is_symlink, links) {
+        (_, LinkPolicy::Error) => {
+            let what = match is_symlink {
+                true => "Symbolic link",
+                false => "Special file",
+            };
+            Err(CryptError::Compression(format!(
+                "{what}: {}",
+                path.display()
+            )))
+        }
+        (false, _) => Ok(Some(SkipReason::SpecialFile)),
+        (true, LinkPolicy::Skip) => Ok(Some(SkipReason::Symlink)),
+        (true, _) => Ok(None),
+    }
+}
+
+/// Walk `dir_path` once, splitting its entries into those `filter`
+/// selects and those it leaves out. Left-out directories are not
+/// descended into.
+fn select_entries(
+    dir_path: &Path,
+    filter: &DirectoryFilter,
+) -> Result<(Vec<Entry>, Vec<SkippedEntry>)> {
+    let links = filter.links;
+    let mut filter = EntryFilter::new(filter)?;
+    filter.enter_dir(dir_path, "")?;
+
+    let mut entries = Vec::new();
+    let mut skipped = Vec::new();
+    let mut seen = HashSet::new();
+    let mut walk = WalkDir::new(dir_path)
+        .min_depth(1)
+        .follow_links(links == LinkPolicy::Follow)
+        .sort_by_file_name()
+        .into_iter();
+    while let Some(entry) = walk.next() {
+        let entry = entry.map_err(walk_error)?;
+        let rel_path = entry
+            .path()
             .strip_prefix(dir_path)
             .map_err(|e| CryptError::Compression(format!("Strip prefix: {e}")))?;
+        let name = names::archive_name(rel_path)?;
+        if !seen.insert(name.clone()) {
+            return Err(CryptError::Compression(format!(
+                "Two entries share the normalized name {name:?}"
+            )));
+        }
 
-        if rel_path.as_os_str().is_empty() {
+        let file_type = entry.file_type();
+        let is_dir = file_type.is_dir();
+        let size = match is_dir {
+            true => 0,
+            false => entry.metadata().map_err(walk_error)?.len(),
+        };
+        let reason = match filter.check(&name, is_dir, size) {
+            None if !is_dir && !file_type.is_file() => {
+                link_reason(entry.path(), file_type.is_symlink(), links)?
+            }
+            reason => reason,
+        };
+        if let Some(reason) = reason {
+            skipped.push(SkippedEntry {
+                path: rel_path.to_path_buf(),
+                reason,
+            });
+            if is_dir {
+                walk.skip_current_dir();
+            }
             continue;
         }
 
-        let entry_name = rel_path
-            .to_str()
-            .ok_or_else(|| {
-                CryptError::Compression("Non-UTF8 path".to_string())
-            })?
-            .replace('\\', "/");
-
-        if abs_path.is_dir() {
-            let sz_entry = ArchiveEntry::new_directory(&entry_name);
-            writer
-                .push_archive_entry::<&[u8]>(sz_entry, None)
-                .map_err(|e| {
-                    CryptError::Compression(format!("7z add dir: {e}"))
-                })?;
+        let (kind, size) = if is_dir {
+            filter.enter_dir(entry.path(), &name)?;
+            (Kind::Dir, 0)
+        } else if file_type.is_file() {
+            (Kind::File, size)
         } else {
-            let file = File::open(abs_path)?;
-            let sz_entry = ArchiveEntry::new_file(&entry_name);
-            writer
-                .push_archive_entry(sz_entry, Some(file))
-                .map_err(|e| {
-                    CryptError::Compression(format!("7z add file: {e}"))
-                })?;
-        }
+            let target = link_target(entry.path())?;
+            let size = target.len() as u64;
+            (Kind::Link(target), size)
+        };
+        entries.push(Entry {
+            path: entry.path().to_path_buf(),
+            name,
+            kind,
+            size,
+        });
     }
-
-    writer
-        .finish()
-        .map_err(|e| CryptError::Compression(format!("7z finish: {e}")))?;
-
-    Ok(archive_path)
+    Ok((entries, skipped))
 }
 
 /// Decompress a 7z archive into a directory.
@@ -76,8 +305,105 @@ pub fn decompress_archive(
     archive_path: &Path,
     output_dir: &Path,
 ) -> Result<()> {
-    sevenz_rust2::decompress_file(archive_path, output_dir)
-        .map_err(|e| CryptError::Compression(format!("7z decompress: {e}")))?;
+    decompress_archive_with_progress(archive_path, output_dir, |_| {})
+}
+
+/// [`decompress_archive`], reporting the bytes written out and the
+/// files extracted.
+pub fn decompress_archive_with_progress(
+    archive_path: &Path,
+    output_dir: &Path,
+    mut on_progress: impl FnMut(CompressProgress),
+) -> Result<()> {
+    let reader =
+        ArchiveReader::new(File::open(archive_path)?, Password::empty())
+            .map_err(|e| CryptError::Compression(format!("7z open: {e}")))?;
+    let mut progress = CompressProgress::default();
+    for entry in &reader.archive().files {
+        if !entry.is_directory() {
+            progress.total_files += 1;
+            progress.total_bytes += entry.size();
+        }
+    }
+    drop(reader);
+    on_progress(progress);
+
+    sevenz_rust2::decompress_file_with_extract_fn(
+        archive_path,
+        output_dir,
+        |entry, data, _| {
+            let base = progress.bytes;
+            let mut data = ProgressReader::new(data, |bytes| {
+                progress.bytes = base + bytes;
+                on_progress(progress);
+            });
+            extract_entry(output_dir, entry, &mut data).map_err(|e| {
+                sevenz_rust2::Error::Other(e.to_string().into())
+            })?;
+            if !entry.is_directory() {
+                progress.files += 1;
+                progress.bytes = base + entry.size();
+                on_progress(progress);
+            }
+            Ok(true)
+        },
+    )
+    .map_err(|e| CryptError::Compression(format!("7z decompress: {e}")))?;
+    Ok(())
+}
+
+/// Write out one archive entry under `output_dir`. The entry name
+/// must stay inside `output_dir`, and must not lead through a link
+/// an earlier entry created there.
+fn extract_entry(
+    output_dir: &Path,
+    entry: &ArchiveEntry,
+    data: &mut dyn Read,
+) -> Result<()> {
+    let rel_path = names::entry_path(entry.name())?;
+    let mut dest = output_dir.to_path_buf();
+    for component in rel_path.components() {
+        dest.push(component);
+        match fs::symlink_metadata(&dest) {
+            Ok(meta) if meta.file_type().is_symlink() => {
+                return Err(CryptError::Compression(format!(
+                    "Entry {:?} leads through a link",
+                    entry.name()
+                )));
+            }
+            Ok(_) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    if !is_link(entry) {
+        sevenz_rust2::default_entry_extract_fn(entry, data, &dest)
+            .map_err(|e| CryptError::Compression(format!("{e}")))?;
+        return Ok(());
+    }
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut target = Vec::new();
+    data.read_to_end(&mut target)?;
+    create_link(&target, &dest)
+}
+
+#[cfg(unix)]
+fn create_link(target: &[u8], dest: &Path) -> Result<()> {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    std::os::unix::fs::symlink(OsStr::from_bytes(target), dest)?;
+    Ok(())
+}
+
+/// Without Unix links, a stored link becomes a file holding its
+/// target.
+#[cfg(not(unix))]
+fn create_link(target: &[u8], dest: &Path) -> Result<()> {
+    fs::write(dest, target)?;
     Ok(())
 }
 
@@ -119,6 +445,205 @@ mod tests {
         assert_eq!(f2, "Hello from file2");
     }
 
+    fn pack(
+        src: &Path,
+        filter: &DirectoryFilter,
+    ) -> Result<(PathBuf, Vec<SkippedEntry>)> {
+        let archive = src.with_extension("7z");
+        let skipped =
+            compress_directory_filtered(src, &archive, filter, |_| {})?;
+        Ok((archive, skipped))
+    }
+
+    fn links(links: LinkPolicy) -> DirectoryFilter {
+        DirectoryFilter {
+            links,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_policies() {
+        use std::os::unix::fs::symlink;
+
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("source");
+        fs::create_dir_all(src.join("sub")).unwrap();
+        fs::write(src.join("sub/a.txt"), b"target").unwrap();
+        symlink("sub/a.txt", src.join("file-link")).unwrap();
+        symlink("sub", src.join("dir-link")).unwrap();
+
+        // Store: the links come back as links.
+        let (archive, skipped) = pack(&src, &links(LinkPolicy::Store)).unwrap();
+        assert!(skipped.is_empty());
+        let out = dir.path().join("stored");
+        decompress_archive(&archive, &out).unwrap();
+        assert_eq!(
+            fs::read_link(out.join("file-link")).unwrap(),
+            Path::new("sub/a.txt")
+        );
+        assert_eq!(
+            fs::read_link(out.join("dir-link")).unwrap(),
+            Path::new("sub")
+        );
+        assert_eq!(fs::read(out.join("file-link")).unwrap(), b"target");
+
+        // Follow: what they point to, as plain entries.
+        let (archive, _) = pack(&src, &links(LinkPolicy::Follow)).unwrap();
+        let out = dir.path().join("followed");
+        decompress_archive(&archive, &out).unwrap();
+        let meta = fs::symlink_metadata(out.join("file-link")).unwrap();
+        assert!(meta.is_file());
+        assert!(fs::symlink_metadata(out.join("dir-link")).unwrap().is_dir());
+        assert_eq!(fs::read(out.join("dir-link/a.txt")).unwrap(), b"target");
+
+        // Skip: reported, not packed.
+        let (archive, skipped) = pack(&src, &links(LinkPolicy::Skip)).unwrap();
+        let reasons: Vec<_> = skipped
+            .iter()
+            .map(|e| (e.path.to_str().unwrap(), e.reason))
+            .collect();
+        assert_eq!(
+            reasons,
+            [
+                ("dir-link", SkipReason::Symlink),
+                ("file-link", SkipReason::Symlink),
+            ]
+        );
+        let out = dir.path().join("skipped");
+        decompress_archive(&archive, &out).unwrap();
+        assert!(!out.join("file-link").exists());
+        assert!(out.join("sub/a.txt").exists());
+
+        let result = pack(&src, &links(LinkPolicy::Error));
+        assert!(matches!(result, Err(CryptError::Compression(_))));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_special_files_are_left_out() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("source");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("a.txt"), b"a").unwrap();
+        let _listener =
+            std::os::unix::net::UnixListener::bind(src.join("sock")).unwrap();
+
+        let (archive, skipped) =
+            pack(&src, &links(LinkPolicy::Follow)).unwrap();
+        assert_eq!(
+            skipped,
+            [SkippedEntry {
+                path: PathBuf::from("sock"),
+                reason: SkipReason::SpecialFile,
+            }]
+        );
+        let out = dir.path().join("out");
+        decompress_archive(&archive, &out).unwrap();
+        assert!(out.join("a.txt").exists());
+        assert!(!out.join("sock").exists());
+
+        let result = pack(&src, &links(LinkPolicy::Error));
+        assert!(matches!(result, Err(CryptError::Compression(_))));
+    }
+
+    // macOS refuses names that are not valid UTF-8.
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_non_utf8_names_round_trip() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("source");
+        let sub = src.join(OsStr::from_bytes(b"r\xe9sum\xe9"));
+        fs::create_dir_all(&sub).unwrap();
+        fs::write(sub.join(OsStr::from_bytes(b"\xff.bin")), b"raw").unwrap();
+
+        let (archive, _) = pack(&src, &DirectoryFilter::default()).unwrap();
+        let out = dir.path().join("out");
+        decompress_archive(&archive, &out).unwrap();
+        let path = out
+            .join(OsStr::from_bytes(b"r\xe9sum\xe9"))
+            .join(OsStr::from_bytes(b"\xff.bin"));
+        assert_eq!(fs::read(path).unwrap(), b"raw");
+    }
+
+    #[test]
+    fn test_names_are_stored_in_nfc() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("source");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("cafe\u{301}.txt"), b"nfd").unwrap();
+
+        let (archive, _) = pack(&src, &DirectoryFilter::default()).unwrap();
+        let reader =
+            ArchiveReader::new(File::open(&archive).unwrap(), Password::empty())
+                .unwrap();
+        let names: Vec<_> =
+            reader.archive().files.iter().map(|e| e.name()).collect();
+        assert_eq!(names, ["caf\u{e9}.txt"]);
+
+        let out = dir.path().join("out");
+        decompress_archive(&archive, &out).unwrap();
+        assert_eq!(fs::read(out.join("caf\u{e9}.txt")).unwrap(), b"nfd");
+    }
+
+    #[test]
+    fn test_empty_directories_are_kept() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("source");
+        fs::create_dir_all(src.join("empty")).unwrap();
+        fs::create_dir_all(src.join("logs")).unwrap();
+        fs::write(src.join("logs/a.log"), b"log").unwrap();
+
+        let filter = DirectoryFilter {
+            exclude: vec!["*.log".to_string()],
+            ..Default::default()
+        };
+        let (archive, _) = pack(&src, &filter).unwrap();
+        let out = dir.path().join("out");
+        decompress_archive(&archive, &out).unwrap();
+        assert!(out.join("empty").is_dir());
+        assert!(out.join("logs").is_dir());
+        assert!(!out.join("logs/a.log").exists());
+    }
+
+    fn craft(archive: &Path, entries: Vec<(ArchiveEntry, &[u8])>) {
+        let mut writer =
+            sevenz_rust2::ArchiveWriter::new(File::create(archive).unwrap())
+                .unwrap();
+        for (entry, data) in entries {
+            writer.push_archive_entry(entry, Some(data)).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn test_extraction_stays_inside_the_output() {
+        let dir = TempDir::new().unwrap();
+        let out = dir.path().join("out");
+
+        let archive = dir.path().join("climb.7z");
+        craft(&archive, vec![(ArchiveEntry::new_file("../evil"), b"x")]);
+        let result = decompress_archive(&archive, &out);
+        assert!(matches!(result, Err(CryptError::Compression(_))));
+        assert!(!dir.path().join("evil").exists());
+
+        // A link, then a file written through it.
+        let mut link = ArchiveEntry::new_file("escape");
+        link.has_windows_attributes = true;
+        link.windows_attributes = UNIX_EXTENSION | ((S_IFLNK | 0o777) << 16);
+        let target = dir.path().to_str().unwrap().as_bytes().to_vec();
+        let archive = dir.path().join("through.7z");
+        let evil = ArchiveEntry::new_file("escape/evil");
+        craft(&archive, vec![(link, &target), (evil, b"x")]);
+        let result = decompress_archive(&archive, &out);
+        assert!(matches!(result, Err(CryptError::Compression(_))));
+        assert!(!dir.path().join("evil").exists());
+    }
+
     #[test]
     fn test_compress_not_a_directory() {
         let dir = TempDir::new().unwrap();
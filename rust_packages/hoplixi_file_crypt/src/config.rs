@@ -2,15 +2,25 @@
 pub const MAGIC: &[u8; 7] = b"HOPLIXI";
 
 /// Current format version.
-pub const VERSION: u16 = 1;
+pub const VERSION: u16 = 2;
+
+/// Oldest format version that can still be read.
+///
+/// Version 1 headers have no extension area.
+pub const MIN_SUPPORTED_VERSION: u16 = 1;
 
 /// Salt length in bytes.
 pub const SALT_LEN: usize = 32;
 
-/// XChaCha20-Poly1305 nonce length (24 bytes).
+/// Length of the nonces stored in the header (24 bytes, the
+/// XChaCha20-Poly1305 nonce size).
 pub const NONCE_LEN: usize = 24;
 
-/// Poly1305 authentication tag length (16 bytes).
+/// AES-256-GCM-SIV nonce length (12 bytes), derived from the
+/// stored 24-byte nonces.
+pub const SHORT_NONCE_LEN: usize = 12;
+
+/// Authentication tag length of every cipher suite (16 bytes).
 pub const TAG_LEN: usize = 16;
 
 /// Encryption key length (256 bits).
@@ -43,18 +53,118 @@ pub mod argon2_defaults {
     pub const PARALLELISM: u32 = 4;
 }
 
-/// Public header fixed size in bytes.
+/// Public header fixed size in bytes (the whole version 1 header).
 /// MAGIC(7) + VERSION(2) + SALT(32) + ARGON2_T(4) + ARGON2_M(4)
 /// + ARGON2_P(4) + CHUNK_SIZE(4) + DATA_BASE_NONCE(24)
 /// + HEADER_NONCE(24) + ENCRYPTED_META_LEN(4) = 109
 pub const PUBLIC_HEADER_SIZE: usize = 109;
 
+/// Extension area length prefix (bytes), present from version 2.
+///
+/// Version 2 headers append EXT_LEN(4) + EXTENSIONS(EXT_LEN), where each
+/// extension is TAG(1) + LEN(2) + VALUE(LEN).
+pub const HEADER_EXT_LEN_SIZE: usize = 4;
+
+/// Header extension tag: Reed–Solomon parity parameters.
+pub const HEADER_EXT_FEC: u8 = 0x01;
+
+/// Header extension tag: signature algorithm of the payload trailer.
+pub const HEADER_EXT_SIGNATURE: u8 = 0x02;
+
+/// Header extension tag: cipher suite. Absent means XChaCha20-Poly1305.
+pub const HEADER_EXT_CIPHER: u8 = 0x03;
+
+/// Header extension tag: commitment to the header key.
+pub const HEADER_EXT_KEY_COMMITMENT: u8 = 0x04;
+
+/// Header extension tag: file key encapsulated to a recipient key.
+pub const HEADER_EXT_RECIPIENT: u8 = 0x05;
+
+/// Header extension tag: volume set the file is split into.
+pub const HEADER_EXT_VOLUMES: u8 = 0x06;
+
+/// Metadata extension tag: Ed25519 public key of the signer.
+pub const META_EXT_SIGNER_KEY: u8 = 0x01;
+
+/// Metadata extension tag: zero padding, ignored on read.
+pub const META_EXT_PADDING: u8 = 0x02;
+
+/// Metadata extension tag: true payload length of a padded payload.
+pub const META_EXT_PAYLOAD_LEN: u8 = 0x03;
+
+/// Zero bytes reserved after new metadata, so that it can grow by
+/// this much and still be rewritten in place.
+pub const META_RESERVE_LEN: usize = 256;
+
+/// Smallest bucket that length-hiding metadata is padded to.
+pub const META_BUCKET_MIN_LEN: usize = 1024;
+
+/// Magic bytes opening every volume of a split file.
+pub const VOLUME_MAGIC: &[u8; 7] = b"HOPLVOL";
+
+/// Random identifier shared by the volumes of one file (16 bytes).
+pub const VOLUME_SET_ID_LEN: usize = 16;
+
+/// Volume prefix size in bytes.
+/// VOLUME_MAGIC(7) + SET_ID(16) + INDEX(4) + COUNT(4) = 31
+pub const VOLUME_PREFIX_LEN: usize = 31;
+
+/// Ed25519 secret key length (32 bytes).
+pub const ED25519_SECRET_KEY_LEN: usize = 32;
+
+/// Ed25519 public key length (32 bytes).
+pub const ED25519_PUBLIC_KEY_LEN: usize = 32;
+
+/// Ed25519 signature length (64 bytes).
+pub const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// Domain separation prefix of the signed message.
+pub const SIGNATURE_DOMAIN: &[u8] = b"hoplixi-signature-v1";
+
+/// X25519 public and secret key length (32 bytes).
+pub const X25519_KEY_LEN: usize = 32;
+
+/// ML-KEM-768 encapsulation (public) key length (1184 bytes).
+pub const MLKEM768_PUBLIC_KEY_LEN: usize = 1184;
+
+/// ML-KEM seed, the serialized decapsulation key (64 bytes).
+pub const MLKEM_SEED_LEN: usize = 64;
+
+/// ML-KEM-768 ciphertext length (1088 bytes).
+pub const MLKEM768_CIPHERTEXT_LEN: usize = 1088;
+
+/// Hybrid recipient public key: X25519(32) + ML-KEM-768(1184).
+pub const RECIPIENT_PUBLIC_KEY_LEN: usize =
+    X25519_KEY_LEN + MLKEM768_PUBLIC_KEY_LEN;
+
+/// Hybrid recipient secret key: X25519(32) + ML-KEM seed(64).
+pub const RECIPIENT_SECRET_KEY_LEN: usize = X25519_KEY_LEN + MLKEM_SEED_LEN;
+
+/// Hybrid encapsulation stored in the header: ephemeral X25519
+/// key(32) + ML-KEM-768 ciphertext(1088).
+pub const RECIPIENT_ENCAPSULATION_LEN: usize =
+    X25519_KEY_LEN + MLKEM768_CIPHERTEXT_LEN;
+
+/// HKDF info string combining the X25519 and ML-KEM shared secrets
+/// into the file key.
+pub const HKDF_INFO_RECIPIENT: &[u8] = b"hoplixi-recipient-x25519-mlkem768-v1";
+
 /// HKDF info string for deriving the header encryption key.
 pub const HKDF_INFO_HEADER: &[u8] = b"hoplixi-header-key-v1";
 
 /// HKDF info string for deriving the data encryption key.
 pub const HKDF_INFO_DATA: &[u8] = b"hoplixi-data-key-v1";
 
+/// HKDF info string for deriving the key commitment from the header key.
+pub const HKDF_INFO_COMMITMENT: &[u8] = b"hoplixi-key-commitment-v1";
+
+/// Key commitment length (32 bytes).
+pub const KEY_COMMITMENT_LEN: usize = 32;
+
+/// Minimum time between two progress events of the same stage.
+/// Stage changes and the final event of a stage are never held back.
+pub const PROGRESS_MIN_INTERVAL_MS: u64 = 100;
+
 // ── Header validation limits (anti-DoS) ──────────────────────
 
 /// Minimum allowed chunk size (64 bytes).
@@ -66,6 +176,9 @@ pub const MAX_CHUNK_SIZE: u32 = 64 * 1024 * 1024;
 /// Maximum allowed encrypted metadata length (1 MB).
 pub const MAX_ENCRYPTED_META_LEN: u32 = 1024 * 1024;
 
+/// Maximum header extension area length (64 KB).
+pub const MAX_HEADER_EXT_LEN: u32 = 64 * 1024;
+
 /// Maximum Argon2 t_cost (iterations).
 pub const MAX_ARGON2_T_COST: u32 = 100;
 
@@ -74,3 +187,21 @@ pub const MAX_ARGON2_M_COST_KIB: u32 = 4 * 1024 * 1024;
 
 /// Maximum Argon2 parallelism.
 pub const MAX_ARGON2_PARALLELISM: u32 = 255;
+
+/// Maximum number of data chunks per Reed–Solomon group.
+pub const MAX_FEC_DATA_SHARDS: u8 = 64;
+
+/// Maximum number of parity shards per Reed–Solomon group.
+pub const MAX_FEC_PARITY_SHARDS: u8 = 32;
+
+/// Maximum size of one buffered Reed–Solomon group (256 MB).
+pub const MAX_FEC_GROUP_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Reed–Solomon default parameters.
+pub mod fec_defaults {
+    /// Encrypted data chunks per group.
+    pub const DATA_SHARDS: u8 = 16;
+
+    /// Parity shards per group (damaged chunks repairable per group).
+    pub const PARITY_SHARDS: u8 = 2;
+}
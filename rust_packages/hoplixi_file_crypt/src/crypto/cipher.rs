@@ -1,53 +1,160 @@
+use aes_gcm_siv::Aes256GcmSiv;
 use chacha20poly1305::aead::{Aead, KeyInit, Payload};
 use chacha20poly1305::{XChaCha20Poly1305, XNonce};
 
 use crate::config::{KEY_LEN, NONCE_LEN};
+use crate::crypto::nonce;
 use crate::error::{CryptError, Result};
 
-/// Encrypt a plaintext chunk using XChaCha20-Poly1305 with AAD.
+/// AEAD recorded in the public header and used for both the
+/// metadata and the data chunks of a file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CipherSuite {
+    /// XChaCha20-Poly1305 with the full 192-bit nonce.
+    #[default]
+    XChaCha20Poly1305,
+    /// AES-256-GCM-SIV; fast on CPUs with AES-NI and built from
+    /// FIPS-approved primitives.
+    Aes256GcmSiv,
+}
+
+impl CipherSuite {
+    /// Identifier stored in the header extension.
+    pub fn id(self) -> u8 {
+        match self {
+            CipherSuite::XChaCha20Poly1305 => 1,
+            CipherSuite::Aes256GcmSiv => 2,
+        }
+    }
+
+    /// Parse a header identifier.
+    pub fn from_id(id: u8) -> Result<Self> {
+        match id {
+            1 => Ok(CipherSuite::XChaCha20Poly1305),
+            2 => Ok(CipherSuite::Aes256GcmSiv),
+            _ => Err(CryptError::InvalidHeader(format!(
+                "unknown cipher suite {id}"
+            ))),
+        }
+    }
+
+    /// Implementation of the suite.
+    pub fn aead(self) -> &'static dyn ChunkAead {
+        match self {
+            CipherSuite::XChaCha20Poly1305 => &XChaCha20Poly1305Aead,
+            CipherSuite::Aes256GcmSiv => &Aes256GcmSivAead,
+        }
+    }
+}
+
+/// Authenticated encryption of one chunk (or of the metadata).
 ///
 /// AAD (Associated Authenticated Data) binds the ciphertext to
 /// contextual metadata (uuid, version, chunk index, etc.) so that
 /// chunks cannot be reordered, swapped between files, or
 /// transplanted from one context to another without detection.
 ///
-/// Returns ciphertext with appended 16-byte Poly1305 tag.
-pub fn encrypt_chunk(
-    key: &[u8; KEY_LEN],
-    nonce: &[u8; NONCE_LEN],
-    plaintext: &[u8],
-    aad: &[u8],
-) -> Result<Vec<u8>> {
-    let cipher = XChaCha20Poly1305::new_from_slice(key)
-        .map_err(|e| CryptError::Encryption(format!("Cipher init: {e}")))?;
+/// Nonces are always the 24-byte values stored in the header (see
+/// [`crate::crypto::nonce`]); suites with shorter nonces derive
+/// theirs from it. Every suite appends a 16-byte tag.
+pub trait ChunkAead: Send + Sync {
+    /// Encrypt `plaintext`, returning ciphertext followed by the tag.
+    fn encrypt_chunk(
+        &self,
+        key: &[u8; KEY_LEN],
+        nonce: &[u8; NONCE_LEN],
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>>;
 
-    let xnonce = XNonce::from_slice(nonce);
+    /// Decrypt `ciphertext` (including its tag).
+    ///
+    /// The same AAD that was provided during encryption must be
+    /// supplied here, otherwise decryption will fail (tag mismatch).
+    fn decrypt_chunk(
+        &self,
+        key: &[u8; KEY_LEN],
+        nonce: &[u8; NONCE_LEN],
+        ciphertext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>>;
+}
 
-    cipher
-        .encrypt(xnonce, Payload { msg: plaintext, aad })
-        .map_err(|e| CryptError::Encryption(format!("Encrypt: {e}")))
+/// [`CipherSuite::XChaCha20Poly1305`].
+pub struct XChaCha20Poly1305Aead;
+
+impl ChunkAead for XChaCha20Poly1305Aead {
+    fn encrypt_chunk(
+        &self,
+        key: &[u8; KEY_LEN],
+        nonce: &[u8; NONCE_LEN],
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>> {
+        let cipher = XChaCha20Poly1305::new_from_slice(key).map_err(|e| {
+            CryptError::Encryption(format!("Cipher init: {e}"))
+        })?;
+
+        cipher
+            .encrypt(XNonce::from_slice(nonce), Payload { msg: plaintext, aad })
+            .map_err(|e| CryptError::Encryption(format!("Encrypt: {e}")))
+    }
+
+    fn decrypt_chunk(
+        &self,
+        key: &[u8; KEY_LEN],
+        nonce: &[u8; NONCE_LEN],
+        ciphertext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>> {
+        let cipher = XChaCha20Poly1305::new_from_slice(key).map_err(|e| {
+            CryptError::Decryption(format!("Cipher init: {e}"))
+        })?;
+
+        cipher
+            .decrypt(XNonce::from_slice(nonce), Payload { msg: ciphertext, aad })
+            .map_err(|_| CryptError::InvalidPassword)
+    }
 }
 
-/// Decrypt a ciphertext chunk using XChaCha20-Poly1305 with AAD.
-///
-/// The same AAD that was provided during encryption must be
-/// supplied here, otherwise decryption will fail (tag mismatch).
-///
-/// Input must include the 16-byte Poly1305 tag.
-pub fn decrypt_chunk(
-    key: &[u8; KEY_LEN],
-    nonce: &[u8; NONCE_LEN],
-    ciphertext: &[u8],
-    aad: &[u8],
-) -> Result<Vec<u8>> {
-    let cipher = XChaCha20Poly1305::new_from_slice(key)
-        .map_err(|e| CryptError::Decryption(format!("Cipher init: {e}")))?;
+/// [`CipherSuite::Aes256GcmSiv`], keyed with the same 256-bit keys
+/// and using the 96-bit nonce from [`nonce::short_nonce`].
+pub struct Aes256GcmSivAead;
+
+impl ChunkAead for Aes256GcmSivAead {
+    fn encrypt_chunk(
+        &self,
+        key: &[u8; KEY_LEN],
+        nonce: &[u8; NONCE_LEN],
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>> {
+        let cipher = Aes256GcmSiv::new_from_slice(key).map_err(|e| {
+            CryptError::Encryption(format!("Cipher init: {e}"))
+        })?;
 
-    let xnonce = XNonce::from_slice(nonce);
+        let nonce = nonce::short_nonce(nonce);
+        cipher
+            .encrypt(&nonce.into(), Payload { msg: plaintext, aad })
+            .map_err(|e| CryptError::Encryption(format!("Encrypt: {e}")))
+    }
+
+    fn decrypt_chunk(
+        &self,
+        key: &[u8; KEY_LEN],
+        nonce: &[u8; NONCE_LEN],
+        ciphertext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>> {
+        let cipher = Aes256GcmSiv::new_from_slice(key).map_err(|e| {
+            CryptError::Decryption(format!("Cipher init: {e}"))
+        })?;
 
-    cipher
-        .decrypt(xnonce, Payload { msg: ciphertext, aad })
-        .map_err(|_| CryptError::InvalidPassword)
+        let nonce = nonce::short_nonce(nonce);
+        cipher
+            .decrypt(&nonce.into(), Payload { msg: ciphertext, aad })
+            .map_err(|_| CryptError::InvalidPassword)
+    }
 }
 
 /// Encrypt metadata bytes using the header key, nonce, and AAD.
@@ -55,22 +162,24 @@ pub fn decrypt_chunk(
 /// AAD should be the serialized public header, binding the
 /// encrypted metadata to its header context.
 pub fn encrypt_metadata(
+    suite: CipherSuite,
     header_key: &[u8; KEY_LEN],
     header_nonce: &[u8; NONCE_LEN],
     plaintext: &[u8],
     aad: &[u8],
 ) -> Result<Vec<u8>> {
-    encrypt_chunk(header_key, header_nonce, plaintext, aad)
+    suite.aead().encrypt_chunk(header_key, header_nonce, plaintext, aad)
 }
 
 /// Decrypt metadata bytes using the header key, nonce, and AAD.
 pub fn decrypt_metadata(
+    suite: CipherSuite,
     header_key: &[u8; KEY_LEN],
     header_nonce: &[u8; NONCE_LEN],
     ciphertext: &[u8],
     aad: &[u8],
 ) -> Result<Vec<u8>> {
-    decrypt_chunk(header_key, header_nonce, ciphertext, aad)
+    suite.aead().decrypt_chunk(header_key, header_nonce, ciphertext, aad)
 }
 
 /// Build AAD for a data chunk: uuid || version || chunk_index.
@@ -89,7 +198,6 @@ pub fn build_chunk_aad(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::crypto::nonce;
 
     #[test]
     fn test_encrypt_decrypt_round_trip() {
@@ -98,11 +206,11 @@ mod tests {
         let plaintext = b"Hello, HOPLIXI encryption!";
         let aad = b"test-aad-context";
 
-        let ct = encrypt_chunk(&key, &nonce_val, plaintext, aad).unwrap();
+        let ct = XChaCha20Poly1305Aead.encrypt_chunk(&key, &nonce_val, plaintext, aad).unwrap();
         assert_ne!(&ct[..], plaintext);
         assert_eq!(ct.len(), plaintext.len() + 16);
 
-        let pt = decrypt_chunk(&key, &nonce_val, &ct, aad).unwrap();
+        let pt = XChaCha20Poly1305Aead.decrypt_chunk(&key, &nonce_val, &ct, aad).unwrap();
         assert_eq!(&pt[..], plaintext);
     }
 
@@ -114,8 +222,8 @@ mod tests {
         let plaintext = b"secret data";
         let aad = b"context";
 
-        let ct = encrypt_chunk(&key, &nonce_val, plaintext, aad).unwrap();
-        let result = decrypt_chunk(&wrong_key, &nonce_val, &ct, aad);
+        let ct = XChaCha20Poly1305Aead.encrypt_chunk(&key, &nonce_val, plaintext, aad).unwrap();
+        let result = XChaCha20Poly1305Aead.decrypt_chunk(&wrong_key, &nonce_val, &ct, aad);
         assert!(result.is_err());
     }
 
@@ -127,8 +235,8 @@ mod tests {
         let plaintext = b"secret data";
         let aad = b"context";
 
-        let ct = encrypt_chunk(&key, &n1, plaintext, aad).unwrap();
-        let result = decrypt_chunk(&key, &n2, &ct, aad);
+        let ct = XChaCha20Poly1305Aead.encrypt_chunk(&key, &n1, plaintext, aad).unwrap();
+        let result = XChaCha20Poly1305Aead.decrypt_chunk(&key, &n2, &ct, aad);
         assert!(result.is_err());
     }
 
@@ -139,10 +247,10 @@ mod tests {
         let plaintext = b"secret data";
 
         let ct =
-            encrypt_chunk(&key, &nonce_val, plaintext, b"correct-aad")
+            XChaCha20Poly1305Aead.encrypt_chunk(&key, &nonce_val, plaintext, b"correct-aad")
                 .unwrap();
         let result =
-            decrypt_chunk(&key, &nonce_val, &ct, b"wrong-aad");
+            XChaCha20Poly1305Aead.decrypt_chunk(&key, &nonce_val, &ct, b"wrong-aad");
         assert!(result.is_err());
     }
 
@@ -154,10 +262,10 @@ mod tests {
         let aad = b"ctx";
 
         let mut ct =
-            encrypt_chunk(&key, &nonce_val, plaintext, aad).unwrap();
+            XChaCha20Poly1305Aead.encrypt_chunk(&key, &nonce_val, plaintext, aad).unwrap();
         ct[0] ^= 0xFF; // flip a bit
 
-        let result = decrypt_chunk(&key, &nonce_val, &ct, aad);
+        let result = XChaCha20Poly1305Aead.decrypt_chunk(&key, &nonce_val, &ct, aad);
         assert!(result.is_err());
     }
 
@@ -168,11 +276,15 @@ mod tests {
         let meta = b"filename.txt";
         let aad = b"header-bytes";
 
-        let encrypted =
-            encrypt_metadata(&key, &nonce_val, meta, aad).unwrap();
-        let decrypted =
-            decrypt_metadata(&key, &nonce_val, &encrypted, aad).unwrap();
-        assert_eq!(&decrypted[..], meta);
+        for suite in [CipherSuite::XChaCha20Poly1305, CipherSuite::Aes256GcmSiv]
+        {
+            let encrypted =
+                encrypt_metadata(suite, &key, &nonce_val, meta, aad).unwrap();
+            let decrypted =
+                decrypt_metadata(suite, &key, &nonce_val, &encrypted, aad)
+                    .unwrap();
+            assert_eq!(&decrypted[..], meta);
+        }
     }
 
     #[test]
@@ -181,13 +293,68 @@ mod tests {
         let nonce_val = nonce::generate_header_nonce();
         let aad = b"";
 
-        let ct = encrypt_chunk(&key, &nonce_val, b"", aad).unwrap();
+        let ct = XChaCha20Poly1305Aead.encrypt_chunk(&key, &nonce_val, b"", aad).unwrap();
         assert_eq!(ct.len(), 16); // only tag
 
-        let pt = decrypt_chunk(&key, &nonce_val, &ct, aad).unwrap();
+        let pt = XChaCha20Poly1305Aead.decrypt_chunk(&key, &nonce_val, &ct, aad).unwrap();
         assert!(pt.is_empty());
     }
 
+    #[test]
+    fn test_aes_gcm_siv_round_trip() {
+        let aead = CipherSuite::Aes256GcmSiv.aead();
+        let key = [0xABu8; KEY_LEN];
+        let nonce_val = nonce::generate_data_base_nonce();
+        let plaintext = b"Hello, AES-GCM-SIV!";
+
+        let ct = aead.encrypt_chunk(&key, &nonce_val, plaintext, b"aad").unwrap();
+        assert_eq!(ct.len(), plaintext.len() + 16);
+        let pt = aead.decrypt_chunk(&key, &nonce_val, &ct, b"aad").unwrap();
+        assert_eq!(&pt[..], plaintext);
+
+        let wrong_key = [0xCDu8; KEY_LEN];
+        assert!(aead.decrypt_chunk(&wrong_key, &nonce_val, &ct, b"aad").is_err());
+        assert!(aead.decrypt_chunk(&key, &nonce_val, &ct, b"other").is_err());
+    }
+
+    #[test]
+    fn test_aes_gcm_siv_chunk_nonces_differ() {
+        let aead = CipherSuite::Aes256GcmSiv.aead();
+        let key = [0x11u8; KEY_LEN];
+        let base = nonce::generate_data_base_nonce();
+        let n0 = nonce::chunk_nonce(&base, 0);
+        let n1 = nonce::chunk_nonce(&base, 1);
+
+        let c0 = aead.encrypt_chunk(&key, &n0, b"same", b"").unwrap();
+        let c1 = aead.encrypt_chunk(&key, &n1, b"same", b"").unwrap();
+        assert_ne!(c0, c1);
+        assert!(aead.decrypt_chunk(&key, &n1, &c0, b"").is_err());
+    }
+
+    #[test]
+    fn test_suites_are_not_interchangeable() {
+        let key = [0x42u8; KEY_LEN];
+        let nonce_val = nonce::generate_header_nonce();
+        let ct = CipherSuite::XChaCha20Poly1305
+            .aead()
+            .encrypt_chunk(&key, &nonce_val, b"data", b"")
+            .unwrap();
+        let result = CipherSuite::Aes256GcmSiv
+            .aead()
+            .decrypt_chunk(&key, &nonce_val, &ct, b"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cipher_suite_ids() {
+        for suite in [CipherSuite::XChaCha20Poly1305, CipherSuite::Aes256GcmSiv]
+        {
+            assert_eq!(CipherSuite::from_id(suite.id()).unwrap(), suite);
+        }
+        assert_eq!(CipherSuite::default(), CipherSuite::XChaCha20Poly1305);
+        assert!(CipherSuite::from_id(0).is_err());
+    }
+
     #[test]
     fn test_build_chunk_aad() {
         let aad = build_chunk_aad("test-uuid", 1, 42);
@@ -1,9 +1,13 @@
 use argon2::{Algorithm, Argon2, Params, Version};
 use hkdf::Hkdf;
 use sha2::Sha256;
+use subtle::ConstantTimeEq;
 use zeroize::Zeroize;
 
-use crate::config::{HKDF_INFO_DATA, HKDF_INFO_HEADER, KEY_LEN, SALT_LEN};
+use crate::config::{
+    HKDF_INFO_COMMITMENT, HKDF_INFO_DATA, HKDF_INFO_HEADER, KEY_COMMITMENT_LEN,
+    KEY_LEN, SALT_LEN,
+};
 use crate::error::{CryptError, Result};
 
 /// Argon2id parameters for key derivation.
@@ -31,6 +35,34 @@ pub struct DerivedKeys {
     pub data_key: [u8; KEY_LEN],
 }
 
+impl DerivedKeys {
+    /// Commitment to the header key, stored in the public header:
+    /// `HKDF(header_key, "hoplixi-key-commitment-v1")`.
+    ///
+    /// Poly1305-based AEADs are not key-committing: a crafted file can
+    /// authenticate under two different keys. A matching commitment
+    /// pins the file to the single password it was sealed with.
+    pub fn commitment(&self) -> Result<[u8; KEY_COMMITMENT_LEN]> {
+        hkdf_derive(&self.header_key, HKDF_INFO_COMMITMENT)
+    }
+
+    /// Check `expected` against [`DerivedKeys::commitment`] in constant
+    /// time. A mismatch means a wrong password.
+    pub fn verify_commitment(
+        &self,
+        expected: &[u8; KEY_COMMITMENT_LEN],
+    ) -> Result<()> {
+        let mut actual = self.commitment()?;
+        let equal: bool = actual.ct_eq(expected).into();
+        actual.zeroize();
+        if equal {
+            Ok(())
+        } else {
+            Err(CryptError::InvalidPassword)
+        }
+    }
+}
+
 impl Drop for DerivedKeys {
     fn drop(&mut self) {
         self.header_key.zeroize();
@@ -63,7 +95,10 @@ fn derive_master_key(
 }
 
 /// Derive a sub-key from the master key using HKDF-SHA256.
-fn hkdf_derive(master_key: &[u8; KEY_LEN], info: &[u8]) -> Result<[u8; KEY_LEN]> {
+pub(crate) fn hkdf_derive(
+    master_key: &[u8],
+    info: &[u8],
+) -> Result<[u8; KEY_LEN]> {
     let hk =
         Hkdf::<Sha256>::new(None, master_key);
     let mut output = [0u8; KEY_LEN];
@@ -83,15 +118,18 @@ pub fn derive_keys(
     params: &Argon2Params,
 ) -> Result<DerivedKeys> {
     let mut master_key = derive_master_key(password.as_bytes(), salt, params)?;
-
-    let header_key = hkdf_derive(&master_key, HKDF_INFO_HEADER)?;
-    let data_key = hkdf_derive(&master_key, HKDF_INFO_DATA)?;
-
+    let keys = derive_subkeys(&master_key);
     master_key.zeroize();
+    keys
+}
 
+/// Derive header and data keys from a master key that does not come
+/// from a password, such as the file key of a recipient-encrypted
+/// file.
+pub fn derive_subkeys(master_key: &[u8; KEY_LEN]) -> Result<DerivedKeys> {
     Ok(DerivedKeys {
-        header_key,
-        data_key,
+        header_key: hkdf_derive(master_key, HKDF_INFO_HEADER)?,
+        data_key: hkdf_derive(master_key, HKDF_INFO_DATA)?,
     })
 }
 
@@ -166,6 +204,27 @@ mod tests {
         assert_ne!(keys_a.data_key, keys_b.data_key);
     }
 
+    #[test]
+    fn test_key_commitment() {
+        let params = Argon2Params {
+            t_cost: 1,
+            m_cost_kib: 64,
+            parallelism: 1,
+        };
+        let salt = [3u8; SALT_LEN];
+
+        let keys = derive_keys("right", &salt, &params).unwrap();
+        let commitment = keys.commitment().unwrap();
+        assert_ne!(commitment, keys.header_key);
+        assert!(keys.verify_commitment(&commitment).is_ok());
+
+        let other = derive_keys("wrong", &salt, &params).unwrap();
+        assert!(matches!(
+            other.verify_commitment(&commitment),
+            Err(CryptError::InvalidPassword)
+        ));
+    }
+
     #[test]
     fn test_generate_salt_unique() {
         let s1 = generate_salt();
@@ -1,3 +1,5 @@
 pub mod cipher;
 pub mod kdf;
 pub mod nonce;
+pub mod recipient;
+pub mod signature;
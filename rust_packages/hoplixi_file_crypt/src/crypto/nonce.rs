@@ -1,4 +1,4 @@
-use crate::config::{NONCE_LEN, NONCE_RANDOM_LEN};
+use crate::config::{NONCE_LEN, NONCE_RANDOM_LEN, SHORT_NONCE_LEN};
 
 /// Generate a random nonce for header encryption (24 bytes, fully random).
 pub fn generate_header_nonce() -> [u8; NONCE_LEN] {
@@ -28,6 +28,21 @@ pub fn chunk_nonce(base_nonce: &[u8; NONCE_LEN], chunk_index: u64) -> [u8; NONCE
     nonce
 }
 
+/// 96-bit nonce for AEADs that cannot take the full 24 bytes.
+///
+/// Keeps the first 4 random bytes and the last 8 bytes, so a data
+/// chunk nonce becomes `random[0..4] || chunk_index as u64 LE` and
+/// stays unique per chunk; a header nonce keeps 12 random bytes.
+/// Keys are derived per file, so uniqueness only matters within one
+/// file.
+pub fn short_nonce(nonce: &[u8; NONCE_LEN]) -> [u8; SHORT_NONCE_LEN] {
+    let mut short = [0u8; SHORT_NONCE_LEN];
+    let keep = SHORT_NONCE_LEN - (NONCE_LEN - NONCE_RANDOM_LEN);
+    short[..keep].copy_from_slice(&nonce[..keep]);
+    short[keep..].copy_from_slice(&nonce[NONCE_RANDOM_LEN..]);
+    short
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +92,13 @@ mod tests {
             &u64::MAX.to_le_bytes()
         );
     }
+
+    #[test]
+    fn test_short_nonce_keeps_counter() {
+        let base = generate_data_base_nonce();
+        let short = short_nonce(&chunk_nonce(&base, 7));
+        assert_eq!(&short[..4], &base[..4]);
+        assert_eq!(&short[4..], &7u64.to_le_bytes());
+        assert_ne!(short, short_nonce(&chunk_nonce(&base, 8)));
+    }
 }
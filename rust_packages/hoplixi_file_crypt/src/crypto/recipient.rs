@@ -0,0 +1,396 @@
+use ml_kem::array::Array;
+use ml_kem::ml_kem_768::{Ciphertext, DecapsulationKey, EncapsulationKey};
+use ml_kem::{Decapsulate, Encapsulate, KeyExport, Seed};
+use x25519_dalek::{EphemeralSecret, PublicKey, StaticSecret};
+use zeroize::Zeroize;
+
+use crate::config::{
+    HKDF_INFO_RECIPIENT, KEY_LEN, RECIPIENT_ENCAPSULATION_LEN,
+    RECIPIENT_PUBLIC_KEY_LEN, RECIPIENT_SECRET_KEY_LEN, X25519_KEY_LEN,
+};
+use crate::crypto::kdf;
+use crate::error::{CryptError, Result};
+
+/// Key encapsulation scheme recorded in the public header of files
+/// encrypted to a recipient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecipientAlgorithm {
+    /// X25519 and ML-KEM-768, combined with HKDF-SHA256.
+    X25519MlKem768,
+}
+
+impl RecipientAlgorithm {
+    /// Identifier stored in the header extension.
+    pub fn id(self) -> u8 {
+        match self {
+            RecipientAlgorithm::X25519MlKem768 => 1,
+        }
+    }
+
+    /// Parse a header identifier.
+    pub fn from_id(id: u8) -> Result<Self> {
+        match id {
+            1 => Ok(RecipientAlgorithm::X25519MlKem768),
+            _ => Err(CryptError::InvalidHeader(format!(
+                "unknown recipient algorithm {id}"
+            ))),
+        }
+    }
+
+    /// Length of the encapsulated key stored in the header.
+    pub fn encapsulation_len(self) -> usize {
+        match self {
+            RecipientAlgorithm::X25519MlKem768 => RECIPIENT_ENCAPSULATION_LEN,
+        }
+    }
+}
+
+/// File key encapsulated to a recipient, as stored in the header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecipientStanza {
+    pub algorithm: RecipientAlgorithm,
+    /// Ephemeral X25519 public key followed by the ML-KEM ciphertext.
+    pub encapsulated_key: Vec<u8>,
+}
+
+impl RecipientStanza {
+    /// Encode as a header extension value: `ALG(1) + ENCAPSULATION`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(1 + self.encapsulated_key.len());
+        buf.push(self.algorithm.id());
+        buf.extend_from_slice(&self.encapsulated_key);
+        buf
+    }
+
+    /// Parse a header extension value.
+    pub fn from_bytes(value: &[u8]) -> Result<Self> {
+        let (&id, encapsulated_key) = value.split_first().ok_or_else(|| {
+            CryptError::InvalidHeader("empty recipient extension".to_string())
+        })?;
+        let algorithm = RecipientAlgorithm::from_id(id)?;
+        if encapsulated_key.len() != algorithm.encapsulation_len() {
+            return Err(CryptError::InvalidHeader(format!(
+                "recipient encapsulation must be {} bytes, got {}",
+                algorithm.encapsulation_len(),
+                encapsulated_key.len()
+            )));
+        }
+        Ok(Self {
+            algorithm,
+            encapsulated_key: encapsulated_key.to_vec(),
+        })
+    }
+}
+
+/// Public half of a hybrid X25519 + ML-KEM-768 recipient key.
+///
+/// Share it with anyone who should be able to encrypt files that
+/// only the holder of the matching [`RecipientIdentity`] can open.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RecipientPublicKey {
+    x25519: PublicKey,
+    mlkem: EncapsulationKey,
+}
+
+impl std::fmt::Debug for RecipientPublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecipientPublicKey")
+            .field("x25519", self.x25519.as_bytes())
+            .finish_non_exhaustive()
+    }
+}
+
+impl RecipientPublicKey {
+    /// Parse the 1216-byte encoding: X25519 key, then ML-KEM-768 key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != RECIPIENT_PUBLIC_KEY_LEN {
+            return Err(CryptError::Encryption(format!(
+                "recipient public key must be \
+                 {RECIPIENT_PUBLIC_KEY_LEN} bytes, got {}",
+                bytes.len()
+            )));
+        }
+        let (x25519, mlkem) = bytes.split_at(X25519_KEY_LEN);
+        let x25519: [u8; X25519_KEY_LEN] =
+            x25519.try_into().expect("split at X25519_KEY_LEN");
+        let mlkem = Array::try_from(mlkem)
+            .ok()
+            .and_then(|key| EncapsulationKey::new(&key).ok())
+            .ok_or_else(|| {
+                CryptError::Encryption(
+                    "invalid ML-KEM-768 public key".to_string(),
+                )
+            })?;
+        Ok(Self {
+            x25519: PublicKey::from(x25519),
+            mlkem,
+        })
+    }
+
+    /// The 1216-byte encoding accepted by
+    /// [`RecipientPublicKey::from_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(RECIPIENT_PUBLIC_KEY_LEN);
+        bytes.extend_from_slice(self.x25519.as_bytes());
+        bytes.extend_from_slice(&self.mlkem.to_bytes());
+        bytes
+    }
+
+    /// Generate a fresh file key and encapsulate it to this
+    /// recipient. Returns the header stanza and the file key.
+    pub fn encapsulate(&self) -> Result<(RecipientStanza, [u8; KEY_LEN])> {
+        let ephemeral = EphemeralSecret::random_from_rng(&mut rand::rng());
+        let ephemeral_public = PublicKey::from(&ephemeral);
+        let x25519_shared = ephemeral.diffie_hellman(&self.x25519);
+        if !x25519_shared.was_contributory() {
+            return Err(CryptError::Encryption(
+                "recipient X25519 key is a low-order point".to_string(),
+            ));
+        }
+        let (ciphertext, mlkem_shared) =
+            self.mlkem.encapsulate_with_rng(&mut rand::rng());
+
+        let file_key = combine(
+            &mlkem_shared,
+            x25519_shared.as_bytes(),
+            ephemeral_public.as_bytes(),
+            self.x25519.as_bytes(),
+        )?;
+
+        let mut encapsulated_key =
+            Vec::with_capacity(RECIPIENT_ENCAPSULATION_LEN);
+        encapsulated_key.extend_from_slice(ephemeral_public.as_bytes());
+        encapsulated_key.extend_from_slice(&ciphertext);
+        Ok((
+            RecipientStanza {
+                algorithm: RecipientAlgorithm::X25519MlKem768,
+                encapsulated_key,
+            },
+            file_key,
+        ))
+    }
+}
+
+/// Secret half of a hybrid X25519 + ML-KEM-768 recipient key.
+///
+/// Serialized as the 32-byte X25519 secret followed by the 64-byte
+/// ML-KEM seed. Both parts are zeroized on drop.
+#[derive(Clone)]
+pub struct RecipientIdentity {
+    x25519: StaticSecret,
+    mlkem: DecapsulationKey,
+}
+
+impl std::fmt::Debug for RecipientIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecipientIdentity")
+            .field("x25519", PublicKey::from(&self.x25519).as_bytes())
+            .finish_non_exhaustive()
+    }
+}
+
+impl RecipientIdentity {
+    /// Generate a new random identity.
+    pub fn generate() -> Self {
+        let mut secret = [0u8; RECIPIENT_SECRET_KEY_LEN];
+        rand::fill(&mut secret);
+        let identity = Self::from_secret_key(&secret);
+        secret.zeroize();
+        identity
+    }
+
+    /// Restore an identity from its 96-byte secret key.
+    pub fn from_secret_key(secret: &[u8; RECIPIENT_SECRET_KEY_LEN]) -> Self {
+        let (x25519, seed) = secret.split_at(X25519_KEY_LEN);
+        let mut x25519: [u8; X25519_KEY_LEN] =
+            x25519.try_into().expect("split at X25519_KEY_LEN");
+        let mut seed: Seed = Array::try_from(seed).expect("64-byte seed");
+        let identity = Self {
+            x25519: StaticSecret::from(x25519),
+            mlkem: DecapsulationKey::from_seed(seed),
+        };
+        x25519.zeroize();
+        seed.zeroize();
+        identity
+    }
+
+    /// Restore an identity from a secret key slice.
+    pub fn from_secret_slice(secret: &[u8]) -> Result<Self> {
+        let secret: &[u8; RECIPIENT_SECRET_KEY_LEN] =
+            secret.try_into().map_err(|_| {
+                CryptError::Decryption(format!(
+                    "recipient secret key must be \
+                     {RECIPIENT_SECRET_KEY_LEN} bytes, got {}",
+                    secret.len()
+                ))
+            })?;
+        Ok(Self::from_secret_key(secret))
+    }
+
+    /// The 96-byte secret key. Handle with care.
+    pub fn secret_key(&self) -> [u8; RECIPIENT_SECRET_KEY_LEN] {
+        let mut secret = [0u8; RECIPIENT_SECRET_KEY_LEN];
+        secret[..X25519_KEY_LEN].copy_from_slice(self.x25519.as_bytes());
+        let mut seed = self.mlkem.to_bytes();
+        secret[X25519_KEY_LEN..].copy_from_slice(&seed);
+        seed.zeroize();
+        secret
+    }
+
+    /// The public key to encrypt files to this identity with.
+    pub fn public_key(&self) -> RecipientPublicKey {
+        RecipientPublicKey {
+            x25519: PublicKey::from(&self.x25519),
+            mlkem: self.mlkem.encapsulation_key().clone(),
+        }
+    }
+
+    /// Recover the file key from a header stanza.
+    ///
+    /// A stanza made for another identity yields an unrelated key;
+    /// the key commitment or the metadata tag rejects it later.
+    pub fn decapsulate(
+        &self,
+        stanza: &RecipientStanza,
+    ) -> Result<[u8; KEY_LEN]> {
+        // The fields are public, so the stanza may not come from
+        // `from_bytes`.
+        let expected = stanza.algorithm.encapsulation_len();
+        if stanza.encapsulated_key.len() != expected {
+            return Err(CryptError::InvalidHeader(format!(
+                "recipient encapsulation must be {expected} bytes, got {}",
+                stanza.encapsulated_key.len()
+            )));
+        }
+        let (ephemeral, ciphertext) =
+            stanza.encapsulated_key.split_at(X25519_KEY_LEN);
+        let ephemeral: [u8; X25519_KEY_LEN] =
+            ephemeral.try_into().expect("checked length");
+        let ciphertext: Ciphertext =
+            Array::try_from(ciphertext).expect("checked length");
+
+        let ephemeral = PublicKey::from(ephemeral);
+        let x25519_shared = self.x25519.diffie_hellman(&ephemeral);
+        if !x25519_shared.was_contributory() {
+            return Err(CryptError::InvalidPassword);
+        }
+        let mlkem_shared = self.mlkem.decapsulate(&ciphertext);
+
+        combine(
+            &mlkem_shared,
+            x25519_shared.as_bytes(),
+            ephemeral.as_bytes(),
+            PublicKey::from(&self.x25519).as_bytes(),
+        )
+    }
+}
+
+/// Combine both shared secrets into the file key:
+/// `HKDF(ss_mlkem || ss_x25519 || ephemeral_pk || recipient_pk)`.
+///
+/// The X25519 public keys are mixed in because a Diffie–Hellman
+/// secret alone does not bind the exchange it came from; ML-KEM
+/// already binds its ciphertext.
+fn combine(
+    mlkem_shared: &[u8],
+    x25519_shared: &[u8; X25519_KEY_LEN],
+    ephemeral_public: &[u8; X25519_KEY_LEN],
+    recipient_public: &[u8; X25519_KEY_LEN],
+) -> Result<[u8; KEY_LEN]> {
+    let mut ikm = Vec::with_capacity(KEY_LEN + 3 * X25519_KEY_LEN);
+    ikm.extend_from_slice(mlkem_shared);
+    ikm.extend_from_slice(x25519_shared);
+    ikm.extend_from_slice(ephemeral_public);
+    ikm.extend_from_slice(recipient_public);
+    let file_key = kdf::hkdf_derive(&ikm, HKDF_INFO_RECIPIENT);
+    ikm.zeroize();
+    file_key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encapsulate_decapsulate() {
+        let identity = RecipientIdentity::generate();
+        let (stanza, file_key) =
+            identity.public_key().encapsulate().unwrap();
+        assert_eq!(
+            stanza.encapsulated_key.len(),
+            RECIPIENT_ENCAPSULATION_LEN
+        );
+        assert_eq!(identity.decapsulate(&stanza).unwrap(), file_key);
+
+        // Every encapsulation yields a fresh file key.
+        let (_, other_key) = identity.public_key().encapsulate().unwrap();
+        assert_ne!(other_key, file_key);
+    }
+
+    #[test]
+    fn test_wrong_identity_gets_other_key() {
+        let identity = RecipientIdentity::generate();
+        let (stanza, file_key) =
+            identity.public_key().encapsulate().unwrap();
+
+        let stranger = RecipientIdentity::generate();
+        assert_ne!(stranger.decapsulate(&stanza).unwrap(), file_key);
+    }
+
+    #[test]
+    fn test_decapsulate_checks_length() {
+        let identity = RecipientIdentity::generate();
+        let (stanza, _) = identity.public_key().encapsulate().unwrap();
+        for len in [0, 16, RECIPIENT_ENCAPSULATION_LEN - 1] {
+            let short = RecipientStanza {
+                algorithm: stanza.algorithm,
+                encapsulated_key: stanza.encapsulated_key[..len].to_vec(),
+            };
+            assert!(matches!(
+                identity.decapsulate(&short),
+                Err(CryptError::InvalidHeader(_))
+            ));
+        }
+        let mut long = stanza.clone();
+        long.encapsulated_key.push(0);
+        assert!(matches!(
+            identity.decapsulate(&long),
+            Err(CryptError::InvalidHeader(_))
+        ));
+    }
+
+    #[test]
+    fn test_key_serialization_round_trip() {
+        let identity = RecipientIdentity::generate();
+        let restored =
+            RecipientIdentity::from_secret_slice(&identity.secret_key())
+                .unwrap();
+        assert_eq!(restored.secret_key(), identity.secret_key());
+
+        let public = identity.public_key().to_bytes();
+        assert_eq!(public.len(), RECIPIENT_PUBLIC_KEY_LEN);
+        let parsed = RecipientPublicKey::from_bytes(&public).unwrap();
+        assert_eq!(parsed, restored.public_key());
+
+        let (stanza, file_key) = parsed.encapsulate().unwrap();
+        assert_eq!(restored.decapsulate(&stanza).unwrap(), file_key);
+
+        assert!(RecipientPublicKey::from_bytes(&public[1..]).is_err());
+        assert!(RecipientIdentity::from_secret_slice(&[0u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_stanza_round_trip() {
+        let (stanza, _) = RecipientIdentity::generate()
+            .public_key()
+            .encapsulate()
+            .unwrap();
+        let bytes = stanza.to_bytes();
+        assert_eq!(RecipientStanza::from_bytes(&bytes).unwrap(), stanza);
+
+        assert!(RecipientStanza::from_bytes(&bytes[..100]).is_err());
+        let mut unknown = bytes.clone();
+        unknown[0] = 9;
+        assert!(RecipientStanza::from_bytes(&unknown).is_err());
+    }
+}
@@ -0,0 +1,220 @@
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+use crate::config::{
+    ED25519_PUBLIC_KEY_LEN, ED25519_SECRET_KEY_LEN, ED25519_SIGNATURE_LEN,
+    SIGNATURE_DOMAIN,
+};
+use crate::error::{CryptError, Result};
+
+/// Signature scheme recorded in the public header of signed files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureAlgorithm {
+    Ed25519,
+}
+
+impl SignatureAlgorithm {
+    /// Identifier stored in the header extension.
+    pub fn id(self) -> u8 {
+        match self {
+            SignatureAlgorithm::Ed25519 => 1,
+        }
+    }
+
+    /// Parse a header identifier.
+    pub fn from_id(id: u8) -> Result<Self> {
+        match id {
+            1 => Ok(SignatureAlgorithm::Ed25519),
+            _ => Err(CryptError::InvalidHeader(format!(
+                "unknown signature algorithm {id}"
+            ))),
+        }
+    }
+
+    /// Length of the signature trailer appended to the payload.
+    pub fn signature_len(self) -> usize {
+        match self {
+            SignatureAlgorithm::Ed25519 => ED25519_SIGNATURE_LEN,
+        }
+    }
+}
+
+/// Ed25519 key pair used to sign encrypted files.
+///
+/// The secret key is zeroized on drop.
+#[derive(Clone)]
+pub struct SigningIdentity {
+    key: SigningKey,
+}
+
+impl std::fmt::Debug for SigningIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SigningIdentity")
+            .field("public_key", &self.public_key())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SigningIdentity {
+    /// Generate a new random identity.
+    pub fn generate() -> Self {
+        let mut secret = [0u8; ED25519_SECRET_KEY_LEN];
+        rand::fill(&mut secret);
+        let identity = Self::from_secret_key(&secret);
+        zeroize::Zeroize::zeroize(&mut secret);
+        identity
+    }
+
+    /// Restore an identity from its 32-byte secret key.
+    pub fn from_secret_key(secret: &[u8; ED25519_SECRET_KEY_LEN]) -> Self {
+        Self {
+            key: SigningKey::from_bytes(secret),
+        }
+    }
+
+    /// Restore an identity from a secret key slice.
+    pub fn from_secret_slice(secret: &[u8]) -> Result<Self> {
+        let secret: &[u8; ED25519_SECRET_KEY_LEN] =
+            secret.try_into().map_err(|_| {
+                CryptError::InvalidSignature(format!(
+                    "secret key must be {ED25519_SECRET_KEY_LEN} bytes, \
+                     got {}",
+                    secret.len()
+                ))
+            })?;
+        Ok(Self::from_secret_key(secret))
+    }
+
+    /// The 32-byte secret key. Handle with care.
+    pub fn secret_key(&self) -> [u8; ED25519_SECRET_KEY_LEN] {
+        self.key.to_bytes()
+    }
+
+    /// The 32-byte public key stored in the metadata of signed files.
+    pub fn public_key(&self) -> [u8; ED25519_PUBLIC_KEY_LEN] {
+        self.key.verifying_key().to_bytes()
+    }
+
+    /// Sign a message built by [`signed_message`].
+    pub fn sign(&self, message: &[u8]) -> [u8; ED25519_SIGNATURE_LEN] {
+        self.key.sign(message).to_bytes()
+    }
+}
+
+/// Verify an Ed25519 signature made by `public_key`.
+pub fn verify(
+    public_key: &[u8; ED25519_PUBLIC_KEY_LEN],
+    message: &[u8],
+    signature: &[u8],
+) -> Result<()> {
+    let key = VerifyingKey::from_bytes(public_key).map_err(|e| {
+        CryptError::InvalidSignature(format!("Signer key: {e}"))
+    })?;
+    let signature = Signature::from_slice(signature).map_err(|e| {
+        CryptError::InvalidSignature(format!("Signature: {e}"))
+    })?;
+    key.verify_strict(message, &signature).map_err(|_| {
+        CryptError::InvalidSignature(
+            "signature does not match file contents".to_string(),
+        )
+    })
+}
+
+/// Running SHA-256 over the encrypted data chunks, in order.
+///
+/// Parity shards are not hashed, so a signature stays valid after
+/// chunks are rebuilt from parity.
+#[derive(Default)]
+pub struct ChunkHasher {
+    hasher: Sha256,
+}
+
+impl ChunkHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next encrypted chunk.
+    pub fn update(&mut self, ciphertext: &[u8]) {
+        self.hasher.update(ciphertext);
+    }
+
+    /// Finish and return the digest.
+    pub fn finalize(self) -> [u8; 32] {
+        self.hasher.finalize().into()
+    }
+}
+
+/// Build the message covered by a file signature:
+/// `domain || len(header) || header || len(meta) || meta || chunk_digest`.
+///
+/// `header` is the full serialized public header and `sealed_meta`
+/// the encrypted metadata exactly as written to disk.
+pub fn signed_message(
+    header: &[u8],
+    sealed_meta: &[u8],
+    chunk_digest: &[u8; 32],
+) -> Vec<u8> {
+    let mut msg = Vec::with_capacity(
+        SIGNATURE_DOMAIN.len() + 8 + header.len() + sealed_meta.len() + 32,
+    );
+    msg.extend_from_slice(SIGNATURE_DOMAIN);
+    msg.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    msg.extend_from_slice(header);
+    msg.extend_from_slice(&(sealed_meta.len() as u32).to_le_bytes());
+    msg.extend_from_slice(sealed_meta);
+    msg.extend_from_slice(chunk_digest);
+    msg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_message() -> Vec<u8> {
+        let mut hasher = ChunkHasher::new();
+        hasher.update(b"chunk-0");
+        hasher.update(b"chunk-1");
+        signed_message(b"header", b"sealed-meta", &hasher.finalize())
+    }
+
+    #[test]
+    fn test_sign_verify_round_trip() {
+        let identity = SigningIdentity::generate();
+        let msg = sample_message();
+        let sig = identity.sign(&msg);
+
+        verify(&identity.public_key(), &msg, &sig).unwrap();
+    }
+
+    #[test]
+    fn test_tampered_message_fails() {
+        let identity = SigningIdentity::generate();
+        let mut msg = sample_message();
+        let sig = identity.sign(&msg);
+        msg[SIGNATURE_DOMAIN.len() + 5] ^= 0x01;
+
+        let result = verify(&identity.public_key(), &msg, &sig);
+        assert!(matches!(result, Err(CryptError::InvalidSignature(_))));
+    }
+
+    #[test]
+    fn test_other_signer_fails() {
+        let identity = SigningIdentity::generate();
+        let other = SigningIdentity::generate();
+        let msg = sample_message();
+        let sig = identity.sign(&msg);
+
+        assert!(verify(&other.public_key(), &msg, &sig).is_err());
+    }
+
+    #[test]
+    fn test_secret_key_round_trip() {
+        let identity = SigningIdentity::generate();
+        let restored =
+            SigningIdentity::from_secret_slice(&identity.secret_key())
+                .unwrap();
+        assert_eq!(restored.public_key(), identity.public_key());
+        assert!(SigningIdentity::from_secret_slice(&[0u8; 5]).is_err());
+    }
+}
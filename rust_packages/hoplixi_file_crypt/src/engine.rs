@@ -1,35 +1,66 @@
 use std::fs::{self, File};
-use std::io::{BufReader, BufWriter, Read, Write};
+use std::io::{
+    self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write,
+};
+use std::path::{Path, PathBuf};
 
 use tempfile::NamedTempFile;
 use uuid::Uuid;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::compress;
 use crate::config::{
-    DEFAULT_DESKTOP_CHUNK_SIZE, DEFAULT_EXTENSION, TAG_LEN, VERSION,
+    DEFAULT_DESKTOP_CHUNK_SIZE, DEFAULT_EXTENSION, KEY_COMMITMENT_LEN,
+    KEY_LEN, MAX_CHUNK_SIZE, META_RESERVE_LEN, MIN_CHUNK_SIZE, NONCE_LEN,
+    SALT_LEN, TAG_LEN, VERSION, VOLUME_PREFIX_LEN, VOLUME_SET_ID_LEN,
+};
+use crate::crypto::signature::{
+    self, ChunkHasher, SignatureAlgorithm, SigningIdentity,
 };
-use crate::crypto::{cipher, kdf, nonce};
+use crate::crypto::cipher::{self, CipherSuite};
+use crate::crypto::recipient::{
+    RecipientAlgorithm, RecipientIdentity, RecipientPublicKey,
+    RecipientStanza,
+};
+use crate::crypto::{kdf, nonce};
 use crate::error::{CryptError, Result};
+use crate::fec::{self, FecParams};
 use crate::header::encrypted::EncryptedMetadata;
 use crate::header::public::PublicHeader;
-use crate::progress::{
-    ProgressCallback, ProgressEvent, ProgressStage,
-};
+use crate::journal;
+use crate::layout::PayloadLayout;
+use crate::padding;
+use crate::progress::{ProgressCallback, ProgressStage, ProgressTracker};
+use crate::random_access::RandomAccessReader;
+use crate::volume::{self, VolumeReader, VolumeSet, VolumeWriter};
 pub use crate::types::{
-    DecryptOptions, DecryptResult, EncryptOptions,
-    EncryptResult,
+    DecryptOptions, DecryptResult, DirectoryFilter, EncryptOptions,
+    EncryptResult, InspectResult, LinkPolicy, MemoryDecryptResult,
+    MetadataPatch, MetadataUpdateResult, ReencryptParams, RekeyOptions,
+    RekeyResult, RepairResult, ShredEntry, ShredReport, SignatureStatus,
+    SkipReason, SkippedEntry, SpacePlan, StreamDecryptOptions,
+    StreamDecryptResult, StreamEncryptOptions, StreamEncryptResult,
+    VerifyResult,
 };
 
 use crate::cleanup::TempCleanup;
+use crate::shred;
+use crate::space;
 
 // ── FileCrypt engine ─────────────────────────────────────
 
 /// Main encryption/decryption engine.
 ///
-/// Configurable chunk size and Argon2 parameters.
+/// Configurable chunk size, Argon2 parameters and cipher suite.
+/// Every method is synchronous and does its file I/O and key
+/// derivation on the calling thread; with the `async` feature,
+/// `AsyncFileCrypt` runs them on blocking workers of a tokio
+/// runtime.
+#[derive(Debug, Clone)]
 pub struct FileCrypt {
     chunk_size: u32,
     argon2_params: kdf::Argon2Params,
+    cipher_suite: CipherSuite,
 }
 
 impl Default for FileCrypt {
@@ -37,6 +68,7 @@ impl Default for FileCrypt {
         Self {
             chunk_size: DEFAULT_DESKTOP_CHUNK_SIZE,
             argon2_params: kdf::Argon2Params::default(),
+            cipher_suite: CipherSuite::default(),
         }
     }
 }
@@ -68,9 +100,17 @@ impl FileCrypt {
         Self {
             chunk_size,
             argon2_params,
+            ..Default::default()
         }
     }
 
+    /// Encrypt new files with `suite` instead of the default
+    /// XChaCha20-Poly1305. Decryption always follows the header.
+    pub fn with_cipher_suite(mut self, suite: CipherSuite) -> Self {
+        self.cipher_suite = suite;
+        self
+    }
+
     /// Encrypt a file or directory.
     ///
     /// If the input is a directory, it is first compressed to 7z.
@@ -78,174 +118,81 @@ impl FileCrypt {
     ///
     /// All temporary files are cleaned up on both success and
     /// error (via RAII guard).
-    pub async fn encrypt(
+    pub fn encrypt(
         &self,
         opts: EncryptOptions,
     ) -> Result<EncryptResult> {
-        let input_path = opts.input_path.clone();
-        let is_dir = input_path.is_dir();
+        if let Some(fec) = &opts.fec {
+            fec.validate_for_chunk_size(self.chunk_size)?;
+        }
+        if opts.shred_source && opts.filter.is_some() {
+            return Err(CryptError::Encryption(
+                "shredding the source would delete the entries the \
+                 filter leaves out"
+                    .to_string(),
+            ));
+        }
+        // Shredding a link would unlink it and leave its target, the
+        // actual plaintext, behind.
+        if opts.shred_source && opts.input_path.is_symlink() {
+            return Err(CryptError::Encryption(format!(
+                "{} is a symbolic link; shred the file it points to \
+                 instead",
+                opts.input_path.display()
+            )));
+        }
+        self.plan_encrypt(&opts)?.check()?;
 
+        // Steps 1–2: Compress into temp files if asked to.
         let temp_parent = opts
             .temp_dir
             .clone()
             .unwrap_or_else(|| opts.output_dir.clone());
+        let (source, progress, mut cleanup) = prepare_source(
+            &opts.input_path,
+            &opts.output_dir,
+            &temp_parent,
+            opts.gzip_compressed,
+            opts.shred_source,
+            opts.filter.as_ref(),
+            opts.progress.clone(),
+        )?;
 
-        // RAII guard: cleans up all tracked temp files on any
-        // exit path (success or error / early return via `?`).
-        let mut cleanup = TempCleanup::new();
-
-        // Step 1: If directory, compress to 7z first.
-        let (file_to_encrypt, original_filename, original_extension) =
-            if is_dir {
-                let dir_name = input_path
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("archive")
-                    .to_string();
-
-                let temp_archive =
-                    NamedTempFile::new_in(&temp_parent)?;
-                let temp_archive_path =
-                    temp_archive.path().to_path_buf();
-
-                self.emit_progress(
-                    &opts.progress,
-                    ProgressStage::CompressingDirectory,
-                    0,
-                    0,
-                );
-
-                let src_dir = input_path.clone();
-                let archive_dst = temp_archive_path.clone();
-                tokio::task::spawn_blocking(move || {
-                    compress::sevenz::compress_directory(
-                        &src_dir,
-                        &archive_dst,
-                    )
-                })
-                .await
-                .map_err(|e| {
-                    CryptError::Compression(format!("Join: {e}"))
-                })?
-                .map_err(|e| {
-                    CryptError::Compression(format!("{e}"))
-                })?;
-
-                let kept = temp_archive
-                    .into_temp_path()
-                    .keep()
-                    .map_err(|e| CryptError::Io(e.error))?;
-                cleanup.track(kept.clone());
-                (kept, dir_name, "7z".to_string())
-            } else {
-                let filename = input_path
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("file")
-                    .to_string();
-                let extension = input_path
-                    .extension()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("")
-                    .to_string();
-                (input_path.clone(), filename, extension)
-            };
-
-        // Step 2: Optional gzip compression.
-        let (source_to_encrypt, gzip_applied) =
-            if opts.gzip_compressed {
-                let temp_gz =
-                    NamedTempFile::new_in(&temp_parent)?;
-                let temp_gz_path =
-                    temp_gz.path().to_path_buf();
-
-                self.emit_progress(
-                    &opts.progress,
-                    ProgressStage::CompressingGzip,
-                    0,
-                    0,
-                );
-
-                let src = file_to_encrypt.clone();
-                let dst = temp_gz_path.clone();
-                tokio::task::spawn_blocking(move || {
-                    compress::gzip::gzip_compress(&src, &dst)
-                })
-                .await
-                .map_err(|e| {
-                    CryptError::Compression(format!("Join: {e}"))
-                })?
-                .map_err(|e| {
-                    CryptError::Compression(format!("{e}"))
-                })?;
-
-                // 7z temp is no longer needed.
-                if is_dir {
-                    cleanup.remove_now(&file_to_encrypt);
-                }
-
-                let kept_gz = temp_gz
-                    .into_temp_path()
-                    .keep()
-                    .map_err(|e| CryptError::Io(e.error))?;
-                cleanup.track(kept_gz.clone());
-                (kept_gz, true)
-            } else {
-                (file_to_encrypt.clone(), false)
-            };
-
-        // Get original size of the file being encrypted.
-        let original_size =
-            fs::metadata(&source_to_encrypt)?.len();
-
-        // Step 3: Generate cryptographic parameters.
-        let salt = kdf::generate_salt();
-        let header_nonce = nonce::generate_header_nonce();
-        let data_base_nonce = nonce::generate_data_base_nonce();
+        // Steps 3–4: Derive keys, build and seal the header.
         let file_uuid = opts
             .uuid
             .unwrap_or_else(|| Uuid::new_v4().to_string());
-
-        let password = opts.password.clone();
-        let salt_copy = salt;
-        let params = self.argon2_params;
-        let keys = tokio::task::spawn_blocking(move || {
-            kdf::derive_keys(&password, &salt_copy, &params)
-        })
-        .await
-        .map_err(|e| {
-            CryptError::KeyDerivation(format!("Join: {e}"))
-        })??;
-
-        // Step 4: Build metadata and public header.
+        let original_size = source.original_size;
         let enc_meta = EncryptedMetadata {
-            original_filename: original_filename.clone(),
-            original_extension: original_extension.clone(),
-            gzip_compressed: gzip_applied,
+            original_filename: source.original_filename,
+            original_extension: source.original_extension,
+            gzip_compressed: source.gzip_applied,
             original_size,
             uuid: file_uuid.clone(),
             metadata: opts.metadata.unwrap_or_default(),
+            signer_public_key: opts
+                .signer
+                .as_ref()
+                .map(|signer| signer.public_key()),
+            payload_len: opts.hide_lengths.then_some(original_size),
         };
-
-        let chunk_size = self.chunk_size;
-        let mut public_header = PublicHeader {
-            version: VERSION,
-            salt,
-            argon2_params: self.argon2_params,
-            chunk_size,
-            data_base_nonce,
-            header_nonce,
-            encrypted_meta_len: 0, // placeholder
+        let padded_size = match enc_meta.payload_len {
+            Some(len) => padding::padme(len),
+            None => original_size,
         };
-
-        let header_aad = public_header.to_bytes();
-        let sealed_meta = enc_meta.seal(
-            &keys.header_key,
-            &header_nonce,
-            &header_aad,
+        let mut sealed = self.seal_new_header(
+            opts.password,
+            opts.recipient.as_ref(),
+            &enc_meta,
+            opts.fec,
+            opts.signer.is_some(),
         )?;
-        public_header.encrypted_meta_len =
-            sealed_meta.len() as u32;
+        let volume_starts = opts
+            .volume_size
+            .map(|size| {
+                split_volumes(&mut sealed, &enc_meta, size, padded_size)
+            })
+            .transpose()?;
 
         // Step 5: Write the encrypted file.
         let ext = opts
@@ -254,88 +201,353 @@ impl FileCrypt {
             .unwrap_or(DEFAULT_EXTENSION);
         let output_path =
             opts.output_dir.join(format!("{file_uuid}{ext}"));
+        // Step 6: Encrypt data in chunks with AAD, followed by the
+        // length-hiding padding if requested.
+        let mut reader = BufReader::new(File::open(&source.path)?)
+            .chain(io::repeat(0).take(padded_size - original_size));
+        progress.stage(ProgressStage::Encrypting, padded_size, 0);
+
+        let volumes = match volume_starts {
+            None => {
+                // Write to temp file, then atomic rename.
+                // NamedTempFile auto-deletes on drop if
+                // persist/keep is not called — acts as its own
+                // cleanup for the output.
+                let temp_output = NamedTempFile::new_in(&opts.output_dir)?;
+                {
+                    let mut writer =
+                        BufWriter::new(temp_output.as_file());
+                    write_payload(
+                        &mut reader,
+                        &mut writer,
+                        &sealed,
+                        &file_uuid,
+                        opts.signer.as_ref(),
+                        &progress,
+                    )?;
+                    writer.flush()?;
+                }
 
-        // Write to temp file, then atomic rename.
-        // NamedTempFile auto-deletes on drop if persist/keep is
-        // not called — acts as its own cleanup for the output.
-        let temp_output =
-            NamedTempFile::new_in(&opts.output_dir)?;
-        let progress_cb = opts.progress.clone();
+                // Atomic rename.
+                temp_output.persist(&output_path).map_err(|e| {
+                    CryptError::Io(std::io::Error::other(format!(
+                        "Persist: {e}"
+                    )))
+                })?;
+                Vec::new()
+            }
+            Some(starts) => {
+                let set = sealed.header.volumes.expect("volume set");
+                let mut writer = BufWriter::new(VolumeWriter::new(
+                    &output_path,
+                    set,
+                    starts,
+                ));
+                write_payload(
+                    &mut reader,
+                    &mut writer,
+                    &sealed,
+                    &file_uuid,
+                    opts.signer.as_ref(),
+                    &progress,
+                )?;
+                writer
+                    .into_inner()
+                    .map_err(|e| CryptError::Io(e.into_error()))?
+                    .finish()?
+            }
+        };
 
-        {
-            let mut writer =
-                BufWriter::new(temp_output.as_file());
-            public_header.write_to(&mut writer)?;
-            writer.write_all(&sealed_meta)?;
+        // Success — remove intermediate temp files.
+        cleanup.finish();
 
-            // Step 6: Encrypt data in chunks with AAD.
-            let file_in = File::open(&source_to_encrypt)?;
-            let file_size = file_in.metadata()?.len();
-            let mut reader = BufReader::new(file_in);
+        // Only now that the encrypted file is in place may the
+        // plaintext go.
+        let shred = opts
+            .shred_source
+            .then(|| shred::shred_path(&opts.input_path));
 
-            let mut buf = vec![0u8; chunk_size as usize];
-            let mut chunk_index: u64 = 0;
-            let mut bytes_processed: u64 = 0;
+        progress.done();
 
-            loop {
-                let n = read_full(&mut reader, &mut buf)?;
-                if n == 0 {
-                    break;
-                }
+        Ok(EncryptResult {
+            output_path: volumes.first().cloned().unwrap_or(output_path),
+            volumes,
+            uuid: file_uuid,
+            original_size,
+            shred,
+            skipped: source.skipped,
+        })
+    }
 
-                let cn = nonce::chunk_nonce(
-                    &data_base_nonce,
-                    chunk_index,
-                );
-                let chunk_aad = cipher::build_chunk_aad(
-                    &file_uuid, VERSION, chunk_index,
-                );
-                let encrypted = cipher::encrypt_chunk(
-                    &keys.data_key,
-                    &cn,
-                    &buf[..n],
-                    &chunk_aad,
-                )?;
-                writer.write_all(&encrypted)?;
+    /// Disk space [`FileCrypt::encrypt`] takes with `opts`, and the
+    /// free space where it goes. `encrypt` refuses to start when
+    /// that is known to fall short (see [`SpacePlan::check`]).
+    ///
+    /// A directory is walked with its filter to add up what gets
+    /// packed; nothing is written.
+    pub fn plan_encrypt(&self, opts: &EncryptOptions) -> Result<SpacePlan> {
+        let temp_dir = opts
+            .temp_dir
+            .clone()
+            .unwrap_or_else(|| opts.output_dir.clone());
+
+        // Step 1 packs a directory into a temp archive, step 2 gzips
+        // into a second one; the first goes once the second is done.
+        let is_dir = opts.input_path.is_dir();
+        let source_len = match is_dir {
+            true => compress::archive_len_bound(
+                &opts.input_path,
+                opts.filter.as_ref().unwrap_or(&DirectoryFilter::default()),
+            )?,
+            false => fs::metadata(&opts.input_path)?.len(),
+        };
+        let archive_len = if is_dir { source_len } else { 0 };
+        let (plaintext_len, gzip_len) = match opts.gzip_compressed {
+            true => {
+                let len = compress::compressed_len_bound(source_len);
+                (len, len)
+            }
+            false => (source_len, 0),
+        };
+
+        Ok(SpacePlan {
+            output_bytes: self.encrypted_len_bound(opts, plaintext_len)?,
+            output_free: space::available(&opts.output_dir),
+            temp_bytes: archive_len + gzip_len,
+            temp_bytes_at_output: if opts.gzip_compressed {
+                gzip_len
+            } else {
+                archive_len
+            },
+            temp_free: space::available(&temp_dir),
+            shared_volume: space::same_volume(&opts.output_dir, &temp_dir),
+            output_dir: opts.output_dir.clone(),
+            temp_dir,
+            estimated: false,
+        })
+    }
 
-                chunk_index += 1;
-                bytes_processed += n as u64;
+    /// Upper bound of the size of the file [`FileCrypt::encrypt`]
+    /// writes (all its volumes) for `plaintext_len` bytes of
+    /// source. The header is laid out as `encrypt` would, with zeros
+    /// in place of keys, nonces and the recipient encapsulation.
+    fn encrypted_len_bound(
+        &self,
+        opts: &EncryptOptions,
+        plaintext_len: u64,
+    ) -> Result<u64> {
+        let padded_len = match opts.hide_lengths {
+            true => padding::padme(plaintext_len),
+            false => plaintext_len,
+        };
+        let layout = PayloadLayout::for_plaintext_len(
+            padded_len,
+            self.chunk_size,
+            opts.fec,
+        );
 
-                self.emit_progress(
-                    &progress_cb,
-                    ProgressStage::Encrypting,
-                    bytes_processed,
-                    file_size,
-                );
+        let (original_filename, original_extension) =
+            source_names(&opts.input_path, opts.input_path.is_dir());
+        let metadata = EncryptedMetadata {
+            original_filename,
+            original_extension,
+            gzip_compressed: opts.gzip_compressed,
+            original_size: plaintext_len,
+            uuid: opts.uuid.clone().unwrap_or_else(|| Uuid::nil().to_string()),
+            metadata: opts.metadata.clone().unwrap_or_default(),
+            signer_public_key: opts
+                .signer
+                .as_ref()
+                .map(|signer| signer.public_key()),
+            payload_len: opts.hide_lengths.then_some(plaintext_len),
+        };
+        // Only the sizes of the random and encapsulated fields count.
+        let algorithm = RecipientAlgorithm::X25519MlKem768;
+        let recipient = opts.recipient.as_ref().map(|_| RecipientStanza {
+            algorithm,
+            encapsulated_key: vec![0; algorithm.encapsulation_len()],
+        });
+        let header = PublicHeader {
+            version: VERSION,
+            salt: [0; SALT_LEN],
+            argon2_params: self.argon2_params,
+            chunk_size: self.chunk_size,
+            data_base_nonce: [0; NONCE_LEN],
+            header_nonce: [0; NONCE_LEN],
+            encrypted_meta_len: 0,
+            fec: opts.fec,
+            signature: opts
+                .signer
+                .as_ref()
+                .map(|_| SignatureAlgorithm::Ed25519),
+            cipher: self.cipher_suite,
+            key_commitment: Some([0; KEY_COMMITMENT_LEN]),
+            recipient,
+            volumes: opts.volume_size.map(|_| VolumeSet {
+                set_id: [0; VOLUME_SET_ID_LEN],
+                count: 0,
+            }),
+        };
+        let prefix_len = (header.encoded_len()
+            + meta_padded_len(&metadata)?
+            + TAG_LEN) as u64;
+        let trailer_len =
+            header.signature.map_or(0, SignatureAlgorithm::signature_len)
+                as u64;
+
+        let volume_prefixes = match opts.volume_size {
+            Some(size) => {
+                let starts =
+                    volume::plan(size, prefix_len, &layout, trailer_len)?;
+                (starts.len() * VOLUME_PREFIX_LEN) as u64
             }
+            None => 0,
+        };
+        Ok(prefix_len + layout.encoded_len() + trailer_len + volume_prefixes)
+    }
 
-            writer.flush()?;
+    /// Encrypt everything read from `reader` into `writer`.
+    ///
+    /// This is the building block for pipes: nothing touches the
+    /// file system and the input length need not be known, so the
+    /// metadata records an `original_size` of 0. File name and
+    /// extension come from the options. Directories are not
+    /// supported; use [`FileCrypt::encrypt`] for those.
+    ///
+    /// `reader` and `writer` are driven on the calling thread; for
+    /// `tokio::io` streams, the `async` feature provides
+    /// `AsyncEncryptWriter`.
+    pub fn encrypt_stream<R: Read, W: Write>(
+        &self,
+        reader: R,
+        writer: W,
+        mut opts: StreamEncryptOptions,
+    ) -> Result<StreamEncryptResult> {
+        let (uuid, sealed) = self.seal_stream_header(&mut opts)?;
+
+        let progress = ProgressTracker::new(
+            opts.progress,
+            vec![ProgressStage::Encrypting],
+        );
+        progress.stage(ProgressStage::Encrypting, 0, 0);
+
+        let mut input = Counting::new(reader);
+        let mut writer = BufWriter::new(writer);
+        if opts.gzip_compressed {
+            compress::with_gzip_reader(&mut input, |gz| {
+                write_payload(
+                    gz,
+                    &mut writer,
+                    &sealed,
+                    &uuid,
+                    opts.signer.as_ref(),
+                    &progress,
+                )
+            })?;
+        } else {
+            write_payload(
+                &mut input,
+                &mut writer,
+                &sealed,
+                &uuid,
+                opts.signer.as_ref(),
+                &progress,
+            )?;
         }
+        writer.flush()?;
 
-        // Atomic rename.
-        temp_output.persist(&output_path).map_err(|e| {
-            CryptError::Io(std::io::Error::other(format!(
-                "Persist: {e}"
-            )))
-        })?;
+        progress.done();
 
-        // Success — remove intermediate temp files.
-        cleanup.finish();
+        Ok(StreamEncryptResult {
+            uuid,
+            bytes_read: input.count,
+        })
+    }
 
-        self.emit_progress(
-            &opts.progress,
-            ProgressStage::Done,
-            0,
+    /// Decrypt an encrypted stream from `reader` into `writer`.
+    ///
+    /// The payload is read one chunk group at a time, so the input
+    /// length need not be known. Gzip is undone on the fly; a
+    /// directory archive is written out as the raw 7z bytes.
+    ///
+    /// Plaintext reaches `writer` before the end of the stream, and
+    /// with it the signature, has been checked. On error, discard
+    /// whatever was written.
+    ///
+    /// As with [`FileCrypt::encrypt_stream`], `reader` and `writer`
+    /// are driven on the calling thread; see `AsyncDecryptReader`
+    /// for `tokio::io` streams.
+    pub fn decrypt_stream<R: Read, W: Write>(
+        &self,
+        reader: R,
+        writer: W,
+        opts: StreamDecryptOptions,
+    ) -> Result<StreamDecryptResult> {
+        let mut reader = BufReader::new(reader);
+        let (header, sealed_meta) = read_prefix(&mut reader)?;
+        let (keys, metadata) = unlock(
+            &header,
+            &sealed_meta,
+            opts.password,
+            opts.identity.as_ref(),
+        )?;
+
+        let progress = ProgressTracker::new(
+            opts.progress,
+            vec![ProgressStage::Decrypting],
+        );
+        progress.stage(
+            ProgressStage::Decrypting,
+            metadata.original_size,
             0,
         );
 
-        Ok(EncryptResult {
-            output_path,
-            uuid: file_uuid,
-            original_size,
+        let gzip_compressed = metadata.gzip_compressed;
+        let mut decoder =
+            PayloadDecoder::new(header, sealed_meta, keys, metadata)?;
+        let mut output = Counting::new(BufWriter::new(writer));
+        let signature = if gzip_compressed {
+            compress::with_gunzip_writer(&mut output, |gz| {
+                self.stream_payload(&mut reader, gz, &mut decoder, &progress)
+            })?
+        } else {
+            self.stream_payload(
+                &mut reader,
+                &mut output,
+                &mut decoder,
+                &progress,
+            )?
+        };
+        output.flush()?;
+
+        progress.done();
+
+        Ok(StreamDecryptResult {
+            bytes_written: output.count,
+            metadata: decoder.into_metadata(),
+            signature,
         })
     }
 
+    /// Describe an encrypted file from its public header alone.
+    ///
+    /// No password is needed and no key is derived, so this is cheap
+    /// enough to call before asking the user for a password. A
+    /// payload that does not split into whole chunks is reported via
+    /// [`InspectResult::whole_chunks`] instead of an error; a bad
+    /// magic, version or parameter is still an error.
+    pub fn inspect(
+        &self,
+        input_path: PathBuf,
+    ) -> Result<InspectResult> {
+        let mut reader = BufReader::new(VolumeReader::open(&input_path)?);
+        let file_len = reader.get_ref().len();
+
+        let header = PublicHeader::read_from(&mut reader)?;
+        reader.get_ref().check_header(&header)?;
+        Ok(inspect_header(&header, file_len))
+    }
+
     /// Read and decrypt only the header of an encrypted file.
     ///
     /// This is a fast, lightweight operation — it derives the keys and
@@ -343,47 +555,112 @@ impl FileCrypt {
     /// actual encrypted payload.
     ///
     /// Returns the [`EncryptedMetadata`] on success.
-    pub async fn decrypt_header(
+    pub fn decrypt_header(
         &self,
         input_path: std::path::PathBuf,
         password: String,
     ) -> Result<EncryptedMetadata> {
-        let input_file = File::open(&input_path)?;
-        let mut reader = BufReader::new(input_file);
-
-        // Read and validate public header.
-        let public_header =
-            PublicHeader::read_from(&mut reader)?;
-
-        // Read encrypted metadata bytes.
-        let mut sealed_meta =
-            vec![0u8; public_header.encrypted_meta_len as usize];
-        reader.read_exact(&mut sealed_meta)?;
-
-        // Derive keys (CPU-heavy – offload to blocking thread).
-        let salt = public_header.salt;
-        let params = public_header.argon2_params;
-        let keys = tokio::task::spawn_blocking(move || {
-            kdf::derive_keys(&password, &salt, &params)
-        })
-        .await
-        .map_err(|e| {
-            CryptError::KeyDerivation(format!("Join: {e}"))
-        })??;
+        let mut reader = BufReader::new(VolumeReader::open(&input_path)?);
+        let (header, sealed_meta) = read_prefix(&mut reader)?;
+        reader.get_ref().check_header(&header)?;
+        let (_, metadata) =
+            unlock(&header, &sealed_meta, password, None)?;
+        Ok(metadata)
+    }
 
-        // Decrypt metadata with AAD matching the public header.
-        let mut header_for_aad = public_header.clone();
-        header_for_aad.encrypted_meta_len = 0;
-        let header_aad = header_for_aad.to_bytes();
+    /// Edit the user-defined metadata of an encrypted file without
+    /// touching its payload.
+    ///
+    /// The metadata is sealed again under a fresh header nonce. If it
+    /// still fits the space reserved when the file was written, only
+    /// the header region is rewritten, through a journal next to the
+    /// file that the next edit, rekey or repair replays after a crash
+    /// (reading the file is refused until then); otherwise the
+    /// header is written to a new file and the payload copied after
+    /// it, which then atomically replaces the original.
+    ///
+    /// Signed files are refused, as the signature covers the metadata;
+    /// so are files encrypted to a recipient key.
+    pub fn update_metadata(
+        &self,
+        input_path: PathBuf,
+        password: String,
+        patch: MetadataPatch,
+    ) -> Result<MetadataUpdateResult> {
+        journal::recover(&input_path)?;
+        let mut reader = BufReader::new(VolumeReader::open(&input_path)?);
+        let (mut header, sealed_meta) = read_prefix(&mut reader)?;
+        reader.get_ref().check_header(&header)?;
+        drop(reader);
+        check_not_split(&header, "edited")?;
+        if header.signature.is_some() {
+            return Err(CryptError::Encryption(
+                "the metadata of a signed file cannot be edited"
+                    .to_string(),
+            ));
+        }
+        let (keys, mut metadata) =
+            unlock(&header, &sealed_meta, password, None)?;
 
-        let metadata = EncryptedMetadata::unseal(
-            &sealed_meta,
-            &keys.header_key,
-            &public_header.header_nonce,
-            &header_aad,
-        )?;
+        for key in &patch.remove {
+            metadata.metadata.remove(key);
+        }
+        metadata.metadata.extend(patch.set);
+
+        let old_prefix_len =
+            (header.encoded_len() + sealed_meta.len()) as u64;
+        header.header_nonce = nonce::generate_header_nonce();
+        header.encrypted_meta_len = 0;
+        let aad = header.to_bytes();
+        let seal = |padded_len| {
+            metadata.seal_padded(
+                header.cipher,
+                &keys.header_key,
+                &header.header_nonce,
+                &aad,
+                padded_len,
+            )
+        };
 
-        Ok(metadata)
+        let reserved_len = sealed_meta.len() - TAG_LEN;
+        if let Some(sealed) = seal(reserved_len)? {
+            header.encrypted_meta_len = sealed.len() as u32;
+            let mut prefix = header.to_bytes();
+            prefix.extend_from_slice(&sealed);
+            journal::rewrite_prefix(&input_path, &prefix)?;
+            return Ok(MetadataUpdateResult {
+                metadata,
+                in_place: true,
+            });
+        }
+
+        let sealed = seal(meta_padded_len(&metadata)?)?
+            .expect("the reserve fits a padding extension");
+        header.encrypted_meta_len = sealed.len() as u32;
+
+        let parent = input_path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let temp_output = NamedTempFile::new_in(parent)?;
+        {
+            let mut source = File::open(&input_path)?;
+            source.seek(SeekFrom::Start(old_prefix_len))?;
+            let mut output = temp_output.as_file();
+            header.write_to(&mut output)?;
+            output.write_all(&sealed)?;
+            // Plain file-to-file copy, so the OS can use a fast path.
+            std::io::copy(&mut source, &mut output)?;
+            output.sync_all()?;
+        }
+        temp_output.persist(&input_path).map_err(|e| {
+            CryptError::Io(std::io::Error::other(format!("Persist: {e}")))
+        })?;
+
+        Ok(MetadataUpdateResult {
+            metadata,
+            in_place: false,
+        })
     }
 
     /// Decrypt an encrypted file.
@@ -392,646 +669,3874 @@ impl FileCrypt {
     ///
     /// All temporary files are cleaned up on both success and
     /// error (via RAII guard).
-    pub async fn decrypt(
+    pub fn decrypt(
         &self,
         opts: DecryptOptions,
     ) -> Result<DecryptResult> {
-        let input_file = File::open(&opts.input_path)?;
-        let mut reader = BufReader::new(input_file);
-
-        // Step 1: Read and validate public header.
-        let public_header =
-            PublicHeader::read_from(&mut reader)?;
-
-        // Step 2: Read encrypted metadata.
-        let mut sealed_meta =
-            vec![0u8; public_header.encrypted_meta_len as usize];
-        reader.read_exact(&mut sealed_meta)?;
-
-        // Step 3: Derive keys.
-        let password = opts.password.clone();
-        let salt = public_header.salt;
-        let params = public_header.argon2_params;
-        let keys = tokio::task::spawn_blocking(move || {
-            kdf::derive_keys(&password, &salt, &params)
-        })
-        .await
-        .map_err(|e| {
-            CryptError::KeyDerivation(format!("Join: {e}"))
-        })??;
+        // Steps 1–4: Read the header, derive keys and decrypt the
+        // metadata.
+        let opened = open_encrypted(
+            &opts.input_path,
+            opts.password.clone(),
+            opts.identity.as_ref(),
+        )?;
 
-        // Step 4: Decrypt metadata with AAD.
-        let mut header_for_aad = public_header.clone();
-        header_for_aad.encrypted_meta_len = 0;
-        let header_aad = header_for_aad.to_bytes();
+        decrypt_payload(opened, opts)
+    }
 
-        let metadata = EncryptedMetadata::unseal(
-            &sealed_meta,
-            &keys.header_key,
-            &public_header.header_nonce,
-            &header_aad,
+    /// Disk space [`FileCrypt::decrypt`] takes with `opts`, and the
+    /// free space where it goes. Reading the metadata needs the
+    /// password; `decrypt` runs the same check once it has it.
+    pub fn plan_decrypt(&self, opts: &DecryptOptions) -> Result<SpacePlan> {
+        let opened = open_encrypted(
+            &opts.input_path,
+            opts.password.clone(),
+            opts.identity.as_ref(),
         )?;
+        Ok(decrypt_space_plan(&opened, opts))
+    }
 
-        // RAII guard for all temp files created below.
-        let temp_parent = opts
-            .temp_dir
-            .clone()
-            .unwrap_or_else(|| opts.output_dir.clone());
-        let mut cleanup = TempCleanup::new();
+    /// Decrypt an encrypted file into memory, for viewing it without
+    /// writing plaintext to disk.
+    ///
+    /// Files whose decrypted size exceeds `max_size` bytes are
+    /// refused with [`CryptError::TooLarge`]: up front for files
+    /// stored uncompressed, gzip files as soon as the limit is
+    /// crossed while decompressing. Gzip is undone in memory; a
+    /// directory comes back as its raw 7z archive.
+    pub fn decrypt_to_memory(
+        &self,
+        input_path: PathBuf,
+        password: String,
+        max_size: u64,
+    ) -> Result<MemoryDecryptResult> {
+        let mut opened = open_encrypted(&input_path, password, None)?;
+
+        // The decompressed size of gzip data is not recorded (its
+        // `original_size` is that of the compressed file), so only
+        // the sink enforces the limit there.
+        let expected = opened.payload_len();
+        if !opened.metadata.gzip_compressed && expected > max_size {
+            return Err(CryptError::TooLarge(max_size));
+        }
 
-        // Step 5: Decrypt data chunks into a temp file.
-        let temp_decrypted =
-            NamedTempFile::new_in(&temp_parent)?;
+        let mut output = MemorySink::new(expected.min(max_size), max_size);
+        let signature = if opened.metadata.gzip_compressed {
+            compress::with_gunzip_writer(&mut output, |gz| {
+                opened.read_payload(|group| {
+                    for chunk in &group.plaintext {
+                        gz.write_all(chunk)
+                            .map_err(|e| MemorySink::error(e, max_size))?;
+                    }
+                    Ok(())
+                })
+            })
+            .map_err(|e| match e {
+                CryptError::Io(e) => MemorySink::error(e, max_size),
+                e => e,
+            })?
+        } else {
+            opened.read_payload(|group| {
+                for chunk in &group.plaintext {
+                    output
+                        .write_all(chunk)
+                        .map_err(|e| MemorySink::error(e, max_size))?;
+                }
+                Ok(())
+            })?
+        };
 
-        let chunk_size = public_header.chunk_size as usize;
-        let encrypted_chunk_size = chunk_size + TAG_LEN;
-        let progress_cb = opts.progress.clone();
+        Ok(MemoryDecryptResult {
+            data: std::mem::take(&mut *output.buf),
+            metadata: opened.metadata,
+            signature,
+        })
+    }
 
-        {
-            let mut writer =
-                BufWriter::new(temp_decrypted.as_file());
-            let mut encrypted_buf =
-                vec![0u8; encrypted_chunk_size];
-            let mut chunk_index: u64 = 0;
-            let mut bytes_decrypted: u64 = 0;
-
-            loop {
-                let n = read_full(
-                    &mut reader,
-                    &mut encrypted_buf,
-                )?;
-                if n == 0 {
-                    break;
-                }
+    /// Open an encrypted file for reading its plaintext at arbitrary
+    /// offsets, e.g. to serve byte ranges of a video.
+    ///
+    /// Gzip-compressed files cannot be read at an offset and are
+    /// refused with [`CryptError::Decryption`].
+    pub fn open_random_access(
+        &self,
+        input_path: PathBuf,
+        password: String,
+    ) -> Result<RandomAccessReader> {
+        let opened = open_encrypted(&input_path, password, None)?;
+        RandomAccessReader::new(opened)
+    }
 
-                let cn = nonce::chunk_nonce(
-                    &public_header.data_base_nonce,
-                    chunk_index,
-                );
-                let chunk_aad = cipher::build_chunk_aad(
-                    &metadata.uuid,
-                    public_header.version,
-                    chunk_index,
-                );
-                let decrypted = cipher::decrypt_chunk(
-                    &keys.data_key,
-                    &cn,
-                    &encrypted_buf[..n],
-                    &chunk_aad,
-                )?;
+    /// Authenticate every chunk of an encrypted file without
+    /// writing any plaintext.
+    ///
+    /// Damaged chunks are rebuilt in memory when the file carries a
+    /// parity layer; the file itself is left untouched (see
+    /// [`FileCrypt::repair`]).
+    pub fn verify(
+        &self,
+        input_path: PathBuf,
+        password: String,
+    ) -> Result<VerifyResult> {
+        let mut opened = open_encrypted(&input_path, password, None)?;
 
-                writer.write_all(&decrypted)?;
+        let mut repaired_chunks: u64 = 0;
+        let signature = opened.read_payload(|group| {
+            repaired_chunks += group.repaired as u64;
+            Ok(())
+        })?;
 
-                chunk_index += 1;
-                bytes_decrypted += decrypted.len() as u64;
+        Ok(VerifyResult {
+            chunk_count: opened.layout.chunk_count,
+            metadata: opened.metadata,
+            repaired_chunks,
+            signature,
+        })
+    }
 
-                self.emit_progress(
-                    &progress_cb,
-                    ProgressStage::Decrypting,
-                    bytes_decrypted,
-                    metadata.original_size,
-                );
-            }
+    /// Rebuild damaged chunks and parity shards of an encrypted
+    /// file and rewrite it in place.
+    ///
+    /// The healed file is written to a temp file next to the
+    /// original and atomically renamed over it. Nothing is written
+    /// if the file is intact. The public header and sealed metadata
+    /// are not covered by parity and cannot be repaired.
+    pub fn repair(
+        &self,
+        input_path: PathBuf,
+        password: String,
+    ) -> Result<RepairResult> {
+        journal::recover(&input_path)?;
+        let mut opened = open_encrypted(&input_path, password, None)?;
+        check_not_split(&opened.header, "repaired")?;
 
-            writer.flush()?;
-        }
+        let shard_len = opened.layout.encrypted_chunk_len;
 
-        // Convert NamedTempFile → kept path & track it.
-        let temp_decrypted_kept = temp_decrypted
-            .into_temp_path()
-            .keep()
-            .map_err(|e| CryptError::Io(e.error))?;
-        cleanup.track(temp_decrypted_kept.clone());
-
-        // Step 6: Decompress if gzip was applied.
-        let data_path = if metadata.gzip_compressed {
-            self.emit_progress(
-                &opts.progress,
-                ProgressStage::DecompressingGzip,
-                0,
-                0,
-            );
+        let parent = input_path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let temp_output = NamedTempFile::new_in(parent)?;
 
-            let temp_ungz =
-                NamedTempFile::new_in(&temp_parent)?;
-            let temp_ungz_kept = temp_ungz
-                .into_temp_path()
-                .keep()
-                .map_err(|e| CryptError::Io(e.error))?;
-            cleanup.track(temp_ungz_kept.clone());
+        let mut repaired_chunks: u64 = 0;
+        let mut repaired_parity: u64 = 0;
 
-            let src = temp_decrypted_kept.clone();
-            let dst = temp_ungz_kept.clone();
+        {
+            let mut writer = BufWriter::new(temp_output.as_file());
+            opened.header.write_to(&mut writer)?;
+            writer.write_all(&opened.sealed_meta)?;
+
+            opened.read_payload(|group| {
+                repaired_chunks += group.repaired as u64;
+                for chunk in &group.ciphertext {
+                    writer.write_all(chunk)?;
+                }
 
-            tokio::task::spawn_blocking(move || {
-                compress::gzip::gzip_decompress(&src, &dst)
-            })
-            .await
-            .map_err(|e| {
-                CryptError::Compression(format!("Join: {e}"))
-            })?
-            .map_err(|e| {
-                CryptError::Compression(format!("{e}"))
+                if group.parity.is_empty() {
+                    return Ok(());
+                }
+                let parity = fec::encode_parity(
+                    &group.ciphertext,
+                    group.parity.len(),
+                    shard_len,
+                )?;
+                for (fresh, stored) in parity.iter().zip(&group.parity) {
+                    if fresh != stored {
+                        repaired_parity += 1;
+                    }
+                    writer.write_all(fresh)?;
+                }
+                Ok(())
             })?;
 
-            // Encrypted temp no longer needed.
-            cleanup.remove_now(&temp_decrypted_kept);
-            temp_ungz_kept
-        } else {
-            temp_decrypted_kept
+            // The signature trailer was verified above; keep it.
+            writer.write_all(&opened.trailer)?;
+            writer.flush()?;
+        }
+
+        let result = RepairResult {
+            output_path: input_path.clone(),
+            repaired_chunks,
+            repaired_parity,
         };
 
-        // Step 7: If the original was a directory (7z),
-        // decompress the archive.
-        let output_path =
-            if metadata.original_extension == "7z" {
-                self.emit_progress(
-                    &opts.progress,
-                    ProgressStage::DecompressingDirectory,
-                    0,
-                    0,
-                );
-
-                let out_dir = opts
-                    .output_dir
-                    .join(&metadata.original_filename);
-                let archive = data_path.clone();
-                let target = out_dir.clone();
-
-                tokio::task::spawn_blocking(move || {
-                    compress::sevenz::decompress_archive(
-                        &archive, &target,
-                    )
-                })
-                .await
-                .map_err(|e| {
-                    CryptError::Compression(format!("Join: {e}"))
-                })?
-                .map_err(|e| {
-                    CryptError::Compression(format!("{e}"))
-                })?;
+        if result.rewritten() {
+            drop(opened);
+            temp_output.persist(&input_path).map_err(|e| {
+                CryptError::Io(std::io::Error::other(format!(
+                    "Persist: {e}"
+                )))
+            })?;
+        }
 
-                // data_path is tracked — will be removed by
-                // cleanup.finish() below.
-                out_dir
-            } else {
-                // Rename temp file to original name.
-                let ext_part =
-                    if metadata.original_extension.is_empty() {
-                        String::new()
-                    } else {
-                        format!(
-                            ".{}",
-                            metadata.original_extension
-                        )
-                    };
-                let final_name = format!(
-                    "{}{}",
-                    metadata.original_filename, ext_part
-                );
-                let final_path =
-                    opts.output_dir.join(final_name);
-
-                // Move the temp file to its final location.
-                // Stop tracking it first so cleanup doesn't
-                // delete the final output.
-                cleanup.paths.retain(|p| *p != data_path);
-
-                fs::rename(&data_path, &final_path).or_else(
-                    |_| {
-                        // rename may fail across drives.
-                        fs::copy(&data_path, &final_path)?;
-                        fs::remove_file(&data_path)?;
-                        Ok::<_, std::io::Error>(())
-                    },
-                )?;
+        Ok(result)
+    }
 
-                final_path
-            };
+    /// Re-encrypt a file under a new password.
+    ///
+    /// Every key depends on the password, so all chunks are
+    /// decrypted and encrypted again with a fresh salt and nonces,
+    /// streaming from the old layout into the new one; no plaintext
+    /// reaches the disk. The UUID, parity layout and metadata are
+    /// kept. [`RekeyOptions::params`] may raise the KDF cost (the
+    /// engine's parameters by default) or change the chunk size.
+    /// The original signature is checked, and the new file is signed
+    /// by [`RekeyOptions::signer`] if set.
+    pub fn rekey(&self, opts: RekeyOptions) -> Result<RekeyResult> {
+        let argon2_params =
+            opts.params.argon2_params.unwrap_or(self.argon2_params);
+        journal::recover(&opts.input_path)?;
+        let mut opened =
+            open_encrypted(&opts.input_path, opts.old_password, None)?;
+        if opts.output_path.is_none() {
+            check_not_split(&opened.header, "re-encrypted")?;
+        }
+        let chunk_size =
+            opts.params.chunk_size.unwrap_or(opened.header.chunk_size);
+        if !(MIN_CHUNK_SIZE..=MAX_CHUNK_SIZE).contains(&chunk_size) {
+            return Err(CryptError::Encryption(format!(
+                "chunk_size {chunk_size} out of range \
+                 [{MIN_CHUNK_SIZE}..{MAX_CHUNK_SIZE}]"
+            )));
+        }
+        // The parity layer is kept, so it must fit the new chunks.
+        if let Some(fec) = &opened.header.fec {
+            fec.validate_for_chunk_size(chunk_size)?;
+        }
 
-        // Remove any remaining temp files.
-        cleanup.finish();
+        let salt = kdf::generate_salt();
+        let password = opts.new_password;
+        let keys = kdf::derive_keys(&password, &salt, &argon2_params)?;
 
-        self.emit_progress(
-            &opts.progress,
-            ProgressStage::Done,
-            0,
+        let mut metadata = opened.metadata.clone();
+        metadata.signer_public_key =
+            opts.signer.as_ref().map(|signer| signer.public_key());
+
+        let mut header = PublicHeader {
+            version: VERSION,
+            salt,
+            argon2_params,
+            chunk_size,
+            data_base_nonce: nonce::generate_data_base_nonce(),
+            header_nonce: nonce::generate_header_nonce(),
+            encrypted_meta_len: 0,
+            fec: opened.header.fec,
+            signature: opts
+                .signer
+                .as_ref()
+                .map(|_| SignatureAlgorithm::Ed25519),
+            cipher: opened.header.cipher,
+            key_commitment: Some(keys.commitment()?),
+            recipient: None,
+            volumes: None,
+        };
+        let sealed_meta =
+            seal_metadata(&mut header, &metadata, &keys.header_key)?;
+
+        let output_path = opts
+            .output_path
+            .unwrap_or_else(|| opts.input_path.clone());
+        let parent = output_path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let temp_output = NamedTempFile::new_in(parent)?;
+
+        let progress = ProgressTracker::new(
+            opts.progress,
+            vec![ProgressStage::Encrypting],
+        );
+        progress.stage(
+            ProgressStage::Encrypting,
+            opened.payload_len(),
             0,
         );
 
-        Ok(DecryptResult {
+        let (source_signature, chunk_count) = {
+            let mut writer = BufWriter::new(temp_output.as_file());
+            header.write_to(&mut writer)?;
+            writer.write_all(&sealed_meta)?;
+
+            let mut payload = PayloadWriter::new(
+                writer,
+                &header,
+                &keys.data_key,
+                &metadata.uuid,
+                opts.signer.is_some(),
+            );
+            // Plaintext not yet cut into chunks of the new size.
+            let mut pending = Zeroizing::new(Vec::new());
+            let mut bytes_processed: u64 = 0;
+            let source_signature = opened.read_payload(|group| {
+                for chunk in &group.plaintext {
+                    pending.extend_from_slice(chunk);
+                    let full = pending.len() / chunk_size as usize
+                        * chunk_size as usize;
+                    for new_chunk in
+                        pending[..full].chunks(chunk_size as usize)
+                    {
+                        payload.write_chunk(new_chunk)?;
+                    }
+                    pending.drain(..full);
+                    bytes_processed += chunk.len() as u64;
+                }
+                progress.update(bytes_processed, 0);
+                Ok(())
+            })?;
+            // The padding was dropped on reading; lay it out again.
+            let mut padding_left = metadata
+                .payload_len
+                .map_or(0, |len| padding::padme(len) - len);
+            while padding_left > 0 || !pending.is_empty() {
+                let fill = padding_left
+                    .min((chunk_size as usize - pending.len()) as u64);
+                let len = pending.len() + fill as usize;
+                pending.resize(len, 0);
+                padding_left -= fill;
+                payload.write_chunk(&pending)?;
+                pending.clear();
+            }
+
+            let chunk_count =
+                payload.finish(&sealed_meta, opts.signer.as_ref())?;
+            payload.get_mut().flush()?;
+            (source_signature, chunk_count)
+        };
+
+        drop(opened);
+        temp_output.persist(&output_path).map_err(|e| {
+            CryptError::Io(std::io::Error::other(format!("Persist: {e}")))
+        })?;
+
+        progress.done();
+
+        Ok(RekeyResult {
             output_path,
-            metadata,
+            chunk_count,
+            source_signature,
         })
     }
 
-    fn emit_progress(
+    /// Re-encrypt `input_path` in place under `new_password`,
+    /// applying `params`.
+    ///
+    /// Shorthand for [`FileCrypt::rekey`] without progress or a new
+    /// signature, e.g. to migrate a vault to stronger Argon2
+    /// parameters. The original is replaced atomically only once the
+    /// new file is complete.
+    pub fn reencrypt(
         &self,
-        cb: &Option<ProgressCallback>,
-        stage: ProgressStage,
-        bytes: u64,
-        total: u64,
-    ) {
-        if let Some(callback) = cb {
-            callback(ProgressEvent::new(stage, bytes, total));
-        }
+        input_path: PathBuf,
+        old_password: String,
+        new_password: String,
+        params: ReencryptParams,
+    ) -> Result<RekeyResult> {
+        self.rekey(RekeyOptions {
+            input_path,
+            output_path: None,
+            old_password,
+            new_password,
+            params,
+            signer: None,
+            progress: None,
+        })
     }
-}
 
-/// Read as many bytes as possible to fill the buffer.
-///
-/// Unlike `read`, this loops until the buffer is full or EOF.
-fn read_full<R: Read>(
-    reader: &mut R,
-    buf: &mut [u8],
-) -> Result<usize> {
-    let mut total = 0;
-    while total < buf.len() {
-        match reader.read(&mut buf[total..])? {
-            0 => break,
-            n => total += n,
+    /// Check the options of a stream, then build and seal its header
+    /// (see [`FileCrypt::encrypt_stream`]). Returns the stream UUID.
+    pub(crate) fn seal_stream_header(
+        &self,
+        opts: &mut StreamEncryptOptions,
+    ) -> Result<(String, SealedHeader)> {
+        if let Some(fec) = &opts.fec {
+            fec.validate_for_chunk_size(self.chunk_size)?;
         }
-    }
-    Ok(total)
-}
 
-#[cfg(test)]
-mod tests {
+        let uuid = opts
+            .uuid
+            .take()
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        let metadata = EncryptedMetadata {
+            original_filename: std::mem::take(&mut opts.original_filename),
+            original_extension: std::mem::take(
+                &mut opts.original_extension,
+            ),
+            gzip_compressed: opts.gzip_compressed,
+            original_size: 0,
+            uuid: uuid.clone(),
+            metadata: opts.metadata.take().unwrap_or_default(),
+            signer_public_key: opts
+                .signer
+                .as_ref()
+                .map(|signer| signer.public_key()),
+            payload_len: None,
+        };
+        let sealed = self
+            .seal_new_header(
+                std::mem::take(&mut opts.password),
+                opts.recipient.as_ref(),
+                &metadata,
+                opts.fec,
+                opts.signer.is_some(),
+            )?;
+        Ok((uuid, sealed))
+    }
+
+    /// Derive fresh keys for `password`, or encapsulate a fresh file
+    /// key to `recipient`, and build a public header for this engine,
+    /// with `metadata` sealed against it.
+    fn seal_new_header(
+        &self,
+        password: String,
+        recipient: Option<&RecipientPublicKey>,
+        metadata: &EncryptedMetadata,
+        fec: Option<FecParams>,
+        signed: bool,
+    ) -> Result<SealedHeader> {
+        let salt = kdf::generate_salt();
+        let (keys, recipient) = match recipient {
+            Some(recipient) => {
+                let (stanza, mut file_key) = recipient.encapsulate()?;
+                let keys = kdf::derive_subkeys(&file_key);
+                file_key.zeroize();
+                (keys?, Some(stanza))
+            }
+            None => {
+                let keys =
+                    kdf::derive_keys(&password, &salt, &self.argon2_params)?;
+                (keys, None)
+            }
+        };
+
+        let mut header = PublicHeader {
+            version: VERSION,
+            salt,
+            argon2_params: self.argon2_params,
+            chunk_size: self.chunk_size,
+            data_base_nonce: nonce::generate_data_base_nonce(),
+            header_nonce: nonce::generate_header_nonce(),
+            encrypted_meta_len: 0,
+            fec,
+            signature: signed.then_some(SignatureAlgorithm::Ed25519),
+            cipher: self.cipher_suite,
+            key_commitment: Some(keys.commitment()?),
+            recipient,
+            volumes: None,
+        };
+        let sealed_meta =
+            seal_metadata(&mut header, metadata, &keys.header_key)?;
+
+        Ok(SealedHeader {
+            header,
+            sealed_meta,
+            keys,
+        })
+    }
+
+    /// Feed everything left in `reader` to `decoder`, writing the
+    /// plaintext to `writer`, and check the signature.
+    fn stream_payload<R: BufRead, W: Write + ?Sized>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+        decoder: &mut PayloadDecoder,
+        progress: &ProgressTracker,
+    ) -> Result<SignatureStatus> {
+        loop {
+            let data = reader.fill_buf()?;
+            if data.is_empty() {
+                return decoder.finish(writer);
+            }
+            let n = data.len();
+            decoder.push(data, writer)?;
+            reader.consume(n);
+            progress.update(decoder.written(), 0);
+        }
+    }
+}
+
+/// An encrypted file opened for reading, positioned at the start
+/// of the payload.
+pub(crate) struct OpenedFile {
+    pub(crate) reader: BufReader<VolumeReader>,
+    pub(crate) header: PublicHeader,
+    sealed_meta: Vec<u8>,
+    pub(crate) keys: kdf::DerivedKeys,
+    pub(crate) metadata: EncryptedMetadata,
+    pub(crate) layout: PayloadLayout,
+    /// Signature trailer, filled in by [`OpenedFile::read_payload`].
+    trailer: Vec<u8>,
+}
+
+impl OpenedFile {
+    /// Plaintext length without the length-hiding padding.
+    pub(crate) fn payload_len(&self) -> u64 {
+        self.metadata
+            .payload_len
+            .unwrap_or_else(|| self.layout.plaintext_len())
+    }
+
+    /// Read the whole payload (see [`read_payload`]) and, for signed
+    /// files, check the trailing signature against the signer key
+    /// in the metadata.
+    fn read_payload(
+        &mut self,
+        mut on_group: impl FnMut(PayloadGroup) -> Result<()>,
+    ) -> Result<SignatureStatus> {
+        let mut hasher = self.header.signature.map(|_| ChunkHasher::new());
+        let mut remaining = self.payload_len();
+
+        read_payload(
+            &mut self.reader,
+            &self.header,
+            &self.layout,
+            0,
+            &self.keys.data_key,
+            &self.metadata.uuid,
+            |mut group| {
+                if let Some(hasher) = hasher.as_mut() {
+                    for chunk in &group.ciphertext {
+                        hasher.update(chunk);
+                    }
+                }
+                trim_padding(&mut group, &mut remaining);
+                on_group(group)
+            },
+        )?;
+
+        if let Some(alg) = self.header.signature {
+            self.trailer = vec![0u8; alg.signature_len()];
+            self.reader.read_exact(&mut self.trailer)?;
+        }
+        verify_signature(
+            &self.header,
+            &self.sealed_meta,
+            &self.metadata,
+            hasher,
+            &self.trailer,
+        )
+    }
+}
+
+/// A freshly built public header, its sealed metadata and the keys
+/// derived for it.
+pub(crate) struct SealedHeader {
+    pub(crate) header: PublicHeader,
+    pub(crate) sealed_meta: Vec<u8>,
+    pub(crate) keys: kdf::DerivedKeys,
+}
+
+/// Check the signature trailer of a signed payload against the
+/// signer key in the metadata. `hasher` must have seen every data
+/// chunk; unsigned payloads have neither.
+fn verify_signature(
+    header: &PublicHeader,
+    sealed_meta: &[u8],
+    metadata: &EncryptedMetadata,
+    hasher: Option<ChunkHasher>,
+    trailer: &[u8],
+) -> Result<SignatureStatus> {
+    let (Some(hasher), Some(public_key)) =
+        (hasher, metadata.signer_public_key)
+    else {
+        return Ok(SignatureStatus::Unsigned);
+    };
+
+    let message = signature::signed_message(
+        &header.to_bytes(),
+        sealed_meta,
+        &hasher.finalize(),
+    );
+    signature::verify(&public_key, &message, trailer)?;
+
+    Ok(SignatureStatus::Valid { public_key })
+}
+
+/// Seal the metadata against `header` and record its length there.
+///
+/// The AAD is the header with `encrypted_meta_len` still zero, as
+/// expected by [`open_encrypted`]. [`META_RESERVE_LEN`] bytes of
+/// padding are left for [`FileCrypt::update_metadata`].
+fn seal_metadata(
+    header: &mut PublicHeader,
+    metadata: &EncryptedMetadata,
+    header_key: &[u8; KEY_LEN],
+) -> Result<Vec<u8>> {
+    header.encrypted_meta_len = 0;
+    let sealed = metadata
+        .seal_padded(
+            header.cipher,
+            header_key,
+            &header.header_nonce,
+            &header.to_bytes(),
+            meta_padded_len(metadata)?,
+        )?
+        .expect("the reserve fits a padding extension");
+    header.encrypted_meta_len = sealed.len() as u32;
+    Ok(sealed)
+}
+
+/// Plaintext length new metadata is padded to: its own length plus
+/// the reserve for edits, rounded up to a bucket if the file hides
+/// its lengths.
+fn meta_padded_len(metadata: &EncryptedMetadata) -> Result<usize> {
+    let len = metadata.encoded_len()? + META_RESERVE_LEN;
+    Ok(match metadata.payload_len {
+        Some(_) => padding::meta_bucket(len),
+        None => len,
+    })
+}
+
+/// Record in the header that the file is split into volumes of at
+/// most `volume_size` bytes, and seal the metadata again against it.
+///
+/// Returns the container offset each volume starts at (see
+/// [`volume::plan`]).
+fn split_volumes(
+    sealed: &mut SealedHeader,
+    metadata: &EncryptedMetadata,
+    volume_size: u64,
+    plaintext_len: u64,
+) -> Result<Vec<u64>> {
+    let header = &mut sealed.header;
+    // The length of the extension does not depend on the count, so
+    // the offsets can be planned before it is known.
+    header.volumes = Some(VolumeSet::generate());
+    let layout = PayloadLayout::for_plaintext_len(
+        plaintext_len,
+        header.chunk_size,
+        header.fec,
+    );
+    let trailer_len =
+        header.signature.map_or(0, SignatureAlgorithm::signature_len);
+    let starts = volume::plan(
+        volume_size,
+        (header.encoded_len() + sealed.sealed_meta.len()) as u64,
+        &layout,
+        trailer_len as u64,
+    )?;
+    let count = u32::try_from(starts.len()).map_err(|_| {
+        CryptError::Volume(format!("too many volumes of {volume_size} bytes"))
+    })?;
+    if let Some(set) = header.volumes.as_mut() {
+        set.count = count;
+    }
+
+    // The header changed, so the metadata is sealed again under a
+    // fresh nonce; its length stays the same.
+    header.header_nonce = nonce::generate_header_nonce();
+    sealed.sealed_meta =
+        seal_metadata(header, metadata, &sealed.keys.header_key)?;
+    Ok(starts)
+}
+
+/// Steps 5–7 of [`FileCrypt::decrypt`]: decrypt the payload into a
+/// temp file, undo gzip and unpack a directory archive.
+fn decrypt_payload(
+    mut opened: OpenedFile,
+    opts: DecryptOptions,
+) -> Result<DecryptResult> {
+    decrypt_space_plan(&opened, &opts).check()?;
+
+    // RAII guard for all temp files created below.
+    let temp_parent = opts
+        .temp_dir
+        .clone()
+        .unwrap_or_else(|| opts.output_dir.clone());
+    let mut cleanup = TempCleanup::new();
+
+    // Step 5: Decrypt data chunks into a temp file.
+    let temp_decrypted = NamedTempFile::new_in(&temp_parent)?;
+
+    let mut stages = vec![ProgressStage::Decrypting];
+    if opened.metadata.gzip_compressed {
+        stages.push(ProgressStage::DecompressingGzip);
+    }
+    if opened.metadata.original_extension == "7z" {
+        stages.push(ProgressStage::DecompressingDirectory);
+    }
+    let progress = ProgressTracker::new(opts.progress.clone(), stages);
+    progress.stage(ProgressStage::Decrypting, opened.payload_len(), 0);
+
+    let signature = {
+        let mut writer = BufWriter::new(temp_decrypted.as_file());
+        let mut bytes_decrypted: u64 = 0;
+
+        // Damaged chunks are rebuilt from parity transparently; the
+        // signature of signed files is checked at the end.
+        let signature = opened.read_payload(|group| {
+            for chunk in &group.plaintext {
+                writer.write_all(chunk)?;
+                bytes_decrypted += chunk.len() as u64;
+                progress.update(bytes_decrypted, 0);
+            }
+            Ok(())
+        })?;
+
+        writer.flush()?;
+        signature
+    };
+    let metadata = opened.metadata;
+
+    // Convert NamedTempFile → kept path & track it.
+    let temp_decrypted_kept = temp_decrypted
+        .into_temp_path()
+        .keep()
+        .map_err(|e| CryptError::Io(e.error))?;
+    cleanup.track(temp_decrypted_kept.clone());
+
+    // Step 6: Decompress if gzip was applied.
+    let data_path = if metadata.gzip_compressed {
+        progress.stage(ProgressStage::DecompressingGzip, 0, 0);
+
+        let temp_ungz = NamedTempFile::new_in(&temp_parent)?;
+        let temp_ungz_kept = temp_ungz
+            .into_temp_path()
+            .keep()
+            .map_err(|e| CryptError::Io(e.error))?;
+        cleanup.track(temp_ungz_kept.clone());
+
+        compress::gunzip_file(
+            &temp_decrypted_kept,
+            &temp_ungz_kept,
+            |p| progress.compress_update(p),
+        )
+        .map_err(|e| CryptError::Compression(format!("{e}")))?;
+
+        // Encrypted temp no longer needed.
+        cleanup.remove_now(&temp_decrypted_kept);
+        temp_ungz_kept
+    } else {
+        temp_decrypted_kept
+    };
+
+    // Step 7: If the original was a directory (7z), decompress the
+    // archive.
+    let output_path = if metadata.original_extension == "7z" {
+        progress.stage(ProgressStage::DecompressingDirectory, 0, 0);
+
+        let out_dir = opts.output_dir.join(&metadata.original_filename);
+        compress::unpack_archive(
+            &data_path,
+            &out_dir,
+            |p| progress.compress_update(p),
+        )
+        .map_err(|e| CryptError::Compression(format!("{e}")))?;
+
+        // data_path is tracked — will be removed by cleanup.finish()
+        // below.
+        out_dir
+    } else {
+        // Rename temp file to original name.
+        let ext_part = if metadata.original_extension.is_empty() {
+            String::new()
+        } else {
+            format!(".{}", metadata.original_extension)
+        };
+        let final_name =
+            format!("{}{}", metadata.original_filename, ext_part);
+        let final_path = opts.output_dir.join(final_name);
+
+        // Move the temp file to its final location. Stop tracking it
+        // first so cleanup doesn't delete the final output.
+        cleanup.paths.retain(|p| *p != data_path);
+
+        fs::rename(&data_path, &final_path).or_else(|_| {
+            // rename may fail across drives.
+            fs::copy(&data_path, &final_path)?;
+            fs::remove_file(&data_path)?;
+            Ok::<_, std::io::Error>(())
+        })?;
+
+        final_path
+    };
+
+    // Remove any remaining temp files.
+    cleanup.finish();
+
+    progress.done();
+
+    Ok(DecryptResult {
+        output_path,
+        metadata,
+        signature,
+    })
+}
+
+/// Space plan of [`decrypt_payload`]: the payload is decrypted into
+/// a temp file, gunzipped into a second one, then renamed into place
+/// or unpacked. Decompressed sizes are taken to be the compressed
+/// ones.
+fn decrypt_space_plan(
+    opened: &OpenedFile,
+    opts: &DecryptOptions,
+) -> SpacePlan {
+    let temp_dir = opts
+        .temp_dir
+        .clone()
+        .unwrap_or_else(|| opts.output_dir.clone());
+    let metadata = &opened.metadata;
+    let is_archive = metadata.original_extension == "7z";
+    let payload_len = opened.payload_len();
+    let gunzipped_len = match metadata.gzip_compressed {
+        true => payload_len,
+        false => 0,
+    };
+    SpacePlan {
+        output_bytes: payload_len,
+        output_free: space::available(&opts.output_dir),
+        temp_bytes: payload_len + gunzipped_len,
+        // A single file is renamed into place, an archive unpacked
+        // next to it.
+        temp_bytes_at_output: if is_archive { payload_len } else { 0 },
+        temp_free: space::available(&temp_dir),
+        shared_volume: space::same_volume(&opts.output_dir, &temp_dir),
+        output_dir: opts.output_dir.clone(),
+        temp_dir,
+        estimated: metadata.gzip_compressed || is_archive,
+    }
+}
+
+/// The file that ends up being encrypted, after compression.
+struct SourceFile {
+    path: PathBuf,
+    original_filename: String,
+    original_extension: String,
+    gzip_applied: bool,
+    original_size: u64,
+    /// Entries of a directory left out by its filter.
+    skipped: Vec<SkippedEntry>,
+}
+
+/// File name and extension recorded in the metadata for `input_path`;
+/// a directory is stored as a 7z archive named after it.
+fn source_names(input_path: &Path, is_dir: bool) -> (String, String) {
+    if is_dir {
+        let dir_name = input_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("archive")
+            .to_string();
+        return (dir_name, "7z".to_string());
+    }
+    let filename = input_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("file")
+        .to_string();
+    let extension = input_path
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_string();
+    (filename, extension)
+}
+
+/// Steps 1–2 of [`FileCrypt::encrypt`]: compress a directory to 7z
+/// and gzip the result if asked to. Intermediate files are tracked
+/// by the returned guard.
+fn prepare_source(
+    input_path: &Path,
+    output_dir: &Path,
+    temp_parent: &Path,
+    gzip_compressed: bool,
+    shred_source: bool,
+    filter: Option<&DirectoryFilter>,
+    callback: Option<ProgressCallback>,
+) -> Result<(SourceFile, ProgressTracker, TempCleanup)> {
+    let is_dir = input_path.is_dir();
+    if shred_source && is_dir {
+        check_output_outside(input_path, output_dir)?;
+    }
+
+    let mut stages = Vec::new();
+    if is_dir {
+        stages.push(ProgressStage::CompressingDirectory);
+    }
+    if gzip_compressed {
+        stages.push(ProgressStage::CompressingGzip);
+    }
+    stages.push(ProgressStage::Encrypting);
+    let progress = ProgressTracker::new(callback, stages);
+
+    // RAII guard: cleans up all tracked temp files on any exit path
+    // (success or error / early return via `?`).
+    let mut cleanup = TempCleanup::new();
+
+    // Step 1: If directory, compress to 7z first.
+    let mut skipped = Vec::new();
+    let (original_filename, original_extension) =
+        source_names(input_path, is_dir);
+    let file_to_encrypt = if is_dir {
+        let temp_archive = NamedTempFile::new_in(temp_parent)?;
+        progress.stage(ProgressStage::CompressingDirectory, 0, 0);
+        skipped = compress::pack_directory(
+            input_path,
+            temp_archive.path(),
+            filter.unwrap_or(&DirectoryFilter::default()),
+            |p| progress.compress_update(p),
+        )
+        .map_err(|e| CryptError::Compression(format!("{e}")))?;
+
+        let kept = temp_archive
+            .into_temp_path()
+            .keep()
+            .map_err(|e| CryptError::Io(e.error))?;
+        cleanup.track(kept.clone());
+        kept
+    } else {
+        input_path.to_path_buf()
+    };
+
+    // Step 2: Optional gzip compression.
+    let path = if gzip_compressed {
+        let temp_gz = NamedTempFile::new_in(temp_parent)?;
+        progress.stage(ProgressStage::CompressingGzip, 0, 0);
+        compress::gzip_file(
+            &file_to_encrypt,
+            temp_gz.path(),
+            |p| progress.compress_update(p),
+        )
+        .map_err(|e| CryptError::Compression(format!("{e}")))?;
+
+        // 7z temp is no longer needed.
+        if is_dir {
+            cleanup.remove_now(&file_to_encrypt);
+        }
+
+        let kept_gz = temp_gz
+            .into_temp_path()
+            .keep()
+            .map_err(|e| CryptError::Io(e.error))?;
+        cleanup.track(kept_gz.clone());
+        kept_gz
+    } else {
+        file_to_encrypt
+    };
+
+    // Get original size of the file being encrypted.
+    let original_size = fs::metadata(&path)?.len();
+
+    let source = SourceFile {
+        path,
+        original_filename,
+        original_extension,
+        gzip_applied: gzip_compressed,
+        original_size,
+        skipped,
+    };
+    Ok((source, progress, cleanup))
+}
+
+/// Refuse to rewrite a split file in place, which would replace its
+/// first volume only.
+fn check_not_split(header: &PublicHeader, action: &str) -> Result<()> {
+    match header.volumes {
+        Some(set) => Err(CryptError::Volume(format!(
+            "a file split into {} volumes cannot be {action} in place",
+            set.count
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Refuse to shred a directory that the encrypted output would be
+/// written into.
+fn check_output_outside(input_dir: &Path, output_dir: &Path) -> Result<()> {
+    let input_dir = fs::canonicalize(input_dir)?;
+    let output_dir = fs::canonicalize(output_dir)?;
+    if output_dir.starts_with(&input_dir) {
+        return Err(CryptError::Encryption(format!(
+            "output directory {} is inside the directory to shred",
+            output_dir.display()
+        )));
+    }
+    Ok(())
+}
+
+/// Length of the chunk payload: the file minus the public header,
+/// the sealed metadata and the optional signature trailer.
+fn payload_len(file_len: u64, header: &PublicHeader) -> Result<u64> {
+    let trailer_len =
+        header.signature.map_or(0, SignatureAlgorithm::signature_len);
+    file_len
+        .checked_sub(
+            (header.encoded_len()
+                + header.encrypted_meta_len as usize
+                + trailer_len) as u64,
+        )
+        .ok_or_else(|| {
+            CryptError::CorruptedData(
+                "file too short for its header and trailer".to_string(),
+            )
+        })
+}
+
+/// Describe a container of `file_len` bytes from its public header.
+pub(crate) fn inspect_header(
+    header: &PublicHeader,
+    file_len: u64,
+) -> InspectResult {
+    let prefix_len =
+        (header.encoded_len() + header.encrypted_meta_len as usize) as u64;
+    let layout = payload_len(file_len, header).and_then(|len| {
+        PayloadLayout::from_payload_len(len, header.chunk_size, header.fec)
+    });
+
+    InspectResult {
+        version: header.version,
+        argon2_params: header.argon2_params,
+        chunk_size: header.chunk_size,
+        fec: header.fec,
+        signature: header.signature,
+        cipher: header.cipher,
+        key_commitment: header.key_commitment.is_some(),
+        recipient: header.recipient.as_ref().map(|r| r.algorithm),
+        file_len,
+        ciphertext_len: file_len.saturating_sub(prefix_len),
+        chunk_count: layout.as_ref().map_or(0, |l| l.chunk_count),
+        estimated_plaintext_size: layout
+            .as_ref()
+            .map_or(0, PayloadLayout::plaintext_len),
+        whole_chunks: layout.is_ok(),
+    }
+}
+
+/// Read the public header, derive keys and decrypt the metadata.
+pub(crate) fn open_encrypted(
+    input_path: &Path,
+    password: String,
+    identity: Option<&RecipientIdentity>,
+) -> Result<OpenedFile> {
+    let mut reader = BufReader::new(VolumeReader::open(input_path)?);
+    let file_len = reader.get_ref().len();
+
+    let (header, sealed_meta) = read_prefix(&mut reader)?;
+    reader.get_ref().check_header(&header)?;
+
+    // Everything between the metadata and the optional signature
+    // trailer must form whole chunks (and groups).
+    let layout = PayloadLayout::from_payload_len(
+        payload_len(file_len, &header)?,
+        header.chunk_size,
+        header.fec,
+    )?;
+
+    let (keys, metadata) =
+        unlock(&header, &sealed_meta, password, identity)?;
+
+    Ok(OpenedFile {
+        reader,
+        header,
+        sealed_meta,
+        keys,
+        metadata,
+        layout,
+        trailer: Vec::new(),
+    })
+}
+
+/// Read and validate the public header and the sealed metadata.
+fn read_prefix<R: Read>(reader: &mut R) -> Result<(PublicHeader, Vec<u8>)> {
+    let header = PublicHeader::read_from(reader)?;
+    let mut sealed_meta = vec![0u8; header.encrypted_meta_len as usize];
+    reader.read_exact(&mut sealed_meta)?;
+    Ok((header, sealed_meta))
+}
+
+/// Derive the keys for `header` and decrypt the metadata.
+///
+/// Files encrypted to a recipient are opened with `identity`; the
+/// password is only used for the others.
+pub(crate) fn unlock(
+    header: &PublicHeader,
+    sealed_meta: &[u8],
+    password: String,
+    identity: Option<&RecipientIdentity>,
+) -> Result<(kdf::DerivedKeys, EncryptedMetadata)> {
+    // Every version 2 file is written with a commitment; without it a
+    // crafted file could open under several keys.
+    if header.version >= 2 && header.key_commitment.is_none() {
+        return Err(CryptError::InvalidHeader(
+            "missing key commitment".to_string(),
+        ));
+    }
+
+    let keys = match (&header.recipient, identity) {
+        (Some(stanza), Some(identity)) => {
+            let mut file_key = identity.decapsulate(stanza)?;
+            let keys = kdf::derive_subkeys(&file_key);
+            file_key.zeroize();
+            keys?
+        }
+        (Some(_), None) => return Err(CryptError::RecipientKeyRequired),
+        (None, _) => {
+            kdf::derive_keys(&password, &header.salt, &header.argon2_params)?
+        }
+    };
+
+    // A wrong password fails here, in constant time and before any
+    // AEAD work. Only version 1 files lack a commitment.
+    if let Some(commitment) = &header.key_commitment {
+        keys.verify_commitment(commitment)?;
+    }
+
+    // Decrypt metadata with AAD matching the public header.
+    let mut header_for_aad = header.clone();
+    header_for_aad.encrypted_meta_len = 0;
+    let header_aad = header_for_aad.to_bytes();
+
+    let metadata = EncryptedMetadata::unseal(
+        header.cipher,
+        sealed_meta,
+        &keys.header_key,
+        &header.header_nonce,
+        &header_aad,
+    )?;
+
+    // The header flag and the signer key are sealed together; one
+    // without the other means a malformed file.
+    if header.signature.is_some() != metadata.signer_public_key.is_some() {
+        return Err(CryptError::InvalidSignature(
+            "signature trailer and signer key do not match".to_string(),
+        ));
+    }
+
+    Ok((keys, metadata))
+}
+
+/// One group of payload chunks after authentication (and repair).
+pub(crate) struct PayloadGroup {
+    /// Ciphertext of every data chunk, with damaged chunks healed.
+    ciphertext: Vec<Vec<u8>>,
+    /// Plaintext of every data chunk.
+    pub(crate) plaintext: Vec<Vec<u8>>,
+    /// Parity shards as stored on disk (empty without FEC).
+    parity: Vec<Vec<u8>>,
+    /// Number of data chunks rebuilt from parity.
+    repaired: usize,
+}
+
+/// Drop the length-hiding padding from the plaintext of `group`.
+/// `remaining` counts the payload bytes not passed on yet.
+fn trim_padding(group: &mut PayloadGroup, remaining: &mut u64) {
+    for chunk in &mut group.plaintext {
+        let keep = (*remaining).min(chunk.len() as u64);
+        chunk.truncate(keep as usize);
+        *remaining -= keep;
+    }
+}
+
+/// Read, authenticate and decrypt the payload group by group.
+///
+/// Without a parity layer every chunk is its own group and the
+/// first authentication failure is returned as is. With parity,
+/// chunks whose tag fails are treated as erasures and rebuilt, up
+/// to `parity_shards` per group. `first_chunk` is the index of the
+/// first chunk of `layout` within the whole payload.
+pub(crate) fn read_payload<R: Read>(
+    reader: &mut R,
+    header: &PublicHeader,
+    layout: &PayloadLayout,
+    first_chunk: u64,
+    data_key: &[u8; KEY_LEN],
+    uuid: &str,
+    mut on_group: impl FnMut(PayloadGroup) -> Result<()>,
+) -> Result<()> {
+    for group_index in 0..layout.group_count() {
+        let group = layout.group(group_index);
+
+        let mut ciphertext = Vec::with_capacity(group.data_chunks);
+        for i in 0..group.data_chunks {
+            let len = layout.chunk_len(group.first_chunk + i as u64);
+            let mut chunk = vec![0u8; len];
+            reader.read_exact(&mut chunk)?;
+            ciphertext.push(chunk);
+        }
+
+        let mut parity = Vec::with_capacity(group.parity_shards);
+        for _ in 0..group.parity_shards {
+            let mut shard = vec![0u8; layout.encrypted_chunk_len];
+            reader.read_exact(&mut shard)?;
+            parity.push(shard);
+        }
+
+        let mut plaintext = Vec::with_capacity(group.data_chunks);
+        let mut damaged = Vec::new();
+        for (i, chunk) in ciphertext.iter().enumerate() {
+            let index = first_chunk + group.first_chunk + i as u64;
+            match decrypt_data_chunk(data_key, header, uuid, index, chunk)
+            {
+                Ok(pt) => plaintext.push(pt),
+                Err(e) if parity.is_empty() => return Err(e),
+                Err(_) => {
+                    damaged.push(i);
+                    plaintext.push(Vec::new());
+                }
+            }
+        }
+
+        if !damaged.is_empty() {
+            if damaged.len() > parity.len() {
+                return Err(CryptError::CorruptedData(format!(
+                    "chunk group {group_index}: {} damaged chunks exceed \
+                     {} parity shards",
+                    damaged.len(),
+                    parity.len()
+                )));
+            }
+
+            let lens: Vec<usize> = ciphertext.iter().map(Vec::len).collect();
+            let mut shards: Vec<Option<Vec<u8>>> = ciphertext
+                .into_iter()
+                .enumerate()
+                .map(|(i, c)| (!damaged.contains(&i)).then_some(c))
+                .collect();
+            fec::reconstruct(
+                &mut shards,
+                &lens,
+                &parity,
+                layout.encrypted_chunk_len,
+            )?;
+            ciphertext = shards.into_iter().flatten().collect();
+
+            for &i in &damaged {
+                let index = first_chunk + group.first_chunk + i as u64;
+                plaintext[i] = decrypt_data_chunk(
+                    data_key,
+                    header,
+                    uuid,
+                    index,
+                    &ciphertext[i],
+                )
+                .map_err(|_| {
+                    CryptError::CorruptedData(format!(
+                        "chunk {index} could not be repaired"
+                    ))
+                })?;
+            }
+        }
+
+        on_group(PayloadGroup {
+            ciphertext,
+            plaintext,
+            parity,
+            repaired: damaged.len(),
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Write the header, the metadata and the encrypted chunks of
+/// `reader`, followed by parity and the signature trailer as
+/// configured in the header.
+fn write_payload<R: Read + ?Sized, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    sealed: &SealedHeader,
+    uuid: &str,
+    signer: Option<&SigningIdentity>,
+    progress: &ProgressTracker,
+) -> Result<()> {
+    let header = &sealed.header;
+    header.write_to(writer)?;
+    writer.write_all(&sealed.sealed_meta)?;
+
+    let mut buf = vec![0u8; header.chunk_size as usize];
+    let mut bytes_processed: u64 = 0;
+    let mut payload = PayloadWriter::new(
+        writer,
+        header,
+        &sealed.keys.data_key,
+        uuid,
+        signer.is_some(),
+    );
+
+    loop {
+        let n = read_full(reader, &mut buf)?;
+        if n == 0 {
+            break;
+        }
+        payload.write_chunk(&buf[..n])?;
+        bytes_processed += n as u64;
+        progress.update(bytes_processed, 0);
+    }
+
+    payload.finish(&sealed.sealed_meta, signer)?;
+    Ok(())
+}
+
+/// Decrypts a payload of unknown length fed to it piece by piece
+/// (see [`read_payload`]) and checks its signature at the end.
+///
+/// A group is only decoded once more than a full group plus the
+/// trailer is buffered; whatever is left at the end of the stream
+/// is the final group and the trailer.
+pub(crate) struct PayloadDecoder {
+    header: PublicHeader,
+    sealed_meta: Vec<u8>,
+    keys: kdf::DerivedKeys,
+    metadata: EncryptedMetadata,
+    full_group: PayloadLayout,
+    group_bytes: usize,
+    trailer_len: usize,
+    buf: Vec<u8>,
+    first_chunk: u64,
+    hasher: Option<ChunkHasher>,
+    /// Payload bytes not passed on yet, without the padding.
+    remaining: u64,
+    /// Plaintext bytes passed on so far.
+    written: u64,
+}
+
+impl PayloadDecoder {
+    pub(crate) fn new(
+        header: PublicHeader,
+        sealed_meta: Vec<u8>,
+        keys: kdf::DerivedKeys,
+        metadata: EncryptedMetadata,
+    ) -> Result<Self> {
+        let trailer_len =
+            header.signature.map_or(0, SignatureAlgorithm::signature_len);
+        let enc = header.chunk_size as usize + TAG_LEN;
+        let group_bytes = enc * header.fec.map_or(1, |p| p.group_len());
+        let full_group = PayloadLayout::from_payload_len(
+            group_bytes as u64,
+            header.chunk_size,
+            header.fec,
+        )?;
+        Ok(Self {
+            hasher: header.signature.map(|_| ChunkHasher::new()),
+            remaining: metadata.payload_len.unwrap_or(u64::MAX),
+            header,
+            sealed_meta,
+            keys,
+            metadata,
+            full_group,
+            group_bytes,
+            trailer_len,
+            buf: Vec::with_capacity(group_bytes + trailer_len + 1),
+            first_chunk: 0,
+            written: 0,
+        })
+    }
+
+    pub(crate) fn metadata(&self) -> &EncryptedMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn into_metadata(self) -> EncryptedMetadata {
+        self.metadata
+    }
+
+    /// Plaintext bytes written so far.
+    pub(crate) fn written(&self) -> u64 {
+        self.written
+    }
+
+    /// Feed the next bytes of the stream, writing the plaintext of
+    /// every group known not to be the last one to `out`.
+    pub(crate) fn push<W: Write + ?Sized>(
+        &mut self,
+        mut data: &[u8],
+        out: &mut W,
+    ) -> Result<()> {
+        let window = self.group_bytes + self.trailer_len;
+        while !data.is_empty() {
+            let take = (window + 1 - self.buf.len()).min(data.len());
+            self.buf.extend_from_slice(&data[..take]);
+            data = &data[take..];
+
+            if self.buf.len() > window {
+                let layout = self.full_group;
+                self.decode(self.group_bytes, &layout, out)?;
+                self.buf.drain(..self.group_bytes);
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode the final group at the end of the stream and check the
+    /// signature trailer.
+    pub(crate) fn finish<W: Write + ?Sized>(
+        &mut self,
+        out: &mut W,
+    ) -> Result<SignatureStatus> {
+        let payload_len =
+            self.buf.len().checked_sub(self.trailer_len).ok_or_else(|| {
+                CryptError::CorruptedData(
+                    "stream too short for its trailer".to_string(),
+                )
+            })?;
+        let tail = PayloadLayout::from_payload_len(
+            payload_len as u64,
+            self.header.chunk_size,
+            self.header.fec,
+        )?;
+        self.decode(payload_len, &tail, out)?;
+        let trailer = self.buf.split_off(payload_len);
+        self.buf.clear();
+        verify_signature(
+            &self.header,
+            &self.sealed_meta,
+            &self.metadata,
+            self.hasher.take(),
+            &trailer,
+        )
+    }
+
+    /// Decode the first `len` buffered bytes, laid out as `layout`.
+    fn decode<W: Write + ?Sized>(
+        &mut self,
+        len: usize,
+        layout: &PayloadLayout,
+        out: &mut W,
+    ) -> Result<()> {
+        let Self {
+            header,
+            keys,
+            metadata,
+            buf,
+            first_chunk,
+            hasher,
+            remaining,
+            written,
+            ..
+        } = self;
+        read_payload(
+            &mut &buf[..len],
+            header,
+            layout,
+            *first_chunk,
+            &keys.data_key,
+            &metadata.uuid,
+            |mut group| {
+                if let Some(hasher) = hasher.as_mut() {
+                    for chunk in &group.ciphertext {
+                        hasher.update(chunk);
+                    }
+                }
+                trim_padding(&mut group, remaining);
+                for chunk in &group.plaintext {
+                    out.write_all(chunk)?;
+                    *written += chunk.len() as u64;
+                }
+                Ok(())
+            },
+        )?;
+        *first_chunk += layout.chunk_count;
+        Ok(())
+    }
+}
+
+/// Encrypts plaintext chunks in order and writes them out, each
+/// parity group followed by its parity shards.
+///
+/// Owns its state, so it can be moved onto a blocking worker and
+/// back between chunks (see `AsyncEncryptWriter`).
+pub(crate) struct PayloadWriter<W: Write> {
+    writer: W,
+    header: PublicHeader,
+    data_key: Zeroizing<[u8; KEY_LEN]>,
+    uuid: String,
+    chunk_index: u64,
+    /// Encrypted chunks of the current parity group.
+    group: Vec<Vec<u8>>,
+    hasher: Option<ChunkHasher>,
+}
+
+impl<W: Write> PayloadWriter<W> {
+    pub(crate) fn new(
+        writer: W,
+        header: &PublicHeader,
+        data_key: &[u8; KEY_LEN],
+        uuid: &str,
+        signed: bool,
+    ) -> Self {
+        Self {
+            writer,
+            header: header.clone(),
+            data_key: Zeroizing::new(*data_key),
+            uuid: uuid.to_string(),
+            chunk_index: 0,
+            group: Vec::new(),
+            hasher: signed.then(ChunkHasher::new),
+        }
+    }
+
+    /// The underlying writer.
+    pub(crate) fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    fn shard_len(&self) -> usize {
+        self.header.chunk_size as usize + TAG_LEN
+    }
+
+    /// Encrypt and write the next chunk, at most `chunk_size` long.
+    pub(crate) fn write_chunk(&mut self, plaintext: &[u8]) -> Result<()> {
+        let encrypted = encrypt_data_chunk(
+            &self.data_key,
+            &self.header,
+            &self.uuid,
+            self.chunk_index,
+            plaintext,
+        )?;
+        if let Some(hasher) = self.hasher.as_mut() {
+            hasher.update(&encrypted);
+        }
+        match self.header.fec {
+            Some(fec) => {
+                self.group.push(encrypted);
+                if self.group.len() == fec.data_shards as usize {
+                    let shard_len = self.shard_len();
+                    write_fec_group(
+                        &mut self.writer,
+                        &mut self.group,
+                        fec,
+                        shard_len,
+                    )?;
+                }
+            }
+            None => self.writer.write_all(&encrypted)?,
+        }
+        self.chunk_index += 1;
+        Ok(())
+    }
+
+    /// Write the last, short parity group and the signature trailer
+    /// over header, metadata and chunks. Returns the chunk count.
+    pub(crate) fn finish(
+        &mut self,
+        sealed_meta: &[u8],
+        signer: Option<&SigningIdentity>,
+    ) -> Result<u64> {
+        if let Some(fec) = self.header.fec
+            && !self.group.is_empty()
+        {
+            let shard_len = self.shard_len();
+            write_fec_group(
+                &mut self.writer,
+                &mut self.group,
+                fec,
+                shard_len,
+            )?;
+        }
+
+        if let (Some(signer), Some(hasher)) = (signer, self.hasher.take()) {
+            let message = signature::signed_message(
+                &self.header.to_bytes(),
+                sealed_meta,
+                &hasher.finalize(),
+            );
+            self.writer.write_all(&signer.sign(&message))?;
+        }
+        Ok(self.chunk_index)
+    }
+}
+
+/// Encrypt a single data chunk with its index-bound nonce and AAD.
+fn encrypt_data_chunk(
+    data_key: &[u8; KEY_LEN],
+    header: &PublicHeader,
+    uuid: &str,
+    chunk_index: u64,
+    plaintext: &[u8],
+) -> Result<Vec<u8>> {
+    let cn = nonce::chunk_nonce(&header.data_base_nonce, chunk_index);
+    let chunk_aad =
+        cipher::build_chunk_aad(uuid, header.version, chunk_index);
+    header
+        .cipher
+        .aead()
+        .encrypt_chunk(data_key, &cn, plaintext, &chunk_aad)
+}
+
+/// Decrypt a single data chunk with its index-bound nonce and AAD.
+pub(crate) fn decrypt_data_chunk(
+    data_key: &[u8; KEY_LEN],
+    header: &PublicHeader,
+    uuid: &str,
+    chunk_index: u64,
+    ciphertext: &[u8],
+) -> Result<Vec<u8>> {
+    let cn = nonce::chunk_nonce(&header.data_base_nonce, chunk_index);
+    let chunk_aad =
+        cipher::build_chunk_aad(uuid, header.version, chunk_index);
+    header
+        .cipher
+        .aead()
+        .decrypt_chunk(data_key, &cn, ciphertext, &chunk_aad)
+}
+
+/// Write one group of encrypted chunks followed by its parity
+/// shards, draining `group`.
+fn write_fec_group<W: Write>(
+    writer: &mut W,
+    group: &mut Vec<Vec<u8>>,
+    fec: FecParams,
+    shard_len: usize,
+) -> Result<()> {
+    let parity = fec::encode_parity(
+        group,
+        fec.parity_shards as usize,
+        shard_len,
+    )?;
+    for chunk in group.drain(..).chain(parity) {
+        writer.write_all(&chunk)?;
+    }
+    Ok(())
+}
+
+/// In-memory output of [`FileCrypt::decrypt_to_memory`] that
+/// refuses to grow past a limit. The buffer is wiped if decryption
+/// fails half way.
+struct MemorySink {
+    buf: Zeroizing<Vec<u8>>,
+    limit: u64,
+}
+
+impl MemorySink {
+    fn new(expected: u64, limit: u64) -> Self {
+        Self {
+            buf: Zeroizing::new(Vec::with_capacity(expected as usize)),
+            limit,
+        }
+    }
+
+    /// Map a write error back to [`CryptError::TooLarge`] if the
+    /// sink refused it.
+    fn error(e: std::io::Error, limit: u64) -> CryptError {
+        if e.kind() == std::io::ErrorKind::FileTooLarge {
+            CryptError::TooLarge(limit)
+        } else {
+            CryptError::Io(e)
+        }
+    }
+}
+
+impl Write for MemorySink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if (self.buf.len() + buf.len()) as u64 > self.limit {
+            return Err(std::io::ErrorKind::FileTooLarge.into());
+        }
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Counts the bytes read from or written to the inner stream.
+struct Counting<T> {
+    inner: T,
+    count: u64,
+}
+
+impl<T> Counting<T> {
+    fn new(inner: T) -> Self {
+        Self { inner, count: 0 }
+    }
+}
+
+impl<T: Read> Read for Counting<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.count += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: Write> Write for Counting<T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.count += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Read as many bytes as possible to fill the buffer.
+///
+/// Unlike `read`, this loops until the buffer is full or EOF.
+fn read_full<R: Read + ?Sized>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> Result<usize> {
+    let mut total = 0;
+    while total < buf.len() {
+        match reader.read(&mut buf[total..])? {
+            0 => break,
+            n => total += n,
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
     use super::*;
     use std::{collections::HashMap, sync::atomic::{AtomicU32, Ordering}};
+    use crate::crypto::signature::SigningIdentity;
+    use crate::progress::ProgressEvent;
     use tempfile::TempDir;
 
-    fn fast_crypt() -> FileCrypt {
-        FileCrypt::new(
-            256,
-            kdf::Argon2Params {
-                t_cost: 1,
-                m_cost_kib: 64,
-                parallelism: 1,
+    fn fast_crypt() -> FileCrypt {
+        FileCrypt::new(
+            256,
+            kdf::Argon2Params {
+                t_cost: 1,
+                m_cost_kib: 64,
+                parallelism: 1,
+            },
+        )
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_file_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("hello.txt");
+        fs::write(&original, b"Hello, HOPLIXI encryption!")
+            .unwrap();
+
+        let crypt = fast_crypt();
+
+        let enc_result = crypt
+            .encrypt(EncryptOptions {
+                input_path: original.clone(),
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "test-password".to_string(),
+                progress: None,
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap();
+
+        assert!(enc_result.output_path.exists());
+
+        let out_dir = dir.path().join("decrypted");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        let dec_result = crypt
+            .decrypt(DecryptOptions {
+                input_path: enc_result.output_path,
+                output_dir: out_dir.clone(),
+                password: "test-password".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            })
+            .unwrap();
+
+        let decrypted =
+            fs::read_to_string(&dec_result.output_path)
+                .unwrap();
+        assert_eq!(decrypted, "Hello, HOPLIXI encryption!");
+        assert_eq!(
+            dec_result.metadata.original_filename,
+            "hello"
+        );
+        assert_eq!(
+            dec_result.metadata.original_extension,
+            "txt"
+        );
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_encrypt_decrypt_with_gzip() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("data.bin");
+        let data = vec![0xABu8; 10_000];
+        fs::write(&original, &data).unwrap();
+
+        let crypt = fast_crypt();
+
+        let enc_result = crypt
+            .encrypt(EncryptOptions {
+                input_path: original,
+                output_dir: dir.path().to_path_buf(),
+                uuid: Some("test-uuid".to_string()),
+                password: "gzip-pass".to_string(),
+                progress: None,
+                gzip_compressed: true,
+                output_extension: Some(
+                    ".encrypted".to_string(),
+                ),
+                temp_dir: None,
+                metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap();
+
+        assert!(enc_result.output_path.exists());
+        assert!(enc_result
+            .output_path
+            .to_str()
+            .unwrap()
+            .ends_with(".encrypted"));
+
+        let out_dir = dir.path().join("out");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        let dec_result = crypt
+            .decrypt(DecryptOptions {
+                input_path: enc_result.output_path,
+                output_dir: out_dir.clone(),
+                password: "gzip-pass".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            })
+            .unwrap();
+
+        let result = fs::read(&dec_result.output_path).unwrap();
+        assert_eq!(result, data);
+        assert!(dec_result.metadata.gzip_compressed);
+    }
+
+    #[test]
+    fn test_wrong_password_fails() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("secret.txt");
+        fs::write(&original, b"top secret").unwrap();
+
+        let crypt = fast_crypt();
+
+        let enc_result = crypt
+            .encrypt(EncryptOptions {
+                input_path: original,
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "correct-password".to_string(),
+                progress: None,
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap();
+
+        let out_dir = dir.path().join("out");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        let result = crypt
+            .decrypt(DecryptOptions {
+                input_path: enc_result.output_path,
+                output_dir: out_dir,
+                password: "wrong-password".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            });
+
+        assert!(result.is_err());
+
+        // Verify no temp files leaked in out_dir.
+        let leaked: Vec<_> = fs::read_dir(
+            dir.path().join("out"),
+        )
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .collect();
+        assert!(
+            leaked.is_empty(),
+            "temp files leaked on error: {leaked:?}"
+        );
+    }
+
+    #[test]
+    fn test_progress_callback() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("progress.txt");
+        fs::write(
+            &original,
+            b"progress test data that spans chunks",
+        )
+        .unwrap();
+
+        let crypt = fast_crypt();
+        let call_count =
+            std::sync::Arc::new(AtomicU32::new(0));
+        let cc = call_count.clone();
+
+        let progress = std::sync::Arc::new(
+            move |_event: ProgressEvent| {
+                cc.fetch_add(1, Ordering::SeqCst);
             },
+        );
+
+        let _ = crypt
+            .encrypt(EncryptOptions {
+                input_path: original,
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "progress-pw".to_string(),
+                progress: Some(progress),
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap();
+
+        // At least the encrypting + done events.
+        assert!(call_count.load(Ordering::SeqCst) >= 2);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_progress_spans_gzip_and_encrypt() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("staged.txt");
+        fs::write(&original, vec![b'p'; 4096]).unwrap();
+
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let progress = std::sync::Arc::new(move |event: ProgressEvent| {
+            sink.lock().unwrap().push(event);
+        });
+
+        fast_crypt()
+            .encrypt(EncryptOptions {
+                input_path: original,
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "staged-pw".to_string(),
+                progress: Some(progress),
+                gzip_compressed: true,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap();
+
+        let events = events.lock().unwrap();
+        assert!(events.iter().all(|e| e.stage_count == 2));
+        let gzip_done = events
+            .iter()
+            .filter(|e| e.stage == ProgressStage::CompressingGzip)
+            .any(|e| e.total_bytes == 4096 && e.bytes_processed == 4096);
+        assert!(gzip_done);
+        let overall: Vec<f64> =
+            events.iter().map(|e| e.overall_percentage).collect();
+        assert!(overall.windows(2).all(|w| w[0] <= w[1]));
+        let last = events.last().unwrap();
+        assert_eq!(last.stage, ProgressStage::Done);
+        assert_eq!(last.overall_percentage, 100.0);
+    }
+
+    #[cfg(feature = "sevenz")]
+    #[test]
+    fn test_encrypt_decrypt_directory() {
+        let dir = TempDir::new().unwrap();
+
+        // Create directory structure.
+        let src = dir.path().join("my_folder");
+        fs::create_dir_all(src.join("sub")).unwrap();
+        fs::write(src.join("a.txt"), b"file a content")
+            .unwrap();
+        fs::write(
+            src.join("sub/b.txt"),
+            b"file b content",
+        )
+        .unwrap();
+
+        let crypt = fast_crypt();
+
+        let enc_result = crypt
+            .encrypt(EncryptOptions {
+                input_path: src,
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "dir-pass".to_string(),
+                progress: None,
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap();
+
+        let out_dir = dir.path().join("out");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        let dec_result = crypt
+            .decrypt(DecryptOptions {
+                input_path: enc_result.output_path,
+                output_dir: out_dir.clone(),
+                password: "dir-pass".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            })
+            .unwrap();
+
+        // Should have extracted the directory.
+        assert!(dec_result.output_path.is_dir());
+
+        let fa = fs::read_to_string(
+            dec_result.output_path.join("a.txt"),
+        )
+        .unwrap();
+        assert_eq!(fa, "file a content");
+
+        let fb = fs::read_to_string(
+            dec_result.output_path.join("sub/b.txt"),
         )
+        .unwrap();
+        assert_eq!(fb, "file b content");
+    }
+
+    #[cfg(feature = "sevenz")]
+    #[test]
+    fn test_encrypt_directory_with_filter() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("project");
+        fs::create_dir_all(src.join(".git")).unwrap();
+        fs::create_dir_all(src.join("web/node_modules/pkg")).unwrap();
+        fs::write(src.join(".git/HEAD"), b"ref").unwrap();
+        fs::write(src.join(".gitignore"), b"*.log\n").unwrap();
+        fs::write(src.join("web/.gitignore"), b"node_modules/\n").unwrap();
+        fs::write(src.join("web/node_modules/pkg/i.js"), b"x").unwrap();
+        fs::write(src.join("web/app.js"), b"app").unwrap();
+        fs::write(src.join("debug.log"), b"log").unwrap();
+        fs::write(src.join("big.bin"), [0u8; 600]).unwrap();
+        fs::write(src.join("notes.md"), b"notes").unwrap();
+
+        let filter = DirectoryFilter {
+            include: Vec::new(),
+            exclude: vec![".git/".to_string()],
+            ignore_file: Some(".gitignore".to_string()),
+            max_file_size: Some(512),
+            links: LinkPolicy::Store,
+        };
+        let crypt = fast_crypt();
+        let opts = |filter: Option<DirectoryFilter>, shred_source| {
+            EncryptOptions {
+                input_path: src.clone(),
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "dir-pass".to_string(),
+                progress: None,
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source,
+                hide_lengths: false,
+                volume_size: None,
+                filter,
+            }
+        };
+
+        // The entries left out must not be shredded unencrypted.
+        let refused = crypt.encrypt(opts(Some(filter.clone()), true));
+        assert!(matches!(refused, Err(CryptError::Encryption(_))));
+        assert!(src.join("big.bin").exists());
+
+        let enc_result = crypt.encrypt(opts(Some(filter), false)).unwrap();
+        let skipped: Vec<_> = enc_result
+            .skipped
+            .iter()
+            .map(|e| (e.path.to_str().unwrap().replace('\\', "/"), e.reason))
+            .collect();
+        assert_eq!(
+            skipped,
+            [
+                (".git".to_string(), SkipReason::Excluded),
+                ("big.bin".to_string(), SkipReason::TooLarge { size: 600 }),
+                ("debug.log".to_string(), SkipReason::Ignored),
+                ("web/node_modules".to_string(), SkipReason::Ignored),
+            ]
+        );
+
+        let out_dir = dir.path().join("out");
+        fs::create_dir_all(&out_dir).unwrap();
+        let dec_result = crypt
+            .decrypt(DecryptOptions {
+                input_path: enc_result.output_path,
+                output_dir: out_dir,
+                password: "dir-pass".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            })
+            .unwrap();
+        let root = dec_result.output_path;
+        assert_eq!(fs::read(root.join("web/app.js")).unwrap(), b"app");
+        assert_eq!(fs::read(root.join("notes.md")).unwrap(), b"notes");
+        assert!(root.join(".gitignore").exists());
+        for gone in [".git", "big.bin", "debug.log", "web/node_modules"] {
+            assert!(!root.join(gone).exists(), "{gone}");
+        }
+    }
+
+    #[test]
+    fn test_multi_chunk_file() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("big.bin");
+
+        // File bigger than chunk size (256 bytes).
+        let data: Vec<u8> =
+            (0..1000).map(|i| (i % 256) as u8).collect();
+        fs::write(&original, &data).unwrap();
+
+        let crypt = fast_crypt();
+
+        let enc_result = crypt
+            .encrypt(EncryptOptions {
+                input_path: original,
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "multi-chunk".to_string(),
+                progress: None,
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap();
+
+        let out_dir = dir.path().join("out");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        let dec_result = crypt
+            .decrypt(DecryptOptions {
+                input_path: enc_result.output_path,
+                output_dir: out_dir,
+                password: "multi-chunk".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            })
+            .unwrap();
+
+        let result =
+            fs::read(&dec_result.output_path).unwrap();
+        assert_eq!(result, data);
+    }
+
+    #[test]
+    fn test_metadata_preserved() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("meta.txt");
+        fs::write(&original, b"metadata test").unwrap();
+
+        let crypt = fast_crypt();
+        let mut custom_meta = HashMap::new();
+        custom_meta.insert(
+            "author".to_string(),
+            "test".to_string(),
+        );
+        custom_meta.insert(
+            "version".to_string(),
+            "1.0".to_string(),
+        );
+
+        let enc_result = crypt
+            .encrypt(EncryptOptions {
+                input_path: original,
+                output_dir: dir.path().to_path_buf(),
+                uuid: Some("custom-uuid-123".to_string()),
+                password: "meta-pw".to_string(),
+                progress: None,
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: Some(custom_meta.clone()),
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap();
+
+        assert_eq!(enc_result.uuid, "custom-uuid-123");
+
+        let out_dir = dir.path().join("out");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        let dec_result = crypt
+            .decrypt(DecryptOptions {
+                input_path: enc_result.output_path,
+                output_dir: out_dir,
+                password: "meta-pw".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            dec_result.metadata.uuid,
+            "custom-uuid-123"
+        );
+        assert_eq!(
+            dec_result.metadata.metadata,
+            custom_meta
+        );
+    }
+
+    /// Offset of the payload (first data chunk) in an encrypted file.
+    fn payload_offset(path: &Path) -> u64 {
+        let mut reader = BufReader::new(File::open(path).unwrap());
+        let header = PublicHeader::read_from(&mut reader).unwrap();
+        (header.encoded_len() + header.encrypted_meta_len as usize) as u64
+    }
+
+    /// Flip a byte inside each listed shard (data or parity).
+    fn damage_shards(path: &Path, shards: &[u64]) {
+        let shard_len = 256 + TAG_LEN as u64;
+        let offset = payload_offset(path);
+        let mut bytes = fs::read(path).unwrap();
+        for shard in shards {
+            bytes[(offset + shard * shard_len + 7) as usize] ^= 0xFF;
+        }
+        fs::write(path, bytes).unwrap();
+    }
+
+    fn encrypt_with_fec(
+        dir: &TempDir,
+        data: &[u8],
+    ) -> EncryptResult {
+        let original = dir.path().join("archive.bin");
+        fs::write(&original, data).unwrap();
+
+        fast_crypt()
+            .encrypt(EncryptOptions {
+                input_path: original,
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "fec-pass".to_string(),
+                progress: None,
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec: Some(FecParams::new(4, 2).unwrap()),
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap()
     }
 
-    #[tokio::test]
-    async fn test_encrypt_decrypt_file_round_trip() {
+    #[test]
+    fn test_fec_decrypt_repairs_damaged_chunks() {
         let dir = TempDir::new().unwrap();
-        let original = dir.path().join("hello.txt");
-        fs::write(&original, b"Hello, HOPLIXI encryption!")
+        // 12 chunks of 256 bytes -> 3 groups of 4 data + 2 parity.
+        let data: Vec<u8> =
+            (0..3000).map(|i| (i * 7 % 251) as u8).collect();
+        let enc_result = encrypt_with_fec(&dir, &data);
+
+        // Two chunks in the first group, one in the last.
+        damage_shards(&enc_result.output_path, &[0, 3, 12]);
+
+        let out_dir = dir.path().join("out");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        let dec_result = fast_crypt()
+            .decrypt(DecryptOptions {
+                input_path: enc_result.output_path,
+                output_dir: out_dir,
+                password: "fec-pass".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            })
             .unwrap();
 
+        assert_eq!(fs::read(&dec_result.output_path).unwrap(), data);
+    }
+
+    #[test]
+    fn test_fec_too_many_damaged_chunks_fails() {
+        let dir = TempDir::new().unwrap();
+        let data = vec![0x5Au8; 3000];
+        let enc_result = encrypt_with_fec(&dir, &data);
+
+        damage_shards(&enc_result.output_path, &[0, 1, 2]);
+
+        let result = fast_crypt()
+            .verify(enc_result.output_path, "fec-pass".to_string());
+        assert!(matches!(result, Err(CryptError::CorruptedData(_))));
+    }
+
+    #[test]
+    fn test_repair_restores_original_file() {
+        let dir = TempDir::new().unwrap();
+        let data: Vec<u8> = (0..3000).map(|i| (i % 256) as u8).collect();
+        let enc_result = encrypt_with_fec(&dir, &data);
+        let pristine = fs::read(&enc_result.output_path).unwrap();
+
+        // Data chunk 1, parity shard 5 (group 0) and data chunk 7.
+        damage_shards(&enc_result.output_path, &[1, 5, 7]);
+
         let crypt = fast_crypt();
+        let verify = crypt
+            .verify(
+                enc_result.output_path.clone(),
+                "fec-pass".to_string(),
+            )
+            .unwrap();
+        assert_eq!(verify.chunk_count, 12);
+        assert_eq!(verify.repaired_chunks, 2);
+
+        let repair = crypt
+            .repair(
+                enc_result.output_path.clone(),
+                "fec-pass".to_string(),
+            )
+            .unwrap();
+        assert_eq!(repair.repaired_chunks, 2);
+        assert_eq!(repair.repaired_parity, 1);
+        assert!(repair.rewritten());
+
+        assert_eq!(fs::read(&enc_result.output_path).unwrap(), pristine);
+
+        let again = crypt
+            .repair(enc_result.output_path, "fec-pass".to_string())
+            .unwrap();
+        assert!(!again.rewritten());
+    }
+
+    #[test]
+    fn test_damaged_chunk_without_fec_fails() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("plain.bin");
+        fs::write(&original, vec![1u8; 1000]).unwrap();
 
+        let crypt = fast_crypt();
         let enc_result = crypt
             .encrypt(EncryptOptions {
-                input_path: original.clone(),
+                input_path: original,
                 output_dir: dir.path().to_path_buf(),
                 uuid: None,
-                password: "test-password".to_string(),
+                password: "plain".to_string(),
                 progress: None,
                 gzip_compressed: false,
                 output_extension: None,
                 temp_dir: None,
                 metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
             })
-            .await
             .unwrap();
 
-        assert!(enc_result.output_path.exists());
+        damage_shards(&enc_result.output_path, &[2]);
 
-        let out_dir = dir.path().join("decrypted");
+        let result = crypt
+            .verify(enc_result.output_path, "plain".to_string());
+        assert!(result.is_err());
+    }
+
+    fn encrypt_signed(
+        dir: &TempDir,
+        signer: &SigningIdentity,
+        fec: Option<FecParams>,
+    ) -> EncryptResult {
+        let original = dir.path().join("signed.bin");
+        let data: Vec<u8> = (0..2000).map(|i| (i % 199) as u8).collect();
+        fs::write(&original, data).unwrap();
+
+        fast_crypt()
+            .encrypt(EncryptOptions {
+                input_path: original,
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "sign-pass".to_string(),
+                progress: None,
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec,
+                signer: Some(signer.clone()),
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_signed_file_verifies() {
+        let dir = TempDir::new().unwrap();
+        let signer = SigningIdentity::generate();
+        let enc_result = encrypt_signed(&dir, &signer, None);
+
+        let out_dir = dir.path().join("out");
         fs::create_dir_all(&out_dir).unwrap();
 
-        let dec_result = crypt
+        let dec_result = fast_crypt()
             .decrypt(DecryptOptions {
                 input_path: enc_result.output_path,
-                output_dir: out_dir.clone(),
-                password: "test-password".to_string(),
+                output_dir: out_dir,
+                password: "sign-pass".to_string(),
                 progress: None,
                 temp_dir: None,
+                identity: None,
             })
-            .await
             .unwrap();
 
-        let decrypted =
-            fs::read_to_string(&dec_result.output_path)
-                .unwrap();
-        assert_eq!(decrypted, "Hello, HOPLIXI encryption!");
         assert_eq!(
-            dec_result.metadata.original_filename,
-            "hello"
+            dec_result.signature,
+            SignatureStatus::Valid {
+                public_key: signer.public_key()
+            }
         );
         assert_eq!(
-            dec_result.metadata.original_extension,
-            "txt"
+            dec_result.metadata.signer_public_key,
+            Some(signer.public_key())
         );
     }
 
-    #[tokio::test]
-    async fn test_encrypt_decrypt_with_gzip() {
+    #[test]
+    fn test_forged_signature_rejected() {
         let dir = TempDir::new().unwrap();
-        let original = dir.path().join("data.bin");
-        let data = vec![0xABu8; 10_000];
-        fs::write(&original, &data).unwrap();
+        let signer = SigningIdentity::generate();
+        let enc_result = encrypt_signed(&dir, &signer, None);
+
+        // Overwrite the trailer with a signature from another key.
+        let mut bytes = fs::read(&enc_result.output_path).unwrap();
+        let len = bytes.len();
+        let forged = SigningIdentity::generate().sign(b"anything");
+        bytes[len - forged.len()..].copy_from_slice(&forged);
+        fs::write(&enc_result.output_path, bytes).unwrap();
+
+        let result = fast_crypt()
+            .verify(enc_result.output_path, "sign-pass".to_string());
+        assert!(matches!(result, Err(CryptError::InvalidSignature(_))));
+    }
+
+    #[test]
+    fn test_signature_survives_fec_repair() {
+        let dir = TempDir::new().unwrap();
+        let signer = SigningIdentity::generate();
+        let enc_result = encrypt_signed(
+            &dir,
+            &signer,
+            Some(FecParams::new(4, 2).unwrap()),
+        );
+
+        damage_shards(&enc_result.output_path, &[2]);
+
+        let crypt = fast_crypt();
+        let verify = crypt
+            .verify(
+                enc_result.output_path.clone(),
+                "sign-pass".to_string(),
+            )
+            .unwrap();
+        assert_eq!(verify.repaired_chunks, 1);
+        assert!(matches!(verify.signature, SignatureStatus::Valid { .. }));
+
+        let repair = crypt
+            .repair(
+                enc_result.output_path.clone(),
+                "sign-pass".to_string(),
+            )
+            .unwrap();
+        assert!(repair.rewritten());
+
+        let verify = crypt
+            .verify(enc_result.output_path, "sign-pass".to_string())
+            .unwrap();
+        assert_eq!(verify.repaired_chunks, 0);
+        assert!(matches!(verify.signature, SignatureStatus::Valid { .. }));
+    }
+
+    #[test]
+    fn test_inspect_reports_public_header() {
+        let dir = TempDir::new().unwrap();
+        // 12 chunks of 256 bytes -> 3 groups of 4 data + 2 parity.
+        let data = vec![0x5Au8; 3000];
+        let enc_result = encrypt_with_fec(&dir, &data);
+
+        let info = fast_crypt()
+            .inspect(enc_result.output_path.clone())
+            .unwrap();
+
+        assert_eq!(info.version, VERSION);
+        assert_eq!(info.chunk_size, 256);
+        assert_eq!(info.argon2_params.m_cost_kib, 64);
+        assert_eq!(info.fec, Some(FecParams::new(4, 2).unwrap()));
+        assert_eq!(info.signature, None);
+        assert!(info.whole_chunks);
+        assert_eq!(info.chunk_count, 12);
+        assert_eq!(info.estimated_plaintext_size, 3000);
+        assert_eq!(
+            info.file_len,
+            fs::metadata(&enc_result.output_path).unwrap().len()
+        );
+        assert_eq!(
+            info.ciphertext_len,
+            info.file_len - payload_offset(&enc_result.output_path)
+        );
+    }
+
+    #[test]
+    fn test_inspect_flags_truncated_payload() {
+        let dir = TempDir::new().unwrap();
+        let signer = SigningIdentity::generate();
+        let enc_result = encrypt_signed(&dir, &signer, None);
+        let path = enc_result.output_path;
+
+        let info = fast_crypt().inspect(path.clone()).unwrap();
+        assert_eq!(info.signature, Some(SignatureAlgorithm::Ed25519));
+        assert!(info.whole_chunks);
+        assert_eq!(info.estimated_plaintext_size, 2000);
+
+        // 2000 bytes = 7 full chunks + a 224-byte encrypted tail,
+        // then the 64-byte trailer. After cutting 214 bytes the
+        // layout sees a 10-byte tail chunk, shorter than a tag.
+        let bytes = fs::read(&path).unwrap();
+        fs::write(&path, &bytes[..bytes.len() - 214]).unwrap();
+
+        let info = fast_crypt().inspect(path).unwrap();
+        assert!(!info.whole_chunks);
+        assert_eq!(info.chunk_count, 0);
+    }
+
+    #[test]
+    fn test_inspect_rejects_non_encrypted_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("plain.txt");
+        fs::write(&path, vec![b'x'; 500]).unwrap();
+
+        let result = fast_crypt().inspect(path);
+        assert!(matches!(result, Err(CryptError::InvalidMagic)));
+    }
+
+    #[test]
+    fn test_update_metadata() {
+        let dir = TempDir::new().unwrap();
+        let data: Vec<u8> = (0..3000).map(|i| (i * 5 % 256) as u8).collect();
+        let path = encrypt_with_fec(&dir, &data).output_path;
+        let crypt = fast_crypt();
+        let len = fs::metadata(&path).unwrap().len();
+        let before = fs::read(&path).unwrap();
+
+        // A small edit fits the reserve: same length, same payload.
+        let small = crypt
+            .update_metadata(
+                path.clone(),
+                "fec-pass".to_string(),
+                MetadataPatch {
+                    remove: Vec::new(),
+                    set: HashMap::from([(
+                        "tags".to_string(),
+                        "invoice,2026".to_string(),
+                    )]),
+                },
+            )
+            .unwrap();
+        assert!(small.in_place);
+        assert_eq!(fs::metadata(&path).unwrap().len(), len);
+
+        // A crash before the header is overwritten: the journal holds
+        // both prefixes, reads are refused and the next write path
+        // completes the edit.
+        let after = fs::read(&path).unwrap();
+        let end = before
+            .iter()
+            .zip(&after)
+            .rposition(|(old, new)| old != new)
+            .unwrap()
+            + 1;
+        let mut journal = before[..end].to_vec();
+        journal.extend_from_slice(&after[..end]);
+        let journal_path = dir.path().join(format!(
+            "{}.journal",
+            path.file_name().unwrap().to_string_lossy()
+        ));
+        fs::write(&journal_path, journal).unwrap();
+        fs::write(&path, &before).unwrap();
+        let err = crypt
+            .decrypt_header(path.clone(), "fec-pass".to_string())
+            .unwrap_err();
+        assert!(matches!(err, CryptError::InvalidHeader(_)));
+        assert!(crypt.verify(path.clone(), "fec-pass".to_string()).is_err());
+        assert_eq!(fs::read(&path).unwrap(), before);
+        assert!(journal_path.exists());
+        crypt
+            .update_metadata(
+                path.clone(),
+                "fec-pass".to_string(),
+                MetadataPatch::default(),
+            )
+            .unwrap();
+        let metadata = crypt
+            .decrypt_header(path.clone(), "fec-pass".to_string())
+            .unwrap();
+        assert_eq!(metadata.metadata["tags"], "invoice,2026");
+        assert!(!journal_path.exists());
+
+        // A large one outgrows it and the file is copied.
+        let large = crypt
+            .update_metadata(
+                path.clone(),
+                "fec-pass".to_string(),
+                MetadataPatch {
+                    remove: vec!["tags".to_string()],
+                    set: HashMap::from([(
+                        "note".to_string(),
+                        "x".repeat(1000),
+                    )]),
+                },
+            )
+            .unwrap();
+        assert!(!large.in_place);
+        assert!(fs::metadata(&path).unwrap().len() > len + 1000);
+
+        let metadata = crypt
+            .decrypt_header(path.clone(), "fec-pass".to_string())
+            .unwrap();
+        assert!(!metadata.metadata.contains_key("tags"));
+        assert_eq!(metadata.metadata["note"].len(), 1000);
+        let decrypted = crypt
+            .decrypt_to_memory(path, "fec-pass".to_string(), 1 << 20)
+            .unwrap();
+        assert_eq!(decrypted.data, data);
+
+        let signed_dir = TempDir::new().unwrap();
+        let signer = SigningIdentity::generate();
+        let signed = encrypt_signed(&signed_dir, &signer, None);
+        let result = crypt
+            .update_metadata(
+                signed.output_path,
+                "sign-pass".to_string(),
+                MetadataPatch::default(),
+            );
+        assert!(matches!(result, Err(CryptError::Encryption(_))));
+    }
+
+    #[test]
+    fn test_rekey_refuses_chunks_too_large_for_parity() {
+        let dir = TempDir::new().unwrap();
+        let data: Vec<u8> = (0..3000).map(|i| (i * 3 % 256) as u8).collect();
+        let path = encrypt_with_fec(&dir, &data).output_path;
+        let before = fs::read(&path).unwrap();
+
+        let crypt = fast_crypt();
+        let err = crypt
+            .rekey(RekeyOptions {
+                input_path: path.clone(),
+                output_path: None,
+                old_password: "fec-pass".to_string(),
+                new_password: "new-pass".to_string(),
+                params: ReencryptParams {
+                    argon2_params: None,
+                    chunk_size: Some(MAX_CHUNK_SIZE),
+                },
+                signer: None,
+                progress: None,
+            })
+            .unwrap_err();
+        assert!(matches!(err, CryptError::InvalidHeader(_)), "{err}");
+        assert_eq!(fs::read(&path).unwrap(), before);
+        assert!(crypt.verify(path, "fec-pass".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_rekey_in_place_changes_password() {
+        let dir = TempDir::new().unwrap();
+        let data: Vec<u8> = (0..3000).map(|i| (i * 3 % 256) as u8).collect();
+        let enc_result = encrypt_with_fec(&dir, &data);
+        let path = enc_result.output_path;
+
+        let crypt = fast_crypt();
+        let rekey = crypt
+            .rekey(RekeyOptions {
+                input_path: path.clone(),
+                output_path: None,
+                old_password: "fec-pass".to_string(),
+                new_password: "new-pass".to_string(),
+                params: ReencryptParams::default(),
+                signer: None,
+                progress: None,
+            })
+            .unwrap();
+        assert_eq!(rekey.output_path, path);
+        assert_eq!(rekey.chunk_count, 12);
+        assert_eq!(rekey.source_signature, SignatureStatus::Unsigned);
 
+        let old = crypt.verify(path.clone(), "fec-pass".to_string());
+        assert!(old.is_err());
+
+        // The parity layer is rebuilt for the new ciphertext.
+        damage_shards(&path, &[1]);
+
+        let out_dir = dir.path().join("out");
+        fs::create_dir_all(&out_dir).unwrap();
+        let dec_result = crypt
+            .decrypt(DecryptOptions {
+                input_path: path,
+                output_dir: out_dir,
+                password: "new-pass".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            })
+            .unwrap();
+        assert_eq!(fs::read(&dec_result.output_path).unwrap(), data);
+        assert_eq!(dec_result.metadata.uuid, enc_result.uuid);
+    }
+
+    #[test]
+    fn test_reencrypt_changes_chunk_size_and_kdf() {
+        let dir = TempDir::new().unwrap();
+        let data: Vec<u8> = (0..3000).map(|i| (i * 7 % 256) as u8).collect();
+        let enc_result = encrypt_with_fec(&dir, &data);
+        let path = enc_result.output_path;
+
+        let argon2_params = kdf::Argon2Params {
+            t_cost: 2,
+            m_cost_kib: 128,
+            parallelism: 1,
+        };
         let crypt = fast_crypt();
+        let result = crypt
+            .reencrypt(
+                path.clone(),
+                "fec-pass".to_string(),
+                "new-pass".to_string(),
+                ReencryptParams {
+                    argon2_params: Some(argon2_params),
+                    chunk_size: Some(1000),
+                },
+            )
+            .unwrap();
+        assert_eq!(result.chunk_count, 3);
+
+        let info = crypt.inspect(path.clone()).unwrap();
+        assert_eq!(info.chunk_size, 1000);
+        assert_eq!(info.argon2_params.t_cost, 2);
+        assert_eq!(info.argon2_params.m_cost_kib, 128);
+        assert!(info.fec.is_some());
+
+        let decrypted = crypt
+            .decrypt_to_memory(path.clone(), "new-pass".to_string(), 1 << 20)
+            .unwrap();
+        assert_eq!(decrypted.data, data);
+        assert_eq!(decrypted.metadata.uuid, enc_result.uuid);
+
+        let too_small = crypt
+            .reencrypt(
+                path,
+                "new-pass".to_string(),
+                "other".to_string(),
+                ReencryptParams {
+                    argon2_params: None,
+                    chunk_size: Some(8),
+                },
+            );
+        assert!(matches!(too_small, Err(CryptError::Encryption(_))));
+    }
+
+    #[test]
+    fn test_hide_lengths_pads_and_trims() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("short.bin");
+        let data: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+        fs::write(&original, &data).unwrap();
 
+        let crypt = fast_crypt();
         let enc_result = crypt
             .encrypt(EncryptOptions {
                 input_path: original,
                 output_dir: dir.path().to_path_buf(),
-                uuid: Some("test-uuid".to_string()),
-                password: "gzip-pass".to_string(),
+                uuid: None,
+                password: "hide-pass".to_string(),
                 progress: None,
-                gzip_compressed: true,
-                output_extension: Some(
-                    ".encrypted".to_string(),
-                ),
+                gzip_compressed: false,
+                output_extension: None,
                 temp_dir: None,
                 metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: true,
+                volume_size: None,
+                filter: None,
             })
-            .await
             .unwrap();
+        let path = enc_result.output_path;
 
-        assert!(enc_result.output_path.exists());
-        assert!(enc_result
-            .output_path
-            .to_str()
-            .unwrap()
-            .ends_with(".encrypted"));
+        // 1000 bytes are padded to 1024, four whole chunks, and the
+        // metadata to at least one bucket.
+        let info = crypt.inspect(path.clone()).unwrap();
+        assert_eq!(info.estimated_plaintext_size, 1024);
+        assert_eq!(info.chunk_count, 4);
+        assert!(info.file_len - info.ciphertext_len > 1024);
+
+        let decrypted = crypt
+            .decrypt_to_memory(path.clone(), "hide-pass".to_string(), 1000)
+            .unwrap();
+        assert_eq!(decrypted.data, data);
+        assert_eq!(decrypted.metadata.payload_len, Some(1000));
+
+        let (plain, _) =
+            decrypt_bytes(&fs::read(&path).unwrap(), "hide-pass")
+                .unwrap();
+        assert_eq!(plain, data);
+
+        let mut reader = crypt
+            .open_random_access(path.clone(), "hide-pass".to_string())
+            .unwrap();
+        assert_eq!(reader.len(), 1000);
+        reader.seek(SeekFrom::Start(990)).unwrap();
+        let mut tail = Vec::new();
+        reader.read_to_end(&mut tail).unwrap();
+        assert_eq!(tail, &data[990..]);
+
+        // Re-chunking keeps the padding.
+        crypt
+            .reencrypt(
+                path.clone(),
+                "hide-pass".to_string(),
+                "new-pass".to_string(),
+                ReencryptParams {
+                    argon2_params: None,
+                    chunk_size: Some(300),
+                },
+            )
+            .unwrap();
+        let info = crypt.inspect(path.clone()).unwrap();
+        assert_eq!(info.estimated_plaintext_size, 1024);
+        assert_eq!(info.chunk_count, 4);
 
         let out_dir = dir.path().join("out");
         fs::create_dir_all(&out_dir).unwrap();
+        let dec_result = crypt
+            .decrypt(DecryptOptions {
+                input_path: path,
+                output_dir: out_dir,
+                password: "new-pass".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            })
+            .unwrap();
+        assert_eq!(fs::read(&dec_result.output_path).unwrap(), data);
+    }
+
+    #[test]
+    fn test_split_into_volumes() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("split.bin");
+        let data: Vec<u8> = (0..3000).map(|i| (i * 13 % 251) as u8).collect();
+        fs::write(&original, &data).unwrap();
+
+        // Prefix plus four 272-byte chunks or parity shards.
+        let volume_size = (crate::config::VOLUME_PREFIX_LEN + 4 * 272) as u64;
+        let signer = SigningIdentity::generate();
+        let crypt = fast_crypt();
+        let enc_result = crypt
+            .encrypt(EncryptOptions {
+                input_path: original,
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "split-pass".to_string(),
+                progress: None,
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec: Some(FecParams::new(4, 2).unwrap()),
+                signer: Some(signer.clone()),
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: Some(volume_size),
+                filter: None,
+            })
+            .unwrap();
+        let volumes = enc_result.volumes;
+        assert!(volumes.len() > 4);
+        assert_eq!(enc_result.output_path, volumes[0]);
+        assert!(volumes[0].to_string_lossy().ends_with(".enc.001"));
+        for volume in &volumes {
+            assert!(fs::metadata(volume).unwrap().len() <= volume_size);
+        }
+
+        let info = crypt.inspect(volumes[0].clone()).unwrap();
+        assert_eq!(info.chunk_count, 12);
+        assert!(info.whole_chunks);
+
+        let verified = crypt
+            .verify(volumes[0].clone(), "split-pass".to_string())
+            .unwrap();
+        assert_eq!(
+            verified.signature,
+            SignatureStatus::Valid {
+                public_key: signer.public_key()
+            }
+        );
 
+        let mut reader = crypt
+            .open_random_access(volumes[0].clone(), "split-pass".to_string())
+            .unwrap();
+        reader.seek(SeekFrom::Start(1500)).unwrap();
+        let mut middle = vec![0u8; 600];
+        reader.read_exact(&mut middle).unwrap();
+        assert_eq!(middle, &data[1500..2100]);
+
+        let out_dir = dir.path().join("out");
+        fs::create_dir_all(&out_dir).unwrap();
         let dec_result = crypt
             .decrypt(DecryptOptions {
-                input_path: enc_result.output_path,
-                output_dir: out_dir.clone(),
-                password: "gzip-pass".to_string(),
+                input_path: volumes[0].clone(),
+                output_dir: out_dir,
+                password: "split-pass".to_string(),
                 progress: None,
                 temp_dir: None,
+                identity: None,
             })
-            .await
             .unwrap();
+        assert_eq!(fs::read(&dec_result.output_path).unwrap(), data);
+
+        let open = |path: &Path| {
+            crypt.decrypt_to_memory(
+                path.to_path_buf(),
+                "split-pass".to_string(),
+                1 << 20,
+            )
+        };
+        assert!(matches!(
+            crypt
+                .update_metadata(
+                    volumes[0].clone(),
+                    "split-pass".to_string(),
+                    MetadataPatch::default(),
+                )
+                ,
+            Err(CryptError::Volume(_))
+        ));
+        assert!(matches!(
+            open(&volumes[1]),
+            Err(CryptError::Volume(_))
+        ));
+
+        // Swapped volumes are caught by their prefixes.
+        let spare = dir.path().join("spare");
+        fs::rename(&volumes[1], &spare).unwrap();
+        fs::rename(&volumes[2], &volumes[1]).unwrap();
+        fs::rename(&spare, &volumes[2]).unwrap();
+        assert!(matches!(
+            open(&volumes[0]),
+            Err(CryptError::Volume(_))
+        ));
+
+        fs::remove_file(volumes.last().unwrap()).unwrap();
+        assert!(matches!(
+            open(&volumes[0]),
+            Err(CryptError::Volume(_))
+        ));
+    }
 
-        let result = fs::read(&dec_result.output_path).unwrap();
-        assert_eq!(result, data);
-        assert!(dec_result.metadata.gzip_compressed);
+    #[test]
+    fn test_rekey_re_signs_to_new_output() {
+        let dir = TempDir::new().unwrap();
+        let signer = SigningIdentity::generate();
+        let enc_result = encrypt_signed(&dir, &signer, None);
+        let pristine = fs::read(&enc_result.output_path).unwrap();
+
+        let crypt = fast_crypt();
+        let new_signer = SigningIdentity::generate();
+        let output = dir.path().join("rekeyed.enc");
+        let rekey = crypt
+            .rekey(RekeyOptions {
+                input_path: enc_result.output_path.clone(),
+                output_path: Some(output.clone()),
+                old_password: "sign-pass".to_string(),
+                new_password: "other-pass".to_string(),
+                params: ReencryptParams::default(),
+                signer: Some(new_signer.clone()),
+                progress: None,
+            })
+            .unwrap();
+        assert_eq!(
+            rekey.source_signature,
+            SignatureStatus::Valid {
+                public_key: signer.public_key()
+            }
+        );
+        assert_eq!(fs::read(&enc_result.output_path).unwrap(), pristine);
+
+        let verify = crypt
+            .verify(output.clone(), "other-pass".to_string())
+            .unwrap();
+        assert_eq!(
+            verify.signature,
+            SignatureStatus::Valid {
+                public_key: new_signer.public_key()
+            }
+        );
+
+        // Without a signer the copy comes out unsigned.
+        crypt
+            .rekey(RekeyOptions {
+                input_path: output.clone(),
+                output_path: None,
+                old_password: "other-pass".to_string(),
+                new_password: "third-pass".to_string(),
+                params: ReencryptParams::default(),
+                signer: None,
+                progress: None,
+            })
+            .unwrap();
+        let verify = crypt
+            .verify(output, "third-pass".to_string())
+            .unwrap();
+        assert_eq!(verify.signature, SignatureStatus::Unsigned);
+        assert_eq!(verify.metadata.signer_public_key, None);
     }
 
-    #[tokio::test]
-    async fn test_wrong_password_fails() {
+    #[test]
+    fn test_aes_gcm_siv_suite_round_trip() {
         let dir = TempDir::new().unwrap();
-        let original = dir.path().join("secret.txt");
-        fs::write(&original, b"top secret").unwrap();
-
-        let crypt = fast_crypt();
+        let data: Vec<u8> = (0..3000).map(|i| (i * 7 % 256) as u8).collect();
+        let original = dir.path().join("aes.bin");
+        fs::write(&original, &data).unwrap();
 
+        let crypt =
+            fast_crypt().with_cipher_suite(CipherSuite::Aes256GcmSiv);
         let enc_result = crypt
             .encrypt(EncryptOptions {
                 input_path: original,
                 output_dir: dir.path().to_path_buf(),
                 uuid: None,
-                password: "correct-password".to_string(),
+                password: "aes-pass".to_string(),
                 progress: None,
                 gzip_compressed: false,
                 output_extension: None,
                 temp_dir: None,
                 metadata: None,
+                fec: Some(FecParams::new(4, 2).unwrap()),
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap();
+        let path = enc_result.output_path;
+
+        let info = crypt.inspect(path.clone()).unwrap();
+        assert_eq!(info.cipher, CipherSuite::Aes256GcmSiv);
+
+        // Rekeying keeps the suite; decryption follows the header,
+        // whatever the engine would encrypt with.
+        fast_crypt()
+            .rekey(RekeyOptions {
+                input_path: path.clone(),
+                output_path: None,
+                old_password: "aes-pass".to_string(),
+                new_password: "aes-pass-2".to_string(),
+                params: ReencryptParams::default(),
+                signer: None,
+                progress: None,
             })
-            .await
             .unwrap();
+        let info = fast_crypt().inspect(path.clone()).unwrap();
+        assert_eq!(info.cipher, CipherSuite::Aes256GcmSiv);
 
+        damage_shards(&path, &[2]);
         let out_dir = dir.path().join("out");
         fs::create_dir_all(&out_dir).unwrap();
-
-        let result = crypt
+        let dec_result = fast_crypt()
             .decrypt(DecryptOptions {
-                input_path: enc_result.output_path,
+                input_path: path,
                 output_dir: out_dir,
-                password: "wrong-password".to_string(),
+                password: "aes-pass-2".to_string(),
                 progress: None,
                 temp_dir: None,
+                identity: None,
             })
-            .await;
-
-        assert!(result.is_err());
-
-        // Verify no temp files leaked in out_dir.
-        let leaked: Vec<_> = fs::read_dir(
-            dir.path().join("out"),
-        )
-        .unwrap()
-        .filter_map(|e| e.ok())
-        .collect();
-        assert!(
-            leaked.is_empty(),
-            "temp files leaked on error: {leaked:?}"
-        );
+            .unwrap();
+        assert_eq!(fs::read(&dec_result.output_path).unwrap(), data);
     }
 
-    #[tokio::test]
-    async fn test_progress_callback() {
-        let dir = TempDir::new().unwrap();
-        let original = dir.path().join("progress.txt");
-        fs::write(
-            &original,
-            b"progress test data that spans chunks",
+    /// Rewrite the public header of `path` through `edit` and reseal
+    /// the metadata against it, leaving the payload untouched.
+    fn reseal_header(
+        path: &Path,
+        password: &str,
+        edit: impl FnOnce(&mut PublicHeader),
+    ) {
+        let bytes = fs::read(path).unwrap();
+        let mut cursor = std::io::Cursor::new(&bytes);
+        let (mut header, sealed_meta) = read_prefix(&mut cursor).unwrap();
+        let rest = &bytes[cursor.position() as usize..];
+
+        let keys = kdf::derive_keys(
+            password,
+            &header.salt,
+            &header.argon2_params,
+        )
+        .unwrap();
+        let mut aad_header = header.clone();
+        aad_header.encrypted_meta_len = 0;
+        let metadata = EncryptedMetadata::unseal(
+            header.cipher,
+            &sealed_meta,
+            &keys.header_key,
+            &header.header_nonce,
+            &aad_header.to_bytes(),
         )
         .unwrap();
 
-        let crypt = fast_crypt();
-        let call_count =
-            std::sync::Arc::new(AtomicU32::new(0));
-        let cc = call_count.clone();
+        edit(&mut header);
+        let sealed_meta =
+            seal_metadata(&mut header, &metadata, &keys.header_key).unwrap();
+        let mut out = header.to_bytes();
+        out.extend_from_slice(&sealed_meta);
+        out.extend_from_slice(rest);
+        fs::write(path, out).unwrap();
+    }
 
-        let progress = std::sync::Arc::new(
-            move |_event: ProgressEvent| {
-                cc.fetch_add(1, Ordering::SeqCst);
-            },
-        );
+    #[test]
+    fn test_key_commitment_checked_before_metadata() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("commit.txt");
+        fs::write(&original, b"committed").unwrap();
 
-        let _ = crypt
+        let crypt = fast_crypt();
+        let path = crypt
             .encrypt(EncryptOptions {
                 input_path: original,
                 output_dir: dir.path().to_path_buf(),
                 uuid: None,
-                password: "progress-pw".to_string(),
-                progress: Some(progress),
+                password: "pw".to_string(),
+                progress: None,
                 gzip_compressed: false,
                 output_extension: None,
                 temp_dir: None,
                 metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
             })
-            .await
-            .unwrap();
-
-        // At least the encrypting + done events.
-        assert!(call_count.load(Ordering::SeqCst) >= 2);
+            .unwrap()
+            .output_path;
+        let info = crypt.inspect(path.clone()).unwrap();
+        assert!(info.key_commitment);
+
+        let err = crypt
+            .decrypt_header(path.clone(), "not-pw".to_string())
+            .unwrap_err();
+        assert!(matches!(err, CryptError::InvalidPassword));
+
+        // Stripping the commitment does not skip the check.
+        reseal_header(&path, "pw", |h| h.key_commitment = None);
+        assert!(!crypt.inspect(path.clone()).unwrap().key_commitment);
+        let err = crypt
+            .decrypt_header(path.clone(), "pw".to_string())
+            .unwrap_err();
+        assert!(matches!(err, CryptError::InvalidHeader(_)));
+
+        // A commitment to another key is refused even though the
+        // metadata itself authenticates.
+        reseal_header(&path, "pw", |h| h.key_commitment = Some([9u8; 32]));
+        let err = crypt
+            .decrypt_header(path, "pw".to_string())
+            .unwrap_err();
+        assert!(matches!(err, CryptError::InvalidPassword));
     }
 
-    #[tokio::test]
-    async fn test_encrypt_decrypt_directory() {
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_recipient_round_trip() {
         let dir = TempDir::new().unwrap();
+        let data: Vec<u8> = (0..2000).map(|i| (i * 13 % 256) as u8).collect();
+        let original = dir.path().join("export.bin");
+        fs::write(&original, &data).unwrap();
 
-        // Create directory structure.
-        let src = dir.path().join("my_folder");
-        fs::create_dir_all(src.join("sub")).unwrap();
-        fs::write(src.join("a.txt"), b"file a content")
-            .unwrap();
-        fs::write(
-            src.join("sub/b.txt"),
-            b"file b content",
-        )
-        .unwrap();
-
+        let identity = RecipientIdentity::generate();
         let crypt = fast_crypt();
-
-        let enc_result = crypt
+        let path = crypt
             .encrypt(EncryptOptions {
-                input_path: src,
+                input_path: original,
                 output_dir: dir.path().to_path_buf(),
                 uuid: None,
-                password: "dir-pass".to_string(),
+                password: String::new(),
                 progress: None,
-                gzip_compressed: false,
+                gzip_compressed: true,
                 output_extension: None,
                 temp_dir: None,
                 metadata: None,
+                fec: None,
+                signer: None,
+                recipient: Some(identity.public_key()),
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
             })
-            .await
-            .unwrap();
+            .unwrap()
+            .output_path;
+
+        let info = crypt.inspect(path.clone()).unwrap();
+        assert_eq!(
+            info.recipient,
+            Some(crate::RecipientAlgorithm::X25519MlKem768)
+        );
+        assert!(info.key_commitment);
 
         let out_dir = dir.path().join("out");
         fs::create_dir_all(&out_dir).unwrap();
+        let decrypt = |identity: Option<RecipientIdentity>| DecryptOptions {
+            input_path: path.clone(),
+            output_dir: out_dir.clone(),
+            password: String::new(),
+            progress: None,
+            temp_dir: None,
+            identity,
+        };
 
-        let dec_result = crypt
+        let err = crypt.decrypt(decrypt(None)).unwrap_err();
+        assert!(matches!(err, CryptError::RecipientKeyRequired));
+
+        let stranger = RecipientIdentity::generate();
+        let err = crypt.decrypt(decrypt(Some(stranger))).unwrap_err();
+        assert!(matches!(err, CryptError::InvalidPassword));
+
+        // The identity round-trips through its serialized secret key.
+        let restored =
+            RecipientIdentity::from_secret_slice(&identity.secret_key())
+                .unwrap();
+        let result = crypt.decrypt(decrypt(Some(restored))).unwrap();
+        assert_eq!(fs::read(&result.output_path).unwrap(), data);
+    }
+
+    #[test]
+    fn test_stream_recipient_round_trip() {
+        let identity = RecipientIdentity::generate();
+        let mut opts = stream_opts(false, None, None);
+        opts.recipient = Some(identity.public_key());
+
+        let mut encrypted = Vec::new();
+        fast_crypt()
+            .encrypt_stream(&b"to the future"[..], &mut encrypted, opts)
+            .unwrap();
+
+        let mut plain = Vec::new();
+        fast_crypt()
+            .decrypt_stream(
+                &encrypted[..],
+                &mut plain,
+                StreamDecryptOptions {
+                    password: "pipe-pass".to_string(),
+                    progress: None,
+                    identity: Some(identity),
+                },
+            )
+            .unwrap();
+        assert_eq!(plain, b"to the future");
+    }
+
+    fn shred_opts(input: &Path, output_dir: &Path) -> EncryptOptions {
+        EncryptOptions {
+            input_path: input.to_path_buf(),
+            output_dir: output_dir.to_path_buf(),
+            uuid: None,
+            password: "shred-pw".to_string(),
+            progress: None,
+            gzip_compressed: true,
+            output_extension: None,
+            temp_dir: None,
+            metadata: None,
+            fec: None,
+            signer: None,
+            recipient: None,
+            shred_source: true,
+            hide_lengths: false,
+            volume_size: None,
+            filter: None,
+        }
+    }
+
+    #[cfg(feature = "sevenz")]
+    #[test]
+    fn test_shred_source_directory() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("private");
+        fs::create_dir_all(src.join("sub")).unwrap();
+        fs::write(src.join("a.txt"), b"alpha").unwrap();
+        fs::write(src.join("sub/b.txt"), b"beta").unwrap();
+        let vault = dir.path().join("vault");
+        fs::create_dir(&vault).unwrap();
+
+        let crypt = fast_crypt();
+        let result = crypt.encrypt(shred_opts(&src, &vault)).unwrap();
+        let shred = result.shred.unwrap();
+        assert!(shred.all_shredded());
+        assert_eq!(shred.entries.len(), 4);
+        assert!(!shred.caveat.is_empty());
+        assert!(!src.exists());
+
+        let out_dir = dir.path().join("out");
+        fs::create_dir_all(&out_dir).unwrap();
+        let dec = crypt
             .decrypt(DecryptOptions {
-                input_path: enc_result.output_path,
-                output_dir: out_dir.clone(),
-                password: "dir-pass".to_string(),
+                input_path: result.output_path,
+                output_dir: out_dir,
+                password: "shred-pw".to_string(),
                 progress: None,
                 temp_dir: None,
+                identity: None,
             })
-            .await
             .unwrap();
+        assert_eq!(
+            fs::read(dec.output_path.join("sub/b.txt")).unwrap(),
+            b"beta"
+        );
+    }
 
-        // Should have extracted the directory.
-        assert!(dec_result.output_path.is_dir());
+    #[test]
+    fn test_shred_source_skipped_on_failure() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("keep.txt");
+        fs::write(&src, b"still here").unwrap();
 
-        let fa = fs::read_to_string(
-            dec_result.output_path.join("a.txt"),
-        )
-        .unwrap();
-        assert_eq!(fa, "file a content");
+        // Encryption fails: the output directory does not exist.
+        let missing = dir.path().join("missing");
+        let crypt = fast_crypt();
+        assert!(crypt.encrypt(shred_opts(&src, &missing)).is_err());
+        assert_eq!(fs::read(&src).unwrap(), b"still here");
+
+        // Shredding a directory that receives the output is refused
+        // up front.
+        let tree = dir.path().join("tree");
+        fs::create_dir(&tree).unwrap();
+        fs::write(tree.join("c.txt"), b"gamma").unwrap();
+        assert!(crypt.encrypt(shred_opts(&tree, &tree)).is_err());
+        assert_eq!(fs::read(tree.join("c.txt")).unwrap(), b"gamma");
+    }
 
-        let fb = fs::read_to_string(
-            dec_result.output_path.join("sub/b.txt"),
-        )
-        .unwrap();
-        assert_eq!(fb, "file b content");
+    #[cfg(unix)]
+    #[test]
+    fn test_shred_source_refuses_symlink() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("secret.txt");
+        fs::write(&target, b"plaintext").unwrap();
+        let link = dir.path().join("link.txt");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let err = fast_crypt()
+            .encrypt(shred_opts(&link, dir.path()))
+            .unwrap_err();
+        assert!(matches!(err, CryptError::Encryption(_)));
+        assert!(link.is_symlink());
+        assert_eq!(fs::read(&target).unwrap(), b"plaintext");
+    }
+
+    fn stream_opts(
+        gzip: bool,
+        fec: Option<FecParams>,
+        signer: Option<SigningIdentity>,
+    ) -> StreamEncryptOptions {
+        StreamEncryptOptions {
+            original_filename: "dump".to_string(),
+            original_extension: "sql".to_string(),
+            uuid: None,
+            password: "pipe-pass".to_string(),
+            progress: None,
+            gzip_compressed: gzip,
+            metadata: None,
+            fec,
+            signer,
+            recipient: None,
+        }
+    }
+
+    fn decrypt_bytes(
+        encrypted: &[u8],
+        password: &str,
+    ) -> Result<(Vec<u8>, StreamDecryptResult)> {
+        let mut plain = Vec::new();
+        let result = fast_crypt()
+            .decrypt_stream(
+                encrypted,
+                &mut plain,
+                StreamDecryptOptions {
+                    password: password.to_string(),
+                    progress: None,
+                    identity: None,
+                },
+            )?;
+        Ok((plain, result))
+    }
+
+    #[test]
+    fn test_stream_round_trip() {
+        let data: Vec<u8> = (0..5000).map(|i| (i % 251) as u8).collect();
+        let signer = SigningIdentity::generate();
+        let cases = [
+            (false, None, None),
+            (true, None, None),
+            (false, Some(FecParams::new(4, 2).unwrap()), None),
+            (false, None, Some(signer.clone())),
+            (true, Some(FecParams::new(4, 2).unwrap()), Some(signer)),
+        ];
+
+        for (gzip, fec, signer) in cases {
+            if gzip && !cfg!(feature = "gzip") {
+                continue;
+            }
+            let signed = signer.as_ref().map(|s| s.public_key());
+            let mut encrypted = Vec::new();
+            let enc = fast_crypt()
+                .encrypt_stream(
+                    &data[..],
+                    &mut encrypted,
+                    stream_opts(gzip, fec, signer),
+                )
+                .unwrap();
+            assert_eq!(enc.bytes_read, data.len() as u64);
+
+            let (plain, dec) =
+                decrypt_bytes(&encrypted, "pipe-pass").unwrap();
+            assert_eq!(plain, data);
+            assert_eq!(dec.bytes_written, data.len() as u64);
+            assert_eq!(dec.metadata.uuid, enc.uuid);
+            assert_eq!(dec.metadata.original_filename, "dump");
+            assert_eq!(dec.metadata.original_size, 0);
+            assert_eq!(
+                dec.signature,
+                match signed {
+                    Some(public_key) => SignatureStatus::Valid { public_key },
+                    None => SignatureStatus::Unsigned,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_aes_gcm_siv_suite() {
+        let data = vec![0x5Au8; 1000];
+        let mut encrypted = Vec::new();
+        fast_crypt()
+            .with_cipher_suite(CipherSuite::Aes256GcmSiv)
+            .encrypt_stream(
+                &data[..],
+                &mut encrypted,
+                stream_opts(cfg!(feature = "gzip"), None, None),
+            )
+            .unwrap();
+
+        let mut cursor = std::io::Cursor::new(&encrypted);
+        let header = PublicHeader::read_from(&mut cursor).unwrap();
+        assert_eq!(header.cipher, CipherSuite::Aes256GcmSiv);
+
+        let (plain, _) =
+            decrypt_bytes(&encrypted, "pipe-pass").unwrap();
+        assert_eq!(plain, data);
+    }
+
+    #[test]
+    fn test_stream_empty_input() {
+        let mut encrypted = Vec::new();
+        fast_crypt()
+            .encrypt_stream(
+                &[][..],
+                &mut encrypted,
+                stream_opts(false, None, None),
+            )
+            .unwrap();
+
+        let (plain, dec) =
+            decrypt_bytes(&encrypted, "pipe-pass").unwrap();
+        assert!(plain.is_empty());
+        assert_eq!(dec.bytes_written, 0);
     }
 
-    #[tokio::test]
-    async fn test_multi_chunk_file() {
+    #[test]
+    fn test_decrypt_stream_reads_encrypted_file() {
         let dir = TempDir::new().unwrap();
-        let original = dir.path().join("big.bin");
+        let signer = SigningIdentity::generate();
+        let enc_result = encrypt_signed(&dir, &signer, None);
+        let encrypted = fs::read(&enc_result.output_path).unwrap();
+
+        let (plain, dec) =
+            decrypt_bytes(&encrypted, "sign-pass").unwrap();
+        let expected: Vec<u8> = (0..2000).map(|i| (i % 199) as u8).collect();
+        assert_eq!(plain, expected);
+        assert_eq!(dec.metadata.original_size, 2000);
+        assert_eq!(
+            dec.signature,
+            SignatureStatus::Valid {
+                public_key: signer.public_key()
+            }
+        );
+    }
 
-        // File bigger than chunk size (256 bytes).
-        let data: Vec<u8> =
-            (0..1000).map(|i| (i % 256) as u8).collect();
-        fs::write(&original, &data).unwrap();
+    #[test]
+    fn test_decrypt_to_memory() {
+        let dir = TempDir::new().unwrap();
+        let signer = SigningIdentity::generate();
+        let enc_result = encrypt_signed(&dir, &signer, None);
+
+        let crypt = fast_crypt();
+        let result = crypt
+            .decrypt_to_memory(
+                enc_result.output_path.clone(),
+                "sign-pass".to_string(),
+                2000,
+            )
+            .unwrap();
+        let expected: Vec<u8> = (0..2000).map(|i| (i % 199) as u8).collect();
+        assert_eq!(result.data, expected);
+        assert_eq!(result.metadata.original_size, 2000);
+        assert!(matches!(result.signature, SignatureStatus::Valid { .. }));
+
+        let err = crypt
+            .decrypt_to_memory(
+                enc_result.output_path,
+                "sign-pass".to_string(),
+                1999,
+            )
+            .unwrap_err();
+        assert!(matches!(err, CryptError::TooLarge(1999)));
+    }
 
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_decrypt_to_memory_gzip_limit() {
+        let dir = TempDir::new().unwrap();
         let crypt = fast_crypt();
 
-        let enc_result = crypt
+        // A stream records no original size, so the limit is only
+        // hit while decompressing.
+        let data = vec![0u8; 50_000];
+        let path = dir.path().join("zeros.enc");
+        let file = File::create(&path).unwrap();
+        crypt
+            .encrypt_stream(&data[..], file, stream_opts(true, None, None))
+            .unwrap();
+        assert!(fs::metadata(&path).unwrap().len() < 10_000);
+
+        let result = crypt
+            .decrypt_to_memory(path.clone(), "pipe-pass".to_string(), 50_000)
+            .unwrap();
+        assert_eq!(result.data, data);
+        assert!(result.metadata.gzip_compressed);
+
+        let err = crypt
+            .decrypt_to_memory(path, "pipe-pass".to_string(), 10_000)
+            .unwrap_err();
+        assert!(matches!(err, CryptError::TooLarge(10_000)));
+
+        // A gzip file records the compressed size, which for data
+        // that does not compress exceeds the decompressed one.
+        let mut state = 0x2545_f491_u32;
+        let noise: Vec<u8> = (0..5000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state >> 24) as u8
+            })
+            .collect();
+        let input = dir.path().join("noise.bin");
+        fs::write(&input, &noise).unwrap();
+        let path = crypt
             .encrypt(EncryptOptions {
-                input_path: original,
+                input_path: input,
                 output_dir: dir.path().to_path_buf(),
                 uuid: None,
-                password: "multi-chunk".to_string(),
+                password: "gz-pass".to_string(),
                 progress: None,
-                gzip_compressed: false,
+                gzip_compressed: true,
                 output_extension: None,
                 temp_dir: None,
                 metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
             })
-            .await
+            .unwrap()
+            .output_path;
+        let result = crypt
+            .decrypt_to_memory(path.clone(), "gz-pass".to_string(), 5000)
             .unwrap();
+        assert!(result.metadata.original_size > 5000);
+        assert_eq!(result.data, noise);
+        let err = crypt
+            .decrypt_to_memory(path, "gz-pass".to_string(), 4999)
+            .unwrap_err();
+        assert!(matches!(err, CryptError::TooLarge(4999)));
+    }
 
-        let out_dir = dir.path().join("out");
-        fs::create_dir_all(&out_dir).unwrap();
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn test_gzip_needs_feature() {
+        let err = fast_crypt()
+            .encrypt_stream(
+                &[1u8; 100][..],
+                Vec::new(),
+                stream_opts(true, None, None),
+            )
+            .unwrap_err();
+        assert!(matches!(err, CryptError::Compression(_)));
+    }
 
-        let dec_result = crypt
-            .decrypt(DecryptOptions {
-                input_path: enc_result.output_path,
-                output_dir: out_dir,
-                password: "multi-chunk".to_string(),
-                progress: None,
-                temp_dir: None,
-            })
-            .await
+    #[test]
+    fn test_stream_damage_and_truncation_fail() {
+        let data = vec![7u8; 1000];
+        let mut encrypted = Vec::new();
+        fast_crypt()
+            .encrypt_stream(
+                &data[..],
+                &mut encrypted,
+                stream_opts(false, None, Some(SigningIdentity::generate())),
+            )
             .unwrap();
 
-        let result =
-            fs::read(&dec_result.output_path).unwrap();
-        assert_eq!(result, data);
+        let wrong = decrypt_bytes(&encrypted, "nope");
+        assert!(matches!(wrong, Err(CryptError::InvalidPassword)));
+
+        // Dropping bytes shifts the trailer into the last chunk.
+        let truncated = &encrypted[..encrypted.len() - 10];
+        assert!(decrypt_bytes(truncated, "pipe-pass").is_err());
+
+        let mut forged = encrypted.clone();
+        let last = forged.len() - 1;
+        forged[last] ^= 1;
+        let forged = decrypt_bytes(&forged, "pipe-pass");
+        assert!(matches!(forged, Err(CryptError::InvalidSignature(_))));
     }
 
-    #[tokio::test]
-    async fn test_metadata_preserved() {
+    #[test]
+    fn test_space_plans_bound_the_output() {
         let dir = TempDir::new().unwrap();
-        let original = dir.path().join("meta.txt");
-        fs::write(&original, b"metadata test").unwrap();
+        let input = dir.path().join("data.bin");
+        let data: Vec<u8> =
+            (0..5000u32).map(|i| (i * 7 % 256) as u8).collect();
+        fs::write(&input, &data).unwrap();
 
         let crypt = fast_crypt();
-        let mut custom_meta = HashMap::new();
-        custom_meta.insert(
-            "author".to_string(),
-            "test".to_string(),
-        );
-        custom_meta.insert(
-            "version".to_string(),
-            "1.0".to_string(),
-        );
-
-        let enc_result = crypt
-            .encrypt(EncryptOptions {
-                input_path: original,
-                output_dir: dir.path().to_path_buf(),
-                uuid: Some("custom-uuid-123".to_string()),
-                password: "meta-pw".to_string(),
-                progress: None,
-                gzip_compressed: false,
-                output_extension: None,
-                temp_dir: None,
-                metadata: Some(custom_meta.clone()),
-            })
-            .await
-            .unwrap();
-
-        assert_eq!(enc_result.uuid, "custom-uuid-123");
+        let opts = |gzip_compressed, volume_size| EncryptOptions {
+            input_path: input.clone(),
+            output_dir: dir.path().to_path_buf(),
+            uuid: None,
+            password: "plan-pass".to_string(),
+            progress: None,
+            gzip_compressed,
+            output_extension: None,
+            temp_dir: None,
+            metadata: Some(HashMap::from([("k".into(), "v".into())])),
+            fec: Some(FecParams::new(4, 2).unwrap()),
+            signer: Some(SigningIdentity::generate()),
+            recipient: None,
+            shred_source: false,
+            hide_lengths: true,
+            volume_size,
+            filter: None,
+        };
+        let gzip_built = cfg!(feature = "gzip");
+        for (gzip, volume_size) in [(false, None), (gzip_built, Some(2000))] {
+            let plan = crypt.plan_encrypt(&opts(gzip, volume_size)).unwrap();
+            let result = crypt.encrypt(opts(gzip, volume_size)).unwrap();
+            let written: u64 = match result.volumes.is_empty() {
+                true => vec![result.output_path.clone()],
+                false => result.volumes.clone(),
+            }
+            .iter()
+            .map(|path| fs::metadata(path).unwrap().len())
+            .sum();
+            assert!(written <= plan.output_bytes, "{written} > {plan:?}");
+            // Without compression the bound is close to exact.
+            if !gzip {
+                assert!(plan.output_bytes < written + 1024, "{plan:?}");
+            }
+            assert_eq!(plan.temp_bytes > 0, gzip);
+            assert!(plan.shared_volume && !plan.estimated);
+            assert!(plan.output_free.is_some());
+
+            let dec_plan = crypt
+                .plan_decrypt(&DecryptOptions {
+                    input_path: result.output_path,
+                    output_dir: dir.path().join("out"),
+                    password: "plan-pass".to_string(),
+                    progress: None,
+                    temp_dir: None,
+                    identity: None,
+                })
+                .unwrap();
+            assert_eq!(dec_plan.estimated, gzip);
+            if !gzip {
+                assert_eq!(dec_plan.output_bytes, data.len() as u64);
+                assert_eq!(dec_plan.temp_bytes, data.len() as u64);
+            }
+        }
 
-        let out_dir = dir.path().join("out");
-        fs::create_dir_all(&out_dir).unwrap();
+        // The recipient block is sized without encapsulating a key.
+        let identity = RecipientIdentity::generate();
+        let mut recipient_opts = opts(false, None);
+        recipient_opts.recipient = Some(identity.public_key());
+        let plan = crypt.plan_encrypt(&recipient_opts).unwrap();
+        let path = crypt.encrypt(recipient_opts).unwrap().output_path;
+        let written = fs::metadata(path).unwrap().len();
+        assert!(written <= plan.output_bytes, "{written} > {plan:?}");
+        assert!(plan.output_bytes < written + 1024, "{plan:?}");
+    }
 
-        let dec_result = crypt
-            .decrypt(DecryptOptions {
-                input_path: enc_result.output_path,
-                output_dir: out_dir,
-                password: "meta-pw".to_string(),
-                progress: None,
-                temp_dir: None,
-            })
-            .await
-            .unwrap();
+    #[test]
+    fn test_space_plans_name_the_source_like_encrypt() {
+        // The plan sizes the metadata with the names `encrypt` stores.
+        let names = |path: &str, is_dir| {
+            let (name, extension) = source_names(Path::new(path), is_dir);
+            format!("{name}|{extension}")
+        };
+        assert_eq!(names("a/report.pdf", false), "report|pdf");
+        assert_eq!(names("a/notes", false), "notes|");
+        assert_eq!(names("a/photos.2026", true), "photos.2026|7z");
+    }
 
+    #[cfg(all(feature = "gzip", feature = "sevenz"))]
+    #[test]
+    fn test_insufficient_space_is_refused() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("a.txt"), [1u8; 3000]).unwrap();
+
+        let opts = EncryptOptions {
+            input_path: src,
+            output_dir: dir.path().to_path_buf(),
+            uuid: None,
+            password: "plan-pass".to_string(),
+            progress: None,
+            gzip_compressed: true,
+            output_extension: None,
+            temp_dir: None,
+            metadata: None,
+            fec: None,
+            signer: None,
+            recipient: None,
+            shred_source: false,
+            hide_lengths: false,
+            volume_size: None,
+            filter: None,
+        };
+        let mut plan = fast_crypt().plan_encrypt(&opts).unwrap();
+        // Archive and gzip temp files exist at once, then the gzip
+        // one next to the output.
+        assert!(plan.temp_bytes > plan.temp_bytes_at_output);
         assert_eq!(
-            dec_result.metadata.uuid,
-            "custom-uuid-123"
-        );
-        assert_eq!(
-            dec_result.metadata.metadata,
-            custom_meta
+            plan.output_needed(),
+            plan.temp_bytes
+                .max(plan.temp_bytes_at_output + plan.output_bytes)
         );
+        assert!(plan.check().is_ok());
+
+        plan.output_free = Some(plan.output_needed() - 1);
+        match plan.check() {
+            Err(CryptError::InsufficientSpace {
+                path,
+                needed,
+                available,
+            }) => {
+                assert_eq!(path, dir.path());
+                assert_eq!(needed, available + 1);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+
+        // On separate file systems each one only needs its own part.
+        plan.shared_volume = false;
+        plan.output_free = Some(plan.output_bytes);
+        plan.temp_free = Some(plan.temp_bytes - 1);
+        assert!(matches!(
+            plan.check(),
+            Err(CryptError::InsufficientSpace { path, .. })
+                if path == plan.temp_dir
+        ));
     }
 }
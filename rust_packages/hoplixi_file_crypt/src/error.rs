@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use thiserror::Error;
 
 /// All possible errors in the encryption/decryption pipeline.
@@ -38,6 +40,119 @@ pub enum CryptError {
 
     #[error("Serialization error: {0}")]
     Serialization(String),
+
+    #[error("Signature verification failed: {0}")]
+    InvalidSignature(String),
+
+    #[error("File is encrypted to a recipient key; its identity is required")]
+    RecipientKeyRequired,
+
+    #[error("Decrypted data exceeds the limit of {0} bytes")]
+    TooLarge(u64),
+
+    #[error("Volume error: {0}")]
+    Volume(String),
+
+    #[error(
+        "Not enough space in {}: {needed} bytes needed, {available} free",
+        path.display()
+    )]
+    InsufficientSpace {
+        path: PathBuf,
+        needed: u64,
+        available: u64,
+    },
+}
+
+impl CryptError {
+    /// Stable snake_case name of the variant, for machine-readable
+    /// output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CryptError::Io(_) => "io",
+            CryptError::Encryption(_) => "encryption",
+            CryptError::Decryption(_) => "decryption",
+            CryptError::InvalidMagic => "invalid_magic",
+            CryptError::UnsupportedVersion(_) => "unsupported_version",
+            CryptError::InvalidPassword => "invalid_password",
+            CryptError::CorruptedData(_) => "corrupted_data",
+            CryptError::Compression(_) => "compression",
+            CryptError::KeyDerivation(_) => "key_derivation",
+            CryptError::HeaderParse(_) => "header_parse",
+            CryptError::InvalidHeader(_) => "invalid_header",
+            CryptError::Serialization(_) => "serialization",
+            CryptError::InvalidSignature(_) => "invalid_signature",
+            CryptError::RecipientKeyRequired => "recipient_key_required",
+            CryptError::TooLarge(_) => "too_large",
+            CryptError::Volume(_) => "volume",
+            CryptError::InsufficientSpace { .. } => "insufficient_space",
+        }
+    }
+
+    /// Process exit code reported by the `hoplixi-crypt` CLI.
+    ///
+    /// Codes start at 10 so they never clash with 1 (generic
+    /// failure) and 2 (usage error).
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CryptError::Io(_) => 10,
+            CryptError::Encryption(_) => 11,
+            CryptError::Decryption(_) => 12,
+            CryptError::InvalidMagic => 13,
+            CryptError::UnsupportedVersion(_) => 14,
+            CryptError::InvalidPassword => 15,
+            CryptError::CorruptedData(_) => 16,
+            CryptError::Compression(_) => 17,
+            CryptError::KeyDerivation(_) => 18,
+            CryptError::HeaderParse(_) => 19,
+            CryptError::InvalidHeader(_) => 20,
+            CryptError::Serialization(_) => 21,
+            CryptError::InvalidSignature(_) => 22,
+            CryptError::RecipientKeyRequired => 23,
+            CryptError::TooLarge(_) => 24,
+            CryptError::Volume(_) => 25,
+            CryptError::InsufficientSpace { .. } => 26,
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, CryptError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_exit_codes_are_distinct() {
+        let all = [
+            CryptError::Io(std::io::Error::other("x")),
+            CryptError::Encryption(String::new()),
+            CryptError::Decryption(String::new()),
+            CryptError::InvalidMagic,
+            CryptError::UnsupportedVersion(9),
+            CryptError::InvalidPassword,
+            CryptError::CorruptedData(String::new()),
+            CryptError::Compression(String::new()),
+            CryptError::KeyDerivation(String::new()),
+            CryptError::HeaderParse(String::new()),
+            CryptError::InvalidHeader(String::new()),
+            CryptError::Serialization(String::new()),
+            CryptError::InvalidSignature(String::new()),
+            CryptError::RecipientKeyRequired,
+            CryptError::TooLarge(0),
+            CryptError::Volume(String::new()),
+            CryptError::InsufficientSpace {
+                path: PathBuf::new(),
+                needed: 1,
+                available: 0,
+            },
+        ];
+
+        let codes: HashSet<i32> = all.iter().map(|e| e.exit_code()).collect();
+        let kinds: HashSet<&str> = all.iter().map(|e| e.kind()).collect();
+        assert_eq!(codes.len(), all.len());
+        assert_eq!(kinds.len(), all.len());
+        assert!(codes.iter().all(|&code| code >= 10));
+    }
+}
@@ -0,0 +1,210 @@
+use reed_solomon_erasure::galois_8::ReedSolomon;
+
+use crate::config::{
+    fec_defaults, MAX_FEC_DATA_SHARDS, MAX_FEC_GROUP_BYTES,
+    MAX_FEC_PARITY_SHARDS, TAG_LEN,
+};
+use crate::error::{CryptError, Result};
+
+/// Reed–Solomon parameters for the optional parity layer.
+///
+/// Encrypted chunks are grouped `data_shards` at a time and each
+/// group is followed by `parity_shards` parity shards. Up to
+/// `parity_shards` damaged chunks per group can be rebuilt; damaged
+/// chunks are located by their failing AEAD tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FecParams {
+    pub data_shards: u8,
+    pub parity_shards: u8,
+}
+
+impl Default for FecParams {
+    fn default() -> Self {
+        Self {
+            data_shards: fec_defaults::DATA_SHARDS,
+            parity_shards: fec_defaults::PARITY_SHARDS,
+        }
+    }
+}
+
+impl FecParams {
+    /// Create validated parameters.
+    pub fn new(data_shards: u8, parity_shards: u8) -> Result<Self> {
+        let params = Self {
+            data_shards,
+            parity_shards,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// Check the shard counts against the format limits.
+    pub fn validate(&self) -> Result<()> {
+        if self.data_shards == 0 || self.data_shards > MAX_FEC_DATA_SHARDS
+        {
+            return Err(CryptError::InvalidHeader(format!(
+                "fec data_shards {} out of range \
+                 [1..{MAX_FEC_DATA_SHARDS}]",
+                self.data_shards
+            )));
+        }
+
+        if self.parity_shards == 0
+            || self.parity_shards > MAX_FEC_PARITY_SHARDS
+        {
+            return Err(CryptError::InvalidHeader(format!(
+                "fec parity_shards {} out of range \
+                 [1..{MAX_FEC_PARITY_SHARDS}]",
+                self.parity_shards
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Check that one buffered group stays within
+    /// [`MAX_FEC_GROUP_BYTES`] for the given chunk size.
+    pub fn validate_for_chunk_size(&self, chunk_size: u32) -> Result<()> {
+        self.validate()?;
+        let shard_len = chunk_size as u64 + TAG_LEN as u64;
+        if shard_len * self.group_len() as u64 > MAX_FEC_GROUP_BYTES {
+            return Err(CryptError::InvalidHeader(format!(
+                "fec group of {} shards x {shard_len} bytes exceeds \
+                 max {MAX_FEC_GROUP_BYTES}",
+                self.group_len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Total number of shards (data + parity) in a full group.
+    pub fn group_len(&self) -> usize {
+        self.data_shards as usize + self.parity_shards as usize
+    }
+}
+
+/// Compute parity shards for one group of encrypted chunks.
+///
+/// Chunks shorter than `shard_len` (the final chunk of a file) are
+/// zero-padded for encoding; the padding is never written.
+pub fn encode_parity(
+    chunks: &[Vec<u8>],
+    parity_shards: usize,
+    shard_len: usize,
+) -> Result<Vec<Vec<u8>>> {
+    let rs = codec(chunks.len(), parity_shards)?;
+
+    let mut shards: Vec<Vec<u8>> = chunks
+        .iter()
+        .map(|c| padded(c, shard_len))
+        .collect();
+    shards.extend((0..parity_shards).map(|_| vec![0u8; shard_len]));
+
+    rs.encode(&mut shards).map_err(|e| {
+        CryptError::Encryption(format!("Reed-Solomon encode: {e}"))
+    })?;
+
+    Ok(shards.split_off(chunks.len()))
+}
+
+/// Rebuild the missing (`None`) chunks of a group in place.
+///
+/// `chunk_lens` gives the true length of every data chunk so the
+/// zero padding used during encoding can be stripped again.
+pub fn reconstruct(
+    chunks: &mut [Option<Vec<u8>>],
+    chunk_lens: &[usize],
+    parity: &[Vec<u8>],
+    shard_len: usize,
+) -> Result<()> {
+    let rs = codec(chunks.len(), parity.len())?;
+
+    let mut shards: Vec<Option<Vec<u8>>> = chunks
+        .iter()
+        .map(|c| c.as_ref().map(|c| padded(c, shard_len)))
+        .chain(parity.iter().map(|p| Some(p.clone())))
+        .collect();
+
+    rs.reconstruct_data(&mut shards).map_err(|e| {
+        CryptError::CorruptedData(format!("Reed-Solomon reconstruct: {e}"))
+    })?;
+
+    for (i, slot) in chunks.iter_mut().enumerate() {
+        if slot.is_none() {
+            let mut shard = shards[i].take().ok_or_else(|| {
+                CryptError::CorruptedData(format!(
+                    "Reed-Solomon shard {i} not rebuilt"
+                ))
+            })?;
+            shard.truncate(chunk_lens[i]);
+            *slot = Some(shard);
+        }
+    }
+
+    Ok(())
+}
+
+fn codec(data_shards: usize, parity_shards: usize) -> Result<ReedSolomon> {
+    ReedSolomon::new(data_shards, parity_shards).map_err(|e| {
+        CryptError::InvalidHeader(format!("Reed-Solomon params: {e}"))
+    })
+}
+
+fn padded(chunk: &[u8], shard_len: usize) -> Vec<u8> {
+    let mut shard = Vec::with_capacity(shard_len);
+    shard.extend_from_slice(chunk);
+    shard.resize(shard_len, 0);
+    shard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_group() -> Vec<Vec<u8>> {
+        vec![vec![1u8; 32], vec![2u8; 32], vec![3u8; 20]]
+    }
+
+    #[test]
+    fn test_reconstruct_missing_chunks() {
+        let chunks = sample_group();
+        let lens: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        let parity = encode_parity(&chunks, 2, 32).unwrap();
+        assert_eq!(parity.len(), 2);
+
+        let mut damaged: Vec<Option<Vec<u8>>> =
+            chunks.iter().cloned().map(Some).collect();
+        damaged[0] = None;
+        damaged[2] = None;
+
+        reconstruct(&mut damaged, &lens, &parity, 32).unwrap();
+
+        let healed: Vec<Vec<u8>> =
+            damaged.into_iter().map(Option::unwrap).collect();
+        assert_eq!(healed, chunks);
+    }
+
+    #[test]
+    fn test_too_many_missing_fails() {
+        let chunks = sample_group();
+        let lens: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        let parity = encode_parity(&chunks, 1, 32).unwrap();
+
+        let mut damaged: Vec<Option<Vec<u8>>> =
+            chunks.into_iter().map(Some).collect();
+        damaged[0] = None;
+        damaged[1] = None;
+
+        let result = reconstruct(&mut damaged, &lens, &parity, 32);
+        assert!(matches!(result, Err(CryptError::CorruptedData(_))));
+    }
+
+    #[test]
+    fn test_params_validation() {
+        assert!(FecParams::new(16, 2).is_ok());
+        assert!(FecParams::new(0, 2).is_err());
+        assert!(FecParams::new(16, 0).is_err());
+        assert!(FecParams::new(MAX_FEC_DATA_SHARDS + 1, 1).is_err());
+        assert!(FecParams::new(1, MAX_FEC_PARITY_SHARDS + 1).is_err());
+    }
+}
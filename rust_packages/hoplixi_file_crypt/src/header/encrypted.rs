@@ -2,9 +2,13 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::config::{KEY_LEN, NONCE_LEN};
-use crate::crypto::cipher;
+use crate::config::{
+    ED25519_PUBLIC_KEY_LEN, KEY_LEN, META_EXT_PADDING, META_EXT_PAYLOAD_LEN,
+    META_EXT_SIGNER_KEY, NONCE_LEN,
+};
+use crate::crypto::cipher::{self, CipherSuite};
 use crate::error::{CryptError, Result};
+use crate::header::{parse_extensions, push_extension};
 
 /// Encrypted metadata stored in the file header.
 ///
@@ -24,6 +28,18 @@ pub struct EncryptedMetadata {
     pub uuid: String,
     /// Additional user-defined metadata.
     pub metadata: HashMap<String, String>,
+    /// Ed25519 public key of the signer (signed files only).
+    ///
+    /// Stored as a trailing extension after the bincode fields, so
+    /// metadata written before it existed still decodes.
+    #[serde(skip)]
+    pub signer_public_key: Option<[u8; ED25519_PUBLIC_KEY_LEN]>,
+    /// True length of the payload plaintext when it was padded to
+    /// hide its size; the padding after it is dropped on decryption.
+    ///
+    /// Stored as a trailing extension, like the signer key.
+    #[serde(skip)]
+    pub payload_len: Option<u64>,
 }
 
 impl EncryptedMetadata {
@@ -33,49 +49,137 @@ impl EncryptedMetadata {
     /// binding the metadata to its header context.
     pub fn seal(
         &self,
+        suite: CipherSuite,
         header_key: &[u8; KEY_LEN],
         header_nonce: &[u8; NONCE_LEN],
         header_aad: &[u8],
     ) -> Result<Vec<u8>> {
-        let encoded = bincode::serde::encode_to_vec(
-            self,
-            bincode::config::standard(),
+        cipher::encrypt_metadata(
+            suite,
+            header_key,
+            header_nonce,
+            &self.encode()?,
+            header_aad,
         )
-        .map_err(|e| {
-            CryptError::Serialization(format!("Bincode encode: {e}"))
-        })?;
+    }
+
+    /// Like [`seal`](Self::seal), with the plaintext padded to
+    /// exactly `padded_len` bytes, so that edited metadata can take
+    /// the place of the old one.
+    ///
+    /// Returns `None` if the metadata does not fit.
+    pub fn seal_padded(
+        &self,
+        suite: CipherSuite,
+        header_key: &[u8; KEY_LEN],
+        header_nonce: &[u8; NONCE_LEN],
+        header_aad: &[u8],
+        padded_len: usize,
+    ) -> Result<Option<Vec<u8>>> {
+        let mut encoded = self.encode()?;
+        match padded_len.checked_sub(encoded.len()) {
+            Some(0) => {}
+            // The padding extension needs its own TAG + LEN.
+            Some(n) if (3..=3 + u16::MAX as usize).contains(&n) => {
+                push_extension(
+                    &mut encoded,
+                    META_EXT_PADDING,
+                    &vec![0u8; n - 3],
+                );
+            }
+            _ => return Ok(None),
+        }
 
         cipher::encrypt_metadata(
+            suite,
             header_key,
             header_nonce,
             &encoded,
             header_aad,
         )
+        .map(Some)
+    }
+
+    /// Length of the plaintext [`seal`](Self::seal) encrypts.
+    pub fn encoded_len(&self) -> Result<usize> {
+        Ok(self.encode()?.len())
+    }
+
+    /// Bincode fields followed by the extensions.
+    fn encode(&self) -> Result<Vec<u8>> {
+        let mut encoded = bincode::serde::encode_to_vec(
+            self,
+            bincode::config::standard(),
+        )
+        .map_err(|e| {
+            CryptError::Serialization(format!("Bincode encode: {e}"))
+        })?;
+        if let Some(key) = &self.signer_public_key {
+            push_extension(&mut encoded, META_EXT_SIGNER_KEY, key);
+        }
+        if let Some(len) = self.payload_len {
+            push_extension(
+                &mut encoded,
+                META_EXT_PAYLOAD_LEN,
+                &len.to_le_bytes(),
+            );
+        }
+        Ok(encoded)
     }
 
     /// Decrypt and deserialize from bincode.
     ///
     /// `header_aad` must match the AAD used during `seal`.
     pub fn unseal(
+        suite: CipherSuite,
         encrypted: &[u8],
         header_key: &[u8; KEY_LEN],
         header_nonce: &[u8; NONCE_LEN],
         header_aad: &[u8],
     ) -> Result<Self> {
         let decrypted = cipher::decrypt_metadata(
+            suite,
             header_key,
             header_nonce,
             encrypted,
             header_aad,
         )?;
 
-        let (meta, _) = bincode::serde::decode_from_slice::<Self, _>(
-            &decrypted,
-            bincode::config::standard(),
-        )
-        .map_err(|e| {
-            CryptError::Serialization(format!("Bincode decode: {e}"))
-        })?;
+        let (mut meta, consumed) =
+            bincode::serde::decode_from_slice::<Self, _>(
+                &decrypted,
+                bincode::config::standard(),
+            )
+            .map_err(|e| {
+                CryptError::Serialization(format!("Bincode decode: {e}"))
+            })?;
+
+        for (tag, value) in parse_extensions(&decrypted[consumed..])? {
+            match tag {
+                META_EXT_SIGNER_KEY => {
+                    let key = value.try_into().map_err(|_| {
+                        CryptError::InvalidHeader(
+                            "malformed signer key extension".to_string(),
+                        )
+                    })?;
+                    meta.signer_public_key = Some(key);
+                }
+                META_EXT_PAYLOAD_LEN => {
+                    let len = value.try_into().map_err(|_| {
+                        CryptError::InvalidHeader(
+                            "malformed payload length extension".to_string(),
+                        )
+                    })?;
+                    meta.payload_len = Some(u64::from_le_bytes(len));
+                }
+                META_EXT_PADDING => {}
+                _ => {
+                    return Err(CryptError::InvalidHeader(format!(
+                        "unknown metadata extension 0x{tag:02x}"
+                    )));
+                }
+            }
+        }
 
         Ok(meta)
     }
@@ -84,6 +188,9 @@ impl EncryptedMetadata {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::TAG_LEN;
+
+    const SUITE: CipherSuite = CipherSuite::XChaCha20Poly1305;
 
     fn sample_metadata() -> EncryptedMetadata {
         let mut metadata = HashMap::new();
@@ -97,6 +204,8 @@ mod tests {
             original_size: 1_048_576,
             uuid: "550e8400-e29b-41d4-a716-446655440000".to_string(),
             metadata,
+            signer_public_key: None,
+            payload_len: None,
         }
     }
 
@@ -107,9 +216,9 @@ mod tests {
         let nonce = [0x13u8; NONCE_LEN];
         let aad = b"public-header-bytes";
 
-        let sealed = meta.seal(&key, &nonce, aad).unwrap();
+        let sealed = meta.seal(SUITE, &key, &nonce, aad).unwrap();
         let unsealed =
-            EncryptedMetadata::unseal(&sealed, &key, &nonce, aad)
+            EncryptedMetadata::unseal(SUITE, &sealed, &key, &nonce, aad)
                 .unwrap();
 
         assert_eq!(unsealed.original_filename, meta.original_filename);
@@ -131,9 +240,9 @@ mod tests {
         let nonce = [0x13u8; NONCE_LEN];
         let aad = b"header";
 
-        let sealed = meta.seal(&key, &nonce, aad).unwrap();
+        let sealed = meta.seal(SUITE, &key, &nonce, aad).unwrap();
         let result =
-            EncryptedMetadata::unseal(&sealed, &wrong_key, &nonce, aad);
+            EncryptedMetadata::unseal(SUITE, &sealed, &wrong_key, &nonce, aad);
         assert!(result.is_err());
     }
 
@@ -143,8 +252,9 @@ mod tests {
         let key = [0x42u8; KEY_LEN];
         let nonce = [0x13u8; NONCE_LEN];
 
-        let sealed = meta.seal(&key, &nonce, b"correct-header").unwrap();
+        let sealed = meta.seal(SUITE, &key, &nonce, b"correct-header").unwrap();
         let result = EncryptedMetadata::unseal(
+            SUITE,
             &sealed,
             &key,
             &nonce,
@@ -162,17 +272,72 @@ mod tests {
             original_size: 0,
             uuid: String::new(),
             metadata: HashMap::new(),
+            signer_public_key: None,
+            payload_len: None,
         };
 
         let key = [0x01u8; KEY_LEN];
         let nonce = [0x02u8; NONCE_LEN];
         let aad = b"";
 
-        let sealed = meta.seal(&key, &nonce, aad).unwrap();
+        let sealed = meta.seal(SUITE, &key, &nonce, aad).unwrap();
         let unsealed =
-            EncryptedMetadata::unseal(&sealed, &key, &nonce, aad)
+            EncryptedMetadata::unseal(SUITE, &sealed, &key, &nonce, aad)
                 .unwrap();
         assert_eq!(unsealed.original_filename, "");
         assert_eq!(unsealed.original_size, 0);
     }
+
+    #[test]
+    fn test_signer_key_round_trip() {
+        let mut meta = sample_metadata();
+        meta.signer_public_key = Some([0x5Au8; ED25519_PUBLIC_KEY_LEN]);
+        let key = [0x42u8; KEY_LEN];
+        let nonce = [0x13u8; NONCE_LEN];
+
+        let sealed = meta.seal(SUITE, &key, &nonce, b"aad").unwrap();
+        let unsealed =
+            EncryptedMetadata::unseal(SUITE, &sealed, &key, &nonce, b"aad")
+                .unwrap();
+        assert_eq!(unsealed.signer_public_key, meta.signer_public_key);
+        assert_eq!(unsealed.metadata, meta.metadata);
+        assert_eq!(unsealed.payload_len, None);
+
+        meta.payload_len = Some(1_000_001);
+        let sealed = meta.seal(SUITE, &key, &nonce, b"aad").unwrap();
+        let unsealed =
+            EncryptedMetadata::unseal(SUITE, &sealed, &key, &nonce, b"aad")
+                .unwrap();
+        assert_eq!(unsealed.payload_len, Some(1_000_001));
+        assert_eq!(unsealed.signer_public_key, meta.signer_public_key);
+    }
+
+    #[test]
+    fn test_padded_seal_has_exact_length() {
+        let meta = sample_metadata();
+        let key = [0x42u8; KEY_LEN];
+        let nonce = [0x13u8; NONCE_LEN];
+        let len = meta.encoded_len().unwrap();
+
+        for padded_len in [len, len + 3, len + 500] {
+            let sealed = meta
+                .seal_padded(SUITE, &key, &nonce, b"aad", padded_len)
+                .unwrap()
+                .unwrap();
+            assert_eq!(sealed.len(), padded_len + TAG_LEN);
+            let unsealed = EncryptedMetadata::unseal(
+                SUITE, &sealed, &key, &nonce, b"aad",
+            )
+            .unwrap();
+            assert_eq!(unsealed.metadata, meta.metadata);
+        }
+
+        // Too short, or too little room for the padding extension.
+        for padded_len in [len - 1, len + 1, len + 2] {
+            let sealed = meta
+                .seal_padded(SUITE, &key, &nonce, b"aad", padded_len)
+                .unwrap();
+            assert!(sealed.is_none());
+        }
+    }
 }
@@ -1,2 +1,41 @@
 pub mod encrypted;
 pub mod public;
+
+use crate::error::{CryptError, Result};
+
+/// Append a TAG(1) + LEN(2) + VALUE(LEN) extension entry.
+pub(crate) fn push_extension(buf: &mut Vec<u8>, tag: u8, value: &[u8]) {
+    buf.push(tag);
+    buf.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    buf.extend_from_slice(value);
+}
+
+/// Split an extension area into `(tag, value)` entries.
+///
+/// Duplicate tags are rejected; interpreting the tags is up to the
+/// caller.
+pub(crate) fn parse_extensions(mut ext: &[u8]) -> Result<Vec<(u8, &[u8])>> {
+    let mut entries: Vec<(u8, &[u8])> = Vec::new();
+    while !ext.is_empty() {
+        if ext.len() < 3 {
+            return Err(CryptError::InvalidHeader(
+                "truncated header extension".to_string(),
+            ));
+        }
+        let tag = ext[0];
+        let len = u16::from_le_bytes([ext[1], ext[2]]) as usize;
+        let value = ext.get(3..3 + len).ok_or_else(|| {
+            CryptError::InvalidHeader(format!(
+                "header extension 0x{tag:02x} overruns the extension area"
+            ))
+        })?;
+        if entries.iter().any(|(t, _)| *t == tag) {
+            return Err(CryptError::InvalidHeader(format!(
+                "duplicate header extension 0x{tag:02x}"
+            )));
+        }
+        entries.push((tag, value));
+        ext = &ext[3 + len..];
+    }
+    Ok(entries)
+}
@@ -1,12 +1,23 @@
 use std::io::{Read, Write};
 
 use crate::config::{
-    MAGIC, MAX_ARGON2_M_COST_KIB, MAX_ARGON2_PARALLELISM,
-    MAX_ARGON2_T_COST, MAX_CHUNK_SIZE, MAX_ENCRYPTED_META_LEN,
-    MIN_CHUNK_SIZE, NONCE_LEN, SALT_LEN, VERSION,
+    HEADER_EXT_CIPHER, HEADER_EXT_FEC, HEADER_EXT_KEY_COMMITMENT,
+    HEADER_EXT_LEN_SIZE, HEADER_EXT_RECIPIENT, HEADER_EXT_SIGNATURE,
+    HEADER_EXT_VOLUMES,
+    KEY_COMMITMENT_LEN, MAGIC, MAX_ARGON2_M_COST_KIB,
+    MAX_ARGON2_PARALLELISM, MAX_ARGON2_T_COST, MAX_CHUNK_SIZE,
+    MAX_ENCRYPTED_META_LEN, MAX_HEADER_EXT_LEN, MIN_CHUNK_SIZE,
+    MIN_SUPPORTED_VERSION, NONCE_LEN, PUBLIC_HEADER_SIZE, SALT_LEN,
+    VERSION,
 };
+use crate::crypto::cipher::CipherSuite;
 use crate::crypto::kdf::Argon2Params;
+use crate::crypto::recipient::RecipientStanza;
+use crate::crypto::signature::SignatureAlgorithm;
 use crate::error::{CryptError, Result};
+use crate::fec::FecParams;
+use crate::header::{parse_extensions, push_extension};
+use crate::volume::VolumeSet;
 
 /// Public (unencrypted) portion of the file header.
 ///
@@ -21,6 +32,24 @@ pub struct PublicHeader {
     pub data_base_nonce: [u8; NONCE_LEN],
     pub header_nonce: [u8; NONCE_LEN],
     pub encrypted_meta_len: u32,
+    /// Reed–Solomon parity parameters (version 2+ extension).
+    pub fec: Option<FecParams>,
+    /// Signature scheme of the trailer appended after the payload
+    /// (version 2+ extension). The signer key is in the metadata.
+    pub signature: Option<SignatureAlgorithm>,
+    /// AEAD of the metadata and the chunks. Stored as an extension
+    /// (version 2+) unless it is the default XChaCha20-Poly1305.
+    pub cipher: CipherSuite,
+    /// Commitment to the header key (version 2+ extension), checked
+    /// before the metadata is opened. Required from version 2 on;
+    /// only version 1 files have none.
+    pub key_commitment: Option<[u8; KEY_COMMITMENT_LEN]>,
+    /// File key encapsulated to a recipient key (version 2+
+    /// extension). When present, the salt and Argon2 parameters are
+    /// unused.
+    pub recipient: Option<RecipientStanza>,
+    /// Volume set the file is split into (version 2+ extension).
+    pub volumes: Option<VolumeSet>,
 }
 
 impl PublicHeader {
@@ -36,17 +65,122 @@ impl PublicHeader {
         w.write_all(&self.data_base_nonce)?;
         w.write_all(&self.header_nonce)?;
         w.write_all(&self.encrypted_meta_len.to_le_bytes())?;
+
+        let extensions = self.extensions_to_bytes();
+        if self.version < 2 {
+            if !extensions.is_empty() {
+                return Err(CryptError::InvalidHeader(format!(
+                    "format version {} has no extension area",
+                    self.version
+                )));
+            }
+            return Ok(());
+        }
+        w.write_all(&(extensions.len() as u32).to_le_bytes())?;
+        w.write_all(&extensions)?;
         Ok(())
     }
 
     /// Serialize the public header to a byte vector (for AAD).
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(128);
-        // write_to always succeeds on a Vec
-        self.write_to(&mut buf).expect("Vec write failed");
+        let mut buf = Vec::with_capacity(self.encoded_len());
+        // write_to only fails on a Vec for version 1 headers that
+        // carry extensions, which the engine never builds.
+        self.write_to(&mut buf)
+            .expect("header extensions require format version 2");
+        buf
+    }
+
+    /// Length of the serialized header in bytes.
+    pub fn encoded_len(&self) -> usize {
+        if self.version < 2 {
+            PUBLIC_HEADER_SIZE
+        } else {
+            PUBLIC_HEADER_SIZE
+                + HEADER_EXT_LEN_SIZE
+                + self.extensions_to_bytes().len()
+        }
+    }
+
+    /// Encode the optional fields as TAG(1) + LEN(2) + VALUE entries.
+    fn extensions_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        if let Some(fec) = &self.fec {
+            push_extension(
+                &mut buf,
+                HEADER_EXT_FEC,
+                &[fec.data_shards, fec.parity_shards],
+            );
+        }
+        if let Some(alg) = self.signature {
+            push_extension(&mut buf, HEADER_EXT_SIGNATURE, &[alg.id()]);
+        }
+        if self.cipher != CipherSuite::default() {
+            push_extension(&mut buf, HEADER_EXT_CIPHER, &[self.cipher.id()]);
+        }
+        if let Some(commitment) = &self.key_commitment {
+            push_extension(&mut buf, HEADER_EXT_KEY_COMMITMENT, commitment);
+        }
+        if let Some(stanza) = &self.recipient {
+            push_extension(&mut buf, HEADER_EXT_RECIPIENT, &stanza.to_bytes());
+        }
+        if let Some(volumes) = &self.volumes {
+            push_extension(&mut buf, HEADER_EXT_VOLUMES, &volumes.to_bytes());
+        }
         buf
     }
 
+    /// Decode the extension area into the optional fields.
+    ///
+    /// Unknown tags are rejected: every extension changes how the
+    /// payload must be read, so skipping one is never safe.
+    fn read_extensions(&mut self, ext: &[u8]) -> Result<()> {
+        for (tag, value) in parse_extensions(ext)? {
+            match (tag, value) {
+                (HEADER_EXT_FEC, &[data_shards, parity_shards]) => {
+                    self.fec =
+                        Some(FecParams::new(data_shards, parity_shards)?);
+                }
+                (HEADER_EXT_SIGNATURE, &[alg]) => {
+                    self.signature = Some(SignatureAlgorithm::from_id(alg)?);
+                }
+                (HEADER_EXT_CIPHER, &[id]) => {
+                    self.cipher = CipherSuite::from_id(id)?;
+                }
+                (HEADER_EXT_KEY_COMMITMENT, value)
+                    if value.len() == KEY_COMMITMENT_LEN =>
+                {
+                    let mut commitment = [0u8; KEY_COMMITMENT_LEN];
+                    commitment.copy_from_slice(value);
+                    self.key_commitment = Some(commitment);
+                }
+                (HEADER_EXT_RECIPIENT, value) => {
+                    self.recipient = Some(RecipientStanza::from_bytes(value)?);
+                }
+                (HEADER_EXT_VOLUMES, value) => {
+                    self.volumes = Some(VolumeSet::from_bytes(value)?);
+                }
+                (
+                    HEADER_EXT_FEC
+                    | HEADER_EXT_SIGNATURE
+                    | HEADER_EXT_CIPHER
+                    | HEADER_EXT_KEY_COMMITMENT,
+                    _,
+                ) => {
+                    return Err(CryptError::InvalidHeader(format!(
+                        "malformed header extension 0x{tag:02x}"
+                    )));
+                }
+                _ => {
+                    return Err(CryptError::InvalidHeader(format!(
+                        "unknown header extension 0x{tag:02x}"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Deserialize a public header from a reader.
     ///
     /// Validates all fields against safety limits to prevent
@@ -63,7 +197,7 @@ impl PublicHeader {
         let mut buf2 = [0u8; 2];
         r.read_exact(&mut buf2)?;
         let version = u16::from_le_bytes(buf2);
-        if version != VERSION {
+        if !(MIN_SUPPORTED_VERSION..=VERSION).contains(&version) {
             return Err(CryptError::UnsupportedVersion(version));
         }
 
@@ -134,7 +268,7 @@ impl PublicHeader {
             )));
         }
 
-        Ok(Self {
+        let mut header = Self {
             version,
             salt,
             argon2_params: Argon2Params {
@@ -146,7 +280,30 @@ impl PublicHeader {
             data_base_nonce,
             header_nonce,
             encrypted_meta_len,
-        })
+            fec: None,
+            signature: None,
+            cipher: CipherSuite::default(),
+            key_commitment: None,
+            recipient: None,
+            volumes: None,
+        };
+
+        // Extension area (version 2+).
+        if version >= 2 {
+            r.read_exact(&mut buf4)?;
+            let ext_len = u32::from_le_bytes(buf4);
+            if ext_len > MAX_HEADER_EXT_LEN {
+                return Err(CryptError::InvalidHeader(format!(
+                    "extension length {ext_len} exceeds \
+                     max {MAX_HEADER_EXT_LEN}"
+                )));
+            }
+            let mut ext = vec![0u8; ext_len as usize];
+            r.read_exact(&mut ext)?;
+            header.read_extensions(&ext)?;
+        }
+
+        Ok(header)
     }
 }
 
@@ -168,6 +325,12 @@ mod tests {
             data_base_nonce: [0xBB; NONCE_LEN],
             header_nonce: [0xCC; NONCE_LEN],
             encrypted_meta_len: 256,
+            fec: None,
+            signature: None,
+            cipher: CipherSuite::default(),
+            key_commitment: None,
+            recipient: None,
+            volumes: None,
         }
     }
 
@@ -295,4 +458,124 @@ mod tests {
         h.write_to(&mut buf).unwrap();
         assert_eq!(bytes, buf);
     }
+
+    #[test]
+    fn test_fec_extension_round_trip() {
+        let mut h = sample_header();
+        h.fec = Some(FecParams::new(8, 3).unwrap());
+        let bytes = h.to_bytes();
+        assert_eq!(bytes.len(), h.encoded_len());
+
+        let mut cursor = Cursor::new(&bytes);
+        let parsed = PublicHeader::read_from(&mut cursor).unwrap();
+        assert_eq!(parsed.fec, h.fec);
+        assert_eq!(cursor.position() as usize, bytes.len());
+    }
+
+    #[test]
+    fn test_volumes_extension_round_trip() {
+        let mut h = sample_header();
+        h.volumes = Some(VolumeSet {
+            set_id: [0x5A; 16],
+            count: 7,
+        });
+        let bytes = h.to_bytes();
+        assert_eq!(bytes.len(), h.encoded_len());
+
+        let mut cursor = Cursor::new(&bytes);
+        let parsed = PublicHeader::read_from(&mut cursor).unwrap();
+        assert_eq!(parsed.volumes, h.volumes);
+    }
+
+    #[test]
+    fn test_signature_extension_round_trip() {
+        let mut h = sample_header();
+        h.fec = Some(FecParams::default());
+        h.signature = Some(SignatureAlgorithm::Ed25519);
+        let bytes = h.to_bytes();
+
+        let mut cursor = Cursor::new(&bytes);
+        let parsed = PublicHeader::read_from(&mut cursor).unwrap();
+        assert_eq!(parsed.signature, Some(SignatureAlgorithm::Ed25519));
+        assert_eq!(parsed.fec, h.fec);
+    }
+
+    #[test]
+    fn test_cipher_extension_round_trip() {
+        let mut h = sample_header();
+        assert_eq!(h.to_bytes().len(), PUBLIC_HEADER_SIZE + 4);
+
+        h.cipher = CipherSuite::Aes256GcmSiv;
+        let bytes = h.to_bytes();
+        assert_eq!(bytes.len(), PUBLIC_HEADER_SIZE + 4 + 4);
+
+        let mut cursor = Cursor::new(&bytes);
+        let parsed = PublicHeader::read_from(&mut cursor).unwrap();
+        assert_eq!(parsed.cipher, CipherSuite::Aes256GcmSiv);
+    }
+
+    #[test]
+    fn test_key_commitment_extension_round_trip() {
+        let mut h = sample_header();
+        h.key_commitment = Some([0x77; KEY_COMMITMENT_LEN]);
+        let bytes = h.to_bytes();
+
+        let mut cursor = Cursor::new(&bytes);
+        let parsed = PublicHeader::read_from(&mut cursor).unwrap();
+        assert_eq!(parsed.key_commitment, h.key_commitment);
+    }
+
+    #[test]
+    fn test_recipient_extension_round_trip() {
+        use crate::crypto::recipient::RecipientIdentity;
+
+        let (stanza, _) = RecipientIdentity::generate()
+            .public_key()
+            .encapsulate()
+            .unwrap();
+        let mut h = sample_header();
+        h.recipient = Some(stanza);
+        let bytes = h.to_bytes();
+        assert_eq!(bytes.len(), h.encoded_len());
+
+        let mut cursor = Cursor::new(&bytes);
+        let parsed = PublicHeader::read_from(&mut cursor).unwrap();
+        assert_eq!(parsed.recipient, h.recipient);
+    }
+
+    #[test]
+    fn test_version_1_header_still_readable() {
+        let mut h = sample_header();
+        h.version = 1;
+        let bytes = h.to_bytes();
+        assert_eq!(bytes.len(), PUBLIC_HEADER_SIZE);
+
+        let mut cursor = Cursor::new(&bytes);
+        let parsed = PublicHeader::read_from(&mut cursor).unwrap();
+        assert_eq!(parsed.version, 1);
+        assert!(parsed.fec.is_none());
+        assert_eq!(parsed.cipher, CipherSuite::XChaCha20Poly1305);
+    }
+
+    #[test]
+    fn test_version_1_rejects_extensions() {
+        let mut h = sample_header();
+        h.version = 1;
+        h.fec = Some(FecParams::default());
+        let mut buf = Vec::new();
+        assert!(h.write_to(&mut buf).is_err());
+    }
+
+    #[test]
+    fn test_unknown_extension_rejected() {
+        let mut buf = sample_header().to_bytes();
+        // Replace the empty extension area with an unknown tag.
+        buf.truncate(PUBLIC_HEADER_SIZE);
+        buf.extend_from_slice(&3u32.to_le_bytes());
+        buf.extend_from_slice(&[0xEE, 0, 0]);
+
+        let mut cursor = Cursor::new(&buf);
+        let result = PublicHeader::read_from(&mut cursor);
+        assert!(matches!(result, Err(CryptError::InvalidHeader(_))));
+    }
 }
@@ -0,0 +1,141 @@
+//! Crash safety for [`FileCrypt::update_metadata`], which rewrites
+//! the prefix of a file (public header and sealed metadata) in place.
+//!
+//! Before the prefix is overwritten, the old and the new prefix are
+//! written side by side to `<file>.journal` and synced. A crash while
+//! the file is written leaves each byte of its prefix old or new; the
+//! next operation that writes the file finishes the write from the
+//! journal and removes it. Read-only operations leave the file alone
+//! and refuse it while a journal is pending.
+//!
+//! [`FileCrypt::update_metadata`]: crate::engine::FileCrypt::update_metadata
+
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use tempfile::NamedTempFile;
+
+use crate::error::{CryptError, Result};
+
+fn journal_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(".journal");
+    PathBuf::from(name)
+}
+
+/// Replace the first `new.len()` bytes of `path` with `new`, so that
+/// the old bytes stay recoverable until the new ones are on disk.
+pub(crate) fn rewrite_prefix(path: &Path, new: &[u8]) -> Result<()> {
+    let mut old = vec![0u8; new.len()];
+    File::open(path)?.read_exact(&mut old)?;
+
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let mut journal = NamedTempFile::new_in(dir)?;
+    journal.write_all(&old)?;
+    journal.write_all(new)?;
+    journal.as_file().sync_all()?;
+    journal.persist(journal_path(path)).map_err(|e| {
+        CryptError::Io(io::Error::other(format!("Persist: {e}")))
+    })?;
+    sync_dir(dir)?;
+
+    finish(path, new)
+}
+
+/// Finish an interrupted [`rewrite_prefix`] of `path`, if any.
+pub(crate) fn recover(path: &Path) -> Result<()> {
+    let journal = match fs::read(journal_path(path)) {
+        Ok(journal) => journal,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+    let (old, new) = journal.split_at(journal.len() / 2);
+    let mut current = vec![0u8; new.len()];
+    File::open(path)?.read_exact(&mut current)?;
+    let torn = journal.len() % 2 == 0
+        && current
+            .iter()
+            .zip(old.iter().zip(new))
+            .all(|(byte, (old, new))| byte == old || byte == new);
+    if !torn {
+        return Err(CryptError::InvalidHeader(format!(
+            "{} does not belong to this file",
+            journal_path(path).display()
+        )));
+    }
+    finish(path, new)
+}
+
+/// Refuse to read `path` while an interrupted [`rewrite_prefix`] of
+/// it is pending, as its header may be half written.
+pub(crate) fn check(path: &Path) -> Result<()> {
+    let journal = journal_path(path);
+    match journal.try_exists()? {
+        true => Err(CryptError::InvalidHeader(format!(
+            "interrupted metadata update pending in {}; edit, rekey or \
+             repair the file to finish it",
+            journal.display()
+        ))),
+        false => Ok(()),
+    }
+}
+
+fn finish(path: &Path, new: &[u8]) -> Result<()> {
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    file.write_all(new)?;
+    file.sync_all()?;
+    fs::remove_file(journal_path(path))?;
+    Ok(())
+}
+
+/// Make a rename in `dir` durable.
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> Result<()> {
+    File::open(dir)?.sync_all()?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_torn_rewrite_is_finished_on_recovery() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("file.enc");
+        fs::write(&path, b"old-prefix|payload").unwrap();
+
+        rewrite_prefix(&path, b"new-prefix").unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"new-prefix|payload");
+        assert!(!journal_path(&path).exists());
+
+        // A crash halfway through the in-place write.
+        fs::write(journal_path(&path), b"new-prefixNEW-PREFIX").unwrap();
+        fs::write(&path, b"NEW-prefix|payload").unwrap();
+        assert!(matches!(check(&path), Err(CryptError::InvalidHeader(_))));
+        assert_eq!(fs::read(&path).unwrap(), b"NEW-prefix|payload");
+        recover(&path).unwrap();
+        check(&path).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"NEW-PREFIX|payload");
+        assert!(!journal_path(&path).exists());
+        recover(&path).unwrap();
+
+        // A journal left by another file is not applied.
+        fs::write(journal_path(&path), b"aaaaaaaaaabbbbbbbbbb").unwrap();
+        assert!(matches!(
+            recover(&path),
+            Err(CryptError::InvalidHeader(_))
+        ));
+        assert_eq!(fs::read(&path).unwrap(), b"NEW-PREFIX|payload");
+    }
+}
@@ -0,0 +1,306 @@
+use crate::config::TAG_LEN;
+use crate::error::{CryptError, Result};
+use crate::fec::FecParams;
+
+/// Chunk layout of the encrypted payload (everything after the
+/// sealed metadata).
+///
+/// Every encrypted chunk is `chunk_size + TAG_LEN` bytes except the
+/// last one. With a parity layer, each group of `data_shards` chunks
+/// is followed by `parity_shards` full-size parity shards; the final
+/// group may hold fewer data chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayloadLayout {
+    /// Encrypted chunk size (`chunk_size + TAG_LEN`).
+    pub encrypted_chunk_len: usize,
+    /// Optional Reed–Solomon parameters.
+    pub fec: Option<FecParams>,
+    /// Number of encrypted data chunks.
+    pub chunk_count: u64,
+    /// Encrypted length of the final data chunk (0 if no chunks).
+    pub last_chunk_len: usize,
+}
+
+/// Data chunks belonging to one payload group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkGroup {
+    /// Index of the first data chunk in the group.
+    pub first_chunk: u64,
+    /// Number of data chunks in the group.
+    pub data_chunks: usize,
+    /// Number of parity shards following the data chunks.
+    pub parity_shards: usize,
+}
+
+impl PayloadLayout {
+    /// Derive the layout from the payload length stored on disk.
+    ///
+    /// Fails if the length cannot be produced by whole chunks (and
+    /// parity groups), which indicates truncation or garbage.
+    pub fn from_payload_len(
+        payload_len: u64,
+        chunk_size: u32,
+        fec: Option<FecParams>,
+    ) -> Result<Self> {
+        let enc = chunk_size as u64 + TAG_LEN as u64;
+
+        if let Some(p) = fec {
+            p.validate_for_chunk_size(chunk_size)?;
+        }
+
+        let (full_groups, data_len, chunks_per_group) = match fec {
+            None => (0, payload_len, 0),
+            Some(p) => {
+                let group_bytes = enc * p.group_len() as u64;
+                let full = payload_len / group_bytes;
+                let rem = payload_len % group_bytes;
+                let parity_bytes = enc * p.parity_shards as u64;
+                if rem != 0 && rem <= parity_bytes {
+                    return Err(layout_error(payload_len));
+                }
+                let data = rem.saturating_sub(parity_bytes);
+                (full, data, p.data_shards as u64)
+            }
+        };
+
+        let tail_chunks = data_len.div_ceil(enc);
+        let chunk_count = full_groups * chunks_per_group + tail_chunks;
+        let last_chunk_len = if data_len > 0 {
+            (data_len - (tail_chunks - 1) * enc) as usize
+        } else if chunk_count > 0 {
+            enc as usize
+        } else {
+            0
+        };
+
+        if chunk_count > 0 && last_chunk_len < TAG_LEN {
+            return Err(layout_error(payload_len));
+        }
+
+        Ok(Self {
+            encrypted_chunk_len: enc as usize,
+            fec,
+            chunk_count,
+            last_chunk_len,
+        })
+    }
+
+    /// Layout of the payload written for `plaintext_len` bytes.
+    pub fn for_plaintext_len(
+        plaintext_len: u64,
+        chunk_size: u32,
+        fec: Option<FecParams>,
+    ) -> Self {
+        let chunk_count = plaintext_len.div_ceil(chunk_size as u64);
+        let last_chunk_len = if chunk_count > 0 {
+            (plaintext_len - (chunk_count - 1) * chunk_size as u64)
+                as usize
+                + TAG_LEN
+        } else {
+            0
+        };
+        Self {
+            encrypted_chunk_len: chunk_size as usize + TAG_LEN,
+            fec,
+            chunk_count,
+            last_chunk_len,
+        }
+    }
+
+    /// Length of the payload on disk, parity shards included.
+    pub fn encoded_len(&self) -> u64 {
+        match self.group_count() {
+            0 => 0,
+            groups => {
+                self.group_offset(groups - 1) + self.group_len(groups - 1)
+            }
+        }
+    }
+
+    /// Number of data chunks per group (1 without a parity layer).
+    fn chunks_per_group(&self) -> u64 {
+        self.fec.map_or(1, |p| p.data_shards as u64)
+    }
+
+    /// Number of groups in the payload.
+    pub fn group_count(&self) -> u64 {
+        self.chunk_count.div_ceil(self.chunks_per_group())
+    }
+
+    /// Describe group `index`.
+    pub fn group(&self, index: u64) -> ChunkGroup {
+        let per_group = self.chunks_per_group();
+        let first_chunk = index * per_group;
+        let data_chunks =
+            per_group.min(self.chunk_count - first_chunk) as usize;
+        ChunkGroup {
+            first_chunk,
+            data_chunks,
+            parity_shards: self
+                .fec
+                .map_or(0, |p| p.parity_shards as usize),
+        }
+    }
+
+    /// Plaintext bytes carried by the data chunks.
+    pub fn plaintext_len(&self) -> u64 {
+        if self.chunk_count == 0 {
+            return 0;
+        }
+        let full = (self.chunk_count - 1)
+            * (self.encrypted_chunk_len - TAG_LEN) as u64;
+        full + (self.last_chunk_len - TAG_LEN) as u64
+    }
+
+    /// Encrypted length of data chunk `index`.
+    pub fn chunk_len(&self, index: u64) -> usize {
+        if index + 1 == self.chunk_count {
+            self.last_chunk_len
+        } else {
+            self.encrypted_chunk_len
+        }
+    }
+
+    /// Index of the group holding data chunk `index`.
+    pub fn group_of(&self, index: u64) -> u64 {
+        index / self.chunks_per_group()
+    }
+
+    /// Payload offset of the first data chunk of group `index`.
+    pub fn group_offset(&self, index: u64) -> u64 {
+        let shards = self.fec.map_or(1, |p| p.group_len() as u64);
+        index * shards * self.encrypted_chunk_len as u64
+    }
+
+    /// Encrypted length of group `index`, parity shards included.
+    pub fn group_len(&self, index: u64) -> u64 {
+        let group = self.group(index);
+        let data = (group.first_chunk
+            ..group.first_chunk + group.data_chunks as u64)
+            .map(|i| self.chunk_len(i) as u64)
+            .sum::<u64>();
+        data + (group.parity_shards * self.encrypted_chunk_len) as u64
+    }
+
+    /// Payload offset of data chunk `index`.
+    pub fn chunk_offset(&self, index: u64) -> u64 {
+        let group = self.group_of(index);
+        let within = index - group * self.chunks_per_group();
+        self.group_offset(group) + within * self.encrypted_chunk_len as u64
+    }
+}
+
+fn layout_error(payload_len: u64) -> CryptError {
+    CryptError::CorruptedData(format!(
+        "payload length {payload_len} does not match the chunk layout"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHUNK: u32 = 64;
+    const ENC: u64 = CHUNK as u64 + TAG_LEN as u64;
+
+    #[test]
+    fn test_plain_layout() {
+        let layout =
+            PayloadLayout::from_payload_len(2 * ENC + 20, CHUNK, None)
+                .unwrap();
+        assert_eq!(layout.chunk_count, 3);
+        assert_eq!(layout.last_chunk_len, 20);
+        assert_eq!(layout.group_count(), 3);
+        assert_eq!(layout.chunk_len(0), ENC as usize);
+        assert_eq!(layout.chunk_len(2), 20);
+        assert_eq!(
+            layout.plaintext_len(),
+            2 * CHUNK as u64 + 20 - TAG_LEN as u64
+        );
+    }
+
+    #[test]
+    fn test_layout_for_plaintext_len_round_trips() {
+        let fec = Some(FecParams::new(4, 2).unwrap());
+        for fec in [None, fec] {
+            for len in [0, 1, CHUNK as u64, 5 * CHUNK as u64 + 7, 4096] {
+                let layout =
+                    PayloadLayout::for_plaintext_len(len, CHUNK, fec);
+                assert_eq!(layout.plaintext_len(), len);
+                let parsed = PayloadLayout::from_payload_len(
+                    layout.encoded_len(),
+                    CHUNK,
+                    fec,
+                )
+                .unwrap();
+                assert_eq!(parsed, layout);
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_payload() {
+        let layout =
+            PayloadLayout::from_payload_len(0, CHUNK, None).unwrap();
+        assert_eq!(layout.chunk_count, 0);
+        assert_eq!(layout.group_count(), 0);
+        assert_eq!(layout.plaintext_len(), 0);
+    }
+
+    #[test]
+    fn test_fec_layout_with_partial_group() {
+        let fec = FecParams::new(4, 2).unwrap();
+        // One full group (4 data + 2 parity), then 2 data chunks
+        // (the last one short) + 2 parity.
+        let len = 6 * ENC + (ENC + 30) + 2 * ENC;
+        let layout =
+            PayloadLayout::from_payload_len(len, CHUNK, Some(fec))
+                .unwrap();
+        assert_eq!(layout.chunk_count, 6);
+        assert_eq!(layout.last_chunk_len, 30);
+        assert_eq!(layout.group_count(), 2);
+        assert_eq!(
+            layout.group(1),
+            ChunkGroup {
+                first_chunk: 4,
+                data_chunks: 2,
+                parity_shards: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_chunk_offsets_skip_parity() {
+        let fec = FecParams::new(4, 2).unwrap();
+        let len = 6 * ENC + (ENC + 30) + 2 * ENC;
+        let layout =
+            PayloadLayout::from_payload_len(len, CHUNK, Some(fec))
+                .unwrap();
+        assert_eq!(layout.chunk_offset(3), 3 * ENC);
+        assert_eq!(layout.group_of(4), 1);
+        assert_eq!(layout.chunk_offset(4), 6 * ENC);
+        assert_eq!(layout.chunk_offset(5), 7 * ENC);
+        assert_eq!(layout.group_len(0), 6 * ENC);
+        assert_eq!(layout.group_len(1), ENC + 30 + 2 * ENC);
+
+        let plain =
+            PayloadLayout::from_payload_len(2 * ENC + 20, CHUNK, None)
+                .unwrap();
+        assert_eq!(plain.chunk_offset(2), 2 * ENC);
+        assert_eq!(plain.group_len(2), 20);
+    }
+
+    #[test]
+    fn test_truncated_payload_rejected() {
+        // A trailing fragment shorter than a tag.
+        let result =
+            PayloadLayout::from_payload_len(ENC + 5, CHUNK, None);
+        assert!(matches!(result, Err(CryptError::CorruptedData(_))));
+
+        // Parity shards without any data chunk.
+        let fec = FecParams::new(4, 2).unwrap();
+        let result =
+            PayloadLayout::from_payload_len(2 * ENC, CHUNK, Some(fec));
+        assert!(matches!(result, Err(CryptError::CorruptedData(_))));
+    }
+}
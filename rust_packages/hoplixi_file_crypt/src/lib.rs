@@ -1,19 +1,51 @@
+#[cfg(feature = "async")]
+pub mod async_engine;
+#[cfg(feature = "async")]
+pub mod async_io;
 pub mod compress;
 pub mod cleanup;
 pub mod config;
 pub mod crypto;
 pub mod engine;
 pub mod error;
+pub mod fec;
 pub mod header;
+mod journal;
+pub mod layout;
+pub mod memory;
+pub mod padding;
 pub mod progress;
+pub mod random_access;
+pub mod shred;
+mod space;
 pub mod types;
+pub mod volume;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use engine::{
-    DecryptOptions, DecryptResult, EncryptOptions,
-    EncryptResult, FileCrypt,
+    DecryptOptions, DecryptResult, DirectoryFilter, EncryptOptions,
+    EncryptResult, FileCrypt, InspectResult, LinkPolicy,
+    MemoryDecryptResult, MetadataPatch, MetadataUpdateResult,
+    ReencryptParams, RekeyOptions, RekeyResult, RepairResult, ShredEntry,
+    ShredReport, SignatureStatus, SkipReason, SkippedEntry, SpacePlan,
+    StreamDecryptOptions, StreamDecryptResult, StreamEncryptOptions,
+    StreamEncryptResult, VerifyResult,
 };
+#[cfg(feature = "async")]
+pub use async_engine::AsyncFileCrypt;
+#[cfg(feature = "async")]
+pub use async_io::{AsyncDecryptReader, AsyncEncryptWriter};
+pub use crypto::cipher::CipherSuite;
+pub use crypto::kdf::Argon2Params;
+pub use crypto::recipient::{
+    RecipientAlgorithm, RecipientIdentity, RecipientPublicKey,
+};
+pub use crypto::signature::{SignatureAlgorithm, SigningIdentity};
 pub use error::{CryptError, Result};
+pub use fec::FecParams;
 pub use header::encrypted::EncryptedMetadata;
+pub use random_access::RandomAccessReader;
 pub use progress::{
     ProgressCallback, ProgressEvent, ProgressStage,
 };
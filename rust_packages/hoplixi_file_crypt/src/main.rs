@@ -1,102 +1,331 @@
-use std::path::PathBuf;
+mod cli;
+
 use std::sync::Arc;
 
+use clap::Parser;
 use hoplixi_file_crypt::{
-    DecryptOptions, EncryptOptions, FileCrypt, ProgressEvent,
+    DecryptOptions, FileCrypt, ProgressCallback, ProgressEvent,
+    ReencryptParams, RekeyOptions, SignatureStatus,
 };
+use serde_json::json;
 
-#[tokio::main]
-async fn main() {
-    let args: Vec<String> = std::env::args().collect();
+use cli::args::{
+    Cli, Command, DecryptArgs, EncryptArgs, HeaderArgs, InspectArgs,
+    RekeyArgs, VerifyArgs,
+};
+use cli::password::PasswordSource;
+use cli::report::{self, Report};
+use cli::{batch, load_signer, pipe, CliError, EXIT_PARTIAL};
 
-    if args.len() < 4 {
-        eprintln!(
-            "Usage:\n  \
-             hoplixi-crypt encrypt <input> <output-dir> <password> [--gzip]\n  \
-             hoplixi-crypt decrypt <input.enc> <output-dir> <password>"
-        );
-        std::process::exit(1);
-    }
+fn main() {
+    let cli = Cli::parse();
 
-    let command = &args[1];
-    let input = PathBuf::from(&args[2]);
-    let output_dir = PathBuf::from(&args[3]);
-    let password = args[4].clone();
-
-    let progress: Option<hoplixi_file_crypt::ProgressCallback> =
-        Some(Arc::new(|event: ProgressEvent| {
-            if event.total_bytes > 0 {
-                println!(
-                    "[{:?}] {:.1}%  ({}/{})",
-                    event.stage,
-                    event.percentage(),
-                    event.bytes_processed,
-                    event.total_bytes
-                );
-            } else {
-                println!("[{:?}]", event.stage);
-            }
-        }));
-
-    let crypt = FileCrypt::default();
-
-    match command.as_str() {
-        "encrypt" => {
-            let gzip = args.iter().any(|a| a == "--gzip");
-
-            match crypt
-                .encrypt(EncryptOptions {
-                    input_path: input,
-                    output_dir,
-                    uuid: None,
-                    password,
-                    progress,
-                    gzip_compressed: gzip,
-                    output_extension: None,
-                    temp_dir: None,
-                    metadata: None,
-                })
-                .await
-            {
-                Ok(result) => {
-                    println!("Encrypted: {}", result.output_path.display());
-                    println!("UUID: {}", result.uuid);
-                    println!("Original size: {} bytes", result.original_size);
-                }
-                Err(e) => {
-                    eprintln!("Encryption failed: {e}");
-                    std::process::exit(1);
-                }
-            }
+    let progress = (!cli.json && !cli.quiet).then(progress_printer);
+    let data_on_stdout = pipe::writes_stdout(&cli.command);
+    let result = match cli.command {
+        Command::Encrypt(args) => encrypt(args, progress),
+        Command::Decrypt(args) => decrypt(args, progress),
+        Command::Inspect(args) => inspect(args),
+        Command::Verify(args) => verify(args),
+        Command::Rekey(args) => rekey(args, progress),
+        Command::Header(args) => header(args),
+        Command::EncryptBatch(args) => {
+            batch::encrypt_batch(args, !cli.json && !cli.quiet)
         }
-        "decrypt" => {
-            match crypt
-                .decrypt(DecryptOptions {
-                    input_path: input,
-                    output_dir,
-                    password,
-                    progress,
-                    temp_dir: None,
-                })
-                .await
-            {
-                Ok(result) => {
-                    println!("Decrypted: {}", result.output_path.display());
-                    println!(
-                        "Original: {}.{}",
-                        result.metadata.original_filename,
-                        result.metadata.original_extension
-                    );
-                }
-                Err(e) => {
-                    eprintln!("Decryption failed: {e}");
-                    std::process::exit(1);
-                }
+        Command::DecryptBatch(args) => {
+            batch::decrypt_batch(args, !cli.json && !cli.quiet)
+        }
+    };
+
+    match result {
+        Ok(report) => {
+            let code = report.exit_code();
+            report.print(cli.json);
+            if code != 0 {
+                std::process::exit(code);
             }
         }
-        _ => {
-            eprintln!("Unknown command: {command}. Use 'encrypt' or 'decrypt'.");
-            std::process::exit(1);
+        Err(e) => {
+            report::print_error(&e, cli.json, data_on_stdout);
+            std::process::exit(e.exit_code);
         }
     }
 }
+
+/// Progress goes to stderr so stdout only carries the result.
+fn progress_printer() -> ProgressCallback {
+    Arc::new(|event: ProgressEvent| {
+        let mut line = format!(
+            "[{}/{} {:?}] {:.1}%",
+            (event.stage_index + 1).min(event.stage_count),
+            event.stage_count,
+            event.stage,
+            event.overall_percentage
+        );
+        if event.total_files > 0 {
+            line += &format!(
+                "  {}/{} files",
+                event.files_processed, event.total_files
+            );
+        }
+        if event.total_bytes > 0 {
+            line += &format!(
+                "  {}/{} bytes",
+                event.bytes_processed, event.total_bytes
+            );
+        } else if event.bytes_processed > 0 {
+            line += &format!("  {} bytes", event.bytes_processed);
+        }
+        if event.bytes_per_second > 0.0 {
+            line += &format!(
+                "  {:.1} MiB/s",
+                event.bytes_per_second / (1024.0 * 1024.0)
+            );
+        }
+        if let Some(eta) = event.eta_seconds.filter(|eta| *eta > 0.0) {
+            line += &format!("  ETA {eta:.0}s");
+        }
+        eprintln!("{line}");
+    })
+}
+
+fn encrypt(
+    args: EncryptArgs,
+    progress: Option<ProgressCallback>,
+) -> Result<Report, CliError> {
+    let Some(output_dir) =
+        pipe::output_dir(&args.input, args.output_dir.as_deref())
+            .filter(|_| !pipe::is_stdio(&args.input))
+    else {
+        return pipe::encrypt(args, progress);
+    };
+
+    let settings = args.settings;
+    let signer = load_signer(settings.sign_key.as_deref())?;
+    let password =
+        PasswordSource::from(&args.password).read("Password", true)?;
+
+    let mut opts = settings.options(
+        args.input,
+        output_dir,
+        password,
+        signer,
+        progress,
+    );
+    opts.uuid = args.uuid;
+    opts.filter = args.filter.filter();
+    let result = settings.engine().encrypt(opts)?;
+
+    let report = Report::new("encrypt")
+        .field("output_path", result.output_path.display().to_string())
+        .field("uuid", result.uuid.clone())
+        .field("original_size", result.original_size)
+        .field("shred", result.shred.as_ref().map(report::shred_json))
+        .field("skipped", report::skipped_json(&result.skipped))
+        .line(format!("Encrypted: {}", result.output_path.display()))
+        .line(format!("UUID: {}", result.uuid))
+        .line(format!("Original size: {} bytes", result.original_size));
+    let report = report::skipped_lines(report, &result.skipped);
+    Ok(match &result.shred {
+        Some(shred) if !shred.all_shredded() => {
+            report::shred_lines(report, shred).with_exit_code(EXIT_PARTIAL)
+        }
+        Some(shred) => report::shred_lines(report, shred),
+        None => report,
+    })
+}
+
+fn decrypt(
+    args: DecryptArgs,
+    progress: Option<ProgressCallback>,
+) -> Result<Report, CliError> {
+    let source = PasswordSource::from(&args.password);
+    pipe::check_password_source(&args.input, &source)?;
+    let password = source.read("Password", false)?;
+
+    let Some(output_dir) =
+        pipe::output_dir(&args.input, args.output_dir.as_deref())
+    else {
+        return pipe::decrypt_to_stdout(&args.input, password, progress);
+    };
+
+    // Standard input into a directory: spool, then decrypt the copy.
+    let spool = if pipe::is_stdio(&args.input) {
+        let dir = args.temp_dir.as_deref().unwrap_or(&output_dir);
+        Some(pipe::spool_stdin(dir)?)
+    } else {
+        None
+    };
+    let input_path = spool
+        .as_ref()
+        .map_or(args.input, |spool| spool.path().to_path_buf());
+
+    let result = FileCrypt::default()
+        .decrypt(DecryptOptions {
+            input_path,
+            output_dir,
+            password,
+            progress,
+            temp_dir: args.temp_dir,
+            identity: None,
+        })?;
+
+    let report = Report::new("decrypt")
+        .field("output_path", result.output_path.display().to_string())
+        .field("metadata", report::metadata_json(&result.metadata))
+        .field("signature", report::signature_json(&result.signature))
+        .line(format!("Decrypted: {}", result.output_path.display()));
+    Ok(report::metadata_lines(report, &result.metadata)
+        .line(report::signature_text(&result.signature)))
+}
+
+fn inspect(args: InspectArgs) -> Result<Report, CliError> {
+    let info = FileCrypt::default().inspect(args.input)?;
+
+    let fec = info.fec.map(|fec| {
+        json!({
+            "data_shards": fec.data_shards,
+            "parity_shards": fec.parity_shards,
+        })
+    });
+    let signature = info.signature.map(|_| "ed25519");
+
+    let mut report = Report::new("inspect")
+        .field("version", info.version)
+        .field(
+            "argon2",
+            json!({
+                "t_cost": info.argon2_params.t_cost,
+                "m_cost_kib": info.argon2_params.m_cost_kib,
+                "parallelism": info.argon2_params.parallelism,
+            }),
+        )
+        .field("chunk_size", info.chunk_size)
+        .field("cipher", report::cipher_name(info.cipher))
+        .field("key_commitment", info.key_commitment)
+        .field("recipient", info.recipient.map(|_| "x25519-mlkem768"))
+        .field("fec", fec)
+        .field("signature", signature)
+        .field("file_len", info.file_len)
+        .field("ciphertext_len", info.ciphertext_len)
+        .field("chunk_count", info.chunk_count)
+        .field("estimated_plaintext_size", info.estimated_plaintext_size)
+        .field("whole_chunks", info.whole_chunks)
+        .line(format!("Format version: {}", info.version))
+        .line(format!(
+            "Argon2id: t={} m={} KiB p={}",
+            info.argon2_params.t_cost,
+            info.argon2_params.m_cost_kib,
+            info.argon2_params.parallelism
+        ))
+        .line(format!("Chunk size: {} bytes", info.chunk_size))
+        .line(format!("Cipher: {}", report::cipher_name(info.cipher)))
+        .line(format!(
+            "Key commitment: {}",
+            if info.key_commitment { "yes" } else { "no" }
+        ))
+        .line(match info.recipient {
+            Some(_) => "Key: recipient (X25519 + ML-KEM-768)",
+            None => "Key: password (Argon2id)",
+        })
+        .line(match info.fec {
+            Some(fec) => format!(
+                "FEC: {} data + {} parity",
+                fec.data_shards, fec.parity_shards
+            ),
+            None => "FEC: none".to_string(),
+        })
+        .line(format!("Signature: {}", signature.unwrap_or("none")))
+        .line(format!("File size: {} bytes", info.file_len))
+        .line(format!("Ciphertext: {} bytes", info.ciphertext_len));
+
+    report = if info.whole_chunks {
+        report
+            .line(format!("Chunks: {}", info.chunk_count))
+            .line(format!(
+                "Estimated payload: {} bytes",
+                info.estimated_plaintext_size
+            ))
+    } else {
+        report.line("Payload length does not match whole chunks (truncated?)")
+    };
+    Ok(report)
+}
+
+fn verify(args: VerifyArgs) -> Result<Report, CliError> {
+    let password =
+        PasswordSource::from(&args.password).read("Password", false)?;
+
+    let result = FileCrypt::default().verify(args.input, password)?;
+
+    Ok(Report::new("verify")
+        .field("chunk_count", result.chunk_count)
+        .field("repaired_chunks", result.repaired_chunks)
+        .field("metadata", report::metadata_json(&result.metadata))
+        .field("signature", report::signature_json(&result.signature))
+        .line(format!("OK: {} chunks authenticated", result.chunk_count))
+        .line(format!(
+            "Rebuilt from parity: {} chunks",
+            result.repaired_chunks
+        ))
+        .line(report::signature_text(&result.signature)))
+}
+
+fn rekey(
+    args: RekeyArgs,
+    progress: Option<ProgressCallback>,
+) -> Result<Report, CliError> {
+    let signer = load_signer(args.sign_key.as_deref())?;
+    let old_password =
+        PasswordSource::from(&args.password).read("Current password", false)?;
+    let new_password =
+        PasswordSource::from(&args.new_password).read("New password", true)?;
+    let re_signed = signer.is_some();
+
+    let result = FileCrypt::default()
+        .rekey(RekeyOptions {
+            input_path: args.input,
+            output_path: args.output,
+            old_password,
+            new_password,
+            params: ReencryptParams {
+                argon2_params: None,
+                chunk_size: args.chunk_size,
+            },
+            signer,
+            progress,
+        })?;
+
+    let signature_dropped = !re_signed
+        && matches!(result.source_signature, SignatureStatus::Valid { .. });
+
+    let mut report = Report::new("rekey")
+        .field("output_path", result.output_path.display().to_string())
+        .field("chunk_count", result.chunk_count)
+        .field(
+            "source_signature",
+            report::signature_json(&result.source_signature),
+        )
+        .field("signed", re_signed)
+        .line(format!("Rekeyed: {}", result.output_path.display()))
+        .line(format!("Chunks: {}", result.chunk_count));
+    if signature_dropped {
+        report = report.line(
+            "Warning: the original signature was dropped; \
+             pass --sign-key to re-sign",
+        );
+    }
+    Ok(report)
+}
+
+fn header(args: HeaderArgs) -> Result<Report, CliError> {
+    let password =
+        PasswordSource::from(&args.password).read("Password", false)?;
+
+    let metadata = FileCrypt::default()
+        .decrypt_header(args.input, password)?;
+
+    let report = Report::new("header")
+        .field("metadata", report::metadata_json(&metadata));
+    Ok(report::metadata_lines(report, &metadata))
+}
@@ -0,0 +1,524 @@
+//! Encryption and decryption of byte slices.
+//!
+//! Nothing here touches a file, a temp file, a thread or the clock,
+//! so this part of the crate builds for `wasm32-unknown-unknown`
+//! (the `wasm` feature adds JavaScript bindings on top of it). The
+//! output is the stream format of [`FileCrypt::encrypt_stream`]:
+//! a `.enc` file sealed in a browser opens on the desktop and the
+//! other way round.
+//!
+//! [`seal`] and [`unseal`] work on a whole file at once.
+//! [`ChunkEncryptor`] and [`ChunkDecryptor`] take the data in pieces
+//! of any size, for inputs that should not be held in memory twice.
+
+#[cfg(feature = "gzip")]
+use std::io::Write;
+
+#[cfg(feature = "gzip")]
+use flate2::write::{GzDecoder, GzEncoder};
+#[cfg(feature = "gzip")]
+use zeroize::Zeroize;
+use zeroize::Zeroizing;
+
+use crate::compress;
+use crate::config::{
+    HEADER_EXT_LEN_SIZE, MAGIC, MAX_ENCRYPTED_META_LEN, MAX_HEADER_EXT_LEN,
+    PUBLIC_HEADER_SIZE,
+};
+use crate::crypto::recipient::RecipientIdentity;
+use crate::crypto::signature::SigningIdentity;
+use crate::engine::{
+    self, FileCrypt, InspectResult, MemoryDecryptResult, PayloadDecoder,
+    PayloadWriter,
+};
+use crate::error::{CryptError, Result};
+use crate::header::encrypted::EncryptedMetadata;
+use crate::header::public::PublicHeader;
+use crate::types::{
+    SignatureStatus, StreamDecryptOptions, StreamEncryptOptions,
+};
+
+/// Length of the header and the sealed metadata at the start of
+/// `data`, or `None` while `data` is too short to tell.
+///
+/// Only the lengths are checked; the header itself is validated by
+/// [`ChunkDecryptor::new`].
+pub fn prefix_len(data: &[u8]) -> Result<Option<usize>> {
+    let magic = data.len().min(MAGIC.len());
+    if data[..magic] != MAGIC[..magic] {
+        return Err(CryptError::InvalidMagic);
+    }
+    if data.len() < PUBLIC_HEADER_SIZE {
+        return Ok(None);
+    }
+
+    let read_u32 = |at: usize| {
+        u32::from_le_bytes(data[at..at + 4].try_into().expect("4 bytes"))
+    };
+    let meta_len = read_u32(PUBLIC_HEADER_SIZE - 4);
+    if meta_len > MAX_ENCRYPTED_META_LEN {
+        return Err(CryptError::InvalidHeader(format!(
+            "metadata length {meta_len} exceeds max \
+             {MAX_ENCRYPTED_META_LEN}"
+        )));
+    }
+
+    let mut len = PUBLIC_HEADER_SIZE;
+    let version =
+        u16::from_le_bytes([data[MAGIC.len()], data[MAGIC.len() + 1]]);
+    if version >= 2 {
+        if data.len() < len + HEADER_EXT_LEN_SIZE {
+            return Ok(None);
+        }
+        let ext_len = read_u32(len);
+        if ext_len > MAX_HEADER_EXT_LEN {
+            return Err(CryptError::InvalidHeader(format!(
+                "extension length {ext_len} exceeds \
+                 max {MAX_HEADER_EXT_LEN}"
+            )));
+        }
+        len += HEADER_EXT_LEN_SIZE + ext_len as usize;
+    }
+    Ok(Some(len + meta_len as usize))
+}
+
+/// Describe an encrypted file held in `data` from its public header
+/// alone; see [`FileCrypt::inspect`].
+pub fn inspect(data: &[u8]) -> Result<InspectResult> {
+    let header = PublicHeader::read_from(&mut &data[..])?;
+    Ok(engine::inspect_header(&header, data.len() as u64))
+}
+
+/// Encrypt `data` with `engine`'s settings into a complete file.
+///
+/// Options are those of [`FileCrypt::encrypt_stream`]; progress is
+/// reported as there.
+pub fn seal(
+    engine: &FileCrypt,
+    data: &[u8],
+    opts: StreamEncryptOptions,
+) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    engine.encrypt_stream(data, &mut out, opts)?;
+    Ok(out)
+}
+
+/// Decrypt a complete encrypted file held in `data`.
+pub fn unseal(
+    data: &[u8],
+    opts: StreamDecryptOptions,
+) -> Result<MemoryDecryptResult> {
+    let mut out = Vec::new();
+    let result = FileCrypt::default().decrypt_stream(data, &mut out, opts)?;
+    Ok(MemoryDecryptResult {
+        data: out,
+        metadata: result.metadata,
+        signature: result.signature,
+    })
+}
+
+fn already_finished() -> CryptError {
+    CryptError::Encryption("the stream is already finished".to_string())
+}
+
+/// Encrypts plaintext handed over in pieces of any size.
+///
+/// [`ChunkEncryptor::prefix`] is the start of the file; every
+/// [`push`](ChunkEncryptor::push) returns the chunks completed by
+/// its input, and [`finish`](ChunkEncryptor::finish) the last, short
+/// chunk, parity and the signature. Their concatenation is the same
+/// file [`FileCrypt::encrypt_stream`] writes for the same input.
+pub struct ChunkEncryptor {
+    #[cfg(feature = "gzip")]
+    gzip: Option<GzEncoder<Vec<u8>>>,
+    /// Plaintext (after gzip) not yet making up a whole chunk.
+    pending: Zeroizing<Vec<u8>>,
+    payload: PayloadWriter<Vec<u8>>,
+    chunk_size: usize,
+    prefix: Vec<u8>,
+    sealed_meta: Vec<u8>,
+    signer: Option<SigningIdentity>,
+    uuid: String,
+    finished: bool,
+}
+
+impl ChunkEncryptor {
+    /// Derive the keys and seal the header with `engine`'s settings.
+    ///
+    /// Options are those of [`FileCrypt::encrypt_stream`], except
+    /// that no progress is reported.
+    pub fn new(
+        engine: &FileCrypt,
+        mut opts: StreamEncryptOptions,
+    ) -> Result<Self> {
+        #[cfg(not(feature = "gzip"))]
+        if opts.gzip_compressed {
+            return Err(compress::unsupported("gzip"));
+        }
+        let (uuid, sealed) = engine.seal_stream_header(&mut opts)?;
+
+        let mut prefix = sealed.header.to_bytes();
+        prefix.extend_from_slice(&sealed.sealed_meta);
+        Ok(Self {
+            #[cfg(feature = "gzip")]
+            gzip: opts
+                .gzip_compressed
+                .then(|| compress::gzip::compressing_writer(Vec::new())),
+            pending: Zeroizing::new(Vec::new()),
+            payload: PayloadWriter::new(
+                Vec::new(),
+                &sealed.header,
+                &sealed.keys.data_key,
+                &uuid,
+                opts.signer.is_some(),
+            ),
+            chunk_size: sealed.header.chunk_size as usize,
+            prefix,
+            sealed_meta: sealed.sealed_meta,
+            signer: opts.signer,
+            uuid,
+            finished: false,
+        })
+    }
+
+    /// Public header and sealed metadata: the first bytes of the file.
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    /// UUID recorded in the encrypted metadata.
+    pub fn uuid(&self) -> &str {
+        &self.uuid
+    }
+
+    /// Plaintext bytes per chunk.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Encrypt every whole chunk of `data` and what was pending.
+    pub fn push(&mut self, data: &[u8]) -> Result<Vec<u8>> {
+        if self.finished {
+            return Err(already_finished());
+        }
+        #[cfg(feature = "gzip")]
+        if let Some(gz) = self.gzip.as_mut() {
+            gz.write_all(data)?;
+            self.pending.extend_from_slice(gz.get_mut());
+            gz.get_mut().zeroize();
+        } else {
+            self.pending.extend_from_slice(data);
+        }
+        #[cfg(not(feature = "gzip"))]
+        self.pending.extend_from_slice(data);
+        self.write_whole_chunks()?;
+        Ok(std::mem::take(self.payload.get_mut()))
+    }
+
+    /// Encrypt the rest, the final parity group and the signature.
+    pub fn finish(&mut self) -> Result<Vec<u8>> {
+        if std::mem::replace(&mut self.finished, true) {
+            return Err(already_finished());
+        }
+        #[cfg(feature = "gzip")]
+        if let Some(mut gz) = self.gzip.take() {
+            gz.try_finish()?;
+            self.pending.extend_from_slice(gz.get_mut());
+            gz.get_mut().zeroize();
+        }
+        self.write_whole_chunks()?;
+        if !self.pending.is_empty() {
+            self.payload.write_chunk(&self.pending)?;
+            self.pending.clear();
+        }
+        self.payload.finish(&self.sealed_meta, self.signer.as_ref())?;
+        Ok(std::mem::take(self.payload.get_mut()))
+    }
+
+    fn write_whole_chunks(&mut self) -> Result<()> {
+        let whole = self.pending.len() / self.chunk_size * self.chunk_size;
+        for chunk in self.pending[..whole].chunks(self.chunk_size) {
+            self.payload.write_chunk(chunk)?;
+        }
+        self.pending.drain(..whole);
+        Ok(())
+    }
+}
+
+/// Decrypts an encrypted file handed over in pieces of any size.
+///
+/// Created from the [`prefix_len`] first bytes of the file; the rest
+/// goes through [`push`](ChunkDecryptor::push), which returns the
+/// plaintext of every parity group (or chunk) known not to be the
+/// last. As with [`FileCrypt::decrypt_stream`], only
+/// [`finish`](ChunkDecryptor::finish) authenticates the end of the
+/// file and the signature; on error, discard the output.
+pub struct ChunkDecryptor {
+    payload: PayloadDecoder,
+    #[cfg(feature = "gzip")]
+    gzip: Option<GzDecoder<Vec<u8>>>,
+    finished: bool,
+}
+
+impl ChunkDecryptor {
+    /// Derive the keys from `prefix`, the header and the sealed
+    /// metadata, and decrypt the metadata.
+    pub fn new(prefix: &[u8], opts: StreamDecryptOptions) -> Result<Self> {
+        if prefix_len(prefix)? != Some(prefix.len()) {
+            return Err(CryptError::InvalidHeader(
+                "expected exactly the header and the sealed metadata"
+                    .to_string(),
+            ));
+        }
+        let mut reader = prefix;
+        let header = PublicHeader::read_from(&mut reader)?;
+        Self::from_parts(
+            header,
+            reader.to_vec(),
+            opts.password,
+            opts.identity.as_ref(),
+        )
+    }
+
+    pub(crate) fn from_parts(
+        header: PublicHeader,
+        sealed_meta: Vec<u8>,
+        password: String,
+        identity: Option<&RecipientIdentity>,
+    ) -> Result<Self> {
+        let (keys, metadata) =
+            engine::unlock(&header, &sealed_meta, password, identity)?;
+        #[cfg(not(feature = "gzip"))]
+        if metadata.gzip_compressed {
+            return Err(compress::unsupported("gzip"));
+        }
+
+        Ok(Self {
+            #[cfg(feature = "gzip")]
+            gzip: metadata
+                .gzip_compressed
+                .then(|| compress::gzip::decompressing_writer(Vec::new())),
+            payload: PayloadDecoder::new(header, sealed_meta, keys, metadata)?,
+            finished: false,
+        })
+    }
+
+    /// Metadata from the encrypted header.
+    pub fn metadata(&self) -> &EncryptedMetadata {
+        self.payload.metadata()
+    }
+
+    /// Decrypt the groups of `data` known not to be the last.
+    pub fn push(&mut self, data: &[u8]) -> Result<Vec<u8>> {
+        if self.finished {
+            return Err(already_finished());
+        }
+        #[cfg(feature = "gzip")]
+        if let Some(gz) = self.gzip.as_mut() {
+            self.payload.push(data, gz)?;
+            return Ok(std::mem::take(gz.get_mut()));
+        }
+        let mut out = Vec::new();
+        self.payload.push(data, &mut out)?;
+        Ok(out)
+    }
+
+    /// Decrypt the final group and check the signature.
+    pub fn finish(&mut self) -> Result<(Vec<u8>, SignatureStatus)> {
+        if std::mem::replace(&mut self.finished, true) {
+            return Err(already_finished());
+        }
+        #[cfg(feature = "gzip")]
+        if let Some(gz) = self.gzip.as_mut() {
+            let signature = self.payload.finish(gz)?;
+            gz.try_finish()?;
+            return Ok((std::mem::take(gz.get_mut()), signature));
+        }
+        let mut out = Vec::new();
+        let signature = self.payload.finish(&mut out)?;
+        Ok((out, signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::crypto::kdf::Argon2Params;
+    use crate::fec::FecParams;
+    use crate::{DecryptOptions, EncryptOptions};
+
+    fn fast_crypt() -> FileCrypt {
+        FileCrypt::new(
+            256,
+            Argon2Params {
+                t_cost: 1,
+                m_cost_kib: 64,
+                parallelism: 1,
+            },
+        )
+    }
+
+    fn encrypt_opts(
+        gzip: bool,
+        fec: Option<FecParams>,
+    ) -> StreamEncryptOptions {
+        StreamEncryptOptions {
+            original_filename: "photo".to_string(),
+            original_extension: "jpg".to_string(),
+            uuid: None,
+            password: "web-pass".to_string(),
+            progress: None,
+            gzip_compressed: gzip,
+            metadata: None,
+            fec,
+            signer: None,
+            recipient: None,
+        }
+    }
+
+    fn decrypt_opts(password: &str) -> StreamDecryptOptions {
+        StreamDecryptOptions {
+            password: password.to_string(),
+            progress: None,
+            identity: None,
+        }
+    }
+
+    /// Feed `encrypted` to a [`ChunkDecryptor`] in `piece`-byte parts.
+    fn decrypt_in_pieces(encrypted: &[u8], piece: usize) -> Result<Vec<u8>> {
+        let len = prefix_len(encrypted)?.expect("whole prefix");
+        let mut decryptor =
+            ChunkDecryptor::new(&encrypted[..len], decrypt_opts("web-pass"))?;
+        let mut plain = Vec::new();
+        for part in encrypted[len..].chunks(piece) {
+            plain.extend(decryptor.push(part)?);
+        }
+        plain.extend(decryptor.finish()?.0);
+        Ok(plain)
+    }
+
+    #[test]
+    fn test_chunk_api_matches_streams() {
+        let data: Vec<u8> = (0..3000).map(|i| (i % 199) as u8).collect();
+        let fec = Some(FecParams::new(4, 2).unwrap());
+        for (gzip, fec) in [(false, None), (true, None), (false, fec)] {
+            if gzip && !cfg!(feature = "gzip") {
+                continue;
+            }
+
+            let mut encryptor =
+                ChunkEncryptor::new(&fast_crypt(), encrypt_opts(gzip, fec))
+                    .unwrap();
+            let mut encrypted = encryptor.prefix().to_vec();
+            for piece in data.chunks(77) {
+                encrypted.extend(encryptor.push(piece).unwrap());
+            }
+            encrypted.extend(encryptor.finish().unwrap());
+            assert!(encryptor.push(b"late").is_err());
+
+            let plain = unseal(&encrypted, decrypt_opts("web-pass")).unwrap();
+            assert_eq!(plain.data, data);
+            assert_eq!(plain.metadata.uuid, encryptor.uuid());
+
+            let sealed =
+                seal(&fast_crypt(), &data, encrypt_opts(gzip, fec)).unwrap();
+            for piece in [1, 100, 5000] {
+                assert_eq!(decrypt_in_pieces(&sealed, piece).unwrap(), data);
+            }
+        }
+    }
+
+    #[test]
+    fn test_interop_with_files() {
+        let dir = TempDir::new().unwrap();
+        let data = b"browser and desktop ".repeat(40);
+
+        // Sealed in memory, decrypted from disk.
+        let sealed = seal(&fast_crypt(), &data, encrypt_opts(false, None))
+            .unwrap();
+        let enc_path = dir.path().join("photo.enc");
+        fs::write(&enc_path, &sealed).unwrap();
+        let out_dir = dir.path().join("out");
+        fs::create_dir(&out_dir).unwrap();
+        let result = fast_crypt()
+            .decrypt(DecryptOptions {
+                input_path: enc_path,
+                output_dir: out_dir,
+                password: "web-pass".to_string(),
+                progress: None,
+                temp_dir: None,
+                identity: None,
+            })
+            .unwrap();
+        assert_eq!(fs::read(result.output_path).unwrap(), data);
+
+        // Encrypted from disk, unsealed in memory.
+        let input = dir.path().join("notes.txt");
+        fs::write(&input, &data).unwrap();
+        let enc = fast_crypt()
+            .encrypt(EncryptOptions {
+                input_path: input,
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "web-pass".to_string(),
+                progress: None,
+                gzip_compressed: false,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec: None,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: true,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap();
+        let bytes = fs::read(&enc.output_path).unwrap();
+        let plain = unseal(&bytes, decrypt_opts("web-pass")).unwrap();
+        assert_eq!(plain.data, data);
+        assert_eq!(plain.metadata.original_filename, "notes");
+
+        let inspected = inspect(&bytes).unwrap();
+        let on_disk = fast_crypt().inspect(enc.output_path).unwrap();
+        assert_eq!(inspected.file_len, on_disk.file_len);
+        assert_eq!(inspected.chunk_count, on_disk.chunk_count);
+    }
+
+    #[test]
+    fn test_prefix_len_and_errors() {
+        let sealed = seal(&fast_crypt(), b"secret", encrypt_opts(false, None))
+            .unwrap();
+        let len = prefix_len(&sealed).unwrap().unwrap();
+        let header = PublicHeader::read_from(&mut &sealed[..]).unwrap();
+        assert_eq!(
+            len,
+            header.encoded_len() + header.encrypted_meta_len as usize
+        );
+        for short in [0, 3, PUBLIC_HEADER_SIZE - 1, PUBLIC_HEADER_SIZE] {
+            assert_eq!(prefix_len(&sealed[..short]).unwrap(), None);
+        }
+        assert!(matches!(
+            prefix_len(b"NOTHOPLIXI"),
+            Err(CryptError::InvalidMagic)
+        ));
+
+        assert!(ChunkDecryptor::new(&sealed[..len - 1], decrypt_opts("x"))
+            .is_err());
+        assert!(matches!(
+            ChunkDecryptor::new(&sealed[..len], decrypt_opts("wrong")),
+            Err(CryptError::InvalidPassword)
+        ));
+
+        let mut tampered = sealed.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        assert!(decrypt_in_pieces(&tampered, 64).is_err());
+        assert!(decrypt_in_pieces(&sealed[..sealed.len() - 5], 64).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+//! Length-hiding padding.
+//!
+//! Without it the file size gives away the exact plaintext size and
+//! the metadata length the length of the filename. Padded files round
+//! the metadata up to a power-of-two bucket and the payload with
+//! PADMÉ, which leaks at most O(log log n) bits of the size while
+//! adding no more than about 12 % overhead.
+
+use crate::config::META_BUCKET_MIN_LEN;
+
+/// PADMÉ length of a payload of `len` bytes: `len` rounded up so
+/// that only the top `⌊log₂ E⌋ + 1` bits of the exponent `E` remain
+/// significant.
+pub fn padme(len: u64) -> u64 {
+    if len < 2 {
+        return len;
+    }
+    let exponent = len.ilog2();
+    let exponent_bits = exponent.ilog2() + 1;
+    let mask = (1u64 << (exponent - exponent_bits)) - 1;
+    (len + mask) & !mask
+}
+
+/// Bucket that `len` bytes of metadata plaintext are padded to.
+pub fn meta_bucket(len: usize) -> usize {
+    len.next_power_of_two().max(META_BUCKET_MIN_LEN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_padme() {
+        // Small sizes are left alone.
+        for len in 0..=8 {
+            assert_eq!(padme(len), len);
+        }
+        assert_eq!(padme(9), 10);
+        assert_eq!(padme(1000), 1024);
+        assert_eq!(padme(1 << 20), 1 << 20);
+        assert_eq!(padme((1 << 20) + 1), (1 << 20) + (1 << 15));
+
+        for len in [100u64, 12_345, 987_654_321, u32::MAX as u64] {
+            let padded = padme(len);
+            assert!(padded >= len);
+            assert!(padded - len <= len / 8);
+            assert_eq!(padme(padded), padded);
+        }
+    }
+
+    #[test]
+    fn test_meta_bucket() {
+        assert_eq!(meta_bucket(1), META_BUCKET_MIN_LEN);
+        assert_eq!(meta_bucket(META_BUCKET_MIN_LEN), META_BUCKET_MIN_LEN);
+        assert_eq!(meta_bucket(1500), 2048);
+    }
+}
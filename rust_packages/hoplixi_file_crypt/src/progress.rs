@@ -1,4 +1,8 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::compress::CompressProgress;
+use crate::config::PROGRESS_MIN_INTERVAL_MS;
 
 /// Stage of the encryption/decryption pipeline.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -28,20 +32,49 @@ pub struct ProgressEvent {
     pub bytes_processed: u64,
     /// Total bytes expected (0 if unknown).
     pub total_bytes: u64,
+    /// Files added to / extracted from a 7z archive so far.
+    pub files_processed: u64,
+    /// Files in the archive (0 outside the directory stages).
+    pub total_files: u64,
+    /// Throughput of the current stage.
+    pub bytes_per_second: f64,
+    /// Estimated time until the whole operation is done; `None`
+    /// until there is enough progress to extrapolate from.
+    pub eta_seconds: Option<f64>,
+    /// Completion of the whole operation across all its stages
+    /// (0.0 – 100.0).
+    pub overall_percentage: f64,
+    /// Position of the current stage in the operation (0-based).
+    pub stage_index: u32,
+    /// Number of stages the operation goes through (`Done` not
+    /// counted).
+    pub stage_count: u32,
 }
 
 impl ProgressEvent {
-    /// Create a new event.
+    /// Create a new event for a single-stage operation.
     pub fn new(
         stage: ProgressStage,
         bytes_processed: u64,
         total_bytes: u64,
     ) -> Self {
-        Self {
+        let mut event = Self {
             stage,
             bytes_processed,
             total_bytes,
-        }
+            files_processed: 0,
+            total_files: 0,
+            bytes_per_second: 0.0,
+            eta_seconds: None,
+            overall_percentage: 0.0,
+            stage_index: 0,
+            stage_count: 1,
+        };
+        event.overall_percentage = match stage {
+            ProgressStage::Done => 100.0,
+            _ => event.percentage(),
+        };
+        event
     }
 
     /// Percentage complete (0.0 – 100.0). Returns 0 if total is unknown.
@@ -55,3 +88,296 @@ impl ProgressEvent {
 
 /// Thread-safe progress callback type.
 pub type ProgressCallback = Arc<dyn Fn(ProgressEvent) + Send + Sync>;
+
+/// Turns raw stage updates into [`ProgressEvent`]s.
+///
+/// Measures throughput per stage, weighs the planned stages equally
+/// into an overall percentage, extrapolates the remaining time from
+/// it and throttles updates to one per
+/// [`PROGRESS_MIN_INTERVAL_MS`]. Cloning shares the state, so a
+/// clone can be moved into a blocking task.
+#[derive(Clone)]
+pub(crate) struct ProgressTracker {
+    state: Option<Arc<Mutex<TrackerState>>>,
+}
+
+struct TrackerState {
+    callback: ProgressCallback,
+    stages: Vec<ProgressStage>,
+    min_interval: Duration,
+    started: Instant,
+    current: Option<StageState>,
+    last_emit: Option<Instant>,
+}
+
+struct StageState {
+    stage: ProgressStage,
+    index: usize,
+    started: Instant,
+    total_bytes: u64,
+    total_files: u64,
+}
+
+impl ProgressTracker {
+    /// Tracker for an operation running through `stages` in order.
+    /// Without a callback every method is a no-op.
+    pub(crate) fn new(
+        callback: Option<ProgressCallback>,
+        stages: Vec<ProgressStage>,
+    ) -> Self {
+        Self::with_interval(
+            callback,
+            stages,
+            Duration::from_millis(PROGRESS_MIN_INTERVAL_MS),
+        )
+    }
+
+    fn with_interval(
+        callback: Option<ProgressCallback>,
+        stages: Vec<ProgressStage>,
+        min_interval: Duration,
+    ) -> Self {
+        let state = callback.map(|callback| {
+            Arc::new(Mutex::new(TrackerState {
+                callback,
+                stages,
+                min_interval,
+                started: Instant::now(),
+                current: None,
+                last_emit: None,
+            }))
+        });
+        Self { state }
+    }
+
+    /// Enter `stage`, expecting `total_bytes` (0 if unknown) and
+    /// `total_files` (archives only).
+    pub(crate) fn stage(
+        &self,
+        stage: ProgressStage,
+        total_bytes: u64,
+        total_files: u64,
+    ) {
+        self.with_state(|state| {
+            let index = state
+                .stages
+                .iter()
+                .position(|s| *s == stage)
+                .unwrap_or(state.stages.len().saturating_sub(1));
+            state.current = Some(StageState {
+                stage,
+                index,
+                started: Instant::now(),
+                total_bytes,
+                total_files,
+            });
+            state.emit(0, 0);
+        });
+    }
+
+    /// Report the bytes (and files) processed so far in the current
+    /// stage. Throttled, except for the update completing the stage.
+    pub(crate) fn update(&self, bytes: u64, files: u64) {
+        self.with_state(|state| {
+            let Some(current) = &state.current else {
+                return;
+            };
+            let complete = (current.total_bytes > 0
+                && bytes >= current.total_bytes)
+                || (current.total_files > 0 && files >= current.total_files);
+            let due = state
+                .last_emit
+                .is_none_or(|last| last.elapsed() >= state.min_interval);
+            if complete || due {
+                state.emit(bytes, files);
+            }
+        });
+    }
+
+    /// Forward the progress of a gzip or 7z step, whose totals are
+    /// only known once the compressor has looked at its input.
+    pub(crate) fn compress_update(&self, progress: CompressProgress) {
+        self.with_state(|state| {
+            if let Some(current) = &mut state.current {
+                current.total_bytes = progress.total_bytes;
+                current.total_files = progress.total_files;
+            }
+        });
+        self.update(progress.bytes, progress.files);
+    }
+
+    /// Emit the final [`ProgressStage::Done`] event.
+    pub(crate) fn done(&self) {
+        self.with_state(|state| {
+            let index = state.stages.len();
+            state.current = Some(StageState {
+                stage: ProgressStage::Done,
+                index,
+                started: Instant::now(),
+                total_bytes: 0,
+                total_files: 0,
+            });
+            state.emit(0, 0);
+        });
+    }
+
+    fn with_state(&self, f: impl FnOnce(&mut TrackerState)) {
+        if let Some(state) = &self.state {
+            // A panicking callback must not disable progress for
+            // the rest of the operation.
+            let mut guard =
+                state.lock().unwrap_or_else(|poison| poison.into_inner());
+            f(&mut guard);
+        }
+    }
+}
+
+impl TrackerState {
+    fn emit(&mut self, bytes: u64, files: u64) {
+        let Some(current) = &self.current else {
+            return;
+        };
+        let now = Instant::now();
+
+        let stage_fraction = if current.total_bytes > 0 {
+            bytes as f64 / current.total_bytes as f64
+        } else if current.total_files > 0 {
+            files as f64 / current.total_files as f64
+        } else {
+            0.0
+        };
+        let stage_count = self.stages.len().max(1);
+        let overall = if current.stage == ProgressStage::Done {
+            1.0
+        } else {
+            ((current.index as f64 + stage_fraction.min(1.0))
+                / stage_count as f64)
+                .min(1.0)
+        };
+
+        let stage_secs = now.duration_since(current.started).as_secs_f64();
+        let bytes_per_second = if stage_secs > 0.0 {
+            bytes as f64 / stage_secs
+        } else {
+            0.0
+        };
+        let total_secs = now.duration_since(self.started).as_secs_f64();
+        let eta_seconds = if overall >= 1.0 {
+            Some(0.0)
+        } else if overall > 0.0 {
+            Some(total_secs * (1.0 - overall) / overall)
+        } else {
+            None
+        };
+
+        (self.callback)(ProgressEvent {
+            stage: current.stage,
+            bytes_processed: bytes,
+            total_bytes: current.total_bytes,
+            files_processed: files,
+            total_files: current.total_files,
+            bytes_per_second,
+            eta_seconds,
+            overall_percentage: overall * 100.0,
+            stage_index: current.index.min(stage_count - 1) as u32,
+            stage_count: stage_count as u32,
+        });
+        self.last_emit = Some(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording(
+        stages: Vec<ProgressStage>,
+        interval: Duration,
+    ) -> (ProgressTracker, Arc<Mutex<Vec<ProgressEvent>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let callback: ProgressCallback =
+            Arc::new(move |event| sink.lock().unwrap().push(event));
+        let tracker =
+            ProgressTracker::with_interval(Some(callback), stages, interval);
+        (tracker, events)
+    }
+
+    #[test]
+    fn test_overall_percentage_spans_stages() {
+        let (tracker, events) = recording(
+            vec![ProgressStage::CompressingGzip, ProgressStage::Encrypting],
+            Duration::ZERO,
+        );
+
+        tracker.stage(ProgressStage::CompressingGzip, 100, 0);
+        tracker.update(50, 0);
+        tracker.stage(ProgressStage::Encrypting, 40, 0);
+        tracker.update(40, 0);
+        tracker.done();
+
+        let overall: Vec<f64> = events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|e| e.overall_percentage)
+            .collect();
+        assert_eq!(overall, [0.0, 25.0, 50.0, 100.0, 100.0]);
+
+        let events = events.lock().unwrap();
+        assert_eq!(events[2].stage_index, 1);
+        assert_eq!(events[2].stage_count, 2);
+        assert_eq!(events[4].stage, ProgressStage::Done);
+        assert_eq!(events[4].eta_seconds, Some(0.0));
+    }
+
+    #[test]
+    fn test_updates_are_throttled() {
+        let (tracker, events) = recording(
+            vec![ProgressStage::Encrypting],
+            Duration::from_secs(3600),
+        );
+
+        tracker.stage(ProgressStage::Encrypting, 1000, 0);
+        for bytes in (10..1000).step_by(10) {
+            tracker.update(bytes, 0);
+        }
+        // The update finishing the stage always gets through.
+        tracker.update(1000, 0);
+
+        let bytes: Vec<u64> = events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|e| e.bytes_processed)
+            .collect();
+        assert_eq!(bytes, [0, 1000]);
+    }
+
+    #[test]
+    fn test_files_drive_archive_stages() {
+        let (tracker, events) = recording(
+            vec![ProgressStage::DecompressingDirectory],
+            Duration::ZERO,
+        );
+
+        tracker.stage(ProgressStage::DecompressingDirectory, 0, 4);
+        tracker.update(123, 1);
+
+        let events = events.lock().unwrap();
+        let last = events.last().unwrap();
+        assert_eq!(last.files_processed, 1);
+        assert_eq!(last.total_files, 4);
+        assert_eq!(last.overall_percentage, 25.0);
+        assert!(last.eta_seconds.is_some());
+    }
+
+    #[test]
+    fn test_no_callback_is_noop() {
+        let tracker =
+            ProgressTracker::new(None, vec![ProgressStage::Encrypting]);
+        tracker.stage(ProgressStage::Encrypting, 10, 0);
+        tracker.update(5, 0);
+        tracker.done();
+    }
+}
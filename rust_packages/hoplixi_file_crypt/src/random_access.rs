@@ -0,0 +1,315 @@
+//! Random access to the plaintext of an encrypted file.
+//!
+//! Chunk nonces and AAD are bound to the chunk index, so any chunk
+//! can be located from the [`PayloadLayout`] and authenticated on
+//! its own. This is what makes byte-range reads (e.g. seeking in a
+//! video) possible without decrypting the file from the start.
+
+use std::io::{self, Read, Seek, SeekFrom};
+
+use zeroize::Zeroizing;
+
+use crate::crypto::kdf::DerivedKeys;
+use crate::engine::{OpenedFile, decrypt_data_chunk, read_payload};
+use crate::error::{CryptError, Result};
+use crate::header::encrypted::EncryptedMetadata;
+use crate::header::public::PublicHeader;
+use crate::layout::PayloadLayout;
+use crate::volume::VolumeReader;
+
+/// Seekable reader over the plaintext of an encrypted file.
+///
+/// Only the chunks that are read get decrypted, and each one is
+/// authenticated before any of its bytes are returned. With a
+/// parity layer a damaged chunk is rebuilt from its group.
+///
+/// The signature trailer covers the whole payload and is not
+/// checked here; use [`FileCrypt::verify`](crate::FileCrypt::verify)
+/// for that. Opened with
+/// [`FileCrypt::open_random_access`](crate::FileCrypt::open_random_access).
+pub struct RandomAccessReader {
+    file: VolumeReader,
+    header: PublicHeader,
+    keys: DerivedKeys,
+    metadata: EncryptedMetadata,
+    layout: PayloadLayout,
+    /// Container offset of the first data chunk.
+    payload_start: u64,
+    /// Current plaintext position.
+    pos: u64,
+    /// Index and plaintext of the last decrypted chunk.
+    cached: Option<(u64, Zeroizing<Vec<u8>>)>,
+}
+
+impl RandomAccessReader {
+    pub(crate) fn new(opened: OpenedFile) -> Result<Self> {
+        // Offsets into a gzip stream cannot be mapped to chunks.
+        if opened.metadata.gzip_compressed {
+            return Err(CryptError::Decryption(
+                "gzip-compressed files cannot be read at random offsets"
+                    .to_string(),
+            ));
+        }
+
+        let payload_start = (opened.header.encoded_len()
+            + opened.header.encrypted_meta_len as usize)
+            as u64;
+        Ok(Self {
+            file: opened.reader.into_inner(),
+            header: opened.header,
+            keys: opened.keys,
+            metadata: opened.metadata,
+            layout: opened.layout,
+            payload_start,
+            pos: 0,
+            cached: None,
+        })
+    }
+
+    /// Decrypted metadata of the file.
+    pub fn metadata(&self) -> &EncryptedMetadata {
+        &self.metadata
+    }
+
+    /// Plaintext length in bytes, without any length-hiding padding.
+    pub fn len(&self) -> u64 {
+        self.metadata
+            .payload_len
+            .unwrap_or_else(|| self.layout.plaintext_len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Read and authenticate data chunk `index`.
+    fn read_chunk(&mut self, index: u64) -> Result<Vec<u8>> {
+        let offset = self.payload_start + self.layout.chunk_offset(index);
+        self.file.seek(SeekFrom::Start(offset))?;
+        let mut chunk = vec![0u8; self.layout.chunk_len(index)];
+        self.file.read_exact(&mut chunk)?;
+
+        match decrypt_data_chunk(
+            &self.keys.data_key,
+            &self.header,
+            &self.metadata.uuid,
+            index,
+            &chunk,
+        ) {
+            Ok(plaintext) => Ok(plaintext),
+            Err(e) if self.layout.fec.is_none() => Err(e),
+            Err(_) => self.repair_chunk(index),
+        }
+    }
+
+    /// Rebuild data chunk `index` from the rest of its group.
+    fn repair_chunk(&mut self, index: u64) -> Result<Vec<u8>> {
+        let group_index = self.layout.group_of(index);
+        let group = self.layout.group(group_index);
+        let offset =
+            self.payload_start + self.layout.group_offset(group_index);
+        self.file.seek(SeekFrom::Start(offset))?;
+        let mut bytes = vec![0u8; self.layout.group_len(group_index) as usize];
+        self.file.read_exact(&mut bytes)?;
+
+        let layout = PayloadLayout::from_payload_len(
+            bytes.len() as u64,
+            self.header.chunk_size,
+            self.header.fec,
+        )?;
+        let mut plaintext = Vec::new();
+        read_payload(
+            &mut bytes.as_slice(),
+            &self.header,
+            &layout,
+            group.first_chunk,
+            &self.keys.data_key,
+            &self.metadata.uuid,
+            |mut decoded| {
+                let i = (index - group.first_chunk) as usize;
+                plaintext = std::mem::take(&mut decoded.plaintext[i]);
+                Ok(())
+            },
+        )?;
+        Ok(plaintext)
+    }
+}
+
+impl Read for RandomAccessReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.pos >= self.len() {
+            return Ok(0);
+        }
+        let chunk_size = self.header.chunk_size as u64;
+        let index = self.pos / chunk_size;
+        if self.cached.as_ref().is_none_or(|(i, _)| *i != index) {
+            let plaintext =
+                self.read_chunk(index).map_err(io::Error::other)?;
+            self.cached = Some((index, Zeroizing::new(plaintext)));
+        }
+
+        let chunk = &self.cached.as_ref().expect("chunk cached").1;
+        let start = (self.pos % chunk_size) as usize;
+        // The last chunk may end in padding.
+        let n = (buf.len().min(chunk.len() - start) as u64)
+            .min(self.len() - self.pos) as usize;
+        buf[..n].copy_from_slice(&chunk[start..start + n]);
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for RandomAccessReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(delta) => self.len().checked_add_signed(delta),
+            SeekFrom::Current(delta) => self.pos.checked_add_signed(delta),
+        };
+        self.pos = target.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "seek before the start of the plaintext",
+            )
+        })?;
+        Ok(self.pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::{Read, Seek, SeekFrom};
+    use std::path::{Path, PathBuf};
+
+    use tempfile::TempDir;
+
+    use crate::config::TAG_LEN;
+    use crate::crypto::kdf::Argon2Params;
+    use crate::fec::FecParams;
+    use crate::header::public::PublicHeader;
+    use crate::{EncryptOptions, FileCrypt};
+
+    fn fast_crypt() -> FileCrypt {
+        FileCrypt::new(
+            256,
+            Argon2Params {
+                t_cost: 1,
+                m_cost_kib: 64,
+                parallelism: 1,
+            },
+        )
+    }
+
+    fn encrypt(
+        dir: &TempDir,
+        data: &[u8],
+        gzip: bool,
+        fec: Option<FecParams>,
+    ) -> PathBuf {
+        let original = dir.path().join("video.bin");
+        fs::write(&original, data).unwrap();
+        fast_crypt()
+            .encrypt(EncryptOptions {
+                input_path: original,
+                output_dir: dir.path().to_path_buf(),
+                uuid: None,
+                password: "seek".to_string(),
+                progress: None,
+                gzip_compressed: gzip,
+                output_extension: None,
+                temp_dir: None,
+                metadata: None,
+                fec,
+                signer: None,
+                recipient: None,
+                shred_source: false,
+                hide_lengths: false,
+                volume_size: None,
+                filter: None,
+            })
+            .unwrap()
+            .output_path
+    }
+
+    fn damage_chunk(path: &Path, index: u64) {
+        let bytes = fs::read(path).unwrap();
+        let header =
+            PublicHeader::read_from(&mut bytes.as_slice()).unwrap();
+        let offset = header.encoded_len()
+            + header.encrypted_meta_len as usize
+            + index as usize * (256 + TAG_LEN)
+            + 3;
+        let mut bytes = bytes;
+        bytes[offset] ^= 0xFF;
+        fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn test_reads_arbitrary_ranges() {
+        let dir = TempDir::new().unwrap();
+        let data: Vec<u8> = (0..2000).map(|i| (i * 13 % 251) as u8).collect();
+        let path = encrypt(&dir, &data, false, None);
+
+        let mut reader = fast_crypt()
+            .open_random_access(path, "seek".to_string())
+            .unwrap();
+        assert_eq!(reader.len(), data.len() as u64);
+
+        // Inside one chunk, across chunk boundaries, the short tail.
+        for (start, len) in [(0, 10), (250, 20), (700, 900), (1990, 10)] {
+            reader.seek(SeekFrom::Start(start as u64)).unwrap();
+            let mut buf = vec![0u8; len];
+            reader.read_exact(&mut buf).unwrap();
+            assert_eq!(buf, data[start..start + len]);
+        }
+
+        reader.seek(SeekFrom::End(-5)).unwrap();
+        let mut tail = Vec::new();
+        reader.read_to_end(&mut tail).unwrap();
+        assert_eq!(tail, data[data.len() - 5..]);
+        assert!(reader.seek(SeekFrom::Current(-3000)).is_err());
+    }
+
+    #[test]
+    fn test_damaged_chunk() {
+        let dir = TempDir::new().unwrap();
+        let data = vec![0x42u8; 1500];
+
+        // Without parity the damaged chunk is an error...
+        let plain = encrypt(&dir, &data, false, None);
+        damage_chunk(&plain, 2);
+        let mut reader = fast_crypt()
+            .open_random_access(plain, "seek".to_string())
+            .unwrap();
+        let mut buf = [0u8; 16];
+        reader.read_exact(&mut buf).unwrap();
+        reader.seek(SeekFrom::Start(600)).unwrap();
+        assert!(reader.read_exact(&mut buf).is_err());
+
+        // ...with parity it is rebuilt from its group.
+        let fec_dir = TempDir::new().unwrap();
+        let fec = Some(FecParams::new(4, 2).unwrap());
+        let repaired = encrypt(&fec_dir, &data, false, fec);
+        damage_chunk(&repaired, 2);
+        let mut reader = fast_crypt()
+            .open_random_access(repaired, "seek".to_string())
+            .unwrap();
+        reader.seek(SeekFrom::Start(600)).unwrap();
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, data[600..]);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_gzip_refused() {
+        use crate::error::CryptError;
+
+        let dir = TempDir::new().unwrap();
+        let path = encrypt(&dir, &[7u8; 100], true, None);
+        let result = fast_crypt()
+            .open_random_access(path, "seek".to_string());
+        assert!(matches!(result, Err(CryptError::Decryption(_))));
+    }
+}
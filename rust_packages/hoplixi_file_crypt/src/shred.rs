@@ -0,0 +1,150 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+use crate::types::{ShredEntry, ShredReport};
+
+/// Buffer of random bytes written over a file at a time.
+const SHRED_BUFFER_LEN: usize = 64 * 1024;
+
+/// Limits of overwriting in place, returned with every report.
+pub const SHRED_CAVEAT: &str = "Overwriting only reaches the blocks the \
+    file currently occupies. SSDs and flash remap writes (wear levelling), \
+    and copy-on-write or journaling file systems (APFS, Btrfs, ZFS, ext4 \
+    with data=journal), snapshots and backups may keep older copies, so \
+    remnants of the plaintext can survive. Full-disk encryption is the \
+    reliable protection for such media.";
+
+/// Overwrite `path` with random data, sync it, rename it to a random
+/// name and unlink it. Directories are processed depth-first, their
+/// files first and then the emptied directories themselves.
+///
+/// Symbolic links are unlinked without touching their target. A
+/// failure on one entry is recorded and the rest still processed.
+pub fn shred_path(path: &Path) -> ShredReport {
+    let mut entries = Vec::new();
+    let is_dir = fs::symlink_metadata(path).is_ok_and(|m| m.is_dir());
+    if is_dir {
+        for entry in WalkDir::new(path).contents_first(true) {
+            match entry {
+                Ok(entry) => {
+                    let result = if entry.file_type().is_dir() {
+                        remove_dir(entry.path())
+                    } else {
+                        shred_file(entry.path())
+                    };
+                    entries.push(ShredEntry::new(entry.into_path(), result));
+                }
+                Err(e) => {
+                    let path = e
+                        .path()
+                        .map_or_else(|| path.to_path_buf(), Path::to_path_buf);
+                    entries.push(ShredEntry::new(path, Err(e.into())));
+                }
+            }
+        }
+    } else {
+        entries.push(ShredEntry::new(path.to_path_buf(), shred_file(path)));
+    }
+    ShredReport {
+        entries,
+        caveat: SHRED_CAVEAT,
+    }
+}
+
+/// Overwrite, sync, rename and unlink one file (or unlink a link).
+fn shred_file(path: &Path) -> io::Result<()> {
+    if fs::symlink_metadata(path)?.is_file() {
+        let mut file = OpenOptions::new().write(true).open(path)?;
+        let mut remaining = file.metadata()?.len();
+        let mut buf = vec![0u8; SHRED_BUFFER_LEN];
+        while remaining > 0 {
+            let n = remaining.min(SHRED_BUFFER_LEN as u64) as usize;
+            rand::fill(&mut buf[..n]);
+            file.write_all(&buf[..n])?;
+            remaining -= n as u64;
+        }
+        file.sync_all()?;
+    }
+    let renamed = rename_randomly(path)?;
+    fs::remove_file(renamed)
+}
+
+/// Rename and remove an (already emptied) directory.
+fn remove_dir(path: &Path) -> io::Result<()> {
+    let renamed = rename_randomly(path)?;
+    fs::remove_dir(renamed)
+}
+
+/// Rename `path` to a random name in the same directory, so the
+/// original name does not linger in the directory entry.
+fn rename_randomly(path: &Path) -> io::Result<PathBuf> {
+    let mut name = [0u8; 12];
+    rand::fill(&mut name);
+    let name: String = name.iter().map(|b| format!("{b:02x}")).collect();
+    let renamed = path.with_file_name(name);
+    fs::rename(path, &renamed)?;
+    Ok(renamed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_shred_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("secret.txt");
+        fs::write(&path, vec![b'x'; 100_000]).unwrap();
+
+        let report = shred_path(&path);
+        assert!(report.all_shredded());
+        assert_eq!(report.entries.len(), 1);
+        assert_eq!(report.entries[0].path, path);
+        assert!(!path.exists());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_shred_tree() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().join("docs");
+        fs::create_dir_all(root.join("a/b")).unwrap();
+        fs::write(root.join("top.txt"), b"top").unwrap();
+        fs::write(root.join("a/b/deep.txt"), b"deep").unwrap();
+        fs::write(root.join("a/empty.txt"), b"").unwrap();
+
+        let report = shred_path(&root);
+        assert!(report.all_shredded());
+        // Three files and three directories.
+        assert_eq!(report.entries.len(), 6);
+        assert!(!root.exists());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_target_untouched() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("target.txt");
+        fs::write(&target, b"keep me").unwrap();
+        let root = dir.path().join("tree");
+        fs::create_dir(&root).unwrap();
+        std::os::unix::fs::symlink(&target, root.join("link")).unwrap();
+
+        let report = shred_path(&root);
+        assert!(report.all_shredded());
+        assert_eq!(fs::read(&target).unwrap(), b"keep me");
+    }
+
+    #[test]
+    fn test_missing_file_reported() {
+        let dir = TempDir::new().unwrap();
+        let report = shred_path(&dir.path().join("gone"));
+        assert!(!report.all_shredded());
+        assert!(report.entries[0].error.is_some());
+    }
+}
@@ -0,0 +1,92 @@
+//! Free space of the file systems an operation writes to (see
+//! [`SpacePlan`](crate::types::SpacePlan)).
+
+use std::path::{Path, PathBuf};
+
+/// `path`, or its nearest ancestor that exists: directories are
+/// looked at before the operation creates them.
+fn existing(path: &Path) -> Option<PathBuf> {
+    let path = std::path::absolute(path).ok()?;
+    path.ancestors()
+        .find(|dir| dir.exists())
+        .map(Path::to_path_buf)
+}
+
+/// Bytes an unprivileged user may still write to the file system
+/// holding `path`, or `None` where the platform cannot tell.
+pub(crate) fn available(path: &Path) -> Option<u64> {
+    free_bytes(&existing(path)?)
+}
+
+#[cfg(unix)]
+fn free_bytes(path: &Path) -> Option<u64> {
+    let stat = rustix::fs::statvfs(path).ok()?;
+    Some(stat.f_bavail.saturating_mul(stat.f_frsize))
+}
+
+#[cfg(windows)]
+fn free_bytes(path: &Path) -> Option<u64> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let wide: Vec<u16> =
+        path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut free = 0u64;
+    // SAFETY: `wide` is NUL-terminated and outlives the call; the
+    // totals we do not need may be null.
+    let ok = unsafe {
+        GetDiskFreeSpaceExW(
+            wide.as_ptr(),
+            &mut free,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    (ok != 0).then_some(free)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn free_bytes(_path: &Path) -> Option<u64> {
+    None
+}
+
+/// Whether `a` and `b` lie on the same file system, so that what is
+/// written to both draws on one pool of free space. Assumed when it
+/// cannot be told.
+pub(crate) fn same_volume(a: &Path, b: &Path) -> bool {
+    match (existing(a), existing(b)) {
+        (Some(a), Some(b)) => volume_id(&a) == volume_id(&b),
+        _ => true,
+    }
+}
+
+#[cfg(unix)]
+fn volume_id(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+
+    std::fs::metadata(path).ok().map(|meta| meta.dev())
+}
+
+/// The drive or share `path` is on.
+#[cfg(not(unix))]
+fn volume_id(path: &Path) -> Option<std::ffi::OsString> {
+    let path = std::fs::canonicalize(path).ok()?;
+    path.components()
+        .next()
+        .map(|root| root.as_os_str().to_os_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_directories_use_their_parent() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let missing = dir.path().join("not/yet/created");
+        assert_eq!(existing(&missing).as_deref(), Some(dir.path()));
+        assert!(same_volume(dir.path(), &missing));
+        #[cfg(any(unix, windows))]
+        assert!(available(&missing).is_some_and(|free| free > 0));
+    }
+}
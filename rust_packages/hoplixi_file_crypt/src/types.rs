@@ -1,6 +1,15 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::config::ED25519_PUBLIC_KEY_LEN;
+use crate::crypto::cipher::CipherSuite;
+use crate::crypto::kdf::Argon2Params;
+use crate::crypto::recipient::{
+    RecipientAlgorithm, RecipientIdentity, RecipientPublicKey,
+};
+use crate::crypto::signature::{SignatureAlgorithm, SigningIdentity};
+use crate::error::{CryptError, Result};
+use crate::fec::FecParams;
 use crate::header::encrypted::EncryptedMetadata;
 use crate::progress::ProgressCallback;
 
@@ -26,11 +35,119 @@ pub struct EncryptOptions {
     /// Additional key-value metadata to store in the
     /// encrypted header.
     pub metadata: Option<HashMap<String, String>>,
+    /// Optional Reed–Solomon parity layer over the encrypted chunks.
+    pub fec: Option<FecParams>,
+    /// Optional Ed25519 identity that signs the encrypted file.
+    pub signer: Option<SigningIdentity>,
+    /// Encrypt to this hybrid X25519 + ML-KEM-768 public key instead
+    /// of a password. `password` is ignored when set.
+    pub recipient: Option<RecipientPublicKey>,
+    /// Securely delete the input file or directory tree once the
+    /// encrypted file is in place. Never runs if encryption fails.
+    /// A symbolic link as input is refused, as shredding would only
+    /// remove the link.
+    /// See [`ShredReport::caveat`] for what this cannot guarantee.
+    pub shred_source: bool,
+    /// Pad the sealed metadata to a fixed bucket and the payload
+    /// PADMÉ-style, so that the file size reveals neither the exact
+    /// plaintext size nor the filename length. The true size is kept
+    /// in the encrypted metadata.
+    pub hide_lengths: bool,
+    /// Split the output into volumes of at most this many bytes,
+    /// named `<uuid><ext>.001`, `.002`, … Volumes are cut between
+    /// chunks, so the size must hold at least one encrypted chunk.
+    pub volume_size: Option<u64>,
+    /// Which entries of a directory input to encrypt; everything
+    /// when `None`. Ignored for a file input. Cannot be combined
+    /// with [`EncryptOptions::shred_source`], which would delete the
+    /// entries left out.
+    pub filter: Option<DirectoryFilter>,
+}
+
+/// Selects the entries of a directory input that get encrypted.
+///
+/// Patterns are globs over paths relative to the input directory,
+/// with `/` separators, read like `.gitignore` lines: a pattern
+/// without a slash matches a name at any depth, one with a slash
+/// matches from the top; a trailing `/` matches directories only
+/// and a leading `!` takes back an earlier match of the same list.
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryFilter {
+    /// Encrypt only the files matching these; all files if empty.
+    pub include: Vec<String>,
+    /// Leave out the files and whole directories matching these.
+    pub exclude: Vec<String>,
+    /// Name of ignore files, e.g. `.gitignore`, whose rules apply to
+    /// the directory holding them and everything below it.
+    pub ignore_file: Option<String>,
+    /// Leave out files larger than this many bytes.
+    pub max_file_size: Option<u64>,
+    /// What to do with symbolic links and special files.
+    pub links: LinkPolicy,
+}
+
+/// How packing a directory treats symbolic links and special files
+/// (FIFOs, sockets, devices). Special files have no content to pack:
+/// every policy but [`LinkPolicy::Error`] leaves them out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LinkPolicy {
+    /// Store links as links. Decryption recreates them on Unix and
+    /// writes a file holding the link target elsewhere.
+    #[default]
+    Store,
+    /// Pack what links point to, descending into linked directories.
+    Follow,
+    /// Leave links out.
+    Skip,
+    /// Fail on the first link or special file.
+    Error,
+}
+
+/// An entry of a directory input left out by its [`DirectoryFilter`].
+/// A directory left out is reported once, without its contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedEntry {
+    /// Path relative to the input directory.
+    pub path: PathBuf,
+    pub reason: SkipReason,
+}
+
+/// Why a [`SkippedEntry`] was left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// Matched [`DirectoryFilter::exclude`].
+    Excluded,
+    /// Matched a rule of an ignore file.
+    Ignored,
+    /// A file matching none of [`DirectoryFilter::include`].
+    NotIncluded,
+    /// A file over [`DirectoryFilter::max_file_size`].
+    TooLarge { size: u64 },
+    /// A symbolic link, under [`LinkPolicy::Skip`].
+    Symlink,
+    /// A FIFO, socket or device.
+    SpecialFile,
+}
+
+impl SkipReason {
+    /// Stable snake_case name, for machine-readable output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkipReason::Excluded => "excluded",
+            SkipReason::Ignored => "ignored",
+            SkipReason::NotIncluded => "not_included",
+            SkipReason::TooLarge { .. } => "too_large",
+            SkipReason::Symlink => "symlink",
+            SkipReason::SpecialFile => "special_file",
+        }
+    }
 }
 
 /// Options for file decryption.
 pub struct DecryptOptions {
-    /// Path to the encrypted `.enc` file.
+    /// Path to the encrypted `.enc` file, or to the first volume
+    /// (`.001`) of a split one; the other volumes are found next to
+    /// it.
     pub input_path: PathBuf,
     /// Directory where the decrypted output will be saved.
     pub output_dir: PathBuf,
@@ -40,17 +157,148 @@ pub struct DecryptOptions {
     pub progress: Option<ProgressCallback>,
     /// Temporary directory for intermediate files.
     pub temp_dir: Option<PathBuf>,
+    /// Identity that opens files encrypted to a recipient key.
+    /// Password-encrypted files ignore it.
+    pub identity: Option<RecipientIdentity>,
+}
+
+/// Options for changing the password of an encrypted file.
+pub struct RekeyOptions {
+    /// Path to the encrypted `.enc` file.
+    pub input_path: PathBuf,
+    /// Where to write the re-encrypted file. Replaces the input
+    /// when `None`.
+    pub output_path: Option<PathBuf>,
+    /// Current password.
+    pub old_password: String,
+    /// New password.
+    pub new_password: String,
+    /// New KDF cost and chunk size.
+    pub params: ReencryptParams,
+    /// Identity that signs the re-encrypted file. The original
+    /// signature covers the old keys and cannot be carried over.
+    pub signer: Option<SigningIdentity>,
+    /// Optional progress callback.
+    pub progress: Option<ProgressCallback>,
+}
+
+/// Layout changes applied while re-encrypting a file.
+///
+/// The default keeps the chunk size of the file and uses the
+/// Argon2 parameters of the engine.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReencryptParams {
+    /// Argon2id parameters for the new password; the engine's when
+    /// `None`.
+    pub argon2_params: Option<Argon2Params>,
+    /// Plaintext bytes per chunk of the new file; the file's own
+    /// when `None`.
+    pub chunk_size: Option<u32>,
+}
+
+/// Changes to the user-defined metadata of an encrypted file, see
+/// [`FileCrypt::update_metadata`](crate::FileCrypt::update_metadata).
+#[derive(Debug, Clone, Default)]
+pub struct MetadataPatch {
+    /// Keys to remove.
+    pub remove: Vec<String>,
+    /// Entries to add or overwrite, applied after `remove`.
+    pub set: HashMap<String, String>,
+}
+
+/// Options for encrypting a stream with
+/// [`FileCrypt::encrypt_stream`](crate::FileCrypt::encrypt_stream).
+///
+/// A stream has no path, so the name recorded in the metadata is
+/// given explicitly.
+pub struct StreamEncryptOptions {
+    /// Filename recorded in the metadata (without extension).
+    pub original_filename: String,
+    /// Extension recorded in the metadata (may be empty).
+    pub original_extension: String,
+    /// Optional UUID; generated automatically if not provided.
+    pub uuid: Option<String>,
+    /// User password (used for key derivation).
+    pub password: String,
+    /// Optional progress callback. The total is unknown (0).
+    pub progress: Option<ProgressCallback>,
+    /// Whether to gzip the stream before encryption.
+    pub gzip_compressed: bool,
+    /// Additional key-value metadata to store in the
+    /// encrypted header.
+    pub metadata: Option<HashMap<String, String>>,
+    /// Optional Reed–Solomon parity layer over the encrypted chunks.
+    pub fec: Option<FecParams>,
+    /// Optional Ed25519 identity that signs the encrypted stream.
+    pub signer: Option<SigningIdentity>,
+    /// Encrypt to this hybrid X25519 + ML-KEM-768 public key instead
+    /// of a password. `password` is ignored when set.
+    pub recipient: Option<RecipientPublicKey>,
+}
+
+/// Options for decrypting a stream with
+/// [`FileCrypt::decrypt_stream`](crate::FileCrypt::decrypt_stream).
+pub struct StreamDecryptOptions {
+    /// User password.
+    pub password: String,
+    /// Optional progress callback.
+    pub progress: Option<ProgressCallback>,
+    /// Identity that opens streams encrypted to a recipient key.
+    pub identity: Option<RecipientIdentity>,
 }
 
 /// Result returned after successful encryption.
 #[derive(Debug)]
 pub struct EncryptResult {
-    /// Path to the encrypted output file.
+    /// Path to the encrypted output file, or to the first volume.
     pub output_path: PathBuf,
+    /// Every volume written, in order; empty unless
+    /// [`EncryptOptions::volume_size`] was set.
+    pub volumes: Vec<PathBuf>,
     /// UUID assigned to the encrypted file.
     pub uuid: String,
     /// Original file size before compression/encryption.
     pub original_size: u64,
+    /// Outcome of shredding the input, if
+    /// [`EncryptOptions::shred_source`] was set.
+    pub shred: Option<ShredReport>,
+    /// Entries of a directory input left out by
+    /// [`EncryptOptions::filter`].
+    pub skipped: Vec<SkippedEntry>,
+}
+
+/// Outcome of securely deleting the source of an encryption.
+#[derive(Debug)]
+pub struct ShredReport {
+    /// Every file, link and directory processed, depth-first.
+    pub entries: Vec<ShredEntry>,
+    /// Why overwriting in place is best effort on modern storage.
+    pub caveat: &'static str,
+}
+
+impl ShredReport {
+    /// Whether every entry was overwritten and removed.
+    pub fn all_shredded(&self) -> bool {
+        self.entries.iter().all(|e| e.error.is_none())
+    }
+}
+
+/// One path removed by [`ShredReport`].
+#[derive(Debug)]
+pub struct ShredEntry {
+    /// Path before it was renamed.
+    pub path: PathBuf,
+    /// Why the path could not be shredded, if it failed.
+    pub error: Option<String>,
+}
+
+impl ShredEntry {
+    pub(crate) fn new(path: PathBuf, result: std::io::Result<()>) -> Self {
+        Self {
+            path,
+            error: result.err().map(|e| e.to_string()),
+        }
+    }
 }
 
 /// Result returned after successful decryption.
@@ -60,4 +308,209 @@ pub struct DecryptResult {
     pub output_path: PathBuf,
     /// Metadata from the encrypted header.
     pub metadata: EncryptedMetadata,
+    /// Signature check outcome.
+    pub signature: SignatureStatus,
+}
+
+/// Result returned after a stream was encrypted.
+#[derive(Debug)]
+pub struct StreamEncryptResult {
+    /// UUID assigned to the encrypted stream.
+    pub uuid: String,
+    /// Bytes read from the input, before compression.
+    pub bytes_read: u64,
+}
+
+/// Result returned after a stream was decrypted.
+#[derive(Debug)]
+pub struct StreamDecryptResult {
+    /// Bytes written to the output, after decompression.
+    pub bytes_written: u64,
+    /// Metadata from the encrypted header.
+    pub metadata: EncryptedMetadata,
+    /// Signature check outcome.
+    pub signature: SignatureStatus,
+}
+
+/// Result returned after a file was decrypted into memory.
+#[derive(Debug)]
+pub struct MemoryDecryptResult {
+    /// The decrypted (and decompressed) content. For an encrypted
+    /// directory this is the raw 7z archive.
+    pub data: Vec<u8>,
+    /// Metadata from the encrypted header.
+    pub metadata: EncryptedMetadata,
+    /// Signature check outcome.
+    pub signature: SignatureStatus,
+}
+
+/// Outcome of checking the signature of an encrypted file.
+///
+/// An invalid signature is reported as
+/// [`CryptError::InvalidSignature`](crate::CryptError::InvalidSignature)
+/// rather than as a status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureStatus {
+    /// The file carries no signature.
+    Unsigned,
+    /// The file was signed by `public_key` and is unmodified.
+    Valid {
+        public_key: [u8; ED25519_PUBLIC_KEY_LEN],
+    },
+}
+
+/// Result returned after successful verification.
+#[derive(Debug)]
+pub struct VerifyResult {
+    /// Metadata from the encrypted header.
+    pub metadata: EncryptedMetadata,
+    /// Number of authenticated data chunks.
+    pub chunk_count: u64,
+    /// Data chunks that failed authentication and were rebuilt
+    /// from parity (in memory only).
+    pub repaired_chunks: u64,
+    /// Signature check outcome.
+    pub signature: SignatureStatus,
+}
+
+/// Result returned after a repair pass.
+#[derive(Debug)]
+pub struct RepairResult {
+    /// Path to the (possibly rewritten) encrypted file.
+    pub output_path: PathBuf,
+    /// Data chunks rebuilt from parity.
+    pub repaired_chunks: u64,
+    /// Parity shards that no longer matched and were recomputed.
+    pub repaired_parity: u64,
+}
+
+impl RepairResult {
+    /// Whether the file had to be rewritten.
+    pub fn rewritten(&self) -> bool {
+        self.repaired_chunks > 0 || self.repaired_parity > 0
+    }
+}
+
+/// Result returned after a successful password change.
+#[derive(Debug)]
+pub struct RekeyResult {
+    /// Path to the re-encrypted file.
+    pub output_path: PathBuf,
+    /// Number of re-encrypted data chunks.
+    pub chunk_count: u64,
+    /// Signature check outcome of the original file.
+    pub source_signature: SignatureStatus,
+}
+
+/// Result returned after editing the metadata of a file.
+#[derive(Debug)]
+pub struct MetadataUpdateResult {
+    /// The metadata as now stored.
+    pub metadata: EncryptedMetadata,
+    /// `true` if only the header region was rewritten; `false` if
+    /// the metadata outgrew its reserve and the file was copied.
+    pub in_place: bool,
+}
+
+/// Public facts about an encrypted file, read without the password.
+///
+/// Everything here comes from the unencrypted header and the file
+/// length; the original filename and size stay sealed.
+#[derive(Debug, Clone)]
+pub struct InspectResult {
+    /// Format version of the file.
+    pub version: u16,
+    /// Argon2id parameters the password must be run through.
+    pub argon2_params: Argon2Params,
+    /// Plaintext bytes per chunk.
+    pub chunk_size: u32,
+    /// Reed–Solomon parity parameters, if present.
+    pub fec: Option<FecParams>,
+    /// Signature scheme, if the file is signed.
+    pub signature: Option<SignatureAlgorithm>,
+    /// AEAD protecting the metadata and the chunks.
+    pub cipher: CipherSuite,
+    /// Whether the header commits to the key (checked on unlock).
+    pub key_commitment: bool,
+    /// Key encapsulation scheme, if the file is encrypted to a
+    /// recipient key rather than a password.
+    pub recipient: Option<RecipientAlgorithm>,
+    /// Total length of the file on disk; for a split file, of all
+    /// volumes without their prefixes.
+    pub file_len: u64,
+    /// Length of the encrypted payload (data chunks and parity).
+    pub ciphertext_len: u64,
+    /// Number of encrypted data chunks (0 if the length is invalid).
+    pub chunk_count: u64,
+    /// Plaintext bytes carried by the payload, after compression
+    /// (0 if the length is invalid).
+    pub estimated_plaintext_size: u64,
+    /// Whether the payload length matches whole chunks (and parity
+    /// groups). `false` indicates truncation or trailing garbage.
+    pub whole_chunks: bool,
+}
+
+/// Disk space an encryption or decryption needs, from
+/// [`FileCrypt::plan_encrypt`](crate::FileCrypt::plan_encrypt) or
+/// [`FileCrypt::plan_decrypt`](crate::FileCrypt::plan_decrypt).
+///
+/// For encryption the byte counts are upper bounds: compression is
+/// assumed to gain nothing. Decompressing writes more than it reads,
+/// by a ratio only known once it ran, so plans for decrypting
+/// compressed data count the compressed size and are
+/// [`estimated`](SpacePlan::estimated).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpacePlan {
+    pub output_dir: PathBuf,
+    /// Bytes written to `output_dir`.
+    pub output_bytes: u64,
+    /// Free bytes on the file system of `output_dir`, if known.
+    pub output_free: Option<u64>,
+    pub temp_dir: PathBuf,
+    /// Most bytes held in `temp_dir` at once.
+    pub temp_bytes: u64,
+    /// Bytes still held in `temp_dir` while the output is written.
+    pub temp_bytes_at_output: u64,
+    /// Free bytes on the file system of `temp_dir`, if known.
+    pub temp_free: Option<u64>,
+    /// Whether both directories draw on the same free space.
+    pub shared_volume: bool,
+    /// Whether the sizes of decompressed data are lower bounds.
+    pub estimated: bool,
+}
+
+impl SpacePlan {
+    /// Most bytes needed at once on the file system of `output_dir`,
+    /// temp files included when they share it.
+    pub fn output_needed(&self) -> u64 {
+        match self.shared_volume {
+            true => self
+                .temp_bytes
+                .max(self.temp_bytes_at_output + self.output_bytes),
+            false => self.output_bytes,
+        }
+    }
+
+    /// Fail with [`CryptError::InsufficientSpace`] if a file system
+    /// is known to lack the space planned on it.
+    pub fn check(&self) -> Result<()> {
+        let mut needs =
+            vec![(&self.output_dir, self.output_needed(), self.output_free)];
+        if !self.shared_volume {
+            needs.push((&self.temp_dir, self.temp_bytes, self.temp_free));
+        }
+        for (path, needed, free) in needs {
+            match free {
+                Some(available) if needed > available => {
+                    return Err(CryptError::InsufficientSpace {
+                        path: path.clone(),
+                        needed,
+                        available,
+                    });
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
 }
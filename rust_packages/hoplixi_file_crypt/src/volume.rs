@@ -0,0 +1,529 @@
+//! Splitting an encrypted file into fixed-size volumes.
+//!
+//! A split file is the usual container cut at chunk boundaries into
+//! `<name>.001`, `<name>.002`, … Every volume starts with a short
+//! prefix naming its set, its number and the number of volumes, so a
+//! missing or misplaced volume is reported as such instead of as a
+//! failed chunk. The set and the count are also recorded in the
+//! public header, which the metadata authenticates, and every chunk
+//! is bound to its index, so the order of the volumes is
+//! authenticated as well.
+
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use tempfile::NamedTempFile;
+
+use crate::config::{VOLUME_MAGIC, VOLUME_PREFIX_LEN, VOLUME_SET_ID_LEN};
+use crate::error::{CryptError, Result};
+use crate::header::public::PublicHeader;
+use crate::journal;
+use crate::layout::PayloadLayout;
+
+/// Volume set of a split file, as recorded in the public header and
+/// in the prefix of every volume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolumeSet {
+    /// Random identifier shared by all volumes of the file.
+    pub set_id: [u8; VOLUME_SET_ID_LEN],
+    /// Number of volumes.
+    pub count: u32,
+}
+
+impl VolumeSet {
+    /// New set with a random identifier and no volumes yet.
+    pub(crate) fn generate() -> Self {
+        let mut set_id = [0u8; VOLUME_SET_ID_LEN];
+        rand::fill(&mut set_id);
+        Self { set_id, count: 0 }
+    }
+
+    /// Encode as SET_ID(16) + COUNT(4).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = self.set_id.to_vec();
+        buf.extend_from_slice(&self.count.to_le_bytes());
+        buf
+    }
+
+    /// Decode the value of the volume header extension.
+    pub fn from_bytes(value: &[u8]) -> Result<Self> {
+        if value.len() != VOLUME_SET_ID_LEN + 4 {
+            return Err(CryptError::InvalidHeader(
+                "malformed volume extension".to_string(),
+            ));
+        }
+        let (set_id, count) = value.split_at(VOLUME_SET_ID_LEN);
+        Ok(Self {
+            set_id: set_id.try_into().expect("split at VOLUME_SET_ID_LEN"),
+            count: u32::from_le_bytes(
+                count.try_into().expect("4-byte volume count"),
+            ),
+        })
+    }
+}
+
+/// Path of volume `index` (counted from 1) of the file `base`:
+/// `base` with `.001`, `.002`, … appended.
+pub fn volume_path(base: &Path, index: u32) -> PathBuf {
+    let mut name = base.as_os_str().to_owned();
+    name.push(format!(".{index:03}"));
+    PathBuf::from(name)
+}
+
+/// Prefix opening every volume.
+struct VolumePrefix {
+    set: VolumeSet,
+    index: u32,
+}
+
+impl VolumePrefix {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = VOLUME_MAGIC.to_vec();
+        buf.extend_from_slice(&self.set.set_id);
+        buf.extend_from_slice(&self.index.to_le_bytes());
+        buf.extend_from_slice(&self.set.count.to_le_bytes());
+        buf
+    }
+
+    /// Read the prefix of `file`, or rewind it and return `None` if
+    /// it is not a volume.
+    fn read_from(file: &mut File) -> Result<Option<Self>> {
+        let mut buf = Vec::with_capacity(VOLUME_PREFIX_LEN);
+        Read::by_ref(file)
+            .take(VOLUME_PREFIX_LEN as u64)
+            .read_to_end(&mut buf)?;
+        if !buf.starts_with(VOLUME_MAGIC) {
+            file.rewind()?;
+            return Ok(None);
+        }
+        if buf.len() < VOLUME_PREFIX_LEN {
+            return Err(CryptError::Volume(
+                "truncated volume prefix".to_string(),
+            ));
+        }
+        let (set_id, rest) =
+            buf[VOLUME_MAGIC.len()..].split_at(VOLUME_SET_ID_LEN);
+        let (index, count) = rest.split_at(4);
+        let word = |b: &[u8]| {
+            u32::from_le_bytes(b.try_into().expect("4-byte field"))
+        };
+        Ok(Some(Self {
+            set: VolumeSet {
+                set_id: set_id
+                    .try_into()
+                    .expect("split at VOLUME_SET_ID_LEN"),
+                count: word(count),
+            },
+            index: word(index),
+        }))
+    }
+}
+
+/// Container offsets at which the volumes of a file start, cutting
+/// only between the header and metadata (`prefix_len` bytes), the
+/// chunks and parity shards of `layout` and the `trailer_len`-byte
+/// signature trailer.
+///
+/// Each volume is filled with as many of them as fit in
+/// `volume_size` bytes, its own prefix included.
+pub(crate) fn plan(
+    volume_size: u64,
+    prefix_len: u64,
+    layout: &PayloadLayout,
+    trailer_len: u64,
+) -> Result<Vec<u64>> {
+    let capacity = volume_size.saturating_sub(VOLUME_PREFIX_LEN as u64);
+    let shard_len = layout.encrypted_chunk_len as u64;
+    let pieces = (0..layout.group_count()).flat_map(|g| {
+        let group = layout.group(g);
+        let first = group.first_chunk;
+        (first..first + group.data_chunks as u64)
+            .map(|i| layout.chunk_len(i) as u64)
+            .chain(std::iter::repeat_n(shard_len, group.parity_shards))
+    });
+
+    let mut starts = vec![0];
+    let mut offset = 0;
+    let mut used = 0;
+    let all = std::iter::once(prefix_len)
+        .chain(pieces)
+        .chain((trailer_len > 0).then_some(trailer_len));
+    for len in all {
+        if len > capacity {
+            return Err(CryptError::Volume(format!(
+                "volume size {volume_size} cannot hold a {len}-byte \
+                 piece of the file and the {VOLUME_PREFIX_LEN}-byte \
+                 volume prefix"
+            )));
+        }
+        if used + len > capacity {
+            starts.push(offset);
+            used = 0;
+        }
+        used += len;
+        offset += len;
+    }
+    Ok(starts)
+}
+
+/// Writer spreading a container over the volumes planned by
+/// [`plan`].
+///
+/// Volumes are written to temp files next to `base` and only take
+/// their final names in [`VolumeWriter::finish`].
+pub(crate) struct VolumeWriter {
+    base: PathBuf,
+    set: VolumeSet,
+    starts: Vec<u64>,
+    volumes: Vec<NamedTempFile>,
+    /// Container bytes written so far.
+    pos: u64,
+}
+
+impl VolumeWriter {
+    pub(crate) fn new(
+        base: &Path,
+        set: VolumeSet,
+        starts: Vec<u64>,
+    ) -> Self {
+        Self {
+            base: base.to_path_buf(),
+            set,
+            starts,
+            volumes: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    fn open_next(&mut self) -> io::Result<()> {
+        let dir = self
+            .base
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let mut volume = NamedTempFile::new_in(dir)?;
+        let prefix = VolumePrefix {
+            set: self.set,
+            index: self.volumes.len() as u32 + 1,
+        };
+        volume.write_all(&prefix.to_bytes())?;
+        self.volumes.push(volume);
+        Ok(())
+    }
+
+    /// Move every volume to its final name and return the paths.
+    pub(crate) fn finish(mut self) -> Result<Vec<PathBuf>> {
+        if self.volumes.len() != self.starts.len() {
+            return Err(CryptError::Volume(format!(
+                "wrote {} of {} planned volumes",
+                self.volumes.len(),
+                self.starts.len()
+            )));
+        }
+        self.flush()?;
+        let mut paths = Vec::with_capacity(self.volumes.len());
+        for (i, volume) in self.volumes.into_iter().enumerate() {
+            let path = volume_path(&self.base, i as u32 + 1);
+            volume.persist(&path).map_err(|e| {
+                CryptError::Io(io::Error::other(format!("Persist: {e}")))
+            })?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
+
+impl Write for VolumeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        while self
+            .starts
+            .get(self.volumes.len())
+            .is_some_and(|&start| start <= self.pos)
+        {
+            self.open_next()?;
+        }
+        let room = self
+            .starts
+            .get(self.volumes.len())
+            .map_or(u64::MAX, |&next| next - self.pos);
+        let len = (buf.len() as u64).min(room) as usize;
+        let volume = self.volumes.last_mut().expect("first volume opened");
+        let n = volume.write(&buf[..len])?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.volumes.last_mut() {
+            Some(volume) => volume.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Body of one volume (or of a whole unsplit file).
+struct Part {
+    file: File,
+    /// Container offset of the first body byte.
+    start: u64,
+    /// Body length.
+    len: u64,
+    /// File offset of the first body byte.
+    body_offset: u64,
+    /// Current file offset.
+    cursor: u64,
+}
+
+/// Seekable reader over an encrypted container, stored either as one
+/// file or as a set of volumes.
+pub(crate) struct VolumeReader {
+    parts: Vec<Part>,
+    set: Option<VolumeSet>,
+    /// Container length.
+    len: u64,
+    /// Current container position.
+    pos: u64,
+}
+
+impl VolumeReader {
+    /// Open `path`: an encrypted file, or the first volume of a split
+    /// one, whose other volumes are looked up next to it. A file with
+    /// a metadata update cut short by a crash is refused as
+    /// [`CryptError::InvalidHeader`]; write paths finish it first.
+    ///
+    /// Missing volumes, volumes of another file and volumes in the
+    /// wrong place are reported as [`CryptError::Volume`].
+    pub(crate) fn open(path: &Path) -> Result<Self> {
+        journal::check(path)?;
+        let mut file = File::open(path)?;
+        let file_len = file.metadata()?.len();
+        let Some(first) = VolumePrefix::read_from(&mut file)? else {
+            return Ok(Self {
+                parts: vec![Part {
+                    file,
+                    start: 0,
+                    len: file_len,
+                    body_offset: 0,
+                    cursor: 0,
+                }],
+                set: None,
+                len: file_len,
+                pos: 0,
+            });
+        };
+
+        let set = first.set;
+        if first.index != 1 {
+            return Err(CryptError::Volume(format!(
+                "{} is volume {} of {}; open the first volume instead",
+                path.display(),
+                first.index,
+                set.count
+            )));
+        }
+        if path.extension() != Some(OsStr::new("001")) {
+            return Err(CryptError::Volume(format!(
+                "the first volume {} must be named *.001",
+                path.display()
+            )));
+        }
+        let base = path.with_extension("");
+
+        let mut reader = Self {
+            parts: Vec::new(),
+            set: Some(set),
+            len: 0,
+            pos: 0,
+        };
+        reader.push(file, file_len)?;
+        for index in 2..=set.count {
+            let volume = volume_path(&base, index);
+            let mut file = match File::open(&volume) {
+                Ok(file) => file,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    return Err(CryptError::Volume(format!(
+                        "volume {index} of {} is missing: {}",
+                        set.count,
+                        volume.display()
+                    )));
+                }
+                Err(e) => return Err(e.into()),
+            };
+            let file_len = file.metadata()?.len();
+            let prefix = match VolumePrefix::read_from(&mut file)? {
+                Some(prefix) if prefix.set.set_id == set.set_id => prefix,
+                _ => {
+                    return Err(CryptError::Volume(format!(
+                        "{} is not a volume of {}",
+                        volume.display(),
+                        path.display()
+                    )));
+                }
+            };
+            if prefix.index != index || prefix.set.count != set.count {
+                return Err(CryptError::Volume(format!(
+                    "{} holds volume {} of {}, expected volume {index} \
+                     of {}",
+                    volume.display(),
+                    prefix.index,
+                    prefix.set.count,
+                    set.count
+                )));
+            }
+            reader.push(file, file_len)?;
+        }
+        Ok(reader)
+    }
+
+    /// Append the body of a volume whose prefix has just been read.
+    fn push(&mut self, file: File, file_len: u64) -> Result<()> {
+        let prefix_len = VOLUME_PREFIX_LEN as u64;
+        let len = file_len.checked_sub(prefix_len).ok_or_else(|| {
+            CryptError::Volume("truncated volume prefix".to_string())
+        })?;
+        self.parts.push(Part {
+            file,
+            start: self.len,
+            len,
+            body_offset: prefix_len,
+            cursor: prefix_len,
+        });
+        self.len += len;
+        Ok(())
+    }
+
+    /// Container length in bytes.
+    pub(crate) fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Check the volume prefixes against the set recorded in the
+    /// header; the header itself is authenticated with the metadata.
+    pub(crate) fn check_header(&self, header: &PublicHeader) -> Result<()> {
+        match (self.set, header.volumes) {
+            (None, None) => Ok(()),
+            (Some(found), Some(recorded)) if found == recorded => Ok(()),
+            (None, Some(recorded)) => Err(CryptError::Volume(format!(
+                "the file is split into {} volumes; open the first \
+                 volume (*.001)",
+                recorded.count
+            ))),
+            _ => Err(CryptError::Volume(
+                "the volumes do not match the volume set in the header"
+                    .to_string(),
+            )),
+        }
+    }
+}
+
+impl Read for VolumeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.pos >= self.len {
+            return Ok(0);
+        }
+        let pos = self.pos;
+        let i = self.parts.partition_point(|p| p.start + p.len <= pos);
+        let part = &mut self.parts[i];
+        let within = pos - part.start;
+        let offset = part.body_offset + within;
+        if part.cursor != offset {
+            part.file.seek(SeekFrom::Start(offset))?;
+        }
+        let len = (buf.len() as u64).min(part.len - within) as usize;
+        let n = part.file.read(&mut buf[..len])?;
+        part.cursor = offset + n as u64;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for VolumeReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(n) => Some(n),
+            SeekFrom::End(delta) => self.len.checked_add_signed(delta),
+            SeekFrom::Current(delta) => self.pos.checked_add_signed(delta),
+        };
+        self.pos = target.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "seek before the start of the file",
+            )
+        })?;
+        Ok(self.pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TAG_LEN;
+    use tempfile::TempDir;
+
+    const CHUNK: u32 = 64;
+    const ENC: u64 = CHUNK as u64 + TAG_LEN as u64;
+    const PREFIX: u64 = VOLUME_PREFIX_LEN as u64;
+
+    #[test]
+    fn test_plan_cuts_at_chunk_boundaries() {
+        // 5 chunks: 4 full and one of 10 bytes.
+        let layout = PayloadLayout::for_plaintext_len(
+            4 * CHUNK as u64 + 10,
+            CHUNK,
+            None,
+        );
+        // Room for the 100-byte prefix, or for two chunks.
+        let starts = plan(PREFIX + 2 * ENC, 100, &layout, 64).unwrap();
+        assert_eq!(starts, [0, 100, 100 + 2 * ENC, 100 + 4 * ENC]);
+
+        let too_small = plan(PREFIX + ENC - 1, 10, &layout, 0);
+        assert!(matches!(too_small, Err(CryptError::Volume(_))));
+    }
+
+    #[test]
+    fn test_writer_and_reader_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let base = dir.path().join("file.enc");
+        let data: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+        let mut set = VolumeSet::generate();
+        set.count = 3;
+
+        let mut writer =
+            VolumeWriter::new(&base, set, vec![0, 300, 700]);
+        for piece in data.chunks(128) {
+            writer.write_all(piece).unwrap();
+        }
+        let paths = writer.finish().unwrap();
+        let expected: Vec<_> =
+            (1..=3).map(|i| volume_path(&base, i)).collect();
+        assert_eq!(paths, expected);
+        assert_eq!(
+            std::fs::metadata(&paths[1]).unwrap().len(),
+            PREFIX + 400
+        );
+
+        let mut reader = VolumeReader::open(&paths[0]).unwrap();
+        assert_eq!(reader.len(), 1000);
+        assert_eq!(reader.set, Some(set));
+        let mut all = Vec::new();
+        reader.read_to_end(&mut all).unwrap();
+        assert_eq!(all, data);
+
+        reader.seek(SeekFrom::Start(650)).unwrap();
+        let mut across = [0u8; 100];
+        reader.read_exact(&mut across).unwrap();
+        assert_eq!(across, data[650..750]);
+
+        let second = VolumeReader::open(&paths[1]);
+        assert!(matches!(second, Err(CryptError::Volume(_))));
+
+        std::fs::remove_file(&paths[2]).unwrap();
+        let missing = VolumeReader::open(&paths[0]);
+        assert!(matches!(missing, Err(CryptError::Volume(_))));
+    }
+}
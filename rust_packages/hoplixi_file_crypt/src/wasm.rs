@@ -0,0 +1,245 @@
+//! JavaScript bindings for the web target.
+//!
+//! Enabled by the `wasm` feature and built with `wasm-pack` for
+//! `wasm32-unknown-unknown`. A thin layer over [`crate::memory`]:
+//! byte slices in and out as `Uint8Array`s, errors thrown as
+//! `Error`s with the [`CryptError`](crate::CryptError) message.
+//! Files are sealed with the default engine settings and open with
+//! every other front end of the crate.
+
+use wasm_bindgen::prelude::*;
+
+use crate::engine::FileCrypt;
+use crate::header::encrypted::EncryptedMetadata;
+use crate::memory::{self, ChunkDecryptor, ChunkEncryptor};
+use crate::types::{StreamDecryptOptions, StreamEncryptOptions};
+
+fn encrypt_opts(
+    password: String,
+    file_name: String,
+    extension: String,
+    gzip: bool,
+) -> StreamEncryptOptions {
+    StreamEncryptOptions {
+        original_filename: file_name,
+        original_extension: extension,
+        uuid: None,
+        password,
+        progress: None,
+        gzip_compressed: gzip,
+        metadata: None,
+        fec: None,
+        signer: None,
+        recipient: None,
+    }
+}
+
+fn decrypt_opts(password: String) -> StreamDecryptOptions {
+    StreamDecryptOptions {
+        password,
+        progress: None,
+        identity: None,
+    }
+}
+
+/// Encrypt `data` into a complete `.enc` file.
+#[wasm_bindgen(js_name = encryptBytes)]
+pub fn encrypt_bytes(
+    data: &[u8],
+    password: String,
+    file_name: String,
+    extension: String,
+    gzip: bool,
+) -> Result<Vec<u8>, JsError> {
+    let opts = encrypt_opts(password, file_name, extension, gzip);
+    Ok(memory::seal(&FileCrypt::default(), data, opts)?)
+}
+
+/// Decrypt a complete `.enc` file.
+#[wasm_bindgen(js_name = decryptBytes)]
+pub fn decrypt_bytes(
+    data: &[u8],
+    password: String,
+) -> Result<DecryptedFile, JsError> {
+    let result = memory::unseal(data, decrypt_opts(password))?;
+    Ok(DecryptedFile {
+        data: result.data,
+        metadata: result.metadata,
+    })
+}
+
+/// Length of the header and the sealed metadata at the start of
+/// `data`, or `undefined` while `data` is too short to tell.
+#[wasm_bindgen(js_name = prefixLength)]
+pub fn prefix_length(data: &[u8]) -> Result<Option<usize>, JsError> {
+    Ok(memory::prefix_len(data)?)
+}
+
+/// Plaintext and metadata of a decrypted file.
+#[wasm_bindgen]
+pub struct DecryptedFile {
+    data: Vec<u8>,
+    metadata: EncryptedMetadata,
+}
+
+#[wasm_bindgen]
+impl DecryptedFile {
+    #[wasm_bindgen(getter)]
+    pub fn data(&self) -> Vec<u8> {
+        self.data.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = fileName)]
+    pub fn file_name(&self) -> String {
+        self.metadata.original_filename.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn extension(&self) -> String {
+        self.metadata.original_extension.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn uuid(&self) -> String {
+        self.metadata.uuid.clone()
+    }
+
+    /// Value of a user-defined metadata entry.
+    #[wasm_bindgen(js_name = metadataValue)]
+    pub fn metadata_value(&self, key: &str) -> Option<String> {
+        self.metadata.metadata.get(key).cloned()
+    }
+}
+
+/// Encrypts a file handed over in pieces; see
+/// [`ChunkEncryptor`]. The output is `prefix`, then what every
+/// `push` and the final `finish` return, in order.
+#[wasm_bindgen]
+pub struct Encryptor {
+    inner: ChunkEncryptor,
+}
+
+#[wasm_bindgen]
+impl Encryptor {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        password: String,
+        file_name: String,
+        extension: String,
+        gzip: bool,
+    ) -> Result<Encryptor, JsError> {
+        let opts = encrypt_opts(password, file_name, extension, gzip);
+        let inner = ChunkEncryptor::new(&FileCrypt::default(), opts)?;
+        Ok(Self { inner })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn prefix(&self) -> Vec<u8> {
+        self.inner.prefix().to_vec()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn uuid(&self) -> String {
+        self.inner.uuid().to_string()
+    }
+
+    pub fn push(&mut self, data: &[u8]) -> Result<Vec<u8>, JsError> {
+        Ok(self.inner.push(data)?)
+    }
+
+    pub fn finish(&mut self) -> Result<Vec<u8>, JsError> {
+        Ok(self.inner.finish()?)
+    }
+}
+
+/// Decrypts a file handed over in pieces; see [`ChunkDecryptor`].
+/// Created from the first `prefixLength` bytes; the output is only
+/// authenticated once `finish` returned.
+#[wasm_bindgen]
+pub struct Decryptor {
+    inner: ChunkDecryptor,
+}
+
+#[wasm_bindgen]
+impl Decryptor {
+    #[wasm_bindgen(constructor)]
+    pub fn new(prefix: &[u8], password: String) -> Result<Decryptor, JsError> {
+        let inner = ChunkDecryptor::new(prefix, decrypt_opts(password))?;
+        Ok(Self { inner })
+    }
+
+    #[wasm_bindgen(getter, js_name = fileName)]
+    pub fn file_name(&self) -> String {
+        self.inner.metadata().original_filename.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn extension(&self) -> String {
+        self.inner.metadata().original_extension.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn uuid(&self) -> String {
+        self.inner.metadata().uuid.clone()
+    }
+
+    pub fn push(&mut self, data: &[u8]) -> Result<Vec<u8>, JsError> {
+        Ok(self.inner.push(data)?)
+    }
+
+    pub fn finish(&mut self) -> Result<Vec<u8>, JsError> {
+        Ok(self.inner.finish()?.0)
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    fn test_bytes_round_trip() {
+        let data = b"sealed in the browser".repeat(100);
+        let sealed = encrypt_bytes(
+            &data,
+            "pw".to_string(),
+            "note".to_string(),
+            "txt".to_string(),
+            false,
+        )
+        .unwrap();
+        let opened = decrypt_bytes(&sealed, "pw".to_string()).unwrap();
+        assert_eq!(opened.data(), data);
+        assert_eq!(opened.file_name(), "note");
+        assert!(decrypt_bytes(&sealed, "nope".to_string()).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_pieces_round_trip() {
+        let data: Vec<u8> = (0..5000).map(|i| (i % 241) as u8).collect();
+        let mut encryptor = Encryptor::new(
+            "pw".to_string(),
+            "clip".to_string(),
+            "bin".to_string(),
+            cfg!(feature = "gzip"),
+        )
+        .unwrap();
+        let mut sealed = encryptor.prefix();
+        for piece in data.chunks(999) {
+            sealed.extend(encryptor.push(piece).unwrap());
+        }
+        sealed.extend(encryptor.finish().unwrap());
+
+        let len = prefix_length(&sealed).unwrap().unwrap();
+        let mut decryptor =
+            Decryptor::new(&sealed[..len], "pw".to_string()).unwrap();
+        assert_eq!(decryptor.uuid(), encryptor.uuid());
+        let mut plain = Vec::new();
+        for piece in sealed[len..].chunks(1234) {
+            plain.extend(decryptor.push(piece).unwrap());
+        }
+        plain.extend(decryptor.finish().unwrap());
+        assert_eq!(plain, data);
+    }
+}